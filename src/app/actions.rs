@@ -3,10 +3,13 @@
 //!
 //! `actions_for` is the single source of truth for "what can I do to the
 //! selected item" so the menu, hotkey hints, and handlers can never drift.
+use chrono::{DateTime, Local, Utc};
+
 use crate::app::key_binding::DEFAULT_KEYBINDING;
+use crate::app::logs::{self, LogSince, LogTailQuery};
 use crate::app::ActiveBlock;
 use crate::event::Key;
-use crate::network::{IoEvent, ResourcePatch};
+use crate::network::{BulkOp, BulkTarget, IoEvent, ResourcePatch};
 
 /// An action that can be performed on the selected resource. Surfaced both as a
 /// hotkey (with a hint in the hint area) and as an entry in the `m` action menu.
@@ -142,6 +145,8 @@ pub fn actions_for(block: ActiveBlock) -> Vec<ResourceAction> {
 pub struct Modal {
   pub title: String,
   pub prompt: String,
+  /// Every object a bulk action will touch, listed under the prompt.
+  pub targets: Vec<String>,
   /// Event dispatched when the modal is confirmed.
   pub on_confirm: IoEvent,
 }
@@ -152,9 +157,31 @@ impl Modal {
     Modal {
       title: title.into(),
       prompt: prompt.into(),
+      targets: vec![],
       on_confirm,
     }
   }
+
+  /// List the objects a bulk action will touch under the prompt.
+  pub fn with_targets(mut self, targets: Vec<String>) -> Self {
+    self.targets = targets;
+    self
+  }
+}
+
+/// Outcome of a bulk action, one line per target, shown as an overlay until
+/// dismissed with `Esc` or `Enter`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BulkSummary {
+  pub title: String,
+  /// `(target label, Ok(status) | Err(error))` in the order they ran.
+  pub results: Vec<(String, Result<String, String>)>,
+}
+
+impl BulkSummary {
+  pub fn failed(&self) -> usize {
+    self.results.iter().filter(|(_, r)| r.is_err()).count()
+  }
 }
 
 /// Transient single-line text-input overlay for actions that need a value
@@ -184,6 +211,12 @@ pub enum InputAction {
     /// Human-readable kind label for the confirmation prompt.
     kind: String,
   },
+  /// Scale every marked workload to the same replica count.
+  BulkScale {
+    block: ActiveBlock,
+    targets: Vec<BulkTarget>,
+    kind: String,
+  },
   /// Port-forward a pod/service; the buffer is `local:remote` (or a single port).
   PortForward {
     /// kubectl resource type (`pods` / `services`).
@@ -191,6 +224,15 @@ pub enum InputAction {
     namespace: String,
     name: String,
   },
+  /// Set the log view's include (or exclude) regex; a blank buffer clears it.
+  LogFilter { exclude: bool },
+  /// Set how far back the log request starts; a blank buffer goes back to the
+  /// tail line count.
+  LogSince,
+  /// Jump the log view to the first line at or after a time.
+  LogJumpToTime,
+  /// Start a multi-pod tail; a query without `-n`/`-A` covers `namespace`.
+  TailLogs { namespace: Option<String> },
 }
 
 /// What a validated [`InputModal`] feeds into. Impactful actions chain into a
@@ -205,6 +247,15 @@ pub enum InputSubmit {
     local_port: u16,
     remote_port: u16,
   },
+  /// A pattern already checked to compile; applied to the log view directly.
+  SetLogFilter {
+    exclude: bool,
+    pattern: String,
+  },
+  /// Re-stream the logs from this window.
+  SetLogSince(Option<LogSince>),
+  JumpToLogTime(DateTime<Utc>),
+  StartTailLogs(LogTailQuery),
 }
 
 impl InputModal {
@@ -219,11 +270,7 @@ impl InputModal {
         namespace,
         kind,
       } => {
-        let replicas: u32 = self
-          .buffer
-          .trim()
-          .parse()
-          .map_err(|_| "Enter a non-negative whole number".to_owned())?;
+        let replicas = parse_replicas(&self.buffer)?;
         let prompt = match namespace {
           Some(ns) => format!(
             "Scale {} '{}' in namespace '{}' to {} replica(s)?",
@@ -242,6 +289,28 @@ impl InputModal {
           },
         )))
       }
+      InputAction::BulkScale {
+        block,
+        targets,
+        kind,
+      } => {
+        let replicas = parse_replicas(&self.buffer)?;
+        let prompt = format!(
+          "Scale {} {}(s) to {} replica(s)?",
+          targets.len(),
+          kind,
+          replicas
+        );
+        let on_confirm = IoEvent::BulkAction {
+          block: *block,
+          op: BulkOp::Patch(ResourcePatch::SetReplicas(replicas)),
+          targets: targets.clone(),
+        };
+        Ok(InputSubmit::Confirm(
+          Modal::confirm("Confirm scale", prompt, on_confirm)
+            .with_targets(targets.iter().map(BulkTarget::label).collect()),
+        ))
+      }
       InputAction::PortForward {
         kind,
         namespace,
@@ -256,10 +325,33 @@ impl InputModal {
           remote_port,
         })
       }
+      InputAction::LogFilter { exclude } => {
+        logs::parse_filter(&self.buffer)?;
+        Ok(InputSubmit::SetLogFilter {
+          exclude: *exclude,
+          pattern: self.buffer.trim().to_owned(),
+        })
+      }
+      InputAction::LogSince => {
+        logs::parse_since(&self.buffer, Local::now()).map(InputSubmit::SetLogSince)
+      }
+      InputAction::LogJumpToTime => {
+        logs::parse_jump_time(&self.buffer, Local::now()).map(InputSubmit::JumpToLogTime)
+      }
+      InputAction::TailLogs { namespace } => {
+        LogTailQuery::parse(&self.buffer, namespace.as_deref()).map(InputSubmit::StartTailLogs)
+      }
     }
   }
 }
 
+fn parse_replicas(buffer: &str) -> Result<u32, String> {
+  buffer
+    .trim()
+    .parse()
+    .map_err(|_| "Enter a non-negative whole number".to_owned())
+}
+
 /// Parse a `local:remote` port mapping, or a single `port` (local == remote).
 /// Ports must be non-zero `u16`s.
 fn parse_port_mapping(buffer: &str) -> Result<(u16, u16), String> {
@@ -283,6 +375,87 @@ fn parse_port_mapping(buffer: &str) -> Result<(u16, u16), String> {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_bulk_scale_input_confirms_every_target() {
+    let targets = vec![
+      BulkTarget {
+        name: "web".into(),
+        namespace: Some("default".into()),
+      },
+      BulkTarget {
+        name: "api".into(),
+        namespace: Some("default".into()),
+      },
+    ];
+    let input = InputModal {
+      title: "Scale".into(),
+      prompt: String::new(),
+      buffer: " 0 ".into(),
+      error: None,
+      action: InputAction::BulkScale {
+        block: ActiveBlock::Deployments,
+        targets: targets.clone(),
+        kind: "deployment".into(),
+      },
+    };
+
+    let Ok(InputSubmit::Confirm(modal)) = input.validate() else {
+      panic!("a valid count should chain into a confirm");
+    };
+    assert_eq!(modal.prompt, "Scale 2 deployment(s) to 0 replica(s)?");
+    assert_eq!(modal.targets, vec!["default/web", "default/api"]);
+    assert_eq!(
+      modal.on_confirm,
+      IoEvent::BulkAction {
+        block: ActiveBlock::Deployments,
+        op: BulkOp::Patch(ResourcePatch::SetReplicas(0)),
+        targets,
+      }
+    );
+  }
+
+  #[test]
+  fn test_log_filter_input_rejects_invalid_regex() {
+    let mut input = InputModal {
+      title: "Include".into(),
+      prompt: String::new(),
+      buffer: "error(".into(),
+      error: None,
+      action: InputAction::LogFilter { exclude: false },
+    };
+    assert!(input.validate().unwrap_err().starts_with("Invalid regex"));
+
+    input.buffer = " error|warn ".into();
+    assert_eq!(
+      input.validate(),
+      Ok(InputSubmit::SetLogFilter {
+        exclude: false,
+        pattern: "error|warn".into(),
+      })
+    );
+  }
+
+  #[test]
+  fn test_log_since_input_accepts_durations_and_blank() {
+    let mut input = InputModal {
+      title: "Since".into(),
+      prompt: String::new(),
+      buffer: "1h30m".into(),
+      error: None,
+      action: InputAction::LogSince,
+    };
+    assert_eq!(
+      input.validate(),
+      Ok(InputSubmit::SetLogSince(Some(LogSince::Seconds(5400))))
+    );
+
+    input.buffer = "  ".into();
+    assert_eq!(input.validate(), Ok(InputSubmit::SetLogSince(None)));
+
+    input.buffer = "yesterday".into();
+    assert!(input.validate().is_err());
+  }
+
   #[test]
   fn test_actions_for_containers_offers_logs_and_shell() {
     assert_eq!(
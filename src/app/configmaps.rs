@@ -10,7 +10,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils, ActiveBlock, App,
 };
 use crate::{
@@ -50,6 +50,18 @@ impl Named for KubeConfigMap {
   }
 }
 
+impl Sortable for KubeConfigMap {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Data" => SortValue::number(self.data.len() as f64),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<ConfigMap> for KubeConfigMap {
   fn get_k8s_obj(&self) -> &ConfigMap {
     &self.k8s_obj
@@ -76,10 +88,8 @@ impl AppResource for ConfigMapResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeConfigMap> = nw.get_namespaced_resources(ConfigMap::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.config_maps.set_items(items);
+    nw.load_namespaced_resources(ConfigMap::into, |data| &mut data.config_maps)
+      .await;
   }
 }
 
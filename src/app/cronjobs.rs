@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{self, AppResource, KubeResource, Named},
+  models::{self, AppResource, KubeResource, Named, SortValue, Sortable},
   utils, ActiveBlock, App,
 };
 use crate::{
@@ -77,6 +77,22 @@ impl Named for KubeCronJob {
   }
 }
 
+impl Sortable for KubeCronJob {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Schedule" => SortValue::text(&self.schedule),
+      "Last Scheduled" => SortValue::age(&self.last_schedule),
+      "Suspend" => SortValue::text(&self.suspend.to_string()),
+      "Active" => SortValue::number(self.active as f64),
+      "Concurrency" => SortValue::text(&self.concurrency_policy),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<CronJob> for KubeCronJob {
   fn get_k8s_obj(&self) -> &CronJob {
     &self.k8s_obj
@@ -112,10 +128,8 @@ impl AppResource for CronJobResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeCronJob> = nw.get_namespaced_resources(CronJob::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.cronjobs.set_items(items);
+    nw.load_namespaced_resources(CronJob::into, |data| &mut data.cronjobs)
+      .await;
   }
 }
 
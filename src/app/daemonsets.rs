@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{self, AppResource, KubeResource, Named},
+  models::{self, AppResource, KubeResource, Named, SortValue, Sortable},
   utils, ActiveBlock, App,
 };
 use crate::{
@@ -94,6 +94,24 @@ impl Named for KubeDaemonSet {
   }
 }
 
+impl Sortable for KubeDaemonSet {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Desired" => SortValue::number(self.desired),
+      "Current" => SortValue::number(self.current),
+      "Ready" => SortValue::number(self.ready),
+      "Up-to-date" => SortValue::number(self.up_to_date),
+      "Available" => SortValue::number(self.available),
+      "Node Selector" => SortValue::text(&self.node_selector),
+      "Containers" => SortValue::text(&self.containers),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<DaemonSet> for KubeDaemonSet {
   fn get_k8s_obj(&self) -> &DaemonSet {
     &self.k8s_obj
@@ -132,10 +150,8 @@ impl AppResource for DaemonSetResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeDaemonSet> = nw.get_namespaced_resources(DaemonSet::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.daemon_sets.set_items(items);
+    nw.load_namespaced_resources(DaemonSet::into, |data| &mut data.daemon_sets)
+      .await;
   }
 }
 
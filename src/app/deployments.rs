@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{self, AppResource, KubeResource, Named},
+  models::{self, AppResource, KubeResource, Named, SortValue, Sortable},
   utils, ActiveBlock, App,
 };
 use crate::{
@@ -102,6 +102,23 @@ impl Named for KubeDeployment {
   }
 }
 
+impl Sortable for KubeDeployment {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Ready" => SortValue::text(&self.ready),
+      "Up-to-date" => SortValue::number(self.updated),
+      "Available" => SortValue::number(self.available),
+      "Strategy" => SortValue::text(&self.strategy),
+      "Max Surge" => SortValue::text(&self.max_surge),
+      "Max Unavail" => SortValue::text(&self.max_unavailable),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Deployment> for KubeDeployment {
   fn get_k8s_obj(&self) -> &Deployment {
     &self.k8s_obj
@@ -140,10 +157,8 @@ impl AppResource for DeploymentResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeDeployment> = nw.get_namespaced_resources(Deployment::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.deployments.set_items(items);
+    nw.load_namespaced_resources(Deployment::into, |data| &mut data.deployments)
+      .await;
   }
 }
 
@@ -0,0 +1,1750 @@
+//! Native `describe` output built from the cached `k8s_obj` of a resource plus
+//! its related `Event`s, so describe works without `kubectl`. The layout follows
+//! `kubectl describe`: aligned `Key: value` fields, nested sections and an
+//! events table. Kinds without a renderer here still go through kubectl.
+
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use k8s_openapi::{
+  api::{
+    apps::v1::{DaemonSet, Deployment, StatefulSet},
+    batch::v1::{CronJob, Job, JobSpec},
+    core::v1::{
+      Container, ContainerState, ContainerStatus, EnvVar, Event, Node, PersistentVolumeClaim, Pod,
+      PodTemplateSpec, Probe, Service, Volume,
+    },
+  },
+  apimachinery::pkg::{
+    api::resource::Quantity,
+    apis::meta::v1::{LabelSelector, ObjectMeta, OwnerReference, Time},
+    util::intstr::IntOrString,
+  },
+};
+
+use super::{events::event_timestamp, utils};
+
+const NONE: &str = "<none>";
+
+/// Resource kinds with a native describe renderer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DescribeKind {
+  Pod,
+  Deployment,
+  StatefulSet,
+  DaemonSet,
+  Service,
+  Node,
+  PersistentVolumeClaim,
+  Job,
+  CronJob,
+}
+
+impl DescribeKind {
+  /// Parse the kind string used for describe requests (`pod`, `deployment`,
+  /// `persistentvolumeclaims`, `pvc`, ...). Accepts singular, plural and the
+  /// usual kubectl short names.
+  pub fn parse(kind: &str) -> Option<Self> {
+    let kind = kind.to_ascii_lowercase();
+    let kind = match kind.as_str() {
+      "po" | "pod" | "pods" => Self::Pod,
+      "deploy" | "deployment" | "deployments" => Self::Deployment,
+      "sts" | "statefulset" | "statefulsets" => Self::StatefulSet,
+      "ds" | "daemonset" | "daemonsets" => Self::DaemonSet,
+      "svc" | "service" | "services" => Self::Service,
+      "no" | "node" | "nodes" => Self::Node,
+      "pvc" | "persistentvolumeclaim" | "persistentvolumeclaims" => Self::PersistentVolumeClaim,
+      "job" | "jobs" => Self::Job,
+      "cj" | "cronjob" | "cronjobs" => Self::CronJob,
+      _ => return None,
+    };
+    Some(kind)
+  }
+
+  /// The `involvedObject.kind` of events about this resource.
+  pub fn event_kind(self) -> &'static str {
+    match self {
+      Self::Pod => "Pod",
+      Self::Deployment => "Deployment",
+      Self::StatefulSet => "StatefulSet",
+      Self::DaemonSet => "DaemonSet",
+      Self::Service => "Service",
+      Self::Node => "Node",
+      Self::PersistentVolumeClaim => "PersistentVolumeClaim",
+      Self::Job => "Job",
+      Self::CronJob => "CronJob",
+    }
+  }
+
+  pub fn is_namespaced(self) -> bool {
+    self != Self::Node
+  }
+}
+
+/// Whether `kind` can be described without kubectl.
+pub fn is_supported(kind: &str) -> bool {
+  DescribeKind::parse(kind).is_some()
+}
+
+/// Field selector matching the events about one object.
+pub fn events_field_selector(kind: DescribeKind, name: &str, namespace: Option<&str>) -> String {
+  let mut selector = format!(
+    "involvedObject.kind={},involvedObject.name={}",
+    kind.event_kind(),
+    name
+  );
+  if let Some(namespace) = namespace.filter(|_| kind.is_namespaced()) {
+    selector.push_str(&format!(",involvedObject.namespace={namespace}"));
+  }
+  selector
+}
+
+pub fn describe_pod(pod: &Pod, events: &[Event]) -> String {
+  let mut w = DescribeWriter::default();
+  let spec = pod.spec.as_ref();
+  let status = pod.status.as_ref();
+
+  w.field(0, "Name", name_of(&pod.metadata));
+  w.field(0, "Namespace", namespace_of(&pod.metadata));
+  if let Some(priority) = spec.and_then(|s| s.priority) {
+    w.field(0, "Priority", priority.to_string());
+  }
+  if let Some(class) = spec.and_then(|s| s.priority_class_name.as_deref()) {
+    w.field(0, "Priority Class Name", class);
+  }
+  w.field(
+    0,
+    "Service Account",
+    or_none(spec.and_then(|s| s.service_account_name.as_deref())),
+  );
+  let node = match (
+    spec.and_then(|s| s.node_name.as_deref()),
+    status.and_then(|s| s.host_ip.as_deref()),
+  ) {
+    (Some(node), Some(ip)) => format!("{node}/{ip}"),
+    (Some(node), None) => node.to_owned(),
+    _ => NONE.to_owned(),
+  };
+  w.field(0, "Node", node);
+  if let Some(start) = status.and_then(|s| s.start_time.as_ref()) {
+    w.field(0, "Start Time", format_time(start));
+  }
+  w.string_map(0, "Labels", pod.metadata.labels.as_ref(), "=");
+  w.string_map(0, "Annotations", pod.metadata.annotations.as_ref(), ": ");
+  let phase = if pod.metadata.deletion_timestamp.is_some() {
+    "Terminating".to_owned()
+  } else {
+    or_none(status.and_then(|s| s.phase.as_deref()))
+  };
+  w.field(0, "Status", phase);
+  if let Some(reason) = status.and_then(|s| s.reason.as_deref()) {
+    w.field(0, "Reason", reason);
+  }
+  if let Some(message) = status.and_then(|s| s.message.as_deref()) {
+    w.field(0, "Message", message);
+  }
+  w.field(0, "IP", or_none(status.and_then(|s| s.pod_ip.as_deref())));
+  let ips: Vec<String> = status
+    .and_then(|s| s.pod_ips.as_ref())
+    .map(|ips| ips.iter().map(|ip| ip.ip.clone()).collect())
+    .unwrap_or_default();
+  w.list(0, "IPs", &ips);
+  owner(&mut w, &pod.metadata);
+
+  if let Some(spec) = spec {
+    let init_statuses = status.and_then(|s| s.init_container_statuses.as_deref());
+    if let Some(init) = spec.init_containers.as_ref().filter(|c| !c.is_empty()) {
+      w.text(0, "Init Containers:");
+      for container in init {
+        describe_container(&mut w, 1, container, find_status(init_statuses, container));
+      }
+    }
+    let statuses = status.and_then(|s| s.container_statuses.as_deref());
+    w.text(0, "Containers:");
+    for container in &spec.containers {
+      describe_container(&mut w, 1, container, find_status(statuses, container));
+    }
+  }
+
+  let conditions: Vec<Vec<String>> = status
+    .and_then(|s| s.conditions.as_ref())
+    .map(|conditions| {
+      conditions
+        .iter()
+        .map(|c| vec![c.type_.clone(), c.status.clone()])
+        .collect()
+    })
+    .unwrap_or_default();
+  w.table(0, "Conditions", &["Type", "Status"], &conditions);
+
+  if let Some(spec) = spec {
+    volumes(&mut w, 0, spec.volumes.as_deref());
+  }
+  w.field(
+    0,
+    "QoS Class",
+    or_none(status.and_then(|s| s.qos_class.as_deref())),
+  );
+  w.string_map(
+    0,
+    "Node-Selectors",
+    spec.and_then(|s| s.node_selector.as_ref()),
+    "=",
+  );
+  let tolerations: Vec<String> = spec
+    .and_then(|s| s.tolerations.as_ref())
+    .map(|tolerations| {
+      tolerations
+        .iter()
+        .map(|t| {
+          let mut out = t.key.clone().unwrap_or_default();
+          if let Some(value) = t.value.as_deref().filter(|v| !v.is_empty()) {
+            out.push_str(&format!("={value}"));
+          }
+          if let Some(effect) = t.effect.as_deref().filter(|e| !e.is_empty()) {
+            out.push_str(&format!(":{effect}"));
+          }
+          if t.operator.as_deref() == Some("Exists") && t.key.is_none() {
+            out = "op=Exists".to_owned();
+          } else if t.operator.as_deref() == Some("Exists") {
+            out.push_str(" op=Exists");
+          }
+          if let Some(seconds) = t.toleration_seconds {
+            out.push_str(&format!(" for {seconds}s"));
+          }
+          out
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  w.list(0, "Tolerations", &tolerations);
+  describe_events(&mut w, events);
+  w.finish()
+}
+
+pub fn describe_deployment(deployment: &Deployment, events: &[Event]) -> String {
+  let mut w = DescribeWriter::default();
+  let spec = deployment.spec.as_ref();
+  let status = deployment.status.as_ref();
+
+  header(&mut w, &deployment.metadata);
+  w.field(0, "Selector", format_selector(spec.map(|s| &s.selector)));
+  let replicas = |value: Option<i32>| value.unwrap_or_default();
+  w.field(
+    0,
+    "Replicas",
+    format!(
+      "{} desired | {} updated | {} total | {} available | {} unavailable",
+      spec.and_then(|s| s.replicas).unwrap_or(1),
+      replicas(status.and_then(|s| s.updated_replicas)),
+      replicas(status.and_then(|s| s.replicas)),
+      replicas(status.and_then(|s| s.available_replicas)),
+      replicas(status.and_then(|s| s.unavailable_replicas)),
+    ),
+  );
+  let strategy = spec.and_then(|s| s.strategy.as_ref());
+  w.field(
+    0,
+    "StrategyType",
+    or_none(strategy.and_then(|s| s.type_.as_deref())),
+  );
+  w.field(
+    0,
+    "MinReadySeconds",
+    spec
+      .and_then(|s| s.min_ready_seconds)
+      .unwrap_or_default()
+      .to_string(),
+  );
+  if let Some(rolling) = strategy.and_then(|s| s.rolling_update.as_ref()) {
+    w.field(
+      0,
+      "RollingUpdateStrategy",
+      format!(
+        "{} max unavailable, {} max surge",
+        rolling
+          .max_unavailable
+          .as_ref()
+          .map_or_else(|| "25%".to_owned(), format_int_or_string),
+        rolling
+          .max_surge
+          .as_ref()
+          .map_or_else(|| "25%".to_owned(), format_int_or_string),
+      ),
+    );
+  }
+  if let Some(spec) = spec {
+    pod_template(&mut w, &spec.template);
+  }
+  let conditions: Vec<Vec<String>> = status
+    .and_then(|s| s.conditions.as_ref())
+    .map(|conditions| {
+      conditions
+        .iter()
+        .map(|c| {
+          vec![
+            c.type_.clone(),
+            c.status.clone(),
+            c.reason.clone().unwrap_or_default(),
+          ]
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  w.table(0, "Conditions", &["Type", "Status", "Reason"], &conditions);
+  describe_events(&mut w, events);
+  w.finish()
+}
+
+pub fn describe_statefulset(sts: &StatefulSet, events: &[Event]) -> String {
+  let mut w = DescribeWriter::default();
+  let spec = sts.spec.as_ref();
+  let status = sts.status.as_ref();
+
+  header(&mut w, &sts.metadata);
+  w.field(0, "Selector", format_selector(spec.map(|s| &s.selector)));
+  w.field(
+    0,
+    "Replicas",
+    format!(
+      "{} desired | {} total",
+      spec.and_then(|s| s.replicas).unwrap_or(1),
+      status.map(|s| s.replicas).unwrap_or_default()
+    ),
+  );
+  let update_strategy = spec.and_then(|s| s.update_strategy.as_ref());
+  w.field(
+    0,
+    "Update Strategy",
+    or_none(update_strategy.and_then(|s| s.type_.as_deref())),
+  );
+  if let Some(partition) = update_strategy
+    .and_then(|s| s.rolling_update.as_ref())
+    .and_then(|r| r.partition)
+  {
+    w.field(1, "Partition", partition.to_string());
+  }
+  w.field(
+    0,
+    "Pod Management Policy",
+    or_none(spec.and_then(|s| s.pod_management_policy.as_deref())),
+  );
+  if let Some(service) = spec.and_then(|s| s.service_name.as_deref()) {
+    w.field(0, "Service Name", service);
+  }
+  w.field(
+    0,
+    "Pods Status",
+    format!(
+      "{} Ready / {} Current / {} Updated",
+      status.and_then(|s| s.ready_replicas).unwrap_or_default(),
+      status.and_then(|s| s.current_replicas).unwrap_or_default(),
+      status.and_then(|s| s.updated_replicas).unwrap_or_default(),
+    ),
+  );
+  if let Some(revision) = status.and_then(|s| s.current_revision.as_deref()) {
+    w.field(0, "Current Revision", revision);
+  }
+  if let Some(revision) = status.and_then(|s| s.update_revision.as_deref()) {
+    w.field(0, "Update Revision", revision);
+  }
+  if let Some(spec) = spec {
+    pod_template(&mut w, &spec.template);
+    match spec
+      .volume_claim_templates
+      .as_ref()
+      .filter(|t| !t.is_empty())
+    {
+      Some(templates) => {
+        w.text(0, "Volume Claims:");
+        for claim in templates {
+          let claim_spec = claim.spec.as_ref();
+          w.field(1, "Name", name_of(&claim.metadata));
+          w.field(
+            1,
+            "StorageClass",
+            claim_spec
+              .and_then(|s| s.storage_class_name.clone())
+              .unwrap_or_default(),
+          );
+          w.string_map(1, "Labels", claim.metadata.labels.as_ref(), "=");
+          w.string_map(1, "Annotations", claim.metadata.annotations.as_ref(), ": ");
+          w.field(
+            1,
+            "Capacity",
+            or_none(
+              claim_spec
+                .and_then(|s| s.resources.as_ref())
+                .and_then(|r| r.requests.as_ref())
+                .and_then(|r| r.get("storage"))
+                .map(|q| q.0.as_str()),
+            ),
+          );
+          w.field(
+            1,
+            "Access Modes",
+            claim_spec
+              .and_then(|s| s.access_modes.as_ref())
+              .map(|modes| modes.join(", "))
+              .unwrap_or_default(),
+          );
+        }
+      }
+      None => w.field(0, "Volume Claims", NONE),
+    }
+  }
+  describe_events(&mut w, events);
+  w.finish()
+}
+
+pub fn describe_daemonset(ds: &DaemonSet, events: &[Event]) -> String {
+  let mut w = DescribeWriter::default();
+  let spec = ds.spec.as_ref();
+  let status = ds.status.as_ref();
+
+  w.field(0, "Name", name_of(&ds.metadata));
+  w.field(0, "Namespace", namespace_of(&ds.metadata));
+  w.field(0, "Selector", format_selector(spec.map(|s| &s.selector)));
+  w.string_map(
+    0,
+    "Node-Selector",
+    spec
+      .and_then(|s| s.template.spec.as_ref())
+      .and_then(|s| s.node_selector.as_ref()),
+    "=",
+  );
+  w.string_map(0, "Labels", ds.metadata.labels.as_ref(), "=");
+  w.string_map(0, "Annotations", ds.metadata.annotations.as_ref(), ": ");
+  w.field(
+    0,
+    "Desired Number of Nodes Scheduled",
+    status
+      .map(|s| s.desired_number_scheduled)
+      .unwrap_or_default()
+      .to_string(),
+  );
+  w.field(
+    0,
+    "Current Number of Nodes Scheduled",
+    status
+      .map(|s| s.current_number_scheduled)
+      .unwrap_or_default()
+      .to_string(),
+  );
+  w.field(
+    0,
+    "Number of Nodes Scheduled with Up-to-date Pods",
+    status
+      .and_then(|s| s.updated_number_scheduled)
+      .unwrap_or_default()
+      .to_string(),
+  );
+  w.field(
+    0,
+    "Number of Nodes Scheduled with Available Pods",
+    status
+      .and_then(|s| s.number_available)
+      .unwrap_or_default()
+      .to_string(),
+  );
+  w.field(
+    0,
+    "Number of Nodes Misscheduled",
+    status
+      .map(|s| s.number_misscheduled)
+      .unwrap_or_default()
+      .to_string(),
+  );
+  w.field(
+    0,
+    "Update Strategy",
+    or_none(
+      spec
+        .and_then(|s| s.update_strategy.as_ref())
+        .and_then(|s| s.type_.as_deref()),
+    ),
+  );
+  if let Some(spec) = spec {
+    pod_template(&mut w, &spec.template);
+  }
+  describe_events(&mut w, events);
+  w.finish()
+}
+
+pub fn describe_service(svc: &Service, events: &[Event]) -> String {
+  let mut w = DescribeWriter::default();
+  let spec = svc.spec.as_ref();
+
+  w.field(0, "Name", name_of(&svc.metadata));
+  w.field(0, "Namespace", namespace_of(&svc.metadata));
+  w.string_map(0, "Labels", svc.metadata.labels.as_ref(), "=");
+  w.string_map(0, "Annotations", svc.metadata.annotations.as_ref(), ": ");
+  w.field(
+    0,
+    "Selector",
+    join_map(spec.and_then(|s| s.selector.as_ref()), "=", ","),
+  );
+  w.field(0, "Type", or_none(spec.and_then(|s| s.type_.as_deref())));
+  if let Some(policy) = spec.and_then(|s| s.ip_family_policy.as_deref()) {
+    w.field(0, "IP Family Policy", policy);
+  }
+  if let Some(families) = spec.and_then(|s| s.ip_families.as_ref()) {
+    w.field(0, "IP Families", families.join(","));
+  }
+  w.field(0, "IP", or_none(spec.and_then(|s| s.cluster_ip.as_deref())));
+  if let Some(ips) = spec.and_then(|s| s.cluster_ips.as_ref()) {
+    w.field(0, "IPs", ips.join(","));
+  }
+  if let Some(external) = spec
+    .and_then(|s| s.external_ips.as_ref())
+    .filter(|ips| !ips.is_empty())
+  {
+    w.field(0, "External IPs", external.join(","));
+  }
+  if let Some(name) = spec.and_then(|s| s.external_name.as_deref()) {
+    w.field(0, "External Name", name);
+  }
+  let ingress: Vec<String> = svc
+    .status
+    .as_ref()
+    .and_then(|s| s.load_balancer.as_ref())
+    .and_then(|lb| lb.ingress.as_ref())
+    .map(|ingress| {
+      ingress
+        .iter()
+        .filter_map(|i| i.ip.clone().or_else(|| i.hostname.clone()))
+        .collect()
+    })
+    .unwrap_or_default();
+  if !ingress.is_empty() {
+    w.field(0, "LoadBalancer Ingress", ingress.join(", "));
+  }
+  for port in spec.and_then(|s| s.ports.as_ref()).into_iter().flatten() {
+    let protocol = port.protocol.as_deref().unwrap_or("TCP");
+    let name = port.name.as_deref().unwrap_or("<unset>");
+    w.field(0, "Port", format!("{name}  {}/{protocol}", port.port));
+    let target = port
+      .target_port
+      .as_ref()
+      .map_or_else(|| port.port.to_string(), format_int_or_string);
+    w.field(0, "TargetPort", format!("{target}/{protocol}"));
+    if let Some(node_port) = port.node_port {
+      w.field(0, "NodePort", format!("{name}  {node_port}/{protocol}"));
+    }
+  }
+  w.field(
+    0,
+    "Session Affinity",
+    or_none(spec.and_then(|s| s.session_affinity.as_deref())),
+  );
+  if let Some(policy) = spec.and_then(|s| s.external_traffic_policy.as_deref()) {
+    w.field(0, "External Traffic Policy", policy);
+  }
+  if let Some(policy) = spec.and_then(|s| s.internal_traffic_policy.as_deref()) {
+    w.field(0, "Internal Traffic Policy", policy);
+  }
+  describe_events(&mut w, events);
+  w.finish()
+}
+
+pub fn describe_node(node: &Node, events: &[Event]) -> String {
+  let mut w = DescribeWriter::default();
+  let spec = node.spec.as_ref();
+  let status = node.status.as_ref();
+
+  w.field(0, "Name", name_of(&node.metadata));
+  let roles: Vec<&str> = node
+    .metadata
+    .labels
+    .iter()
+    .flatten()
+    .filter_map(|(key, _)| key.strip_prefix("node-role.kubernetes.io/"))
+    .collect();
+  w.field(
+    0,
+    "Roles",
+    if roles.is_empty() {
+      NONE.to_owned()
+    } else {
+      roles.join(",")
+    },
+  );
+  w.string_map(0, "Labels", node.metadata.labels.as_ref(), "=");
+  w.string_map(0, "Annotations", node.metadata.annotations.as_ref(), ": ");
+  w.field(
+    0,
+    "CreationTimestamp",
+    node
+      .metadata
+      .creation_timestamp
+      .as_ref()
+      .map_or_else(|| NONE.to_owned(), format_time),
+  );
+  let taints: Vec<String> = spec
+    .and_then(|s| s.taints.as_ref())
+    .map(|taints| {
+      taints
+        .iter()
+        .map(|t| match t.value.as_deref().filter(|v| !v.is_empty()) {
+          Some(value) => format!("{}={}:{}", t.key, value, t.effect),
+          None => format!("{}:{}", t.key, t.effect),
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  w.list(0, "Taints", &taints);
+  w.field(
+    0,
+    "Unschedulable",
+    spec
+      .and_then(|s| s.unschedulable)
+      .unwrap_or_default()
+      .to_string(),
+  );
+
+  let conditions: Vec<Vec<String>> = status
+    .and_then(|s| s.conditions.as_ref())
+    .map(|conditions| {
+      conditions
+        .iter()
+        .map(|c| {
+          vec![
+            c.type_.clone(),
+            c.status.clone(),
+            c.last_heartbeat_time
+              .as_ref()
+              .map(format_time)
+              .unwrap_or_default(),
+            c.reason.clone().unwrap_or_default(),
+            c.message.clone().unwrap_or_default(),
+          ]
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  w.table(
+    0,
+    "Conditions",
+    &["Type", "Status", "LastHeartbeatTime", "Reason", "Message"],
+    &conditions,
+  );
+
+  let addresses = status.and_then(|s| s.addresses.as_ref());
+  match addresses.filter(|a| !a.is_empty()) {
+    Some(addresses) => {
+      w.text(0, "Addresses:");
+      for address in addresses {
+        w.field(1, &address.type_, address.address.as_str());
+      }
+    }
+    None => w.field(0, "Addresses", NONE),
+  }
+  quantities(
+    &mut w,
+    0,
+    "Capacity",
+    status.and_then(|s| s.capacity.as_ref()),
+  );
+  quantities(
+    &mut w,
+    0,
+    "Allocatable",
+    status.and_then(|s| s.allocatable.as_ref()),
+  );
+  if let Some(info) = status.and_then(|s| s.node_info.as_ref()) {
+    w.text(0, "System Info:");
+    w.field(1, "Machine ID", info.machine_id.as_str());
+    w.field(1, "System UUID", info.system_uuid.as_str());
+    w.field(1, "Boot ID", info.boot_id.as_str());
+    w.field(1, "Kernel Version", info.kernel_version.as_str());
+    w.field(1, "OS Image", info.os_image.as_str());
+    w.field(1, "Operating System", info.operating_system.as_str());
+    w.field(1, "Architecture", info.architecture.as_str());
+    w.field(
+      1,
+      "Container Runtime Version",
+      info.container_runtime_version.as_str(),
+    );
+    w.field(1, "Kubelet Version", info.kubelet_version.as_str());
+  }
+  if let Some(cidr) = spec.and_then(|s| s.pod_cidr.as_deref()) {
+    w.field(0, "PodCIDR", cidr);
+  }
+  if let Some(cidrs) = spec.and_then(|s| s.pod_cidrs.as_ref()) {
+    w.field(0, "PodCIDRs", cidrs.join(","));
+  }
+  if let Some(provider) = spec.and_then(|s| s.provider_id.as_deref()) {
+    w.field(0, "ProviderID", provider);
+  }
+  describe_events(&mut w, events);
+  w.finish()
+}
+
+pub fn describe_pvc(pvc: &PersistentVolumeClaim, events: &[Event]) -> String {
+  let mut w = DescribeWriter::default();
+  let spec = pvc.spec.as_ref();
+  let status = pvc.status.as_ref();
+
+  w.field(0, "Name", name_of(&pvc.metadata));
+  w.field(0, "Namespace", namespace_of(&pvc.metadata));
+  w.field(
+    0,
+    "StorageClass",
+    spec
+      .and_then(|s| s.storage_class_name.clone())
+      .unwrap_or_default(),
+  );
+  let phase = if pvc.metadata.deletion_timestamp.is_some() {
+    "Terminating".to_owned()
+  } else {
+    status.and_then(|s| s.phase.clone()).unwrap_or_default()
+  };
+  w.field(0, "Status", phase);
+  w.field(
+    0,
+    "Volume",
+    spec.and_then(|s| s.volume_name.clone()).unwrap_or_default(),
+  );
+  w.string_map(0, "Labels", pvc.metadata.labels.as_ref(), "=");
+  w.string_map(0, "Annotations", pvc.metadata.annotations.as_ref(), ": ");
+  let finalizers = pvc.metadata.finalizers.clone().unwrap_or_default();
+  w.field(0, "Finalizers", format!("[{}]", finalizers.join(" ")));
+  w.field(
+    0,
+    "Capacity",
+    status
+      .and_then(|s| s.capacity.as_ref())
+      .and_then(|c| c.get("storage"))
+      .map(|q| q.0.clone())
+      .unwrap_or_default(),
+  );
+  w.field(
+    0,
+    "Access Modes",
+    status
+      .and_then(|s| s.access_modes.as_ref())
+      .map(|modes| modes.join(","))
+      .unwrap_or_default(),
+  );
+  w.field(
+    0,
+    "VolumeMode",
+    or_none(spec.and_then(|s| s.volume_mode.as_deref())),
+  );
+  describe_events(&mut w, events);
+  w.finish()
+}
+
+pub fn describe_job(job: &Job, events: &[Event]) -> String {
+  let mut w = DescribeWriter::default();
+  let spec = job.spec.as_ref();
+  let status = job.status.as_ref();
+
+  w.field(0, "Name", name_of(&job.metadata));
+  w.field(0, "Namespace", namespace_of(&job.metadata));
+  w.field(
+    0,
+    "Selector",
+    format_selector(spec.and_then(|s| s.selector.as_ref())),
+  );
+  w.string_map(0, "Labels", job.metadata.labels.as_ref(), "=");
+  w.string_map(0, "Annotations", job.metadata.annotations.as_ref(), ": ");
+  owner(&mut w, &job.metadata);
+  if let Some(spec) = spec {
+    job_spec_fields(&mut w, spec);
+  }
+  if let Some(start) = status.and_then(|s| s.start_time.as_ref()) {
+    w.field(0, "Start Time", format_time(start));
+  }
+  if let Some(completed) = status.and_then(|s| s.completion_time.as_ref()) {
+    w.field(0, "Completed At", format_time(completed));
+    if let Some(start) = status.and_then(|s| s.start_time.as_ref()) {
+      let duration = utils::time_to_chrono(completed) - utils::time_to_chrono(start);
+      w.field(0, "Duration", utils::duration_to_age(duration, true));
+    }
+  }
+  w.field(
+    0,
+    "Pods Statuses",
+    format!(
+      "{} Active / {} Succeeded / {} Failed",
+      status.and_then(|s| s.active).unwrap_or_default(),
+      status.and_then(|s| s.succeeded).unwrap_or_default(),
+      status.and_then(|s| s.failed).unwrap_or_default(),
+    ),
+  );
+  if let Some(spec) = spec {
+    pod_template(&mut w, &spec.template);
+  }
+  let conditions: Vec<Vec<String>> = status
+    .and_then(|s| s.conditions.as_ref())
+    .map(|conditions| {
+      conditions
+        .iter()
+        .map(|c| {
+          vec![
+            c.type_.clone(),
+            c.status.clone(),
+            c.reason.clone().unwrap_or_default(),
+            c.message.clone().unwrap_or_default(),
+          ]
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  if !conditions.is_empty() {
+    w.table(
+      0,
+      "Conditions",
+      &["Type", "Status", "Reason", "Message"],
+      &conditions,
+    );
+  }
+  describe_events(&mut w, events);
+  w.finish()
+}
+
+pub fn describe_cronjob(cronjob: &CronJob, events: &[Event]) -> String {
+  let mut w = DescribeWriter::default();
+  let spec = cronjob.spec.as_ref();
+  let status = cronjob.status.as_ref();
+
+  w.field(0, "Name", name_of(&cronjob.metadata));
+  w.field(0, "Namespace", namespace_of(&cronjob.metadata));
+  w.string_map(0, "Labels", cronjob.metadata.labels.as_ref(), "=");
+  w.string_map(
+    0,
+    "Annotations",
+    cronjob.metadata.annotations.as_ref(),
+    ": ",
+  );
+  w.field(
+    0,
+    "Schedule",
+    spec.map(|s| s.schedule.clone()).unwrap_or_default(),
+  );
+  if let Some(zone) = spec.and_then(|s| s.time_zone.as_deref()) {
+    w.field(0, "Time Zone", zone);
+  }
+  w.field(
+    0,
+    "Concurrency Policy",
+    spec
+      .and_then(|s| s.concurrency_policy.clone())
+      .unwrap_or_else(|| "Allow".to_owned()),
+  );
+  w.field(
+    0,
+    "Suspend",
+    spec.and_then(|s| s.suspend).unwrap_or_default().to_string(),
+  );
+  w.field(
+    0,
+    "Successful Job History Limit",
+    spec
+      .and_then(|s| s.successful_jobs_history_limit)
+      .unwrap_or(3)
+      .to_string(),
+  );
+  w.field(
+    0,
+    "Failed Job History Limit",
+    spec
+      .and_then(|s| s.failed_jobs_history_limit)
+      .unwrap_or(1)
+      .to_string(),
+  );
+  w.field(
+    0,
+    "Starting Deadline Seconds",
+    spec
+      .and_then(|s| s.starting_deadline_seconds)
+      .map_or_else(|| NONE.to_owned(), |s| format!("{s}s")),
+  );
+  if let Some(job_spec) = spec.and_then(|s| s.job_template.spec.as_ref()) {
+    job_spec_fields(&mut w, job_spec);
+    pod_template(&mut w, &job_spec.template);
+  }
+  w.field(
+    0,
+    "Last Schedule Time",
+    status
+      .and_then(|s| s.last_schedule_time.as_ref())
+      .map_or_else(|| NONE.to_owned(), format_time),
+  );
+  if let Some(success) = status.and_then(|s| s.last_successful_time.as_ref()) {
+    w.field(0, "Last Successful Time", format_time(success));
+  }
+  let active: Vec<String> = status
+    .and_then(|s| s.active.as_ref())
+    .map(|jobs| jobs.iter().filter_map(|job| job.name.clone()).collect())
+    .unwrap_or_default();
+  w.list(0, "Active Jobs", &active);
+  describe_events(&mut w, events);
+  w.finish()
+}
+
+// shared sections
+
+fn header(w: &mut DescribeWriter, meta: &ObjectMeta) {
+  w.field(0, "Name", name_of(meta));
+  w.field(0, "Namespace", namespace_of(meta));
+  w.field(
+    0,
+    "CreationTimestamp",
+    meta
+      .creation_timestamp
+      .as_ref()
+      .map_or_else(|| NONE.to_owned(), format_time),
+  );
+  w.string_map(0, "Labels", meta.labels.as_ref(), "=");
+  w.string_map(0, "Annotations", meta.annotations.as_ref(), ": ");
+}
+
+fn owner(w: &mut DescribeWriter, meta: &ObjectMeta) {
+  let controller = meta
+    .owner_references
+    .iter()
+    .flatten()
+    .find(|owner| owner.controller == Some(true));
+  if let Some(OwnerReference { kind, name, .. }) = controller {
+    w.field(0, "Controlled By", format!("{kind}/{name}"));
+  }
+}
+
+fn job_spec_fields(w: &mut DescribeWriter, spec: &JobSpec) {
+  w.field(0, "Parallelism", spec.parallelism.unwrap_or(1).to_string());
+  w.field(
+    0,
+    "Completions",
+    spec
+      .completions
+      .map_or_else(|| "<unset>".to_owned(), |c| c.to_string()),
+  );
+  w.field(
+    0,
+    "Completion Mode",
+    spec
+      .completion_mode
+      .clone()
+      .unwrap_or_else(|| "NonIndexed".to_owned()),
+  );
+  w.field(0, "Suspend", spec.suspend.unwrap_or_default().to_string());
+  w.field(
+    0,
+    "Backoff Limit",
+    spec.backoff_limit.unwrap_or(6).to_string(),
+  );
+  if let Some(deadline) = spec.active_deadline_seconds {
+    w.field(0, "Active Deadline Seconds", format!("{deadline}s"));
+  }
+  if let Some(ttl) = spec.ttl_seconds_after_finished {
+    w.field(0, "TTL Seconds After Finished", ttl.to_string());
+  }
+}
+
+fn pod_template(w: &mut DescribeWriter, template: &PodTemplateSpec) {
+  w.text(0, "Pod Template:");
+  let labels = template.metadata.as_ref().and_then(|m| m.labels.as_ref());
+  w.string_map(1, "Labels", labels, "=");
+  let annotations = template
+    .metadata
+    .as_ref()
+    .and_then(|m| m.annotations.as_ref());
+  if annotations.is_some_and(|a| !a.is_empty()) {
+    w.string_map(1, "Annotations", annotations, ": ");
+  }
+  let Some(spec) = template.spec.as_ref() else {
+    return;
+  };
+  if let Some(account) = spec.service_account_name.as_deref() {
+    w.field(1, "Service Account", account);
+  }
+  if let Some(init) = spec.init_containers.as_ref().filter(|c| !c.is_empty()) {
+    w.text(1, "Init Containers:");
+    for container in init {
+      describe_container(w, 2, container, None);
+    }
+  }
+  w.text(1, "Containers:");
+  for container in &spec.containers {
+    describe_container(w, 2, container, None);
+  }
+  volumes(w, 1, spec.volumes.as_deref());
+  w.string_map(1, "Node-Selectors", spec.node_selector.as_ref(), "=");
+}
+
+fn find_status<'a>(
+  statuses: Option<&'a [ContainerStatus]>,
+  container: &Container,
+) -> Option<&'a ContainerStatus> {
+  statuses?.iter().find(|s| s.name == container.name)
+}
+
+fn describe_container(
+  w: &mut DescribeWriter,
+  level: usize,
+  container: &Container,
+  status: Option<&ContainerStatus>,
+) {
+  w.text(level, format!("{}:", container.name));
+  let l = level + 1;
+  if let Some(status) = status {
+    w.field(
+      l,
+      "Container ID",
+      status.container_id.clone().unwrap_or_default(),
+    );
+  }
+  w.field(l, "Image", container.image.clone().unwrap_or_default());
+  if let Some(status) = status {
+    w.field(l, "Image ID", status.image_id.as_str());
+  }
+  let ports: Vec<String> = container
+    .ports
+    .iter()
+    .flatten()
+    .map(|p| {
+      format!(
+        "{}/{}",
+        p.container_port,
+        p.protocol.as_deref().unwrap_or("TCP")
+      )
+    })
+    .collect();
+  w.field(
+    l,
+    "Port",
+    if ports.is_empty() {
+      NONE.to_owned()
+    } else {
+      ports.join(", ")
+    },
+  );
+  if let Some(command) = container.command.as_ref().filter(|c| !c.is_empty()) {
+    w.text(l, "Command:");
+    for part in command {
+      w.text(l + 1, part.as_str());
+    }
+  }
+  if let Some(args) = container.args.as_ref().filter(|a| !a.is_empty()) {
+    w.text(l, "Args:");
+    for part in args {
+      w.text(l + 1, part.as_str());
+    }
+  }
+  if let Some(status) = status {
+    container_state(w, l, "State", status.state.as_ref());
+    if status
+      .last_state
+      .as_ref()
+      .is_some_and(|s| s.running.is_some() || s.waiting.is_some() || s.terminated.is_some())
+    {
+      container_state(w, l, "Last State", status.last_state.as_ref());
+    }
+    w.field(l, "Ready", status.ready.to_string());
+    w.field(l, "Restart Count", status.restart_count.to_string());
+  }
+  let resources = container.resources.as_ref();
+  if let Some(limits) = resources.and_then(|r| r.limits.as_ref()) {
+    quantities(w, l, "Limits", Some(limits));
+  }
+  if let Some(requests) = resources.and_then(|r| r.requests.as_ref()) {
+    quantities(w, l, "Requests", Some(requests));
+  }
+  for (label, probe) in [
+    ("Liveness", &container.liveness_probe),
+    ("Readiness", &container.readiness_probe),
+    ("Startup", &container.startup_probe),
+  ] {
+    if let Some(probe) = probe {
+      w.field(l, label, format_probe(probe));
+    }
+  }
+  let env_from: Vec<String> = container
+    .env_from
+    .iter()
+    .flatten()
+    .filter_map(|source| {
+      let prefix = source.prefix.clone().unwrap_or_default();
+      if let Some(cm) = source.config_map_ref.as_ref() {
+        Some(format!("{}{}  ConfigMap", prefix, cm.name))
+      } else {
+        source
+          .secret_ref
+          .as_ref()
+          .map(|secret| format!("{}{}  Secret", prefix, secret.name))
+      }
+    })
+    .collect();
+  if !env_from.is_empty() {
+    w.list(l, "Environment Variables from", &env_from);
+  }
+  match container.env.as_ref().filter(|env| !env.is_empty()) {
+    Some(env) => {
+      w.text(l, "Environment:");
+      for var in env {
+        w.field(l + 1, &var.name, format_env_value(var));
+      }
+    }
+    None => w.field(l, "Environment", NONE),
+  }
+  let mounts: Vec<String> = container
+    .volume_mounts
+    .iter()
+    .flatten()
+    .map(|m| {
+      let mut mount = format!("{} from {}", m.mount_path, m.name);
+      let mode = if m.read_only.unwrap_or_default() {
+        "ro"
+      } else {
+        "rw"
+      };
+      match m.sub_path.as_deref().filter(|p| !p.is_empty()) {
+        Some(sub_path) => mount.push_str(&format!(" ({mode},path=\"{sub_path}\")")),
+        None => mount.push_str(&format!(" ({mode})")),
+      }
+      mount
+    })
+    .collect();
+  w.list(l, "Mounts", &mounts);
+}
+
+fn container_state(
+  w: &mut DescribeWriter,
+  level: usize,
+  label: &str,
+  state: Option<&ContainerState>,
+) {
+  let Some(state) = state else {
+    w.field(level, label, "Waiting");
+    return;
+  };
+  if let Some(running) = state.running.as_ref() {
+    w.field(level, label, "Running");
+    if let Some(started) = running.started_at.as_ref() {
+      w.field(level + 1, "Started", format_time(started));
+    }
+  } else if let Some(waiting) = state.waiting.as_ref() {
+    w.field(level, label, "Waiting");
+    if let Some(reason) = waiting.reason.as_deref() {
+      w.field(level + 1, "Reason", reason);
+    }
+    if let Some(message) = waiting.message.as_deref() {
+      w.field(level + 1, "Message", message);
+    }
+  } else if let Some(terminated) = state.terminated.as_ref() {
+    w.field(level, label, "Terminated");
+    if let Some(reason) = terminated.reason.as_deref() {
+      w.field(level + 1, "Reason", reason);
+    }
+    if let Some(message) = terminated.message.as_deref() {
+      w.field(level + 1, "Message", message);
+    }
+    w.field(level + 1, "Exit Code", terminated.exit_code.to_string());
+    if let Some(signal) = terminated.signal {
+      w.field(level + 1, "Signal", signal.to_string());
+    }
+    if let Some(started) = terminated.started_at.as_ref() {
+      w.field(level + 1, "Started", format_time(started));
+    }
+    if let Some(finished) = terminated.finished_at.as_ref() {
+      w.field(level + 1, "Finished", format_time(finished));
+    }
+  } else {
+    w.field(level, label, "Waiting");
+  }
+}
+
+fn format_probe(probe: &Probe) -> String {
+  let action = if let Some(http) = probe.http_get.as_ref() {
+    format!(
+      "http-get {}://{}:{}{}",
+      http.scheme.as_deref().unwrap_or("HTTP").to_lowercase(),
+      http.host.as_deref().unwrap_or_default(),
+      format_int_or_string(&http.port),
+      http.path.as_deref().unwrap_or_default()
+    )
+  } else if let Some(tcp) = probe.tcp_socket.as_ref() {
+    format!(
+      "tcp-socket {}:{}",
+      tcp.host.as_deref().unwrap_or_default(),
+      format_int_or_string(&tcp.port)
+    )
+  } else if let Some(grpc) = probe.grpc.as_ref() {
+    format!(
+      "grpc <pod>:{} {}",
+      grpc.port,
+      grpc.service.as_deref().unwrap_or_default()
+    )
+  } else if let Some(exec) = probe.exec.as_ref() {
+    format!(
+      "exec [{}]",
+      exec.command.clone().unwrap_or_default().join(" ")
+    )
+  } else {
+    "unknown".to_owned()
+  };
+  format!(
+    "{} delay={}s timeout={}s period={}s #success={} #failure={}",
+    action,
+    probe.initial_delay_seconds.unwrap_or_default(),
+    probe.timeout_seconds.unwrap_or(1),
+    probe.period_seconds.unwrap_or(10),
+    probe.success_threshold.unwrap_or(1),
+    probe.failure_threshold.unwrap_or(3)
+  )
+}
+
+fn format_env_value(var: &EnvVar) -> String {
+  if let Some(value) = var.value.as_ref() {
+    return value.clone();
+  }
+  let Some(source) = var.value_from.as_ref() else {
+    return String::new();
+  };
+  if let Some(field) = source.field_ref.as_ref() {
+    format!(
+      " ({}:{})",
+      field.api_version.as_deref().unwrap_or("v1"),
+      field.field_path
+    )
+  } else if let Some(resource) = source.resource_field_ref.as_ref() {
+    format!(" ({})", resource.resource)
+  } else if let Some(secret) = source.secret_key_ref.as_ref() {
+    format!(
+      "<set to the key '{}' in secret '{}'>",
+      secret.key, secret.name
+    )
+  } else if let Some(cm) = source.config_map_key_ref.as_ref() {
+    format!("<set to the key '{}' of config map '{}'>", cm.key, cm.name)
+  } else {
+    String::new()
+  }
+}
+
+fn volumes(w: &mut DescribeWriter, level: usize, volumes: Option<&[Volume]>) {
+  let Some(volumes) = volumes.filter(|v| !v.is_empty()) else {
+    w.field(level, "Volumes", NONE);
+    return;
+  };
+  w.text(level, "Volumes:");
+  for volume in volumes {
+    w.text(level + 1, format!("{}:", volume.name));
+    let (kind, detail) = volume_source(volume);
+    w.field(level + 2, "Type", kind);
+    if let Some((key, value)) = detail {
+      w.field(level + 2, key, value);
+    }
+  }
+}
+
+fn volume_source(volume: &Volume) -> (&'static str, Option<(&'static str, String)>) {
+  if let Some(pvc) = volume.persistent_volume_claim.as_ref() {
+    (
+      "PersistentVolumeClaim",
+      Some(("ClaimName", pvc.claim_name.clone())),
+    )
+  } else if let Some(cm) = volume.config_map.as_ref() {
+    ("ConfigMap", Some(("Name", cm.name.clone())))
+  } else if let Some(secret) = volume.secret.as_ref() {
+    (
+      "Secret",
+      Some(("SecretName", secret.secret_name.clone().unwrap_or_default())),
+    )
+  } else if let Some(empty) = volume.empty_dir.as_ref() {
+    (
+      "EmptyDir",
+      Some(("Medium", empty.medium.clone().unwrap_or_default())),
+    )
+  } else if let Some(host) = volume.host_path.as_ref() {
+    ("HostPath", Some(("Path", host.path.clone())))
+  } else if volume.projected.is_some() {
+    ("Projected", None)
+  } else if volume.downward_api.is_some() {
+    ("DownwardAPI", None)
+  } else if let Some(csi) = volume.csi.as_ref() {
+    ("CSI", Some(("Driver", csi.driver.clone())))
+  } else if volume.ephemeral.is_some() {
+    ("EphemeralVolume", None)
+  } else if let Some(nfs) = volume.nfs.as_ref() {
+    (
+      "NFS",
+      Some(("Server", format!("{}:{}", nfs.server, nfs.path))),
+    )
+  } else {
+    ("Other", None)
+  }
+}
+
+fn quantities(
+  w: &mut DescribeWriter,
+  level: usize,
+  label: &str,
+  values: Option<&BTreeMap<String, Quantity>>,
+) {
+  match values.filter(|v| !v.is_empty()) {
+    Some(values) => {
+      w.text(level, format!("{label}:"));
+      for (key, value) in values {
+        w.field(level + 1, key, value.0.as_str());
+      }
+    }
+    None => w.field(level, label, NONE),
+  }
+}
+
+fn describe_events(w: &mut DescribeWriter, events: &[Event]) {
+  let mut events: Vec<&Event> = events.iter().collect();
+  events.sort_by_key(|event| event_timestamp(event).map(|t| t.0));
+  let now = Utc::now();
+  let rows: Vec<Vec<String>> = events
+    .iter()
+    .map(|event| {
+      let age = utils::to_age(event_timestamp(event).as_ref(), now);
+      let count = event
+        .count
+        .or_else(|| event.series.as_ref().and_then(|s| s.count))
+        .unwrap_or(1);
+      let age = if count > 1 {
+        match event.first_timestamp.as_ref() {
+          Some(first) => format!("{age} (x{count} over {})", utils::to_age(Some(first), now)),
+          None => format!("{age} (x{count})"),
+        }
+      } else {
+        age
+      };
+      let from = event
+        .source
+        .as_ref()
+        .and_then(|s| s.component.clone())
+        .or_else(|| event.reporting_component.clone())
+        .unwrap_or_default();
+      vec![
+        event.type_.clone().unwrap_or_default(),
+        event.reason.clone().unwrap_or_default(),
+        age,
+        from,
+        event.message.clone().unwrap_or_default().trim().to_owned(),
+      ]
+    })
+    .collect();
+  w.table(
+    0,
+    "Events",
+    &["Type", "Reason", "Age", "From", "Message"],
+    &rows,
+  );
+}
+
+// formatting helpers
+
+fn name_of(meta: &ObjectMeta) -> String {
+  meta.name.clone().unwrap_or_default()
+}
+
+fn namespace_of(meta: &ObjectMeta) -> String {
+  meta.namespace.clone().unwrap_or_default()
+}
+
+fn or_none(value: Option<&str>) -> String {
+  value.filter(|v| !v.is_empty()).unwrap_or(NONE).to_owned()
+}
+
+fn format_time(time: &Time) -> String {
+  utils::time_to_chrono(time)
+    .format("%a, %d %b %Y %H:%M:%S %z")
+    .to_string()
+}
+
+fn format_int_or_string(value: &IntOrString) -> String {
+  match value {
+    IntOrString::Int(i) => i.to_string(),
+    IntOrString::String(s) => s.clone(),
+  }
+}
+
+fn join_map(map: Option<&BTreeMap<String, String>>, sep: &str, join: &str) -> String {
+  match map.filter(|m| !m.is_empty()) {
+    Some(map) => map
+      .iter()
+      .map(|(k, v)| format!("{k}{sep}{v}"))
+      .collect::<Vec<_>>()
+      .join(join),
+    None => NONE.to_owned(),
+  }
+}
+
+fn format_selector(selector: Option<&LabelSelector>) -> String {
+  let Some(selector) = selector else {
+    return NONE.to_owned();
+  };
+  let mut parts: Vec<String> = selector
+    .match_labels
+    .iter()
+    .flatten()
+    .map(|(k, v)| format!("{k}={v}"))
+    .collect();
+  for expr in selector.match_expressions.iter().flatten() {
+    let values = expr.values.clone().unwrap_or_default().join(",");
+    parts.push(match expr.operator.as_str() {
+      "In" => format!("{} in ({})", expr.key, values),
+      "NotIn" => format!("{} notin ({})", expr.key, values),
+      "Exists" => expr.key.clone(),
+      "DoesNotExist" => format!("!{}", expr.key),
+      op => format!("{} {} ({})", expr.key, op, values),
+    });
+  }
+  if parts.is_empty() {
+    NONE.to_owned()
+  } else {
+    parts.join(",")
+  }
+}
+
+enum DescribeRow {
+  Field {
+    level: usize,
+    key: String,
+    value: String,
+  },
+  Text {
+    level: usize,
+    text: String,
+  },
+}
+
+impl DescribeRow {
+  fn level(&self) -> usize {
+    match self {
+      DescribeRow::Field { level, .. } | DescribeRow::Text { level, .. } => *level,
+    }
+  }
+}
+
+/// Collects describe rows and renders them with values aligned per section:
+/// fields at the same indent share a value column until a shallower row ends
+/// the section, like kubectl's tabwriter output.
+#[derive(Default)]
+struct DescribeWriter {
+  rows: Vec<DescribeRow>,
+}
+
+impl DescribeWriter {
+  fn field(&mut self, level: usize, key: &str, value: impl Into<String>) {
+    self.rows.push(DescribeRow::Field {
+      level,
+      key: key.to_owned(),
+      value: value.into(),
+    });
+  }
+
+  fn text(&mut self, level: usize, text: impl Into<String>) {
+    self.rows.push(DescribeRow::Text {
+      level,
+      text: text.into(),
+    });
+  }
+
+  /// A multi-value field: the first value on the key's line, the rest aligned
+  /// under it. Empty lists render as `<none>`.
+  fn list(&mut self, level: usize, key: &str, values: &[String]) {
+    let Some((first, rest)) = values.split_first() else {
+      self.field(level, key, NONE);
+      return;
+    };
+    self.field(level, key, first.as_str());
+    for value in rest {
+      self.field(level, "", value.as_str());
+    }
+  }
+
+  fn string_map(
+    &mut self,
+    level: usize,
+    key: &str,
+    map: Option<&BTreeMap<String, String>>,
+    sep: &str,
+  ) {
+    let values: Vec<String> = map
+      .iter()
+      .flat_map(|m| m.iter())
+      .map(|(k, v)| format!("{k}{sep}{v}"))
+      .collect();
+    self.list(level, key, &values);
+  }
+
+  /// A titled table with aligned columns, or `Title:  <none>` without rows.
+  fn table(&mut self, level: usize, title: &str, headers: &[&str], rows: &[Vec<String>]) {
+    if rows.is_empty() {
+      self.field(level, title, NONE);
+      return;
+    }
+    self.text(level, format!("{title}:"));
+    let underline: Vec<String> = headers.iter().map(|h| "-".repeat(h.len())).collect();
+    let header: Vec<String> = headers.iter().map(|h| (*h).to_owned()).collect();
+    let all: Vec<&Vec<String>> = [&header, &underline].into_iter().chain(rows).collect();
+    let widths: Vec<usize> = (0..headers.len())
+      .map(|col| {
+        all
+          .iter()
+          .map(|row| row.get(col).map_or(0, |c| c.chars().count()))
+          .max()
+          .unwrap_or_default()
+      })
+      .collect();
+    for row in all {
+      let line = row
+        .iter()
+        .enumerate()
+        .map(|(col, cell)| {
+          if col + 1 == row.len() {
+            cell.clone()
+          } else {
+            format!("{:<width$}", cell, width = widths[col])
+          }
+        })
+        .collect::<Vec<_>>()
+        .join("   ");
+      self.text(level + 1, line.trim_end());
+    }
+  }
+
+  fn finish(self) -> String {
+    let widths: Vec<usize> = (0..self.rows.len()).map(|i| self.key_width(i)).collect();
+    let mut out = String::new();
+    for (row, width) in self.rows.iter().zip(widths) {
+      let indent = "  ".repeat(row.level());
+      let line = match row {
+        DescribeRow::Field { key, value, .. } => {
+          let key = if key.is_empty() {
+            String::new()
+          } else {
+            format!("{key}:")
+          };
+          format!("{indent}{key:<width$}  {value}")
+        }
+        DescribeRow::Text { text, .. } => format!("{indent}{text}"),
+      };
+      out.push_str(line.trim_end());
+      out.push('\n');
+    }
+    out
+  }
+
+  /// Width of the `key:` column for the field at `index`: the widest key among
+  /// same-level fields in its section.
+  fn key_width(&self, index: usize) -> usize {
+    let DescribeRow::Field { level, .. } = &self.rows[index] else {
+      return 0;
+    };
+    let level = *level;
+    let start = self.rows[..index]
+      .iter()
+      .rposition(|row| row.level() < level)
+      .map_or(0, |i| i + 1);
+    let end = self.rows[index..]
+      .iter()
+      .position(|row| row.level() < level)
+      .map_or(self.rows.len(), |i| index + i);
+    self.rows[start..end]
+      .iter()
+      .filter_map(|row| match row {
+        DescribeRow::Field { level: l, key, .. } if *l == level && !key.is_empty() => {
+          Some(key.chars().count() + 1)
+        }
+        _ => None,
+      })
+      .max()
+      .unwrap_or_default()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use k8s_openapi::api::core::v1::{
+    ContainerStateRunning, ContainerStateWaiting, EventSource, PodCondition, PodSpec, PodStatus,
+    ServicePort, ServiceSpec,
+  };
+
+  use super::*;
+  use crate::app::test_utils::get_time;
+
+  #[test]
+  fn test_describe_kind_parse() {
+    assert_eq!(DescribeKind::parse("pod"), Some(DescribeKind::Pod));
+    assert_eq!(
+      DescribeKind::parse("Deployment"),
+      Some(DescribeKind::Deployment)
+    );
+    assert_eq!(
+      DescribeKind::parse("persistentvolumeclaims"),
+      Some(DescribeKind::PersistentVolumeClaim)
+    );
+    assert_eq!(
+      DescribeKind::parse("pvc"),
+      Some(DescribeKind::PersistentVolumeClaim)
+    );
+    assert_eq!(DescribeKind::parse("cronjob"), Some(DescribeKind::CronJob));
+    assert!(DescribeKind::parse("configmap").is_none());
+    assert!(!is_supported("Widget"));
+  }
+
+  #[test]
+  fn test_events_field_selector() {
+    assert_eq!(
+      events_field_selector(DescribeKind::Pod, "web-0", Some("default")),
+      "involvedObject.kind=Pod,involvedObject.name=web-0,involvedObject.namespace=default"
+    );
+    assert_eq!(
+      events_field_selector(DescribeKind::Node, "node-1", Some("default")),
+      "involvedObject.kind=Node,involvedObject.name=node-1"
+    );
+  }
+
+  #[test]
+  fn test_describe_writer_aligns_sections() {
+    let mut w = DescribeWriter::default();
+    w.field(0, "Name", "web");
+    w.field(0, "Namespace", "default");
+    w.list(0, "Labels", &["app=web".into(), "tier=front".into()]);
+    w.text(0, "Containers:");
+    w.text(1, "nginx:");
+    w.field(2, "Image", "nginx");
+    w.field(2, "Restart Count", "0");
+    w.field(0, "QoS Class", "BestEffort");
+
+    assert_eq!(
+      w.finish(),
+      "Name:       web\n\
+       Namespace:  default\n\
+       Labels:     app=web\n\
+       \x20           tier=front\n\
+       Containers:\n\
+       \x20 nginx:\n\
+       \x20   Image:          nginx\n\
+       \x20   Restart Count:  0\n\
+       QoS Class:  BestEffort\n"
+    );
+  }
+
+  #[test]
+  fn test_describe_pod_with_events() {
+    let pod = Pod {
+      metadata: ObjectMeta {
+        name: Some("web-0".into()),
+        namespace: Some("default".into()),
+        labels: Some(BTreeMap::from([("app".into(), "web".into())])),
+        ..Default::default()
+      },
+      spec: Some(PodSpec {
+        node_name: Some("node-1".into()),
+        containers: vec![
+          Container {
+            name: "nginx".into(),
+            image: Some("nginx:1.27".into()),
+            ..Default::default()
+          },
+          Container {
+            name: "sidecar".into(),
+            image: Some("busybox".into()),
+            ..Default::default()
+          },
+        ],
+        ..Default::default()
+      }),
+      status: Some(PodStatus {
+        phase: Some("Running".into()),
+        conditions: Some(vec![PodCondition {
+          type_: "Ready".into(),
+          status: "False".into(),
+          ..Default::default()
+        }]),
+        container_statuses: Some(vec![
+          ContainerStatus {
+            name: "nginx".into(),
+            ready: true,
+            restart_count: 0,
+            state: Some(ContainerState {
+              running: Some(ContainerStateRunning {
+                started_at: Some(get_time("2023-06-30T17:27:23Z")),
+              }),
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+          ContainerStatus {
+            name: "sidecar".into(),
+            restart_count: 4,
+            state: Some(ContainerState {
+              waiting: Some(ContainerStateWaiting {
+                reason: Some("CrashLoopBackOff".into()),
+                ..Default::default()
+              }),
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+        ]),
+        ..Default::default()
+      }),
+    };
+    let events = vec![Event {
+      type_: Some("Warning".into()),
+      reason: Some("BackOff".into()),
+      message: Some("Back-off restarting failed container".into()),
+      count: Some(3),
+      source: Some(EventSource {
+        component: Some("kubelet".into()),
+        ..Default::default()
+      }),
+      last_timestamp: Some(get_time("2023-06-30T17:27:23Z")),
+      ..Default::default()
+    }];
+
+    let out = describe_pod(&pod, &events);
+    assert!(out.starts_with("Name:             web-0\nNamespace:        default\n"));
+    assert!(out.contains("Node:             node-1\n"));
+    assert!(out.contains("Labels:           app=web\n"));
+    assert!(out.contains("Status:           Running\n"));
+    assert!(out.contains("  nginx:\n"));
+    assert!(out.contains("    State:          Running\n      Started:"));
+    assert!(out.contains("    State:          Waiting\n      Reason:  CrashLoopBackOff\n"));
+    assert!(out.contains("    Restart Count:  4\n"));
+    assert!(out.contains("Conditions:\n  Type    Status\n  ----    ------\n  Ready   False\n"));
+    assert!(out.contains("Events:\n  Type      Reason    Age"));
+    assert!(out.contains("  Warning   BackOff   "));
+    assert!(out.contains("(x3)   kubelet   Back-off restarting failed container\n"));
+  }
+
+  #[test]
+  fn test_describe_service_and_empty_events() {
+    let svc = Service {
+      metadata: ObjectMeta {
+        name: Some("web".into()),
+        namespace: Some("default".into()),
+        ..Default::default()
+      },
+      spec: Some(ServiceSpec {
+        type_: Some("ClusterIP".into()),
+        cluster_ip: Some("10.0.0.10".into()),
+        selector: Some(BTreeMap::from([("app".into(), "web".into())])),
+        ports: Some(vec![ServicePort {
+          name: Some("http".into()),
+          port: 80,
+          target_port: Some(IntOrString::String("http".into())),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let out = describe_service(&svc, &[]);
+    assert!(out.contains("Selector:          app=web\n"));
+    assert!(out.contains("Port:              http  80/TCP\n"));
+    assert!(out.contains("TargetPort:        http/TCP\n"));
+    assert!(out.contains("Labels:            <none>\n"));
+    assert!(out.ends_with("Events:            <none>\n"));
+  }
+}
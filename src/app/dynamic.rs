@@ -21,7 +21,7 @@ use ratatui::{
 use std::collections::{BTreeMap, VecDeque};
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils, ActiveBlock, App,
 };
 use crate::{
@@ -84,6 +84,17 @@ impl Named for KubeDynamicResource {
   }
 }
 
+impl Sortable for KubeDynamicResource {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(self.namespace.as_deref().unwrap_or_default()),
+      "Name" => SortValue::text(&self.name),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<DynamicObject> for KubeDynamicResource {
   fn get_k8s_obj(&self) -> &DynamicObject {
     &self.k8s_obj
@@ -11,7 +11,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils, ActiveBlock, App,
 };
 use crate::{
@@ -57,7 +57,7 @@ impl From<Event> for KubeEvent {
   }
 }
 
-fn event_timestamp(event: &Event) -> Option<Time> {
+pub(crate) fn event_timestamp(event: &Event) -> Option<Time> {
   event
     .series
     .as_ref()
@@ -77,6 +77,21 @@ impl Named for KubeEvent {
   }
 }
 
+impl Sortable for KubeEvent {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Involved Kind" => SortValue::text(&self.involved_kind),
+      "Reason" => SortValue::text(&self.reason),
+      "Message" => SortValue::text(&self.message),
+      "Count" => SortValue::number(self.count),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Event> for KubeEvent {
   fn get_k8s_obj(&self) -> &Event {
     &self.k8s_obj
@@ -103,10 +118,8 @@ impl AppResource for EventResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeEvent> = nw.get_namespaced_resources(Event::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.events.set_items(items);
+    nw.load_namespaced_resources(Event::into, |data| &mut data.events)
+      .await;
   }
 }
 
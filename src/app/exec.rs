@@ -0,0 +1,824 @@
+//! In-app container shell.
+//!
+//! The remote TTY runs over the pod `exec` websocket (see `network::exec`); its
+//! output is fed through [`TermScreen`], a small VT100-style screen model, so the
+//! shell renders inside a kdash pane instead of suspending the dashboard.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+  layout::Rect,
+  style::{Color, Modifier, Style},
+  text::{Line, Span},
+  widgets::Paragraph,
+  Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::{key_binding::DEFAULT_KEYBINDING, App};
+use crate::{
+  cmd::shell::ShellExecTarget,
+  ui::utils::{
+    help_bold_line, layout_block_top_border, loading, style_text, title_with_dual_style,
+  },
+};
+
+const TAB_WIDTH: usize = 8;
+
+/// Input for a running session, forwarded to the websocket by `network::exec`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExecInput {
+  Data(Vec<u8>),
+  Resize { cols: u16, rows: u16 },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExecStatus {
+  /// Probing for a shell and opening the websocket.
+  Connecting,
+  Running {
+    shell: String,
+  },
+  /// The remote process ended or the session failed; carries a short reason.
+  Exited(String),
+}
+
+impl ExecStatus {
+  pub fn label(&self) -> String {
+    match self {
+      ExecStatus::Connecting => "connecting…".into(),
+      ExecStatus::Running { shell } => shell.clone(),
+      ExecStatus::Exited(reason) => reason.clone(),
+    }
+  }
+}
+
+/// The shell pane's state. `id` lets the network task ignore output for a
+/// session the user already closed.
+pub struct ExecSession {
+  pub id: u64,
+  pub target: ShellExecTarget,
+  pub status: ExecStatus,
+  pub screen: TermScreen,
+  input_tx: Option<UnboundedSender<ExecInput>>,
+}
+
+impl ExecSession {
+  pub fn new(id: u64, target: ShellExecTarget) -> Self {
+    ExecSession {
+      id,
+      target,
+      status: ExecStatus::Connecting,
+      screen: TermScreen::new(80, 24),
+      input_tx: None,
+    }
+  }
+
+  /// Hook up the websocket input channel once the shell has started. Returns the
+  /// current pane size so the remote TTY can be sized to match.
+  pub fn attach(&mut self, shell: String, tx: UnboundedSender<ExecInput>) -> (u16, u16) {
+    self.status = ExecStatus::Running { shell };
+    self.input_tx = Some(tx);
+    self.screen.size()
+  }
+
+  /// Mark the session ended. Dropping the sender also stops the network task if
+  /// it is still running.
+  pub fn finish(&mut self, reason: impl Into<String>) {
+    self.status = ExecStatus::Exited(reason.into());
+    self.input_tx = None;
+  }
+
+  pub fn is_running(&self) -> bool {
+    matches!(self.status, ExecStatus::Running { .. }) && self.input_tx.is_some()
+  }
+
+  pub fn send_input(&mut self, data: Vec<u8>) {
+    if let Some(tx) = &self.input_tx {
+      if tx.send(ExecInput::Data(data)).is_err() {
+        self.input_tx = None;
+      }
+    }
+  }
+
+  /// Resize the screen to the pane and tell the remote TTY, if it changed.
+  pub fn resize(&mut self, cols: u16, rows: u16) {
+    if cols == 0 || rows == 0 || self.screen.size() == (cols, rows) {
+      return;
+    }
+    self.screen.resize(cols, rows);
+    if let Some(tx) = &self.input_tx {
+      let _ = tx.send(ExecInput::Resize { cols, rows });
+    }
+  }
+}
+
+/// Translate a key press into the bytes a terminal would send for it.
+pub fn key_event_bytes(key: KeyEvent) -> Option<Vec<u8>> {
+  let seq = |s: &str| s.as_bytes().to_vec();
+  let mut bytes = match key.code {
+    KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+      match c.to_ascii_lowercase() {
+        c @ 'a'..='z' => vec![c as u8 - b'a' + 1],
+        ' ' | '@' | '2' => vec![0],
+        '[' | '3' => vec![0x1b],
+        '\\' | '4' => vec![0x1c],
+        ']' | '5' => vec![0x1d],
+        '^' | '6' => vec![0x1e],
+        '_' | '/' | '7' => vec![0x1f],
+        _ => return None,
+      }
+    }
+    KeyCode::Char(c) => c.to_string().into_bytes(),
+    KeyCode::Enter => vec![b'\r'],
+    KeyCode::Tab => vec![b'\t'],
+    KeyCode::BackTab => seq("\x1b[Z"),
+    KeyCode::Backspace => vec![0x7f],
+    KeyCode::Esc => vec![0x1b],
+    KeyCode::Up => seq("\x1b[A"),
+    KeyCode::Down => seq("\x1b[B"),
+    KeyCode::Right => seq("\x1b[C"),
+    KeyCode::Left => seq("\x1b[D"),
+    KeyCode::Home => seq("\x1b[H"),
+    KeyCode::End => seq("\x1b[F"),
+    KeyCode::PageUp => seq("\x1b[5~"),
+    KeyCode::PageDown => seq("\x1b[6~"),
+    KeyCode::Insert => seq("\x1b[2~"),
+    KeyCode::Delete => seq("\x1b[3~"),
+    KeyCode::F(n) => match n {
+      1 => seq("\x1bOP"),
+      2 => seq("\x1bOQ"),
+      3 => seq("\x1bOR"),
+      4 => seq("\x1bOS"),
+      5 => seq("\x1b[15~"),
+      6 => seq("\x1b[17~"),
+      7 => seq("\x1b[18~"),
+      8 => seq("\x1b[19~"),
+      9 => seq("\x1b[20~"),
+      10 => seq("\x1b[21~"),
+      11 => seq("\x1b[23~"),
+      12 => seq("\x1b[24~"),
+      _ => return None,
+    },
+    _ => return None,
+  };
+  if key.modifiers.contains(KeyModifiers::ALT) {
+    bytes.insert(0, 0x1b);
+  }
+  Some(bytes)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TermCell {
+  pub ch: char,
+  pub style: Style,
+}
+
+impl Default for TermCell {
+  fn default() -> Self {
+    TermCell {
+      ch: ' ',
+      style: Style::default(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ParseState {
+  Ground,
+  Escape,
+  Csi(String),
+  /// Operating system command (window title etc.), ignored. `true` once an ESC
+  /// has been seen, so `ESC \` can terminate it.
+  Osc(bool),
+  /// `ESC (` / `ESC )` charset selection; the next char is swallowed.
+  Charset,
+}
+
+/// A fixed-size character grid driven by the subset of VT100/xterm control
+/// sequences that shells, pagers and editors commonly emit: cursor movement,
+/// erase, insert/delete, scroll regions, SGR colours and the alternate screen.
+pub struct TermScreen {
+  cols: usize,
+  rows: usize,
+  grid: Vec<Vec<TermCell>>,
+  /// The main screen while the alternate screen is active.
+  main_grid: Option<Vec<Vec<TermCell>>>,
+  row: usize,
+  col: usize,
+  saved_cursor: (usize, usize),
+  /// Set after writing the last column; the next printable char wraps first.
+  pending_wrap: bool,
+  style: Style,
+  scroll_top: usize,
+  scroll_bottom: usize,
+  cursor_visible: bool,
+  state: ParseState,
+  /// Trailing bytes of a UTF-8 sequence split across reads.
+  utf8_tail: Vec<u8>,
+}
+
+impl TermScreen {
+  pub fn new(cols: u16, rows: u16) -> Self {
+    let (cols, rows) = (cols.max(1) as usize, rows.max(1) as usize);
+    TermScreen {
+      cols,
+      rows,
+      grid: vec![vec![TermCell::default(); cols]; rows],
+      main_grid: None,
+      row: 0,
+      col: 0,
+      saved_cursor: (0, 0),
+      pending_wrap: false,
+      style: Style::default(),
+      scroll_top: 0,
+      scroll_bottom: rows - 1,
+      cursor_visible: true,
+      state: ParseState::Ground,
+      utf8_tail: vec![],
+    }
+  }
+
+  pub fn size(&self) -> (u16, u16) {
+    (self.cols as u16, self.rows as u16)
+  }
+
+  pub fn resize(&mut self, cols: u16, rows: u16) {
+    let (cols, rows) = (cols.max(1) as usize, rows.max(1) as usize);
+    // keep the cursor line on screen when shrinking, like a terminal would
+    let drop = (self.row + 1).saturating_sub(rows);
+    resize_grid(&mut self.grid, cols, rows, drop);
+    if let Some(main) = self.main_grid.as_mut() {
+      resize_grid(main, cols, rows, 0);
+    }
+    self.cols = cols;
+    self.rows = rows;
+    self.row = self.row.saturating_sub(drop).min(rows - 1);
+    self.col = self.col.min(cols - 1);
+    self.pending_wrap = false;
+    self.scroll_top = 0;
+    self.scroll_bottom = rows - 1;
+  }
+
+  /// Feed raw TTY output into the screen.
+  pub fn feed(&mut self, data: &[u8]) {
+    let mut bytes = std::mem::take(&mut self.utf8_tail);
+    bytes.extend_from_slice(data);
+    let valid = match std::str::from_utf8(&bytes) {
+      Err(e) if e.error_len().is_none() => e.valid_up_to(),
+      _ => bytes.len(),
+    };
+    self.utf8_tail = bytes.split_off(valid);
+    for ch in String::from_utf8_lossy(&bytes).chars() {
+      self.advance(ch);
+    }
+  }
+
+  /// Render the grid as styled lines; the cursor is drawn reversed when shown.
+  pub fn lines(&self, show_cursor: bool) -> Vec<Line<'static>> {
+    let show_cursor = show_cursor && self.cursor_visible;
+    self
+      .grid
+      .iter()
+      .enumerate()
+      .map(|(r, cells)| {
+        let mut spans: Vec<Span<'static>> = vec![];
+        let mut text = String::new();
+        let mut style = None;
+        for (c, cell) in cells.iter().enumerate() {
+          let mut cell_style = cell.style;
+          if show_cursor && r == self.row && c == self.col {
+            cell_style = cell_style.add_modifier(Modifier::REVERSED);
+          }
+          if let Some(prev) = style.filter(|s| *s != cell_style) {
+            spans.push(Span::styled(std::mem::take(&mut text), prev));
+          }
+          style = Some(cell_style);
+          text.push(cell.ch);
+        }
+        if let Some(style) = style {
+          spans.push(Span::styled(text, style));
+        }
+        Line::from(spans)
+      })
+      .collect()
+  }
+
+  #[cfg(test)]
+  pub fn row_text(&self, row: usize) -> String {
+    self.grid[row]
+      .iter()
+      .map(|c| c.ch)
+      .collect::<String>()
+      .trim_end()
+      .to_string()
+  }
+
+  fn advance(&mut self, ch: char) {
+    match std::mem::replace(&mut self.state, ParseState::Ground) {
+      ParseState::Ground => match ch {
+        '\x1b' => self.state = ParseState::Escape,
+        '\r' => self.carriage_return(),
+        '\n' | '\x0b' | '\x0c' => self.line_feed(),
+        '\x08' => {
+          self.pending_wrap = false;
+          self.col = self.col.saturating_sub(1);
+        }
+        '\t' => {
+          self.pending_wrap = false;
+          self.col = ((self.col / TAB_WIDTH + 1) * TAB_WIDTH).min(self.cols - 1);
+        }
+        c if c.is_control() => {}
+        c => self.print(c),
+      },
+      ParseState::Escape => match ch {
+        '[' => self.state = ParseState::Csi(String::new()),
+        ']' => self.state = ParseState::Osc(false),
+        '(' | ')' | '*' | '+' => self.state = ParseState::Charset,
+        '7' => self.saved_cursor = (self.row, self.col),
+        '8' => self.restore_cursor(),
+        'D' => self.line_feed(),
+        'E' => {
+          self.carriage_return();
+          self.line_feed();
+        }
+        'M' => self.reverse_index(),
+        'c' => *self = TermScreen::new(self.cols as u16, self.rows as u16),
+        _ => {}
+      },
+      ParseState::Csi(mut params) => {
+        if ('\x40'..='\x7e').contains(&ch) {
+          self.csi(&params, ch);
+        } else if ch == '\x1b' {
+          self.state = ParseState::Escape;
+        } else {
+          params.push(ch);
+          self.state = ParseState::Csi(params);
+        }
+      }
+      ParseState::Osc(esc) => match ch {
+        '\x07' => {}
+        '\\' if esc => {}
+        '\x1b' => self.state = ParseState::Osc(true),
+        _ => self.state = ParseState::Osc(false),
+      },
+      ParseState::Charset => {}
+    }
+  }
+
+  fn print(&mut self, ch: char) {
+    if self.pending_wrap {
+      self.carriage_return();
+      self.line_feed();
+    }
+    self.grid[self.row][self.col] = TermCell {
+      ch,
+      style: self.style,
+    };
+    if self.col + 1 >= self.cols {
+      self.pending_wrap = true;
+    } else {
+      self.col += 1;
+    }
+  }
+
+  fn carriage_return(&mut self) {
+    self.pending_wrap = false;
+    self.col = 0;
+  }
+
+  fn line_feed(&mut self) {
+    self.pending_wrap = false;
+    if self.row == self.scroll_bottom {
+      self.scroll_up(1);
+    } else if self.row + 1 < self.rows {
+      self.row += 1;
+    }
+  }
+
+  fn reverse_index(&mut self) {
+    self.pending_wrap = false;
+    if self.row == self.scroll_top {
+      self.scroll_down(1);
+    } else {
+      self.row = self.row.saturating_sub(1);
+    }
+  }
+
+  fn restore_cursor(&mut self) {
+    self.pending_wrap = false;
+    self.row = self.saved_cursor.0.min(self.rows - 1);
+    self.col = self.saved_cursor.1.min(self.cols - 1);
+  }
+
+  /// Scroll the region `[scroll_top, scroll_bottom]` up by `n` lines.
+  fn scroll_up(&mut self, n: usize) {
+    self.scroll_lines_up(self.scroll_top, n);
+  }
+
+  fn scroll_down(&mut self, n: usize) {
+    self.scroll_lines_down(self.scroll_top, n);
+  }
+
+  fn scroll_lines_up(&mut self, top: usize, n: usize) {
+    let bottom = self.scroll_bottom;
+    for _ in 0..n.min(bottom + 1 - top) {
+      self.grid.remove(top);
+      self
+        .grid
+        .insert(bottom, vec![TermCell::default(); self.cols]);
+    }
+  }
+
+  fn scroll_lines_down(&mut self, top: usize, n: usize) {
+    let bottom = self.scroll_bottom;
+    for _ in 0..n.min(bottom + 1 - top) {
+      self.grid.remove(bottom);
+      self.grid.insert(top, vec![TermCell::default(); self.cols]);
+    }
+  }
+
+  fn erase(&mut self, row: usize, cols: std::ops::Range<usize>) {
+    for cell in &mut self.grid[row][cols] {
+      *cell = TermCell::default();
+    }
+  }
+
+  fn csi(&mut self, params: &str, action: char) {
+    let private = params.starts_with('?');
+    let args: Vec<usize> = params
+      .trim_start_matches(['?', '>', '='])
+      .split(';')
+      .map(|p| p.parse().unwrap_or(0))
+      .collect();
+    // first/second arg with 0 and missing both meaning `default`
+    let arg = |i: usize, default: usize| match args.get(i) {
+      Some(0) | None => default,
+      Some(v) => *v,
+    };
+    if action != 'm' {
+      self.pending_wrap = false;
+    }
+    let (rows, cols) = (self.rows, self.cols);
+    match action {
+      'A' => self.row = self.row.saturating_sub(arg(0, 1)),
+      'B' => self.row = (self.row + arg(0, 1)).min(rows - 1),
+      'C' => self.col = (self.col + arg(0, 1)).min(cols - 1),
+      'D' => self.col = self.col.saturating_sub(arg(0, 1)),
+      'E' => {
+        self.row = (self.row + arg(0, 1)).min(rows - 1);
+        self.col = 0;
+      }
+      'F' => {
+        self.row = self.row.saturating_sub(arg(0, 1));
+        self.col = 0;
+      }
+      'G' | '`' => self.col = (arg(0, 1) - 1).min(cols - 1),
+      'd' => self.row = (arg(0, 1) - 1).min(rows - 1),
+      'H' | 'f' => {
+        self.row = (arg(0, 1) - 1).min(rows - 1);
+        self.col = (arg(1, 1) - 1).min(cols - 1);
+      }
+      'J' => match arg(0, 0) {
+        0 => {
+          self.erase(self.row, self.col..cols);
+          for r in self.row + 1..rows {
+            self.erase(r, 0..cols);
+          }
+        }
+        1 => {
+          for r in 0..self.row {
+            self.erase(r, 0..cols);
+          }
+          self.erase(self.row, 0..self.col + 1);
+        }
+        _ => {
+          for r in 0..rows {
+            self.erase(r, 0..cols);
+          }
+        }
+      },
+      'K' => match arg(0, 0) {
+        0 => self.erase(self.row, self.col..cols),
+        1 => self.erase(self.row, 0..self.col + 1),
+        _ => self.erase(self.row, 0..cols),
+      },
+      'L' if (self.scroll_top..=self.scroll_bottom).contains(&self.row) => {
+        self.scroll_lines_down(self.row, arg(0, 1))
+      }
+      'M' if (self.scroll_top..=self.scroll_bottom).contains(&self.row) => {
+        self.scroll_lines_up(self.row, arg(0, 1))
+      }
+      '@' => {
+        let line = &mut self.grid[self.row];
+        for _ in 0..arg(0, 1).min(cols - self.col) {
+          line.pop();
+          line.insert(self.col, TermCell::default());
+        }
+      }
+      'P' => {
+        let line = &mut self.grid[self.row];
+        for _ in 0..arg(0, 1).min(cols - self.col) {
+          line.remove(self.col);
+          line.push(TermCell::default());
+        }
+      }
+      'X' => {
+        let end = (self.col + arg(0, 1)).min(cols);
+        self.erase(self.row, self.col..end);
+      }
+      'S' => self.scroll_up(arg(0, 1)),
+      'T' => self.scroll_down(arg(0, 1)),
+      'r' => {
+        let top = arg(0, 1) - 1;
+        let bottom = arg(1, rows).min(rows) - 1;
+        if top < bottom {
+          self.scroll_top = top;
+          self.scroll_bottom = bottom;
+          self.row = 0;
+          self.col = 0;
+        }
+      }
+      's' => self.saved_cursor = (self.row, self.col),
+      'u' => self.restore_cursor(),
+      'm' => self.sgr(&args),
+      'h' | 'l' if private => self.set_private_mode(&args, action == 'h'),
+      _ => {}
+    }
+  }
+
+  fn set_private_mode(&mut self, args: &[usize], enable: bool) {
+    for mode in args {
+      match mode {
+        25 => self.cursor_visible = enable,
+        47 | 1047 | 1049 => {
+          if enable && self.main_grid.is_none() {
+            if *mode == 1049 {
+              self.saved_cursor = (self.row, self.col);
+            }
+            let blank = vec![vec![TermCell::default(); self.cols]; self.rows];
+            self.main_grid = Some(std::mem::replace(&mut self.grid, blank));
+          } else if !enable {
+            if let Some(main) = self.main_grid.take() {
+              self.grid = main;
+              if *mode == 1049 {
+                self.restore_cursor();
+              }
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn sgr(&mut self, args: &[usize]) {
+    let mut i = 0;
+    while i < args.len() {
+      match args[i] {
+        0 => self.style = Style::default(),
+        1 => self.style = self.style.add_modifier(Modifier::BOLD),
+        2 => self.style = self.style.add_modifier(Modifier::DIM),
+        3 => self.style = self.style.add_modifier(Modifier::ITALIC),
+        4 => self.style = self.style.add_modifier(Modifier::UNDERLINED),
+        7 => self.style = self.style.add_modifier(Modifier::REVERSED),
+        22 => self.style = self.style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+        23 => self.style = self.style.remove_modifier(Modifier::ITALIC),
+        24 => self.style = self.style.remove_modifier(Modifier::UNDERLINED),
+        27 => self.style = self.style.remove_modifier(Modifier::REVERSED),
+        n @ 30..=37 => self.style.fg = Some(ansi_color(n - 30)),
+        n @ 90..=97 => self.style.fg = Some(ansi_color(n - 90 + 8)),
+        n @ 40..=47 => self.style.bg = Some(ansi_color(n - 40)),
+        n @ 100..=107 => self.style.bg = Some(ansi_color(n - 100 + 8)),
+        39 => self.style.fg = None,
+        49 => self.style.bg = None,
+        n @ (38 | 48) => {
+          let color = match args.get(i + 1) {
+            Some(5) => {
+              i += 2;
+              args.get(i).map(|c| Color::Indexed(*c as u8))
+            }
+            Some(2) => {
+              i += 4;
+              match args.get(i - 2..=i) {
+                Some([r, g, b]) => Some(Color::Rgb(*r as u8, *g as u8, *b as u8)),
+                _ => None,
+              }
+            }
+            _ => None,
+          };
+          if n == 38 {
+            self.style.fg = color.or(self.style.fg);
+          } else {
+            self.style.bg = color.or(self.style.bg);
+          }
+        }
+        _ => {}
+      }
+      i += 1;
+    }
+  }
+}
+
+fn resize_grid(grid: &mut Vec<Vec<TermCell>>, cols: usize, rows: usize, drop_top: usize) {
+  grid.drain(..drop_top.min(grid.len()));
+  grid.resize(rows, vec![TermCell::default(); cols]);
+  for line in grid.iter_mut() {
+    line.resize(cols, TermCell::default());
+  }
+}
+
+fn ansi_color(n: usize) -> Color {
+  match n {
+    0 => Color::Black,
+    1 => Color::Red,
+    2 => Color::Green,
+    3 => Color::Yellow,
+    4 => Color::Blue,
+    5 => Color::Magenta,
+    6 => Color::Cyan,
+    7 => Color::Gray,
+    8 => Color::DarkGray,
+    9 => Color::LightRed,
+    10 => Color::LightGreen,
+    11 => Color::LightYellow,
+    12 => Color::LightBlue,
+    13 => Color::LightMagenta,
+    14 => Color::LightCyan,
+    _ => Color::White,
+  }
+}
+
+pub(crate) fn draw_exec_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let palette = app.palette;
+  let Some(session) = app.exec_session.as_mut() else {
+    let block = layout_block_top_border(Line::from(" Shell "), palette);
+    loading(f, block, area, false, palette);
+    return;
+  };
+
+  let title = format!(
+    " Shell ({}/{} · {}) ",
+    session.target.pod,
+    session.target.container,
+    session.status.label()
+  );
+  let hint = if session.is_running() {
+    format!("{}:close ", DEFAULT_KEYBINDING.close_shell.key.symbol())
+  } else {
+    format!("{}:back ", DEFAULT_KEYBINDING.esc.key.symbol())
+  };
+  let block = layout_block_top_border(
+    title_with_dual_style(title, help_bold_line(hint, palette), palette),
+    palette,
+  );
+
+  let inner = block.inner(area);
+  session.resize(inner.width, inner.height);
+  let paragraph = Paragraph::new(session.screen.lines(session.is_running()))
+    .block(block)
+    .style(style_text(palette));
+  f.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use tokio::sync::mpsc;
+
+  fn target() -> ShellExecTarget {
+    ShellExecTarget {
+      namespace: "default".into(),
+      pod: "api-123".into(),
+      container: "web".into(),
+    }
+  }
+
+  #[test]
+  fn test_term_screen_prints_wraps_and_scrolls() {
+    let mut screen = TermScreen::new(5, 2);
+    screen.feed(b"hello world\r\nnext");
+
+    // "hello" fills row 0, " worl" wraps, "d" wraps again and scrolls
+    assert_eq!(screen.row_text(0), "d");
+    assert_eq!(screen.row_text(1), "next");
+  }
+
+  #[test]
+  fn test_term_screen_handles_cursor_moves_and_erase() {
+    let mut screen = TermScreen::new(10, 3);
+    screen.feed(b"abcdef\x1b[1;3HX\x1b[K\x1b[3;1Hlast\x1b[2J\x1b[H$ ");
+    assert_eq!(screen.row_text(0), "$");
+    assert_eq!(screen.row_text(2), "");
+
+    let mut line = TermScreen::new(10, 3);
+    line.feed(b"abcdef\x1b[1;3HX\x1b[K");
+    assert_eq!(line.row_text(0), "abX");
+    line.feed(b"\r\x1b[2P");
+    assert_eq!(line.row_text(0), "X");
+  }
+
+  #[test]
+  fn test_term_screen_applies_sgr_colours() {
+    let mut screen = TermScreen::new(10, 1);
+    screen.feed(b"\x1b[1;31mE\x1b[0m \x1b[38;5;82mi\x1b[38;2;1;2;3mr");
+
+    assert_eq!(screen.grid[0][0].style.fg, Some(Color::Red));
+    assert!(screen.grid[0][0]
+      .style
+      .add_modifier
+      .contains(Modifier::BOLD));
+    assert_eq!(screen.grid[0][1].style, Style::default());
+    assert_eq!(screen.grid[0][2].style.fg, Some(Color::Indexed(82)));
+    assert_eq!(screen.grid[0][3].style.fg, Some(Color::Rgb(1, 2, 3)));
+  }
+
+  #[test]
+  fn test_term_screen_alternate_screen_restores_main() {
+    let mut screen = TermScreen::new(10, 2);
+    screen.feed(b"$ vi");
+    screen.feed(b"\x1b[?1049h\x1b[Hediting");
+    assert_eq!(screen.row_text(0), "editing");
+    screen.feed(b"\x1b[?1049l");
+    assert_eq!(screen.row_text(0), "$ vi");
+    assert_eq!((screen.row, screen.col), (0, 4));
+  }
+
+  #[test]
+  fn test_term_screen_ignores_osc_and_joins_split_utf8() {
+    let mut screen = TermScreen::new(10, 1);
+    screen.feed(b"\x1b]0;user@pod: ~\x07\xc3");
+    screen.feed(b"\xa9!");
+    assert_eq!(screen.row_text(0), "é!");
+  }
+
+  #[test]
+  fn test_term_screen_scroll_region() {
+    let mut screen = TermScreen::new(4, 3);
+    screen.feed(b"top\r\nmid\r\nbot");
+    // restrict scrolling to the bottom two rows, then scroll them
+    screen.feed(b"\x1b[2;3r\x1b[3;1H\n");
+    assert_eq!(screen.row_text(0), "top");
+    assert_eq!(screen.row_text(1), "bot");
+    assert_eq!(screen.row_text(2), "");
+  }
+
+  #[test]
+  fn test_term_screen_resize_keeps_cursor_line() {
+    let mut screen = TermScreen::new(10, 4);
+    screen.feed(b"1\r\n2\r\n3\r\n$ ");
+    screen.resize(6, 2);
+    assert_eq!(screen.size(), (6, 2));
+    assert_eq!(screen.row_text(0), "3");
+    assert_eq!(screen.row_text(1), "$");
+    assert_eq!((screen.row, screen.col), (1, 2));
+  }
+
+  #[test]
+  fn test_key_event_bytes() {
+    let key = |code| KeyEvent::from(code);
+    assert_eq!(
+      key_event_bytes(key(KeyCode::Char('a'))),
+      Some(b"a".to_vec())
+    );
+    assert_eq!(key_event_bytes(key(KeyCode::Enter)), Some(b"\r".to_vec()));
+    assert_eq!(key_event_bytes(key(KeyCode::Up)), Some(b"\x1b[A".to_vec()));
+    assert_eq!(
+      key_event_bytes(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+      Some(vec![3])
+    );
+    assert_eq!(
+      key_event_bytes(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT)),
+      Some(b"\x1bb".to_vec())
+    );
+    assert_eq!(key_event_bytes(key(KeyCode::CapsLock)), None);
+  }
+
+  #[test]
+  fn test_exec_session_forwards_input_and_resize() {
+    let mut session = ExecSession::new(1, target());
+    assert!(!session.is_running());
+    assert_eq!(session.status.label(), "connecting…");
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    assert_eq!(session.attach("/bin/sh".into(), tx), (80, 24));
+    assert!(session.is_running());
+
+    session.send_input(b"ls\r".to_vec());
+    session.resize(100, 30);
+    // unchanged size is not re-sent
+    session.resize(100, 30);
+    assert_eq!(rx.try_recv(), Ok(ExecInput::Data(b"ls\r".to_vec())));
+    assert_eq!(
+      rx.try_recv(),
+      Ok(ExecInput::Resize {
+        cols: 100,
+        rows: 30
+      })
+    );
+    assert!(rx.try_recv().is_err());
+
+    session.finish("exited");
+    assert!(!session.is_running());
+    assert_eq!(session.status, ExecStatus::Exited("exited".into()));
+  }
+}
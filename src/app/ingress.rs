@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self, UNKNOWN},
   ActiveBlock, App,
 };
@@ -97,6 +97,22 @@ impl Named for KubeIngress {
   }
 }
 
+impl Sortable for KubeIngress {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Ingress class" => SortValue::text(&self.ingress_class),
+      "Paths" => SortValue::text(&self.paths),
+      "Default backend" => SortValue::text(&self.default_backend),
+      "Addresses" => SortValue::text(&self.address),
+      "TLS" => SortValue::text(&self.tls),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Ingress> for KubeIngress {
   fn get_k8s_obj(&self) -> &Ingress {
     &self.k8s_obj
@@ -198,10 +214,8 @@ impl AppResource for IngressResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeIngress> = nw.get_namespaced_resources(Ingress::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.ingress.set_items(items);
+    nw.load_namespaced_resources(Ingress::into, |data| &mut data.ingress)
+      .await;
   }
 }
 
@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{self, AppResource, KubeResource, Named},
+  models::{self, AppResource, KubeResource, Named, SortValue, Sortable},
   utils, ActiveBlock, App,
 };
 use crate::{
@@ -98,6 +98,21 @@ impl Named for KubeJob {
   }
 }
 
+impl Sortable for KubeJob {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Completions" => SortValue::text(&self.completions),
+      "Duration" => SortValue::age(&self.duration),
+      "Status (A/S/F)" => SortValue::text(&self.status_summary),
+      "Backoff Limit" => SortValue::text(&self.backoff_limit),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Job> for KubeJob {
   fn get_k8s_obj(&self) -> &Job {
     &self.k8s_obj
@@ -137,10 +152,8 @@ impl AppResource for JobResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeJob> = nw.get_namespaced_resources(Job::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.jobs.set_items(items);
+    nw.load_namespaced_resources(Job::into, |data| &mut data.jobs)
+      .await;
   }
 }
 
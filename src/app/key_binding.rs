@@ -68,6 +68,8 @@ generate_keybindings! {
   jump_to_troubleshoot,
   copy_to_clipboard,
   dump_error_log,
+  capture_snapshot,
+  tail_logs,
   pg_up,
   pg_down,
   home,
@@ -78,6 +80,7 @@ generate_keybindings! {
   right,
   toggle_info,
   shell_exec,
+  close_shell,
   log_auto_scroll,
   select_all_namespace,
   open_action_menu,
@@ -105,8 +108,23 @@ generate_keybindings! {
   port_forwards_list,
   cycle_group_by,
   toggle_wide_columns,
+  sort_column,
+  toggle_mark,
+  toggle_mark_all,
   toggle_log_timestamps,
-  toggle_log_wrap
+  toggle_log_wrap,
+  toggle_log_raw,
+  log_search,
+  log_search_next,
+  log_search_prev,
+  log_filter_include,
+  log_filter_exclude,
+  log_min_level,
+  save_logs,
+  tee_logs,
+  log_since,
+  log_jump_to_time,
+  log_split_previous
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
@@ -246,6 +264,18 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Dump recent errors to file",
     context: HContext::General,
   },
+  capture_snapshot: KeyBinding {
+    key: Key::Shift('s'),
+    alt: None,
+    desc: "Capture a cluster snapshot to a directory",
+    context: HContext::General,
+  },
+  tail_logs: KeyBinding {
+    key: Key::Shift('p'),
+    alt: None,
+    desc: "Tail logs from every pod matching a regex",
+    context: HContext::General,
+  },
   down: KeyBinding {
     key: Key::Down,
     alt: Some(Key::Char('j')),
@@ -306,6 +336,12 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Open shell in selected container",
     context: HContext::Overview,
   },
+  close_shell: KeyBinding {
+    key: Key::Ctrl('q'),
+    alt: None,
+    desc: "Close the container shell pane",
+    context: HContext::Overview,
+  },
   log_auto_scroll: KeyBinding {
     key: Key::Char('s'),
     alt: None,
@@ -468,6 +504,24 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Toggle wide view (show all columns)",
     context: HContext::General,
   },
+  sort_column: KeyBinding {
+    key: Key::Char('o'),
+    alt: None,
+    desc: "Sort by next column (ascending, then descending)",
+    context: HContext::General,
+  },
+  toggle_mark: KeyBinding {
+    key: Key::Char(' '),
+    alt: None,
+    desc: "Mark/unmark row for a bulk action",
+    context: HContext::General,
+  },
+  toggle_mark_all: KeyBinding {
+    key: Key::Ctrl('a'),
+    alt: None,
+    desc: "Mark/unmark all visible rows",
+    context: HContext::General,
+  },
   toggle_log_timestamps: KeyBinding {
     key: Key::Char('t'),
     alt: None,
@@ -480,6 +534,78 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Toggle log line wrap",
     context: HContext::Logs,
   },
+  toggle_log_raw: KeyBinding {
+    key: Key::Char('r'),
+    alt: None,
+    desc: "Toggle raw/structured JSON and logfmt lines",
+    context: HContext::Logs,
+  },
+  log_search: KeyBinding {
+    key: Key::Char('/'),
+    alt: None,
+    desc: "Search log lines",
+    context: HContext::Logs,
+  },
+  log_search_next: KeyBinding {
+    key: Key::Char('n'),
+    alt: None,
+    desc: "Next search match",
+    context: HContext::Logs,
+  },
+  log_search_prev: KeyBinding {
+    key: Key::Shift('n'),
+    alt: None,
+    desc: "Previous search match",
+    context: HContext::Logs,
+  },
+  log_filter_include: KeyBinding {
+    key: Key::Char('f'),
+    alt: None,
+    desc: "Show only log lines matching a regex",
+    context: HContext::Logs,
+  },
+  log_filter_exclude: KeyBinding {
+    key: Key::Char('x'),
+    alt: None,
+    desc: "Hide log lines matching a regex",
+    context: HContext::Logs,
+  },
+  log_min_level: KeyBinding {
+    key: Key::Char('v'),
+    alt: None,
+    desc: "Cycle minimum log level",
+    context: HContext::Logs,
+  },
+  save_logs: KeyBinding {
+    key: Key::Shift('w'),
+    alt: None,
+    desc: "Write log buffer to a file",
+    context: HContext::Logs,
+  },
+  tee_logs: KeyBinding {
+    key: Key::Ctrl('w'),
+    alt: None,
+    desc: "Toggle writing new log lines to a file (tee)",
+    context: HContext::Logs,
+  },
+  log_since: KeyBinding {
+    key: Key::Shift('h'),
+    alt: None,
+    desc: "Fetch logs since a duration or time",
+    context: HContext::Logs,
+  },
+  log_jump_to_time: KeyBinding {
+    key: Key::Char('g'),
+    alt: None,
+    desc: "Jump to the first log line at or after a time",
+    context: HContext::Logs,
+  },
+  log_split_previous: KeyBinding {
+    key: Key::Char('p'),
+    alt: None,
+    desc: "Compare with the previous instance side by side (←/→ focus)",
+    context: HContext::Logs,
+  },
 };
 
 static ACTIVE_KEYBINDINGS: OnceLock<KeyBindings> = OnceLock::new();
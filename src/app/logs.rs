@@ -0,0 +1,1204 @@
+//! Log view helpers that sit on top of [`LogsState`](super::models::LogsState):
+//! incremental `/` search with match navigation, include/exclude filters,
+//! structured (JSON / logfmt) line parsing, time windows, multi-pod tail
+//! queries, the previous/current split, saving to disk, and the styles a log
+//! line is rendered with.
+
+use std::{
+  fs::File,
+  io::{self, BufWriter, Write},
+  ops::Range,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use ratatui::{
+  style::Style,
+  text::{Line, Span},
+};
+use regex::Regex;
+use serde_json::Value;
+
+use chrono::{DateTime, Local, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc};
+use k8s_openapi::jiff;
+use kube::api::LogParams;
+
+use super::models::LogsState;
+use crate::config::{LogFieldNames, DEFAULT_LOG_MAX_LINES};
+
+/// How much of a stream the log view keeps; past either cap the oldest records
+/// are dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LogLimits {
+  pub max_lines: Option<usize>,
+  /// Counts the received text plus its structured rendering.
+  pub max_bytes: Option<usize>,
+}
+
+impl Default for LogLimits {
+  fn default() -> Self {
+    LogLimits {
+      max_lines: Some(DEFAULT_LOG_MAX_LINES),
+      max_bytes: None,
+    }
+  }
+}
+
+impl LogLimits {
+  pub fn exceeded(&self, lines: usize, bytes: usize) -> bool {
+    self.max_lines.is_some_and(|max| lines > max) || self.max_bytes.is_some_and(|max| bytes > max)
+  }
+}
+
+/// The previous container instance shown beside the live logs.
+pub struct LogSplit {
+  pub previous: LogsState,
+  /// How the previous instance ended (`lastState.terminated`), once fetched.
+  pub terminated: Option<String>,
+  /// Keys scroll, jump and copy in the previous pane instead of the live one.
+  pub focus_previous: bool,
+  /// The previous pane shows its last lines until it is scrolled.
+  pub follow: bool,
+}
+
+impl LogSplit {
+  pub fn new(previous: LogsState) -> Self {
+    LogSplit {
+      previous,
+      terminated: None,
+      focus_previous: false,
+      follow: true,
+    }
+  }
+}
+
+/// Styles the log pane renders with, resolved from the active palette.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LogStyles {
+  pub text: Style,
+  /// Every occurrence of the search query.
+  pub search_match: Style,
+  pub error: Style,
+  pub warn: Style,
+  pub info: Style,
+  /// Debug and trace.
+  pub debug: Style,
+  /// `[pod]` prefixes in aggregate views; each pod name keeps one of these.
+  pub prefixes: [Style; 5],
+}
+
+impl LogStyles {
+  /// Style for a `[pod]` prefix, picked by a hash of the name so every line
+  /// from a pod gets the same colour.
+  pub fn prefix(&self, name: &str) -> Style {
+    // FNV-1a: stable across runs, unlike the std hasher.
+    let hash = name.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+      (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    self.prefixes[(hash % self.prefixes.len() as u64) as usize]
+  }
+
+  pub fn level(&self, level: LogLevel) -> Style {
+    match level {
+      LogLevel::Error => self.error,
+      LogLevel::Warn => self.warn,
+      LogLevel::Info => self.info,
+      LogLevel::Debug | LogLevel::Trace => self.debug,
+    }
+  }
+}
+
+/// Severity of a log record, ordered from least to most severe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+  Trace,
+  Debug,
+  Info,
+  Warn,
+  Error,
+}
+
+impl LogLevel {
+  /// Read a structured `level` value: a name (`warning`, `ERR`, `fatal`, ...)
+  /// or a bunyan/pino number (`30` is info, `50` is error).
+  pub fn parse(value: &str) -> Option<LogLevel> {
+    if let Ok(number) = value.parse::<u32>() {
+      return match number {
+        0..=19 => Some(LogLevel::Trace),
+        20..=29 => Some(LogLevel::Debug),
+        30..=39 => Some(LogLevel::Info),
+        40..=49 => Some(LogLevel::Warn),
+        _ => Some(LogLevel::Error),
+      };
+    }
+    let value = value.to_ascii_lowercase();
+    let starts = |prefixes: &[&str]| prefixes.iter().any(|p| value.starts_with(p));
+    if starts(&["err", "fatal", "panic", "crit", "emerg", "alert", "severe"]) {
+      Some(LogLevel::Error)
+    } else if starts(&["warn"]) {
+      Some(LogLevel::Warn)
+    } else if starts(&["info", "notice"]) {
+      Some(LogLevel::Info)
+    } else if starts(&["debug", "dbg"]) {
+      Some(LogLevel::Debug)
+    } else if starts(&["trace"]) {
+      Some(LogLevel::Trace)
+    } else {
+      None
+    }
+  }
+
+  /// A bare level word as printed by plain-text loggers: `ERROR`, `warning`,
+  /// `[INFO]`, `E:`. Exact, so `errors` or `information` don't count.
+  fn from_token(token: &str) -> Option<LogLevel> {
+    match token.to_ascii_uppercase().as_str() {
+      "ERROR" | "ERR" | "FATAL" | "PANIC" | "CRITICAL" | "CRIT" | "SEVERE" => Some(LogLevel::Error),
+      "WARN" | "WARNING" => Some(LogLevel::Warn),
+      "INFO" | "NOTICE" => Some(LogLevel::Info),
+      "DEBUG" | "DBG" => Some(LogLevel::Debug),
+      "TRACE" => Some(LogLevel::Trace),
+      _ => None,
+    }
+  }
+
+  /// Next minimum level for the level filter: off, then debug up to error.
+  pub fn cycle_min(current: Option<LogLevel>) -> Option<LogLevel> {
+    match current {
+      None => Some(LogLevel::Debug),
+      Some(LogLevel::Trace | LogLevel::Debug) => Some(LogLevel::Info),
+      Some(LogLevel::Info) => Some(LogLevel::Warn),
+      Some(LogLevel::Warn) => Some(LogLevel::Error),
+      Some(LogLevel::Error) => None,
+    }
+  }
+
+  pub fn label(self) -> &'static str {
+    match self {
+      LogLevel::Trace => "TRACE",
+      LogLevel::Debug => "DEBUG",
+      LogLevel::Info => "INFO",
+      LogLevel::Warn => "WARN",
+      LogLevel::Error => "ERROR",
+    }
+  }
+}
+
+/// `/` search over the log records. Matching is a case-insensitive substring
+/// test; a match is a record (line) containing the query, and `n`/`N` step
+/// through them in record order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LogSearch {
+  pub query: String,
+  /// Keys go to the query until `Enter` or `Esc`.
+  pub input_active: bool,
+  /// Indices into the log records that contain the query, ascending.
+  matches: Vec<usize>,
+  /// Position in `matches` of the match last jumped to.
+  current: Option<usize>,
+}
+
+impl LogSearch {
+  pub fn is_active(&self) -> bool {
+    !self.query.is_empty()
+  }
+
+  pub fn begin_input(&mut self) {
+    self.input_active = true;
+  }
+
+  pub fn clear(&mut self) {
+    *self = LogSearch::default();
+  }
+
+  pub fn match_count(&self) -> usize {
+    self.matches.len()
+  }
+
+  /// 1-based position of the current match, for the `[i/N]` counter.
+  pub fn current_position(&self) -> Option<usize> {
+    self.current.map(|i| i + 1)
+  }
+
+  /// Record index of the current match.
+  pub fn current_record(&self) -> Option<usize> {
+    self.current.and_then(|i| self.matches.get(i).copied())
+  }
+
+  pub fn is_match(&self, record: usize) -> bool {
+    self.matches.binary_search(&record).is_ok()
+  }
+
+  /// Recompute the matches over the visible `(index, text)` records after the
+  /// query or filter changed, and move to the newest one.
+  pub fn rebuild<'a>(&mut self, records: impl Iterator<Item = (usize, &'a str)>) {
+    self.matches = if self.is_active() {
+      records
+        .filter(|(_, text)| !match_ranges(text, &self.query).is_empty())
+        .map(|(i, _)| i)
+        .collect()
+    } else {
+      vec![]
+    };
+    self.current = self.matches.len().checked_sub(1);
+  }
+
+  /// Index visible `(index, text)` records appended by a running stream.
+  pub fn extend<'a>(&mut self, records: impl Iterator<Item = (usize, &'a str)>) {
+    if !self.is_active() {
+      return;
+    }
+    self.matches.extend(
+      records
+        .filter(|(_, text)| !match_ranges(text, &self.query).is_empty())
+        .map(|(i, _)| i),
+    );
+    if self.current.is_none() && !self.matches.is_empty() {
+      self.current = Some(0);
+    }
+  }
+
+  /// Shift the matches after `count` records were dropped from the front.
+  pub fn evict(&mut self, count: usize) {
+    if count == 0 || self.matches.is_empty() {
+      return;
+    }
+    let dropped = self.matches.partition_point(|&i| i < count);
+    self.matches.drain(..dropped);
+    for index in &mut self.matches {
+      *index -= count;
+    }
+    self.current = match self.current {
+      _ if self.matches.is_empty() => None,
+      Some(i) => Some(i.saturating_sub(dropped)),
+      None => None,
+    };
+  }
+
+  /// Step to the following match, wrapping to the first. Returns its record.
+  pub fn next(&mut self) -> Option<usize> {
+    if self.matches.is_empty() {
+      return None;
+    }
+    self.current = Some(match self.current {
+      Some(i) if i + 1 < self.matches.len() => i + 1,
+      _ => 0,
+    });
+    self.current_record()
+  }
+
+  /// Step to the preceding match, wrapping to the last. Returns its record.
+  pub fn prev(&mut self) -> Option<usize> {
+    if self.matches.is_empty() {
+      return None;
+    }
+    self.current = Some(match self.current {
+      Some(i) if i > 0 => i - 1,
+      _ => self.matches.len() - 1,
+    });
+    self.current_record()
+  }
+}
+
+/// Include/exclude regex and minimum-level filters over the log records.
+/// Records that don't pass are hidden from the pane, not dropped, so clearing a
+/// filter brings them back.
+#[derive(Clone, Debug, Default)]
+pub struct LogFilter {
+  /// Show only records matching this.
+  pub include: Option<Regex>,
+  /// Hide records matching this.
+  pub exclude: Option<Regex>,
+  /// Hide records below this level, and those with no level at all.
+  pub min_level: Option<LogLevel>,
+}
+
+impl LogFilter {
+  pub fn is_active(&self) -> bool {
+    self.include.is_some() || self.exclude.is_some() || self.min_level.is_some()
+  }
+
+  pub fn allows(&self, text: &str, level: Option<LogLevel>) -> bool {
+    self.include.as_ref().is_none_or(|re| re.is_match(text))
+      && self.exclude.as_ref().is_none_or(|re| !re.is_match(text))
+      && self
+        .min_level
+        .is_none_or(|min| level.is_some_and(|level| level >= min))
+  }
+
+  /// `+include -exclude >=LEVEL`, for the log title.
+  pub fn label(&self) -> String {
+    let parts = [("+", &self.include), ("-", &self.exclude)];
+    parts
+      .iter()
+      .filter_map(|(sign, re)| re.as_ref().map(|re| format!("{}{}", sign, re.as_str())))
+      .chain(self.min_level.map(|level| format!(">={}", level.label())))
+      .collect::<Vec<_>>()
+      .join(" ")
+  }
+}
+
+/// Compile a filter pattern from the input modal; a blank pattern clears the
+/// filter.
+pub fn parse_filter(pattern: &str) -> Result<Option<Regex>, String> {
+  let pattern = pattern.trim();
+  if pattern.is_empty() {
+    return Ok(None);
+  }
+  Regex::new(pattern)
+    .map(Some)
+    .map_err(|e| format!("Invalid regex: {}", e))
+}
+
+/// What the multi-pod tail follows: pods whose name matches `pod`, their
+/// containers matching `container`, in one namespace or all of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogTailQuery {
+  pub pod: String,
+  pub container: Option<String>,
+  /// `None` tails every namespace.
+  pub namespace: Option<String>,
+}
+
+impl LogTailQuery {
+  /// Parse `<pod-regex> [-c <container-regex>] [-n <namespace> | -A]`, as
+  /// stern takes them. Without `-n`/`-A` the tail covers `default_ns`.
+  pub fn parse(input: &str, default_ns: Option<&str>) -> Result<LogTailQuery, String> {
+    let usage = || "Enter <pod-regex> [-c <container-regex>] [-n <namespace> | -A]".to_owned();
+    let mut pod = None;
+    let mut container = None;
+    let mut namespace = default_ns.map(str::to_owned);
+    let mut words = input.split_whitespace();
+    while let Some(word) = words.next() {
+      match word {
+        "-c" => container = Some(words.next().ok_or_else(usage)?.to_owned()),
+        "-n" => namespace = Some(words.next().ok_or_else(usage)?.to_owned()),
+        "-A" => namespace = None,
+        _ if pod.is_none() && !word.starts_with('-') => pod = Some(word.to_owned()),
+        _ => return Err(usage()),
+      }
+    }
+    let query = LogTailQuery {
+      pod: pod.ok_or_else(usage)?,
+      container,
+      namespace,
+    };
+    query.regexes()?;
+    Ok(query)
+  }
+
+  /// The pod and container patterns, compiled.
+  pub fn regexes(&self) -> Result<(Regex, Option<Regex>), String> {
+    let pod = parse_filter(&self.pod)?.unwrap_or_else(|| Regex::new(".").unwrap());
+    let container = match &self.container {
+      Some(pattern) => parse_filter(pattern)?,
+      None => None,
+    };
+    Ok((pod, container))
+  }
+
+  /// The query as it would be typed, for the log title.
+  pub fn label(&self) -> String {
+    let mut label = self.pod.clone();
+    if let Some(container) = &self.container {
+      label.push_str(&format!(" -c {}", container));
+    }
+    match &self.namespace {
+      Some(namespace) => label.push_str(&format!(" -n {}", namespace)),
+      None => label.push_str(" -A"),
+    }
+    label
+  }
+}
+
+/// A JSON or logfmt record rendered as `time LEVEL message key=value ...`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructuredLine {
+  pub line: String,
+  /// The recognised level and where its label sits in `line`.
+  pub level: Option<(LogLevel, Range<usize>)>,
+}
+
+/// Parse a record whose payload is a JSON object or logfmt pairs. A `[pod]`
+/// prefix and a Kubernetes RFC 3339 timestamp in front of the payload are
+/// kept as-is. Returns `None` for anything else, including payloads that have
+/// none of the configured time, level or message fields.
+pub fn parse_structured(text: &str, names: &LogFieldNames) -> Option<StructuredLine> {
+  let start = payload_start(text);
+  let payload = text[start..].trim_end();
+  let mut fields = if payload.starts_with('{') {
+    json_fields(payload)?
+  } else {
+    logfmt_fields(payload)?
+  };
+  let mut take = |keys: &[String]| {
+    keys.iter().find_map(|key| {
+      let index = fields.iter().position(|(name, _)| name == key)?;
+      Some(fields.remove(index).1)
+    })
+  };
+  let time = take(&names.time);
+  let level = take(&names.level);
+  let message = take(&names.message);
+  if time.is_none() && level.is_none() && message.is_none() {
+    return None;
+  }
+
+  let mut line = text[..start].to_owned();
+  let push = |part: &str, out: &mut String| {
+    if !out.is_empty() && !out.ends_with(' ') {
+      out.push(' ');
+    }
+    out.push_str(part);
+  };
+  if let Some(time) = &time {
+    push(time, &mut line);
+  }
+  let level = level.map(|value| match LogLevel::parse(&value) {
+    Some(parsed) => {
+      push(parsed.label(), &mut line);
+      Some((parsed, line.len() - parsed.label().len()..line.len()))
+    }
+    None => {
+      push(&value.to_uppercase(), &mut line);
+      None
+    }
+  });
+  if let Some(message) = &message {
+    push(message, &mut line);
+  }
+  for (key, value) in &fields {
+    let value = if value.is_empty() || value.contains(char::is_whitespace) {
+      format!("{:?}", value)
+    } else {
+      value.clone()
+    };
+    push(&format!("{}={}", key, value), &mut line);
+  }
+  Some(StructuredLine {
+    line,
+    level: level.flatten(),
+  })
+}
+
+/// Level of a plain-text record and the part of it to colour: the first level
+/// word among the leading few (`ERROR`, `[warn]`, `level=info`, klog's
+/// `E0501`), or the whole line for panics and stack-trace frames.
+pub fn detect_level(text: &str) -> Option<(LogLevel, Range<usize>)> {
+  let start = payload_start(text);
+  let payload = &text[start..];
+  let trimmed = payload.trim_start();
+  let indented = trimmed.len() < payload.len();
+  let whole_line = Some((
+    LogLevel::Error,
+    start + payload.len() - trimmed.len()..text.len(),
+  ));
+  if indented
+    && ["at ", "File \"", "... "]
+      .iter()
+      .any(|frame| trimmed.starts_with(frame))
+  {
+    return whole_line;
+  }
+  if [
+    "panic:",
+    "goroutine ",
+    "Traceback (most recent call last)",
+    "Exception in thread",
+    "Caused by:",
+  ]
+  .iter()
+  .any(|marker| trimmed.starts_with(marker))
+    || trimmed.contains("panicked at")
+  {
+    return whole_line;
+  }
+
+  let mut offset = start;
+  for word in payload.split(' ').take(4) {
+    let word_start = offset;
+    offset += word.len() + 1;
+    let (core_offset, core) = match word.split_once('=') {
+      Some((key, value)) if key.eq_ignore_ascii_case("level") => (key.len() + 1, value),
+      _ => (0, word),
+    };
+    let token = core.trim_start_matches(['[', '(', '<']);
+    let lead = core.len() - token.len();
+    let token = token.trim_end_matches([']', ')', '>', ':', ',', '|']);
+    let range = word_start + core_offset + lead..word_start + core_offset + lead + token.len();
+    if let Some(level) = LogLevel::from_token(token) {
+      return Some((level, range));
+    }
+    if let Some(level) = klog_level(token) {
+      return Some((level, range.start..range.start + 1));
+    }
+  }
+  None
+}
+
+/// klog's `Lmmdd` header, e.g. `E0501` for an error on May 1st.
+fn klog_level(token: &str) -> Option<LogLevel> {
+  let (letter, date) = token.split_at_checked(1)?;
+  if date.len() != 4 || !date.bytes().all(|b| b.is_ascii_digit()) {
+    return None;
+  }
+  match letter {
+    "E" | "F" => Some(LogLevel::Error),
+    "W" => Some(LogLevel::Warn),
+    "I" => Some(LogLevel::Info),
+    _ => None,
+  }
+}
+
+/// The `[pod]` prefix aggregate streams put in front of each line, and the
+/// name inside it.
+pub fn pod_prefix(text: &str) -> Option<(Range<usize>, &str)> {
+  let end = text.strip_prefix('[')?.find("] ")? + 1;
+  Some((0..end + 1, &text[1..end]))
+}
+
+/// Byte offset where a record's payload starts, past a `[pod] ` prefix and a
+/// leading RFC 3339 timestamp.
+fn payload_start(text: &str) -> usize {
+  let start = pod_prefix(text).map_or(0, |(range, _)| range.end + 1);
+  match leading_timestamp(&text[start..]) {
+    Some((_, len)) => start + len + 1,
+    None => start,
+  }
+}
+
+/// The RFC 3339 timestamp Kubernetes puts in front of a line when asked for
+/// timestamps, and its length.
+fn leading_timestamp(text: &str) -> Option<(DateTime<Utc>, usize)> {
+  let (token, _) = text.split_once(' ')?;
+  let time = DateTime::parse_from_rfc3339(token).ok()?;
+  Some((time.with_timezone(&Utc), token.len()))
+}
+
+/// When a record was logged, read from its Kubernetes timestamp (after any
+/// `[pod]` prefix).
+pub fn record_time(text: &str) -> Option<DateTime<Utc>> {
+  let start = pod_prefix(text).map_or(0, |(range, _)| range.end + 1);
+  leading_timestamp(&text[start..]).map(|(time, _)| time)
+}
+
+/// How far back a log request starts, replacing the tail line count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogSince {
+  /// `since_seconds`: a window ending now.
+  Seconds(i64),
+  /// `since_time`: a fixed start.
+  Time(DateTime<Utc>),
+}
+
+impl LogSince {
+  /// Request logs from this point instead of the last `tail_lines`.
+  pub fn apply(self, params: &mut LogParams) {
+    params.tail_lines = None;
+    match self {
+      LogSince::Seconds(seconds) => params.since_seconds = Some(seconds),
+      LogSince::Time(time) => {
+        params.since_time = jiff::Timestamp::from_second(time.timestamp()).ok();
+      }
+    }
+  }
+
+  /// `1h30m` or the local start time, for the log title.
+  pub fn label(self) -> String {
+    match self {
+      LogSince::Seconds(seconds) => format_duration(seconds),
+      LogSince::Time(time) => time.with_timezone(&Local).format("%m-%d %H:%M").to_string(),
+    }
+  }
+
+  /// The window as it would be typed, to prefill the since input.
+  pub fn input(self) -> String {
+    match self {
+      LogSince::Seconds(seconds) => format_duration(seconds),
+      LogSince::Time(time) => time
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string(),
+    }
+  }
+}
+
+const TIME_INPUT_HELP: &str =
+  "Enter a duration like 15m or 1h30m, or a time like 14:02, 2024-05-01 14:02 or RFC 3339";
+
+/// Parse the since input: a duration back from now, or a start time. A blank
+/// input clears the window.
+pub fn parse_since(input: &str, now: DateTime<Local>) -> Result<Option<LogSince>, String> {
+  let input = input.trim();
+  if input.is_empty() {
+    return Ok(None);
+  }
+  if let Some(seconds) = parse_duration(input) {
+    return Ok(Some(LogSince::Seconds(seconds)));
+  }
+  parse_time(input, now)
+    .map(|time| Some(LogSince::Time(time)))
+    .ok_or_else(|| TIME_INPUT_HELP.to_owned())
+}
+
+/// Parse the jump-to-time input: a time, or a duration back from now.
+pub fn parse_jump_time(input: &str, now: DateTime<Local>) -> Result<DateTime<Utc>, String> {
+  let input = input.trim();
+  match parse_duration(input) {
+    Some(seconds) => Ok((now - TimeDelta::seconds(seconds)).with_timezone(&Utc)),
+    None => parse_time(input, now).ok_or_else(|| TIME_INPUT_HELP.to_owned()),
+  }
+}
+
+/// `90s`, `15m`, `1h30m`, `2d`: at least one number-unit pair, > 0 in total.
+fn parse_duration(input: &str) -> Option<i64> {
+  let mut total: i64 = 0;
+  let mut number = String::new();
+  for c in input.chars() {
+    if c.is_ascii_digit() {
+      number.push(c);
+      continue;
+    }
+    let unit = match c {
+      's' => 1,
+      'm' => 60,
+      'h' => 3600,
+      'd' => 86_400,
+      _ => return None,
+    };
+    let value: i64 = std::mem::take(&mut number).parse().ok()?;
+    total = total.checked_add(value.checked_mul(unit)?)?;
+  }
+  (number.is_empty() && total > 0).then_some(total)
+}
+
+/// RFC 3339, a local `YYYY-MM-DD HH:MM[:SS]`, or a local `HH:MM[:SS]` taken as
+/// the most recent such time (yesterday if it is still to come today).
+fn parse_time(input: &str, now: DateTime<Local>) -> Option<DateTime<Utc>> {
+  if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+    return Some(time.with_timezone(&Utc));
+  }
+  let local = |naive: NaiveDateTime| {
+    Local
+      .from_local_datetime(&naive)
+      .earliest()
+      .map(|time| time.with_timezone(&Utc))
+  };
+  for format in [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+  ] {
+    if let Ok(naive) = NaiveDateTime::parse_from_str(input, format) {
+      return local(naive);
+    }
+  }
+  let clock = ["%H:%M:%S", "%H:%M"]
+    .iter()
+    .find_map(|format| NaiveTime::parse_from_str(input, format).ok())?;
+  let today = local(now.date_naive().and_time(clock))?;
+  if today > now.with_timezone(&Utc) {
+    local(now.date_naive().pred_opt()?.and_time(clock))
+  } else {
+    Some(today)
+  }
+}
+
+/// Seconds as `1h30m`, `15m`, `45s`.
+fn format_duration(seconds: i64) -> String {
+  let parts = [(86_400, "d"), (3600, "h"), (60, "m"), (1, "s")];
+  let mut rest = seconds;
+  let mut out = String::new();
+  for (size, unit) in parts {
+    if rest >= size {
+      out.push_str(&format!("{}{}", rest / size, unit));
+      rest %= size;
+    }
+  }
+  if out.is_empty() {
+    out.push_str("0s");
+  }
+  out
+}
+
+/// Top-level fields of a JSON object; strings unquoted, anything else compact.
+fn json_fields(payload: &str) -> Option<Vec<(String, String)>> {
+  let Ok(Value::Object(map)) = serde_json::from_str::<Value>(payload) else {
+    return None;
+  };
+  Some(
+    map
+      .into_iter()
+      .map(|(key, value)| match value {
+        Value::String(s) => (key, s),
+        other => (key, other.to_string()),
+      })
+      .collect(),
+  )
+}
+
+/// `key=value` pairs separated by spaces, values optionally double-quoted with
+/// `\` escapes. Needs at least two pairs and nothing that isn't a pair.
+fn logfmt_fields(payload: &str) -> Option<Vec<(String, String)>> {
+  let mut fields = vec![];
+  let mut chars = payload.trim_start().chars().peekable();
+  while chars.peek().is_some() {
+    let mut key = String::new();
+    loop {
+      match chars.next()? {
+        '=' => break,
+        c if c.is_whitespace() || c == '"' => return None,
+        c => key.push(c),
+      }
+    }
+    if key.is_empty() {
+      return None;
+    }
+    let mut value = String::new();
+    if chars.peek() == Some(&'"') {
+      chars.next();
+      let mut closed = false;
+      while let Some(c) = chars.next() {
+        match c {
+          '\\' => value.extend(chars.next()),
+          '"' => {
+            closed = true;
+            break;
+          }
+          c => value.push(c),
+        }
+      }
+      if !closed || chars.next().is_some_and(|c| !c.is_whitespace()) {
+        return None;
+      }
+    } else {
+      value = chars.by_ref().take_while(|c| !c.is_whitespace()).collect();
+    }
+    fields.push((key, value));
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+  }
+  (fields.len() >= 2).then_some(fields)
+}
+
+/// A file the log records are appended to as they stream in ("tee").
+#[derive(Clone, Debug)]
+pub struct LogTee {
+  pub path: PathBuf,
+  file: Arc<File>,
+}
+
+impl LogTee {
+  /// Create `path` holding `lines`, kept open for [`LogTee::append`].
+  pub fn create<'a>(path: PathBuf, lines: impl Iterator<Item = &'a str>) -> io::Result<LogTee> {
+    let tee = LogTee {
+      file: Arc::new(File::create(&path)?),
+      path,
+    };
+    tee.append(lines)?;
+    Ok(tee)
+  }
+
+  pub fn append<'a>(&self, lines: impl Iterator<Item = &'a str>) -> io::Result<()> {
+    let mut out = BufWriter::new(&*self.file);
+    for line in lines {
+      out.write_all(line.as_bytes())?;
+      out.write_all(b"\n")?;
+    }
+    out.flush()
+  }
+}
+
+/// `dir/kdash-logs-<name>-<timestamp>.log`, with anything but letters, digits,
+/// `.`, `_` and `-` in `name` replaced so pod and container names make a
+/// safe file name.
+pub fn log_file_path(dir: &Path, name: &str) -> PathBuf {
+  let name: String = name
+    .chars()
+    .map(|c| {
+      if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+        c
+      } else {
+        '_'
+      }
+    })
+    .collect();
+  dir.join(format!(
+    "kdash-logs-{}-{}.log",
+    name,
+    chrono::Local::now().format("%Y%m%d%H%M%S")
+  ))
+}
+
+/// Byte ranges of every non-overlapping, ASCII-case-insensitive occurrence of
+/// `needle` in `text`. Only ASCII letters fold, so each range starts and ends
+/// on a char boundary whenever `needle` is valid UTF-8.
+pub fn match_ranges(text: &str, needle: &str) -> Vec<Range<usize>> {
+  let (haystack, needle) = (text.as_bytes(), needle.as_bytes());
+  let mut ranges = vec![];
+  if needle.is_empty() || needle.len() > haystack.len() {
+    return ranges;
+  }
+  let mut start = 0;
+  while start + needle.len() <= haystack.len() {
+    if haystack[start..start + needle.len()].eq_ignore_ascii_case(needle) {
+      ranges.push(start..start + needle.len());
+      start += needle.len();
+    } else {
+      start += 1;
+    }
+  }
+  ranges
+}
+
+/// Style `line` (which starts at byte `offset` of its record) with `regions`,
+/// given as record byte ranges; where regions overlap the later one wins, so
+/// search matches go last. Using record offsets lets a styled part that wraps
+/// across rows keep its style on each of them.
+pub fn highlight_line(
+  line: &str,
+  offset: usize,
+  regions: &[(Range<usize>, Style)],
+  style: Style,
+) -> Line<'static> {
+  let end = offset + line.len();
+  let mut bounds = vec![offset, end];
+  for (range, _) in regions {
+    bounds.extend(
+      [range.start, range.end]
+        .into_iter()
+        .filter(|b| (offset..end).contains(b)),
+    );
+  }
+  bounds.sort_unstable();
+  bounds.dedup();
+
+  let mut spans: Vec<Span<'static>> = vec![];
+  for pair in bounds.windows(2) {
+    let (start, stop) = (pair[0], pair[1]);
+    let segment_style = regions
+      .iter()
+      .rev()
+      .find(|(range, _)| range.start <= start && stop <= range.end)
+      .map_or(style, |(_, style)| *style);
+    let text = &line[start - offset..stop - offset];
+    match spans.last_mut() {
+      Some(last) if last.style == segment_style => last.content.to_mut().push_str(text),
+      _ => spans.push(Span::styled(text.to_owned(), segment_style)),
+    }
+  }
+  if spans.is_empty() {
+    spans.push(Span::styled(String::new(), style));
+  }
+  Line::from(spans)
+}
+
+/// Where each wrapped row starts in its record. `textwrap` only trims the
+/// whitespace it breaks on, so every row is found at or after the previous one.
+pub fn wrapped_offsets(record: &str, rows: &[String]) -> Vec<usize> {
+  let mut cursor = 0;
+  rows
+    .iter()
+    .map(|row| {
+      let offset = record
+        .get(cursor..)
+        .and_then(|rest| rest.find(row.as_str()))
+        .map_or(cursor, |found| cursor + found);
+      cursor = offset + row.len();
+      offset
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use ratatui::style::Modifier;
+
+  use super::*;
+
+  fn search(query: &str, records: &[&str]) -> LogSearch {
+    let mut search = LogSearch {
+      query: query.into(),
+      ..LogSearch::default()
+    };
+    search.rebuild(records.iter().copied().enumerate());
+    search
+  }
+
+  #[test]
+  fn test_match_ranges_is_case_insensitive_and_utf8_safe() {
+    assert_eq!(match_ranges("Error: error", "ERROR"), vec![0..5, 7..12]);
+    assert_eq!(match_ranges("aaaa", "aa"), vec![0..2, 2..4]);
+    assert_eq!(match_ranges("héllo wörld", "wö"), vec![7..10]);
+    assert!(match_ranges("short", "").is_empty());
+    assert!(match_ranges("ab", "abc").is_empty());
+  }
+
+  #[test]
+  fn test_search_navigation_wraps_and_starts_at_newest() {
+    let mut search = search("err", &["ok", "err 1", "ok", "ERR 2"]);
+    assert_eq!(search.match_count(), 2);
+    assert_eq!(search.current_record(), Some(3));
+    assert_eq!(search.next(), Some(1));
+    assert_eq!(search.next(), Some(3));
+    assert_eq!(search.prev(), Some(1));
+    assert_eq!(search.prev(), Some(3));
+    assert_eq!(search.current_position(), Some(2));
+  }
+
+  #[test]
+  fn test_search_tracks_appended_and_evicted_records() {
+    let mut search = search("err", &["err a", "ok"]);
+    search.extend([(2, "ok"), (3, "err b")].into_iter());
+    assert!(search.is_match(0) && search.is_match(3));
+    assert_eq!(search.match_count(), 2);
+
+    search.evict(1);
+    assert_eq!(search.match_count(), 1);
+    assert_eq!(search.current_record(), Some(2));
+
+    search.evict(3);
+    assert_eq!(search.current_record(), None);
+  }
+
+  #[test]
+  fn test_log_filter_include_and_exclude() {
+    let filter = LogFilter {
+      include: parse_filter("error|warn").unwrap(),
+      exclude: parse_filter(" healthz ").unwrap(),
+      min_level: None,
+    };
+    assert!(filter.is_active());
+    assert!(filter.allows("error: db down", None));
+    assert!(!filter.allows("info: started", None));
+    assert!(!filter.allows("warn: GET /healthz slow", None));
+    assert_eq!(filter.label(), "+error|warn -healthz");
+
+    assert!(parse_filter("  ").unwrap().is_none());
+    assert!(parse_filter("(").unwrap_err().starts_with("Invalid regex"));
+    assert!(LogFilter::default().allows("anything", None));
+
+    let min_warn = LogFilter {
+      min_level: Some(LogLevel::Warn),
+      ..LogFilter::default()
+    };
+    assert!(min_warn.allows("x", Some(LogLevel::Error)));
+    assert!(!min_warn.allows("x", Some(LogLevel::Info)));
+    assert!(!min_warn.allows("x", None));
+    assert_eq!(min_warn.label(), ">=WARN");
+  }
+
+  #[test]
+  fn test_parse_structured_json_keeps_prefix_and_compacts_extra_fields() {
+    let names = LogFieldNames::default();
+    let line = r#"[web-1] 2024-05-01T10:00:00Z {"ts":"10:00:00","level":"warning","msg":"slow query","ms":512,"sql":"select 1"}"#;
+    let parsed = parse_structured(line, &names).unwrap();
+    assert_eq!(
+      parsed.line,
+      "[web-1] 2024-05-01T10:00:00Z 10:00:00 WARN slow query ms=512 sql=\"select 1\""
+    );
+    let (level, range) = parsed.level.unwrap();
+    assert_eq!(level, LogLevel::Warn);
+    assert_eq!(&parsed.line[range], "WARN");
+
+    let pino = parse_structured(r#"{"level":50,"msg":"boom"}"#, &names).unwrap();
+    assert_eq!(pino.line, "ERROR boom");
+    // Objects without any of the configured fields are left alone.
+    assert!(parse_structured(r#"{"a":1}"#, &names).is_none());
+    assert!(parse_structured("{not json", &names).is_none());
+  }
+
+  #[test]
+  fn test_parse_structured_logfmt() {
+    let names = LogFieldNames::default();
+    let parsed = parse_structured(
+      r#"time=12:00 level=info msg="user logged in" user=bob path="""#,
+      &names,
+    )
+    .unwrap();
+    assert_eq!(parsed.line, r#"12:00 INFO user logged in user=bob path="""#);
+
+    let custom = LogFieldNames {
+      level: vec!["sev".into()],
+      ..LogFieldNames::default()
+    };
+    let crit = parse_structured("sev=CRIT event=oom", &custom).unwrap();
+    assert_eq!(crit.line, "ERROR event=oom");
+
+    // Plain text, a lone pair and unterminated quotes are not logfmt.
+    assert!(parse_structured("GET /healthz 200 level=info", &names).is_none());
+    assert!(parse_structured("level=info", &names).is_none());
+    assert!(parse_structured(r#"level=info msg="open"#, &names).is_none());
+  }
+
+  #[test]
+  fn test_detect_level_in_plain_text() {
+    fn detect(text: &str) -> Option<(LogLevel, &str)> {
+      detect_level(text).map(|(level, range)| (level, &text[range]))
+    }
+    assert_eq!(
+      detect("2024-05-01 10:00:00 [ERROR] db down"),
+      Some((LogLevel::Error, "ERROR"))
+    );
+    assert_eq!(
+      detect("[web-1] WARNING: disk 91%"),
+      Some((LogLevel::Warn, "WARNING"))
+    );
+    assert_eq!(
+      detect("ts=1 level=debug msg=x"),
+      Some((LogLevel::Debug, "debug"))
+    );
+    assert_eq!(
+      detect("I0501 10:00:00.123 main.go:12] started"),
+      Some((LogLevel::Info, "I"))
+    );
+    assert_eq!(
+      detect("\tat com.example.Main.run(Main.java:10)"),
+      Some((LogLevel::Error, "at com.example.Main.run(Main.java:10)"))
+    );
+    assert_eq!(
+      detect("panic: runtime error: index out of range"),
+      Some((LogLevel::Error, "panic: runtime error: index out of range"))
+    );
+    // Level words later in the message, or inside other words, don't count.
+    assert_eq!(detect("GET /api 200 took 3ms, no error"), None);
+    assert_eq!(detect("errors: 0"), None);
+  }
+
+  #[test]
+  fn test_prefix_style_is_stable_per_pod() {
+    let styles = LogStyles {
+      prefixes: std::array::from_fn(|i| {
+        Style::default().fg(ratatui::style::Color::Indexed(i as u8))
+      }),
+      ..LogStyles::default()
+    };
+    assert_eq!(styles.prefix("web-1"), styles.prefix("web-1"));
+    let distinct: std::collections::HashSet<_> = ["web-1", "web-2", "web-3", "api-1", "api-2"]
+      .iter()
+      .map(|name| format!("{:?}", styles.prefix(name)))
+      .collect();
+    assert!(distinct.len() > 1);
+    assert_eq!(pod_prefix("[web-1] hello").unwrap().1, "web-1");
+    assert!(pod_prefix("[web-1]hello").is_none());
+  }
+
+  #[test]
+  fn test_log_file_path_sanitizes_name() {
+    let path = log_file_path(Path::new("/tmp"), "web-1/app:v2");
+    let name = path.file_name().unwrap().to_str().unwrap();
+    assert!(name.starts_with("kdash-logs-web-1_app_v2-"), "{}", name);
+    assert!(name.ends_with(".log"));
+    assert_eq!(path.parent(), Some(Path::new("/tmp")));
+  }
+
+  #[test]
+  fn test_parse_since_durations_and_times() {
+    let now = Local.with_ymd_and_hms(2024, 5, 1, 14, 30, 0).unwrap();
+    assert_eq!(parse_since("15m", now), Ok(Some(LogSince::Seconds(900))));
+    assert_eq!(
+      parse_since(" 1h30m ", now),
+      Ok(Some(LogSince::Seconds(5400)))
+    );
+    assert_eq!(parse_since("", now), Ok(None));
+    assert_eq!(
+      parse_since("14:02", now),
+      Ok(Some(LogSince::Time(
+        Local
+          .with_ymd_and_hms(2024, 5, 1, 14, 2, 0)
+          .unwrap()
+          .with_timezone(&Utc)
+      )))
+    );
+    // A clock time still to come today means yesterday.
+    assert_eq!(
+      parse_jump_time("15:00", now),
+      Ok(
+        Local
+          .with_ymd_and_hms(2024, 4, 30, 15, 0, 0)
+          .unwrap()
+          .with_timezone(&Utc)
+      )
+    );
+    assert_eq!(
+      parse_jump_time("2024-05-01T12:00:00Z", now),
+      Ok(Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap())
+    );
+    assert_eq!(
+      parse_jump_time("10m", now),
+      Ok((now - TimeDelta::minutes(10)).with_timezone(&Utc))
+    );
+    assert!(parse_since("15", now).is_err());
+    assert!(parse_since("0m", now).is_err());
+    assert!(parse_since("yesterday", now).is_err());
+    assert_eq!(LogSince::Seconds(5400).label(), "1h30m");
+  }
+
+  #[test]
+  fn test_log_tail_query_parses_stern_style_flags() {
+    assert_eq!(
+      LogTailQuery::parse("api-.* -c app", Some("prod")),
+      Ok(LogTailQuery {
+        pod: "api-.*".into(),
+        container: Some("app".into()),
+        namespace: Some("prod".into()),
+      })
+    );
+    let all = LogTailQuery::parse("-A web", Some("prod")).unwrap();
+    assert_eq!(all.namespace, None);
+    assert_eq!(all.label(), "web -A");
+    assert_eq!(
+      LogTailQuery::parse("web -n staging", None).unwrap().label(),
+      "web -n staging"
+    );
+
+    assert!(LogTailQuery::parse("", None).is_err());
+    assert!(LogTailQuery::parse("web -c", None).is_err());
+    assert!(LogTailQuery::parse("web extra", None).is_err());
+    assert!(LogTailQuery::parse("web(", None)
+      .unwrap_err()
+      .starts_with("Invalid regex"));
+  }
+
+  #[test]
+  fn test_since_replaces_tail_lines() {
+    let mut params = LogParams {
+      tail_lines: Some(100),
+      ..LogParams::default()
+    };
+    LogSince::Seconds(60).apply(&mut params);
+    assert_eq!((params.tail_lines, params.since_seconds), (None, Some(60)));
+
+    let mut at_time = LogParams::default();
+    LogSince::Time(Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap()).apply(&mut at_time);
+    assert_eq!(
+      at_time.since_time.map(|time| time.to_string()),
+      Some("2024-05-01T12:00:00Z".to_owned())
+    );
+  }
+
+  #[test]
+  fn test_highlight_line_styles_matches_split_across_rows() {
+    let plain = Style::default();
+    let matched = Style::default().add_modifier(Modifier::REVERSED);
+    let regions: Vec<_> = match_ranges("hello world", "o w")
+      .into_iter()
+      .map(|range| (range, matched))
+      .collect();
+    // "hello" / "world" after wrapping at 5 columns.
+    let first = highlight_line("hello", 0, &regions, plain);
+    let second = highlight_line("world", 6, &regions, plain);
+
+    assert_eq!(first.spans[0].content, "hell");
+    assert_eq!(first.spans[1].content, "o");
+    assert_eq!(first.spans[1].style, matched);
+    assert_eq!(second.spans[0].content, "w");
+    assert_eq!(second.spans[0].style, matched);
+    assert_eq!(second.spans[1].content, "orld");
+  }
+
+  #[test]
+  fn test_wrapped_offsets_skip_trimmed_whitespace() {
+    let rows = vec!["hello".to_owned(), "world".to_owned()];
+    assert_eq!(wrapped_offsets("hello world", &rows), vec![0, 6]);
+  }
+}
@@ -4,11 +4,14 @@ pub(crate) mod contexts;
 pub(crate) mod cronjobs;
 pub(crate) mod daemonsets;
 pub(crate) mod deployments;
+pub(crate) mod describe;
 pub(crate) mod dynamic;
 pub(crate) mod events;
+pub(crate) mod exec;
 pub(crate) mod ingress;
 pub(crate) mod jobs;
 pub(crate) mod key_binding;
+pub(crate) mod logs;
 pub(crate) mod metrics;
 pub(crate) mod models;
 pub(crate) mod network_policies;
@@ -37,13 +40,15 @@ use kubectl_view_allocations::GroupBy;
 use log::{error, info};
 use ratatui::layout::Rect;
 use ratatui::widgets::ListState;
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::process::Child;
 use tokio::sync::{mpsc::Sender, watch};
+use tokio::task::JoinHandle;
 
 use self::{
-  actions::{InputModal, Modal, ResourceAction},
+  actions::{BulkSummary, InputModal, Modal, ResourceAction},
   configmaps::KubeConfigMap,
   contexts::KubeContext,
   cronjobs::KubeCronJob,
@@ -51,9 +56,11 @@ use self::{
   deployments::KubeDeployment,
   dynamic::{DynamicResourceCache, KubeDynamicKind, KubeDynamicResource},
   events::KubeEvent,
+  exec::ExecSession,
   ingress::KubeIngress,
   jobs::KubeJob,
   key_binding::DEFAULT_KEYBINDING,
+  logs::{LogLimits, LogSince, LogSplit, LogTailQuery},
   metrics::KubeNodeMetrics,
   models::{
     FilterableTable, LogsState, ScrollableTxt, StatefulList, StatefulTable, TabRoute, TabsState,
@@ -62,7 +69,7 @@ use self::{
   nodes::KubeNode,
   ns::KubeNs,
   pods::{KubeContainer, KubePod},
-  port_forward::{PortForward, PortForwardStatus},
+  port_forward::{PortForward, PortForwardStats, PortForwardStatus},
   pvcs::KubePVC,
   pvs::KubePV,
   replicasets::KubeReplicaSet,
@@ -75,9 +82,13 @@ use self::{
   svcs::KubeSvc,
 };
 use super::{
-  cmd::IoCmdEvent,
+  cmd::{shell::ShellExecTarget, IoCmdEvent},
   config::KdashConfig,
-  network::{stream::IoStreamEvent, IoEvent},
+  network::{
+    stream::IoStreamEvent,
+    watch::{WatchChanges, WatchKey},
+    IoEvent,
+  },
 };
 use crate::ui::theme::{apply_legacy_overrides, palette_for, Palette, ThemeName};
 
@@ -92,11 +103,22 @@ pub struct ErrorRecord {
   pub message: String,
 }
 
+/// Progress of a paginated list call, shown next to the header spinner.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PendingShellExec {
-  pub namespace: String,
-  pub pod: String,
-  pub container: String,
+pub struct ListProgress {
+  pub kind: String,
+  pub loaded: usize,
+  /// Total when the server reports `remainingItemCount`.
+  pub total: Option<usize>,
+}
+
+impl ListProgress {
+  pub fn label(&self) -> String {
+    match self.total {
+      Some(total) => format!("{} {}/{}", self.kind, self.loaded, total),
+      None => format!("{} {}…", self.kind, self.loaded),
+    }
+  }
 }
 
 /// A resource to open in `$EDITOR` via `kubectl edit`. `namespace` is `None` for
@@ -109,11 +131,10 @@ pub struct PendingEdit {
 }
 
 /// An action that suspends the TUI, runs an interactive child process inheriting
-/// the terminal, then restores the TUI. Shell-exec and `kubectl edit` share this
-/// suspend/restore machinery.
+/// the terminal, then restores the TUI. Only `kubectl edit` needs this; shells
+/// run in-app (see [`exec`]).
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PendingTerminalAction {
-  Shell(PendingShellExec),
   Edit(PendingEdit),
 }
 
@@ -170,6 +191,7 @@ pub enum ActiveBlock {
   Pods,
   Containers,
   Logs,
+  Shell,
   Services,
   Nodes,
   Deployments,
@@ -240,9 +262,21 @@ pub struct Data {
   pub active_context: Option<KubeContext>,
   pub node_metrics: Vec<KubeNodeMetrics>,
   pub logs: LogsState,
+  /// The previous container instance beside `logs`, while the split is open.
+  pub log_split: Option<LogSplit>,
   pub describe_out: ScrollableTxt,
   pub metrics: StatefulTable<metrics::UtilizationQualifier>,
   pub troubleshoot_findings: StatefulTable<troubleshoot::DisplayFinding>,
+  /// When each StatefulSet's pending update started, for the troubleshoot view.
+  pub troubleshoot_revisions: troubleshoot::RevisionTracker,
+  /// The API server's git version (e.g. `v1.30.2`), for the kubelet skew check.
+  pub server_version: Option<String>,
+  /// Every node, regardless of the Nodes tab's selector, for the node checks.
+  pub troubleshoot_nodes: troubleshoot::NodeRefs,
+  /// Endpoint and Secret lookups for the Service and Ingress checks.
+  pub troubleshoot_refs: troubleshoot::ServiceRefs,
+  /// Resources the checks run against, separate from their tabs.
+  pub troubleshoot_inputs: troubleshoot::TroubleshootInputs,
   pub namespaces: StatefulTable<KubeNs>,
   pub nodes: StatefulTable<KubeNode>,
   pub pods: StatefulTable<KubePod>,
@@ -309,6 +343,15 @@ pub struct App {
   pub show_info_bar: bool,
   pub is_streaming: bool,
   pub is_routing: bool,
+  /// Watches whose stores changed since the last tick.
+  pub watch_changes: WatchChanges,
+  /// The event that loads the active tab and the watches its last load read.
+  /// When one of them changes the tab is re-read from the cache on the next
+  /// tick instead of the next poll.
+  pub active_tab_event: Option<IoEvent>,
+  pub active_tab_watches: HashSet<WatchKey>,
+  /// Set while a list call is paging through a large collection.
+  pub list_progress: Option<ListProgress>,
   pub tick_until_poll: u64,
   pub tick_count: u64,
   pub enhanced_graphics: bool,
@@ -333,6 +376,12 @@ pub struct App {
   pub log_timestamps: bool,
   /// Wrap long log lines to the viewport width (vs. truncate). Render-side only.
   pub log_wrap: bool,
+  /// Show JSON / logfmt log lines as received instead of rendered from their
+  /// fields. Render-side only.
+  pub log_raw: bool,
+  /// Request logs from this window instead of the last `log_tail_lines`.
+  /// Setting it re-streams the logs.
+  pub log_since: Option<LogSince>,
   /// The log stream that produced the current log view, kept so a timestamp
   /// toggle can re-issue it with the new option.
   active_log_stream: Option<IoStreamEvent>,
@@ -341,8 +390,8 @@ pub struct App {
   /// Vertical scroll offset for the grouped help page (clamped at render time).
   pub help_scroll: u16,
   pub error_history: VecDeque<ErrorRecord>,
-  /// Active `kubectl port-forward` children, listed and stopped via the
-  /// forwards overlay.
+  /// Active in-process port-forwards, listed and stopped via the forwards
+  /// overlay.
   pub port_forwards: Vec<PortForward>,
   next_port_forward_id: u64,
   /// Whether the active-forwards overlay is open.
@@ -350,12 +399,19 @@ pub struct App {
   /// Selection state for the forwards overlay list.
   pub port_forwards_state: ListState,
   pending_terminal_action: Option<PendingTerminalAction>,
+  /// The in-app container shell shown in the `Shell` pane.
+  pub exec_session: Option<ExecSession>,
+  next_exec_id: u64,
   /// Transient confirmation overlay guarding an impactful action.
   pub modal: Option<Modal>,
   /// Transient single-line input overlay for actions that need a value (scale).
   pub input_modal: Option<InputModal>,
   /// Transient `m` action-menu overlay for the selected resource.
   pub action_menu: Option<StatefulList<ResourceAction>>,
+  /// Per-target outcome of the last bulk action, shown until dismissed.
+  pub bulk_summary: Option<BulkSummary>,
+  /// Directory being browsed with `--snapshot`; the app is read-only while set.
+  pub snapshot: Option<PathBuf>,
   pub config: KdashConfig,
   pub data: Data,
 }
@@ -380,9 +436,15 @@ impl Default for Data {
         pod_selector_resource: None,
       },
       logs: LogsState::new(String::default()),
+      log_split: None,
       describe_out: ScrollableTxt::new(),
       metrics: StatefulTable::new(),
       troubleshoot_findings: StatefulTable::new(),
+      troubleshoot_revisions: troubleshoot::RevisionTracker::default(),
+      server_version: None,
+      troubleshoot_nodes: troubleshoot::NodeRefs::default(),
+      troubleshoot_refs: troubleshoot::ServiceRefs::default(),
+      troubleshoot_inputs: troubleshoot::TroubleshootInputs::default(),
       nodes: StatefulTable::new(),
       pods: StatefulTable::new(),
       containers: StatefulTable::new(),
@@ -582,6 +644,10 @@ impl Default for App {
       loading_counter: 0,
       is_streaming: false,
       is_routing: false,
+      watch_changes: WatchChanges::default(),
+      active_tab_event: None,
+      active_tab_watches: HashSet::new(),
+      list_progress: None,
       tick_until_poll: 0,
       tick_count: 0,
       enhanced_graphics: false,
@@ -600,6 +666,8 @@ impl Default for App {
       log_previous: false,
       log_timestamps: false,
       log_wrap: true,
+      log_raw: false,
+      log_since: None,
       active_log_stream: None,
       log_tail_lines: DEFAULT_LOG_TAIL_LINES,
       utilization_group_by: Self::default_utilization_group_by(),
@@ -611,9 +679,13 @@ impl Default for App {
       show_port_forwards: false,
       port_forwards_state: ListState::default(),
       pending_terminal_action: None,
+      exec_session: None,
+      next_exec_id: 0,
       modal: None,
       input_modal: None,
       action_menu: None,
+      bulk_summary: None,
+      snapshot: None,
       config: KdashConfig::default(),
       data: Data::default(),
     }
@@ -711,6 +783,19 @@ impl App {
     }
   }
 
+  /// Whether `block` is listed from the API server, so an `l:` / `f:` filter
+  /// can be sent as a server-side selector on its next fetch.
+  pub fn supports_list_selector(&self, block: ActiveBlock) -> bool {
+    !matches!(
+      block,
+      ActiveBlock::Contexts
+        | ActiveBlock::Utilization
+        | ActiveBlock::Troubleshoot
+        | ActiveBlock::Containers
+        | ActiveBlock::DynamicResource
+    ) && self.resource_table(block).is_some()
+  }
+
   pub fn current_resource_table(&self) -> Option<&dyn FilterableTable> {
     self.resource_table(self.get_current_route().active_block)
   }
@@ -861,7 +946,11 @@ impl App {
   pub fn reset(&mut self) {
     self.cancel_log_stream();
     self.kill_all_port_forwards();
+    self.exec_session = None;
     self.loading_counter = 0;
+    self.list_progress = None;
+    self.active_tab_event = None;
+    self.active_tab_watches.clear();
     self.tick_count = 0;
     self.api_error = String::new();
     self.status_message.clear();
@@ -930,8 +1019,23 @@ impl App {
     true
   }
 
+  /// Refuse `action` while browsing a snapshot, reporting why. Returns whether
+  /// it was refused.
+  fn refuse_in_snapshot(&mut self, action: &str) -> bool {
+    if self.snapshot.is_none() {
+      return false;
+    }
+    self.handle_error(anyhow!("{} is disabled in read-only snapshot mode", action));
+    true
+  }
+
   // Send a network event to the network thread
   pub async fn dispatch(&mut self, action: IoEvent) {
+    if let Some(mutation) = action.mutation() {
+      if self.refuse_in_snapshot(mutation) {
+        return;
+      }
+    }
     // `loading_counter` will be decremented after the async action has finished in network/mod.rs
     if let Some(io_tx) = &self.io_tx {
       self.loading_counter += 1;
@@ -966,6 +1070,27 @@ impl App {
     }
   }
 
+  /// Route a describe request to the built-in renderer, or to `kubectl
+  /// describe` when configured or when the kind has no native renderer.
+  pub async fn dispatch_describe(&mut self, action: IoCmdEvent) {
+    let native = self.snapshot.is_some() || !self.config.describe_with_kubectl;
+    match action {
+      IoCmdEvent::GetDescribe { kind, value, ns } if native && describe::is_supported(&kind) => {
+        self
+          .dispatch(IoEvent::GetDescribe { kind, value, ns })
+          .await;
+      }
+      // kubectl would describe the live cluster, not the snapshot
+      IoCmdEvent::GetDescribe { kind, .. } if self.snapshot.is_some() => {
+        self.handle_error(anyhow!(
+          "Describing {} needs kubectl and is unavailable in snapshot mode",
+          kind
+        ));
+      }
+      action => self.dispatch_cmd(action).await,
+    }
+  }
+
   pub fn set_contexts(&mut self, contexts: Vec<KubeContext>) {
     self.data.active_context = contexts.iter().find_map(|ctx| {
       if ctx.is_active {
@@ -1003,11 +1128,68 @@ impl App {
     self.status_message.show(message);
   }
 
-  pub fn queue_shell_exec(&mut self, request: PendingShellExec) {
-    self.pending_terminal_action = Some(PendingTerminalAction::Shell(request));
+  /// Capture the selected namespace (or every namespace) into a new
+  /// timestamped directory under `snapshot_dir`.
+  pub async fn capture_snapshot(&mut self) {
+    let base = match &self.config.snapshot_dir {
+      Some(dir) => PathBuf::from(dir),
+      None => std::env::current_dir().unwrap_or_default(),
+    };
+    let dir = base.join(format!(
+      "kdash-snapshot-{}",
+      Local::now().format("%Y%m%d%H%M%S")
+    ));
+    self.set_status_message(format!("Capturing snapshot to {}", dir.display()));
+    let event = IoEvent::CaptureSnapshot {
+      dir,
+      namespace: self.data.selected.ns.clone(),
+      redact_secrets: self.config.snapshot_redact_secrets,
+    };
+    self.dispatch(event).await;
+  }
+
+  /// Open the shell pane for `target` and start the exec session on the stream
+  /// worker. Replaces (and so ends) any previous session.
+  pub async fn open_exec_session(&mut self, target: ShellExecTarget, route_id: RouteId) {
+    if self.refuse_in_snapshot("Opening a shell") {
+      return;
+    }
+    self.next_exec_id += 1;
+    let id = self.next_exec_id;
+    self.exec_session = Some(ExecSession::new(id, target.clone()));
+    self.push_navigation_stack(route_id, ActiveBlock::Shell);
+    self
+      .dispatch_stream(IoStreamEvent::StartExec { id, target })
+      .await;
+  }
+
+  /// End the shell session; dropping it closes the input channel, which makes
+  /// the network task abort the remote process.
+  pub fn close_exec_session(&mut self) {
+    self.exec_session = None;
+  }
+
+  /// The exec session with `id`, if it is still the one on screen.
+  pub fn exec_session_mut(&mut self, id: u64) -> Option<&mut ExecSession> {
+    self
+      .exec_session
+      .as_mut()
+      .filter(|session| session.id == id)
+  }
+
+  /// Whether keys should go to the remote TTY rather than kdash.
+  pub fn is_exec_input_active(&self) -> bool {
+    self.get_current_route().active_block == ActiveBlock::Shell
+      && self
+        .exec_session
+        .as_ref()
+        .is_some_and(ExecSession::is_running)
   }
 
   pub fn queue_edit(&mut self, request: PendingEdit) {
+    if self.refuse_in_snapshot("Editing resources") {
+      return;
+    }
     self.pending_terminal_action = Some(PendingTerminalAction::Edit(request));
   }
 
@@ -1016,18 +1198,15 @@ impl App {
   }
 
   #[cfg(test)]
-  pub fn pending_shell_exec(&self) -> Option<&PendingShellExec> {
-    match &self.pending_terminal_action {
-      Some(PendingTerminalAction::Shell(request)) => Some(request),
-      _ => None,
-    }
+  pub fn exec_target(&self) -> Option<&ShellExecTarget> {
+    self.exec_session.as_ref().map(|session| &session.target)
   }
 
   #[cfg(test)]
   pub fn pending_edit(&self) -> Option<&PendingEdit> {
     match &self.pending_terminal_action {
       Some(PendingTerminalAction::Edit(request)) => Some(request),
-      _ => None,
+      None => None,
     }
   }
 
@@ -1040,8 +1219,8 @@ impl App {
       .any(|pf| pf.local_port == local_port && !matches!(pf.status, PortForwardStatus::Failed(_)))
   }
 
-  /// Start a `kubectl port-forward` for the given target. Rejects a duplicate
-  /// local port up front; the network-stream task spawns the child and tracks it.
+  /// Start an in-process port-forward for the given target. Rejects a duplicate
+  /// local port up front; the network-stream task spawns and tracks the forward.
   pub async fn start_port_forward(
     &mut self,
     kind: String,
@@ -1050,6 +1229,9 @@ impl App {
     local_port: u16,
     remote_port: u16,
   ) {
+    if self.refuse_in_snapshot("Port-forwarding") {
+      return;
+    }
     if self.local_port_in_use(local_port) {
       self.handle_error(anyhow!("Local port {} is already forwarded", local_port));
       return;
@@ -1069,8 +1251,8 @@ impl App {
       .await;
   }
 
-  /// Record a freshly spawned forward and return its stable id. Called from the
-  /// network-stream task, which owns the child handle.
+  /// Record a new forward and return its stable id plus the counters its task
+  /// updates. Called from the network-stream task before it spawns the forward.
   pub fn add_port_forward(
     &mut self,
     kind: String,
@@ -1078,10 +1260,10 @@ impl App {
     name: String,
     local_port: u16,
     remote_port: u16,
-    child: Child,
-  ) -> u64 {
+  ) -> (u64, Arc<PortForwardStats>) {
     let id = self.next_port_forward_id;
     self.next_port_forward_id += 1;
+    let stats = Arc::new(PortForwardStats::default());
     self.port_forwards.push(PortForward {
       id,
       kind,
@@ -1089,10 +1271,21 @@ impl App {
       name,
       local_port,
       remote_port,
+      pod: None,
       status: PortForwardStatus::Starting,
-      child: Some(child),
+      stats: Arc::clone(&stats),
+      task: None,
     });
-    id
+    (id, stats)
+  }
+
+  /// Attach the forwarding task to its record. Aborts the task right away if the
+  /// forward was stopped in the meantime.
+  pub fn set_port_forward_task(&mut self, id: u64, task: JoinHandle<()>) {
+    match self.port_forwards.iter_mut().find(|pf| pf.id == id) {
+      Some(pf) => pf.task = Some(task),
+      None => task.abort(),
+    }
   }
 
   /// Update a tracked forward's status (no-op if it was already removed).
@@ -1102,20 +1295,34 @@ impl App {
     }
   }
 
-  /// Remove a forward, returning its child so the caller can kill+reap it.
-  pub fn remove_port_forward(&mut self, id: u64) -> Option<Child> {
-    let index = self.port_forwards.iter().position(|pf| pf.id == id)?;
+  /// Mark a forward active once it listens locally and knows its backing pod.
+  pub fn set_port_forward_active(&mut self, id: u64, pod: String) {
+    if let Some(pf) = self.port_forwards.iter_mut().find(|pf| pf.id == id) {
+      pf.pod = Some(pod);
+      pf.status = PortForwardStatus::Active;
+    }
+  }
+
+  /// Remove a forward and abort its task, closing the listener and any open
+  /// connections. Returns whether it was tracked.
+  pub fn remove_port_forward(&mut self, id: u64) -> bool {
+    let Some(index) = self.port_forwards.iter().position(|pf| pf.id == id) else {
+      return false;
+    };
     let mut pf = self.port_forwards.remove(index);
     self.clamp_port_forwards_selection();
-    pf.child.take()
+    if let Some(task) = pf.task.take() {
+      task.abort();
+    }
+    true
   }
 
-  /// SIGKILL every tracked forward and clear the list. Used on quit; reaping is
-  /// left to the OS since the process is exiting.
+  /// Abort every tracked forward and clear the list. Used on quit and context
+  /// reset.
   pub fn kill_all_port_forwards(&mut self) {
     for pf in &mut self.port_forwards {
-      if let Some(mut child) = pf.child.take() {
-        let _ = child.start_kill();
+      if let Some(task) = pf.task.take() {
+        task.abort();
       }
     }
     self.port_forwards.clear();
@@ -1293,7 +1500,8 @@ impl App {
   pub async fn dispatch_pod_logs(&mut self, pod_name: String, route_id: RouteId) {
     self.cancel_log_stream();
     self.log_previous = false;
-    self.data.logs = LogsState::new(format!("agg:{}", pod_name));
+    self.data.log_split = None;
+    self.data.logs = self.new_log_state(format!("agg:{}", pod_name));
     self.push_navigation_stack(route_id, ActiveBlock::Logs);
     self.active_log_stream = Some(IoStreamEvent::GetPodAllContainerLogs);
     self
@@ -1304,7 +1512,8 @@ impl App {
   pub async fn dispatch_container_logs(&mut self, id: String, route_id: RouteId) {
     self.cancel_log_stream();
     self.log_previous = false;
-    self.data.logs = LogsState::new(id);
+    self.data.log_split = None;
+    self.data.logs = self.new_log_state(id);
     self.push_navigation_stack(route_id, ActiveBlock::Logs);
     self.active_log_stream = Some(IoStreamEvent::GetPodLogs(true));
     self.dispatch_stream(IoStreamEvent::GetPodLogs(true)).await;
@@ -1316,10 +1525,11 @@ impl App {
   pub async fn dispatch_previous_logs(&mut self, id: String, route_id: RouteId) {
     self.cancel_log_stream();
     self.log_previous = true;
+    self.data.log_split = None;
     // Use the container name as the id (matching the live-logs view) so the log
     // view's `container == logs.id` render guard passes; `log_previous` keeps
     // the periodic poll from replacing it with a live stream.
-    self.data.logs = LogsState::new(id);
+    self.data.logs = self.new_log_state(id);
     self.push_navigation_stack(route_id, ActiveBlock::Logs);
     self.active_log_stream = Some(IoStreamEvent::GetPreviousLogs);
     self.dispatch_stream(IoStreamEvent::GetPreviousLogs).await;
@@ -1336,8 +1546,9 @@ impl App {
   ) {
     self.cancel_log_stream();
     self.log_previous = false;
+    self.data.log_split = None;
     self.data.selected.pod_selector_resource = Some(resource_name);
-    self.data.logs = LogsState::new(format!("agg:{}", name));
+    self.data.logs = self.new_log_state(format!("agg:{}", name));
     self.push_navigation_stack(route_id, ActiveBlock::Logs);
     let event = IoStreamEvent::GetAggregateLogs {
       namespace,
@@ -1347,6 +1558,50 @@ impl App {
     self.dispatch_stream(event).await;
   }
 
+  /// Tail every pod and container `query` matches, stern-style. Pods that
+  /// start later join the stream.
+  pub async fn dispatch_tail_logs(&mut self, query: LogTailQuery, route_id: RouteId) {
+    if self.refuse_in_snapshot("Tailing logs") {
+      return;
+    }
+    self.cancel_log_stream();
+    self.log_previous = false;
+    self.data.log_split = None;
+    self.data.logs = self.new_log_state(format!("agg:{}", query.label()));
+    self.push_navigation_stack(route_id, ActiveBlock::Logs);
+    let event = IoStreamEvent::TailLogs(query);
+    self.active_log_stream = Some(event.clone());
+    self.dispatch_stream(event).await;
+  }
+
+  /// Name for files the log view is saved to: `<pod>-<container>`, or the
+  /// workload for aggregate logs, with `-previous` for a terminated instance.
+  pub fn log_file_name(&self) -> String {
+    let mut name = match self.data.logs.id.strip_prefix("agg:") {
+      Some(name) => name.to_owned(),
+      None => format!(
+        "{}-{}",
+        self.data.selected.pod.as_deref().unwrap_or("pod"),
+        self.data.logs.id
+      ),
+    };
+    if self.log_previous {
+      name.push_str("-previous");
+    }
+    name
+  }
+
+  /// An empty log buffer set up with the configured structured-log fields and
+  /// buffer limits, and the current raw toggle.
+  fn new_log_state(&self, id: String) -> LogsState {
+    LogsState::new(id)
+      .with_format(self.config.log_fields.clone(), self.log_raw)
+      .with_limits(LogLimits {
+        max_lines: self.config.log_max_lines(),
+        max_bytes: self.config.log_max_bytes(),
+      })
+  }
+
   /// Re-issue the stream that produced the current log view so it picks up the
   /// latest log options (e.g. timestamps). Clears the buffer but keeps the id so
   /// the render guard still matches; preserves the previous-logs flag.
@@ -1355,9 +1610,60 @@ impl App {
       return;
     };
     self.cancel_log_stream();
-    let id = self.data.logs.id.clone();
-    self.data.logs = LogsState::new(id);
+    // The restarted stream re-sends its tail, which would duplicate lines in
+    // the file.
+    if let Some(path) = self.data.logs.stop_tee() {
+      self.set_status_message(format!("Stopped writing logs to {}", path.display()));
+    }
+    self.data.logs = self.data.logs.restarted();
     self.dispatch_stream(event).await;
+    if let Some(log_split) = self.data.log_split.as_mut() {
+      log_split.previous = log_split.previous.restarted();
+      log_split.terminated = None;
+      self.dispatch(IoEvent::GetPreviousLogsForSplit).await;
+    }
+  }
+
+  /// Open or close the previous container instance beside the live logs. Only
+  /// a single container's live stream has a previous instance to compare.
+  pub async fn toggle_log_split(&mut self) {
+    if self.data.log_split.take().is_some() {
+      return;
+    }
+    if self.refuse_in_snapshot("Comparing with the previous instance") {
+      return;
+    }
+    if !matches!(self.active_log_stream, Some(IoStreamEvent::GetPodLogs(_))) {
+      self.set_status_message("Side-by-side view needs a single container's live logs");
+      return;
+    }
+    self.data.log_split = Some(LogSplit::new(self.data.logs.restarted()));
+    self.dispatch(IoEvent::GetPreviousLogsForSplit).await;
+  }
+
+  /// The log pane keys act on: the previous instance when the split has focus
+  /// on it, the live logs otherwise.
+  pub fn focused_logs(&self) -> &LogsState {
+    match &self.data.log_split {
+      Some(log_split) if log_split.focus_previous => &log_split.previous,
+      _ => &self.data.logs,
+    }
+  }
+
+  pub fn focused_logs_mut(&mut self) -> &mut LogsState {
+    match &mut self.data.log_split {
+      Some(log_split) if log_split.focus_previous => &mut log_split.previous,
+      _ => &mut self.data.logs,
+    }
+  }
+
+  /// Whether the focused log pane follows new lines: the split's own flag for
+  /// the previous instance, `log_auto_scroll` for the live logs.
+  pub fn focused_log_follow_mut(&mut self) -> &mut bool {
+    match &mut self.data.log_split {
+      Some(log_split) if log_split.focus_previous => &mut log_split.follow,
+      _ => &mut self.log_auto_scroll,
+    }
   }
 
   pub fn refresh(&mut self) {
@@ -1416,9 +1722,11 @@ impl App {
 
   fn active_home_cache_block(&self) -> ActiveBlock {
     match self.get_current_route().active_block {
-      ActiveBlock::Namespaces | ActiveBlock::Describe | ActiveBlock::Yaml | ActiveBlock::Logs => {
-        self.get_prev_route().active_block
-      }
+      ActiveBlock::Namespaces
+      | ActiveBlock::Describe
+      | ActiveBlock::Yaml
+      | ActiveBlock::Logs
+      | ActiveBlock::Shell => self.get_prev_route().active_block,
       active_block => active_block,
     }
   }
@@ -1497,95 +1805,69 @@ impl App {
   }
 
   pub async fn dispatch_by_active_block(&mut self, active_block: ActiveBlock) {
-    match active_block {
+    if active_block == ActiveBlock::Logs {
+      if !self.is_streaming && !self.log_previous {
+        self.dispatch_stream(IoStreamEvent::GetPodLogs(false)).await;
+      }
+      return;
+    }
+    let Some(event) = self.active_block_event(active_block) else {
+      return;
+    };
+    if self.active_tab_event.as_ref() != Some(&event) {
+      self.active_tab_event = Some(event.clone());
+      self.active_tab_watches.clear();
+    }
+    self.dispatch(event).await;
+  }
+
+  /// The event that loads the table of `active_block`, if it has one.
+  fn active_block_event(&self, active_block: ActiveBlock) -> Option<IoEvent> {
+    let event = match active_block {
       ActiveBlock::Pods | ActiveBlock::Containers => {
         // If we're in a workload drill-down, refresh using the label selector
-        if let (Some(selector), Some(namespace)) = (
+        match (
           self.data.selected.pod_selector.clone(),
           self.data.selected.pod_selector_ns.clone(),
         ) {
-          self
-            .dispatch(IoEvent::GetPodsBySelector {
-              namespace,
-              selector,
-            })
-            .await;
-        } else {
-          self.dispatch(IoEvent::GetPods).await;
+          (Some(selector), Some(namespace)) => IoEvent::GetPodsBySelector {
+            namespace,
+            selector,
+          },
+          _ => IoEvent::GetPods,
         }
       }
-      ActiveBlock::Services => {
-        self.dispatch(IoEvent::GetServices).await;
-      }
-      ActiveBlock::ConfigMaps => {
-        self.dispatch(IoEvent::GetConfigMaps).await;
-      }
-      ActiveBlock::StatefulSets => {
-        self.dispatch(IoEvent::GetStatefulSets).await;
-      }
-      ActiveBlock::ReplicaSets => {
-        self.dispatch(IoEvent::GetReplicaSets).await;
-      }
-      ActiveBlock::Deployments => {
-        self.dispatch(IoEvent::GetDeployments).await;
-      }
-      ActiveBlock::Jobs => {
-        self.dispatch(IoEvent::GetJobs).await;
-      }
-      ActiveBlock::DaemonSets => {
-        self.dispatch(IoEvent::GetDaemonSets).await;
-      }
-      ActiveBlock::CronJobs => {
-        self.dispatch(IoEvent::GetCronJobs).await;
-      }
-      ActiveBlock::Secrets => {
-        self.dispatch(IoEvent::GetSecrets).await;
-      }
-      ActiveBlock::ReplicationControllers => {
-        self.dispatch(IoEvent::GetReplicationControllers).await;
-      }
-      ActiveBlock::StorageClasses => {
-        self.dispatch(IoEvent::GetStorageClasses).await;
-      }
-      ActiveBlock::Roles => {
-        self.dispatch(IoEvent::GetRoles).await;
-      }
-      ActiveBlock::RoleBindings => {
-        self.dispatch(IoEvent::GetRoleBindings).await;
-      }
-      ActiveBlock::ClusterRoles => {
-        self.dispatch(IoEvent::GetClusterRoles).await;
-      }
-      ActiveBlock::ClusterRoleBindings => {
-        self.dispatch(IoEvent::GetClusterRoleBinding).await;
-      }
-      ActiveBlock::Ingresses => {
-        self.dispatch(IoEvent::GetIngress).await;
-      }
-      ActiveBlock::PersistentVolumeClaims => {
-        self.dispatch(IoEvent::GetPvcs).await;
-      }
-      ActiveBlock::PersistentVolumes => {
-        self.dispatch(IoEvent::GetPvs).await;
-      }
-      ActiveBlock::ServiceAccounts => {
-        self.dispatch(IoEvent::GetServiceAccounts).await;
-      }
-      ActiveBlock::Events => {
-        self.dispatch(IoEvent::GetEvents).await;
-      }
-      ActiveBlock::DynamicResource => {
-        self.dispatch(IoEvent::GetDynamicRes).await;
-      }
-      ActiveBlock::Logs if !self.is_streaming && !self.log_previous => {
-        self.dispatch_stream(IoStreamEvent::GetPodLogs(false)).await;
-      }
-      _ => {}
-    }
+      ActiveBlock::Services => IoEvent::GetServices,
+      ActiveBlock::ConfigMaps => IoEvent::GetConfigMaps,
+      ActiveBlock::StatefulSets => IoEvent::GetStatefulSets,
+      ActiveBlock::ReplicaSets => IoEvent::GetReplicaSets,
+      ActiveBlock::Deployments => IoEvent::GetDeployments,
+      ActiveBlock::Jobs => IoEvent::GetJobs,
+      ActiveBlock::DaemonSets => IoEvent::GetDaemonSets,
+      ActiveBlock::CronJobs => IoEvent::GetCronJobs,
+      ActiveBlock::Secrets => IoEvent::GetSecrets,
+      ActiveBlock::ReplicationControllers => IoEvent::GetReplicationControllers,
+      ActiveBlock::StorageClasses => IoEvent::GetStorageClasses,
+      ActiveBlock::Roles => IoEvent::GetRoles,
+      ActiveBlock::RoleBindings => IoEvent::GetRoleBindings,
+      ActiveBlock::ClusterRoles => IoEvent::GetClusterRoles,
+      ActiveBlock::ClusterRoleBindings => IoEvent::GetClusterRoleBinding,
+      ActiveBlock::Ingresses => IoEvent::GetIngress,
+      ActiveBlock::PersistentVolumeClaims => IoEvent::GetPvcs,
+      ActiveBlock::PersistentVolumes => IoEvent::GetPvs,
+      ActiveBlock::ServiceAccounts => IoEvent::GetServiceAccounts,
+      ActiveBlock::Events => IoEvent::GetEvents,
+      ActiveBlock::DynamicResource => IoEvent::GetDynamicRes,
+      _ => return None,
+    };
+    Some(event)
   }
 
   pub async fn on_tick(&mut self, first_render: bool) {
     self.clear_expired_status_message(Instant::now());
+    if let Some(error) = self.data.logs.take_tee_error() {
+      self.handle_error(anyhow!(error));
+    }
 
     // Make one time requests on first render or refresh
     let mut did_refresh = false;
@@ -1644,6 +1926,21 @@ impl App {
         _ => {}
       }
       self.is_routing = false;
+      self.watch_changes.clear();
+    } else if self.get_current_route().id == RouteId::Home
+      && self.watch_changes.take_any(&self.active_tab_watches)
+    {
+      // only the watches the active tab read changed, so it is served from the
+      // watch cache; nodes are left to the poll as their load also fetches
+      // metrics
+      let active_block = self.get_current_route().active_block;
+      if active_block == ActiveBlock::Namespaces {
+        self
+          .dispatch_by_active_block(self.get_prev_route().active_block)
+          .await;
+      } else {
+        self.dispatch_by_active_block(active_block).await;
+      }
     }
 
     self.tick_count += 1;
@@ -1653,7 +1950,7 @@ impl App {
 /// utility methods for tests
 #[cfg(test)]
 #[macro_use]
-mod test_utils {
+pub(crate) mod test_utils {
   use std::{fmt, fs};
 
   use chrono::{DateTime, Utc};
@@ -1715,6 +2012,8 @@ mod tests {
   use anyhow::anyhow;
   use tokio::sync::mpsc;
 
+  use crate::network::watch::WatchScope;
+
   use super::*;
 
   #[tokio::test]
@@ -1952,6 +2251,58 @@ mod tests {
     assert_eq!(app.tick_count, 4);
   }
 
+  #[test]
+  fn test_list_progress_label() {
+    let mut progress = ListProgress {
+      kind: "Pod".into(),
+      loaded: 1500,
+      total: Some(4200),
+    };
+    assert_eq!(progress.label(), "Pod 1500/4200");
+    progress.total = None;
+    assert_eq!(progress.label(), "Pod 1500…");
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_watch_change_refreshes_active_block_between_polls() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(500);
+
+    let mut app = App {
+      tick_until_poll: 5,
+      tick_count: 1,
+      refresh: false,
+      io_tx: Some(sync_io_tx),
+      ..App::default()
+    };
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    app.is_routing = false;
+    let deployments = (
+      std::any::TypeId::of::<k8s_openapi::api::apps::v1::Deployment>(),
+      WatchScope::default(),
+    );
+    let pods = (
+      std::any::TypeId::of::<k8s_openapi::api::core::v1::Pod>(),
+      WatchScope::default(),
+    );
+    app.active_tab_event = Some(IoEvent::GetDeployments);
+    app.active_tab_watches = HashSet::from([deployments.clone()]);
+
+    // a watch the active tab did not read changing does not refresh it
+    app.watch_changes.mark(&pods);
+    app.on_tick(false).await;
+    assert!(sync_io_rx.try_recv().is_err());
+
+    app.watch_changes.mark(&deployments);
+    app.on_tick(false).await;
+
+    // only the active tab is re-read from the watch cache
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetDeployments);
+    assert!(sync_io_rx.try_recv().is_err());
+
+    app.on_tick(false).await;
+    assert!(sync_io_rx.try_recv().is_err());
+  }
+
   #[tokio::test]
   async fn test_on_tick_dispatch_by_active_block() {
     // Verify that on polling tick, the active block's resource is fetched
@@ -2373,6 +2724,92 @@ mod tests {
     assert!(!app.is_loading());
   }
 
+  #[tokio::test]
+  async fn test_dispatch_describe_routes_native_kinds_to_network() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(4);
+    let (sync_io_cmd_tx, mut sync_io_cmd_rx) = mpsc::channel::<IoCmdEvent>(4);
+    let mut app = App {
+      io_tx: Some(sync_io_tx),
+      io_cmd_tx: Some(sync_io_cmd_tx),
+      ..App::default()
+    };
+    let describe = |kind: &str| IoCmdEvent::GetDescribe {
+      kind: kind.into(),
+      value: "web".into(),
+      ns: Some("default".into()),
+    };
+
+    app.dispatch_describe(describe("pod")).await;
+    assert_eq!(
+      sync_io_rx.recv().await.unwrap(),
+      IoEvent::GetDescribe {
+        kind: "pod".into(),
+        value: "web".into(),
+        ns: Some("default".into()),
+      }
+    );
+
+    // No native renderer for config maps, so kubectl handles them.
+    app.dispatch_describe(describe("configmap")).await;
+    assert_eq!(sync_io_cmd_rx.recv().await.unwrap(), describe("configmap"));
+
+    app.config.describe_with_kubectl = true;
+    app.dispatch_describe(describe("pod")).await;
+    assert_eq!(sync_io_cmd_rx.recv().await.unwrap(), describe("pod"));
+  }
+
+  #[tokio::test]
+  async fn test_snapshot_mode_refuses_mutations() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(4);
+    let (sync_io_stream_tx, mut sync_io_stream_rx) = mpsc::channel::<IoStreamEvent>(4);
+    let (sync_io_cmd_tx, mut sync_io_cmd_rx) = mpsc::channel::<IoCmdEvent>(4);
+    let mut app = App {
+      io_tx: Some(sync_io_tx),
+      io_stream_tx: Some(sync_io_stream_tx),
+      io_cmd_tx: Some(sync_io_cmd_tx),
+      snapshot: Some(PathBuf::from("/tmp/dump")),
+      ..App::default()
+    };
+
+    app
+      .dispatch(IoEvent::DeleteResource {
+        block: ActiveBlock::Pods,
+        name: "web".into(),
+        namespace: Some("default".into()),
+      })
+      .await;
+    assert!(sync_io_rx.try_recv().is_err());
+    assert_eq!(
+      app.api_error,
+      "Deleting resources is disabled in read-only snapshot mode"
+    );
+
+    app
+      .start_port_forward("pods".into(), "default".into(), "web".into(), 8080, 80)
+      .await;
+    assert!(sync_io_stream_rx.try_recv().is_err());
+    assert!(app.port_forwards.is_empty());
+
+    // describe never falls back to kubectl, which would hit a live cluster
+    app.config.describe_with_kubectl = true;
+    let describe = |kind: &str| IoCmdEvent::GetDescribe {
+      kind: kind.into(),
+      value: "web".into(),
+      ns: Some("default".into()),
+    };
+    app.dispatch_describe(describe("pod")).await;
+    assert!(matches!(
+      sync_io_rx.recv().await.unwrap(),
+      IoEvent::GetDescribe { .. }
+    ));
+    app.dispatch_describe(describe("configmap")).await;
+    assert!(sync_io_cmd_rx.try_recv().is_err());
+
+    // reads still go through
+    app.dispatch(IoEvent::GetPods).await;
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetPods);
+  }
+
   #[tokio::test]
   async fn test_dispatch_pod_logs_enqueues_all_container_logs_and_routes_to_logs() {
     let (sync_io_stream_tx, mut sync_io_stream_rx) = mpsc::channel::<IoStreamEvent>(16);
@@ -2593,8 +3030,10 @@ mod tests {
       name: format!("web-{id}"),
       local_port,
       remote_port: 80,
+      pod: None,
       status,
-      child: None,
+      stats: Default::default(),
+      task: None,
     });
   }
 
@@ -2618,12 +3057,13 @@ mod tests {
     app.open_port_forwards();
     app.port_forwards_state.select(Some(1));
 
-    // No child handle in tests, so nothing to kill; the entry is still removed.
-    assert!(app.remove_port_forward(1).is_none());
+    // No task handle in tests, so nothing to abort; the entry is still removed.
+    assert!(app.remove_port_forward(1));
+    assert!(!app.remove_port_forward(1));
     assert_eq!(app.port_forwards.len(), 1);
     assert_eq!(app.selected_port_forward_id(), Some(0));
 
-    assert!(app.remove_port_forward(0).is_none());
+    assert!(app.remove_port_forward(0));
     assert!(app.port_forwards.is_empty());
     // Overlay auto-closes once the list empties.
     assert!(!app.show_port_forwards);
@@ -1,6 +1,12 @@
-use std::collections::VecDeque;
+use std::{
+  cmp::Ordering,
+  collections::{HashSet, VecDeque},
+  ops::Range,
+  path::PathBuf,
+};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use ratatui::{
   layout::Rect,
   style::{Modifier, Style},
@@ -8,10 +14,14 @@ use ratatui::{
   widgets::{Block, List, ListItem, ListState, TableState},
   Frame,
 };
+use regex::Regex;
 use serde::Serialize;
 
-use super::{ActiveBlock, App, Route};
-use crate::{handlers::ScrollEvent, network::Network};
+use super::{
+  logs::{self, LogFilter, LogLevel, LogLimits, LogSearch, LogStyles, LogTee, StructuredLine},
+  utils, ActiveBlock, App, Route,
+};
+use crate::{config::LogFieldNames, handlers::ScrollEvent, network::Network};
 
 #[async_trait]
 pub trait AppResource {
@@ -41,6 +51,79 @@ pub trait Named {
   fn get_name(&self) -> &String;
 }
 
+/// A cell value typed for comparison when a table is sorted by its column.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SortValue {
+  /// Compared case-insensitively, with digit runs ordered numerically.
+  Text(String),
+  /// Counts, ages in seconds and quantities in base units.
+  Number(f64),
+  /// An empty cell; sorts after every value in either direction.
+  Missing,
+}
+
+impl SortValue {
+  pub fn text(text: &str) -> Self {
+    if text.is_empty() {
+      Self::Missing
+    } else {
+      Self::Text(text.to_owned())
+    }
+  }
+
+  pub fn number(number: impl Into<f64>) -> Self {
+    Self::Number(number.into())
+  }
+
+  /// An age or duration cell such as `3d4h`, compared as a duration.
+  pub fn age(age: &str) -> Self {
+    match utils::age_to_secs(age) {
+      Some(secs) => Self::Number(secs as f64),
+      None => Self::text(age),
+    }
+  }
+
+  /// A CPU or memory cell such as `250m` or `512Mi`, compared as a quantity.
+  pub fn quantity(quantity: &str) -> Self {
+    match utils::quantity_to_f64(quantity) {
+      Some(value) => Self::Number(value),
+      None => Self::text(quantity),
+    }
+  }
+
+  /// Order two values; `Missing` stays last whichever way the column is sorted.
+  fn compare(&self, other: &Self, descending: bool) -> Ordering {
+    let ord = match (self, other) {
+      (Self::Missing, Self::Missing) => return Ordering::Equal,
+      (Self::Missing, _) => return Ordering::Greater,
+      (_, Self::Missing) => return Ordering::Less,
+      (Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+      (Self::Text(a), Self::Text(b)) => utils::natural_cmp(a, b),
+      (Self::Number(_), Self::Text(_)) => Ordering::Less,
+      (Self::Text(_), Self::Number(_)) => Ordering::Greater,
+    };
+    if descending {
+      ord.reverse()
+    } else {
+      ord
+    }
+  }
+}
+
+/// Rows that can be sorted by any of the columns their table shows.
+pub trait Sortable: Named {
+  /// The value shown under `column` (a `ColumnDef` label), typed for comparison.
+  fn sort_value(&self, column: &str) -> SortValue;
+
+  /// Identity used to keep the selection on the same object when rows move.
+  fn sort_id(&self) -> String {
+    match self.sort_value("Namespace") {
+      SortValue::Text(ns) => format!("{}/{}", ns, self.get_name()),
+      _ => self.get_name().clone(),
+    }
+  }
+}
+
 pub trait KubeResource<T: Serialize>: Named {
   fn get_k8s_obj(&self) -> &T;
 
@@ -136,6 +219,100 @@ impl<T> Scrollable for StatefulList<T> {
   }
 }
 
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+  text
+    .get(..prefix.len())
+    .filter(|head| head.eq_ignore_ascii_case(prefix))
+    .map(|_| &text[prefix.len()..])
+}
+
+/// Server-side label/field selector for a resource tab, typed into the filter
+/// as `l:app=api,tier!=db` and/or `f:status.phase=Running`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ListSelector {
+  pub labels: Option<String>,
+  pub fields: Option<String>,
+}
+
+impl ListSelector {
+  /// Parse filter text in selector syntax. Each `l:` / `f:` term runs up to
+  /// the next one, so set-based requirements such as `l:env in (prod,stage)`
+  /// stay whole; terms of the same kind are comma-joined. The prefixes are
+  /// case-insensitive; the selectors are not. Returns `None` when the text is
+  /// a plain name filter, i.e. does not start with a prefix.
+  pub fn parse(filter: &str) -> Option<ListSelector> {
+    // (is a label selector, selector text)
+    let mut terms: Vec<(bool, String)> = vec![];
+    for word in filter.split_whitespace() {
+      if let Some(value) = strip_prefix_ignore_case(word, "l:") {
+        terms.push((true, value.to_owned()));
+      } else if let Some(value) = strip_prefix_ignore_case(word, "f:") {
+        terms.push((false, value.to_owned()));
+      } else {
+        let (_, term) = terms.last_mut()?;
+        if !term.is_empty() {
+          term.push(' ');
+        }
+        term.push_str(word);
+      }
+    }
+    terms.first()?;
+    let join = |labels: bool| {
+      let joined = terms
+        .iter()
+        .filter(|(is_label, term)| *is_label == labels && !term.is_empty())
+        .map(|(_, term)| term.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+      (!joined.is_empty()).then_some(joined)
+    };
+    Some(ListSelector {
+      labels: join(true),
+      fields: join(false),
+    })
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.labels.is_none() && self.fields.is_none()
+  }
+
+  /// Apply to list params for the next fetch.
+  pub fn list_params(&self, lp: kube::api::ListParams) -> kube::api::ListParams {
+    let lp = match &self.labels {
+      Some(labels) => lp.labels(labels),
+      None => lp,
+    };
+    match &self.fields {
+      Some(fields) => lp.fields(fields),
+      None => lp,
+    }
+  }
+
+  /// Apply to watch params so the watch sees the same subset as the list.
+  pub fn watch_params(&self, wp: kube::api::WatchParams) -> kube::api::WatchParams {
+    let wp = match &self.labels {
+      Some(labels) => wp.labels(labels),
+      None => wp,
+    };
+    match &self.fields {
+      Some(fields) => wp.fields(fields),
+      None => wp,
+    }
+  }
+
+  /// Compact form shown in the tab title, e.g. `l:app=api f:status.phase=Running`.
+  pub fn label(&self) -> String {
+    let mut parts = vec![];
+    if let Some(labels) = &self.labels {
+      parts.push(format!("l:{}", labels));
+    }
+    if let Some(fields) = &self.fields {
+      parts.push(format!("f:{}", fields));
+    }
+    parts.join(" ")
+  }
+}
+
 #[derive(Clone, Debug)]
 pub struct StatefulTable<T> {
   pub state: TableState,
@@ -145,12 +322,67 @@ pub struct StatefulTable<T> {
   /// When a filter is active, maps visible row index → `items` index.
   /// Empty when no filter is applied.
   pub filtered_indices: Vec<usize>,
+  /// Server-side selector applied to list calls for this tab until cleared.
+  pub selector: Option<ListSelector>,
+  /// Client-side sort, kept across refreshes until cycled off.
+  pub sort: Option<TableSort>,
+  /// Column labels visible on the last draw; the sort key cycles through these.
+  pub columns: Vec<String>,
+  /// Row ids (see [`Sortable::sort_id`]) marked for a bulk action. Kept across
+  /// refreshes; ids whose rows disappear are simply skipped.
+  pub marked: HashSet<String>,
+  sorter: Option<RowSorter<T>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableSort {
+  pub column: String,
+  pub descending: bool,
+}
+
+/// The [`Sortable`] accessors of a table's rows, captured when the table is
+/// drawn so `set_items` can re-sort without a trait bound on `T`.
+struct RowSorter<T> {
+  value: fn(&T, &str) -> SortValue,
+  id: fn(&T) -> String,
+}
+
+impl<T> Clone for RowSorter<T> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<T> Copy for RowSorter<T> {}
+
+impl<T> std::fmt::Debug for RowSorter<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("RowSorter")
+  }
 }
 
 pub trait FilterableTable {
   fn is_filter_active(&self) -> bool;
   fn count_label(&self) -> String;
   fn filter_parts_mut(&mut self) -> (&mut String, &mut bool, &mut TableState);
+  /// If the filter text is selector syntax, move it into the tab's selector
+  /// (an empty `l:` / `f:` clears it) and close the filter. Returns whether
+  /// the selector changed hands this way.
+  fn commit_selector_filter(&mut self) -> bool;
+  /// Drop the tab's selector. Returns whether one was set.
+  fn clear_selector(&mut self) -> bool;
+  /// Advance the sort to the next step: each visible column ascending, then
+  /// descending, then back to the server order.
+  fn cycle_sort(&mut self);
+  /// Mark or unmark the selected row for a bulk action.
+  fn toggle_mark(&mut self);
+  /// Mark every visible (filtered) row, or unmark them all when every one of
+  /// them is already marked.
+  fn toggle_mark_all(&mut self);
+  /// Drop all marks. Returns whether any were set.
+  fn clear_marks(&mut self) -> bool;
+  /// Number of marked rows still present in the table.
+  fn marked_count(&self) -> usize;
 }
 
 impl<T> StatefulTable<T> {
@@ -161,6 +393,11 @@ impl<T> StatefulTable<T> {
       filter: String::new(),
       filter_active: false,
       filtered_indices: Vec::new(),
+      selector: None,
+      sort: None,
+      columns: Vec::new(),
+      marked: HashSet::new(),
+      sorter: None,
     }
   }
 
@@ -175,6 +412,10 @@ impl<T> StatefulTable<T> {
   }
 
   pub fn set_items(&mut self, items: Vec<T>) {
+    let (selected_id, filtered_ids) = match self.sort {
+      Some(_) => (self.selected_sort_id(), self.filtered_sort_ids()),
+      None => (None, None),
+    };
     let item_len = items.len();
     self.items = items;
     if !self.items.is_empty() {
@@ -189,6 +430,121 @@ impl<T> StatefulTable<T> {
       });
       self.state.select(Some(i));
     }
+    self.sort_items(selected_id, filtered_ids);
+  }
+
+  /// Append the next page of a list that is still being fetched, keeping the
+  /// selection on the same object.
+  pub fn extend_items(&mut self, items: Vec<T>) {
+    let (selected_id, filtered_ids) = match self.sort {
+      Some(_) => (self.selected_sort_id(), self.filtered_sort_ids()),
+      None => (None, None),
+    };
+    self.items.extend(items);
+    if self.state.selected().is_none() && !self.items.is_empty() {
+      self.state.select(Some(0));
+    }
+    self.sort_items(selected_id, filtered_ids);
+  }
+
+  fn selected_sort_id(&self) -> Option<String> {
+    let sorter = self.sorter?;
+    let selected = self.state.selected()?;
+    let index = if self.filtered_indices.is_empty() {
+      selected
+    } else {
+      *self.filtered_indices.get(selected)?
+    };
+    self.items.get(index).map(sorter.id)
+  }
+
+  /// Ids of the rows currently on screen: the filtered rows when a filter is
+  /// typed, otherwise every row.
+  fn visible_sort_ids(&self) -> Vec<String> {
+    let Some(sorter) = self.sorter else {
+      return vec![];
+    };
+    if self.filter.is_empty() {
+      self.items.iter().map(sorter.id).collect()
+    } else {
+      self
+        .filtered_indices
+        .iter()
+        .filter_map(|&i| self.items.get(i).map(sorter.id))
+        .collect()
+    }
+  }
+
+  fn filtered_sort_ids(&self) -> Option<HashSet<String>> {
+    let sorter = self.sorter?;
+    (!self.filtered_indices.is_empty()).then(|| {
+      self
+        .filtered_indices
+        .iter()
+        .filter_map(|&i| self.items.get(i).map(sorter.id))
+        .collect()
+    })
+  }
+
+  /// Apply the active sort (stable, so ties keep their server order), then move
+  /// the selection back onto the object that was selected before.
+  fn sort_items(&mut self, selected_id: Option<String>, filtered_ids: Option<HashSet<String>>) {
+    let (Some(sort), Some(sorter)) = (&self.sort, self.sorter) else {
+      return;
+    };
+    let mut keyed: Vec<(SortValue, T)> = std::mem::take(&mut self.items)
+      .into_iter()
+      .map(|item| ((sorter.value)(&item, &sort.column), item))
+      .collect();
+    keyed.sort_by(|(a, _), (b, _)| a.compare(b, sort.descending));
+    self.items = keyed.into_iter().map(|(_, item)| item).collect();
+
+    // Until the next draw re-applies the filter, keep the visible rows mapped
+    // to the same objects at their new positions.
+    if let Some(ids) = filtered_ids {
+      self.filtered_indices = self
+        .items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| ids.contains(&(sorter.id)(item)))
+        .map(|(i, _)| i)
+        .collect();
+    }
+    let Some(id) = selected_id else {
+      return;
+    };
+    let Some(index) = self.items.iter().position(|item| (sorter.id)(item) == id) else {
+      return;
+    };
+    let visible = if self.filtered_indices.is_empty() {
+      Some(index)
+    } else {
+      self.filtered_indices.iter().position(|&i| i == index)
+    };
+    if let Some(visible) = visible {
+      self.state.select(Some(visible));
+    }
+  }
+}
+
+impl<T: Sortable> StatefulTable<T> {
+  /// Record the columns the table is drawn with and capture the row accessors
+  /// used for sorting.
+  pub fn set_sort_columns(&mut self, columns: &[&str]) {
+    if self
+      .columns
+      .iter()
+      .map(String::as_str)
+      .ne(columns.iter().copied())
+    {
+      self.columns = columns.iter().map(|c| c.to_string()).collect();
+    }
+    if self.sorter.is_none() {
+      self.sorter = Some(RowSorter {
+        value: T::sort_value,
+        id: T::sort_id,
+      });
+    }
   }
 }
 
@@ -208,6 +564,86 @@ impl<T> FilterableTable for StatefulTable<T> {
   fn filter_parts_mut(&mut self) -> (&mut String, &mut bool, &mut TableState) {
     (&mut self.filter, &mut self.filter_active, &mut self.state)
   }
+
+  fn commit_selector_filter(&mut self) -> bool {
+    let Some(selector) = ListSelector::parse(&self.filter) else {
+      return false;
+    };
+    self.selector = (!selector.is_empty()).then_some(selector);
+    self.filter.clear();
+    self.filter_active = false;
+    self.filtered_indices.clear();
+    self.state.select(Some(0));
+    true
+  }
+
+  fn clear_selector(&mut self) -> bool {
+    self.selector.take().is_some()
+  }
+
+  fn cycle_sort(&mut self) {
+    if self.sorter.is_none() || self.columns.is_empty() {
+      return;
+    }
+    let selected_id = self.selected_sort_id();
+    let filtered_ids = self.filtered_sort_ids();
+    let first = || TableSort {
+      column: self.columns[0].clone(),
+      descending: false,
+    };
+    self.sort = match &self.sort {
+      None => Some(first()),
+      Some(sort) if !sort.descending => Some(TableSort {
+        column: sort.column.clone(),
+        descending: true,
+      }),
+      Some(sort) => match self.columns.iter().position(|c| *c == sort.column) {
+        Some(i) if i + 1 < self.columns.len() => Some(TableSort {
+          column: self.columns[i + 1].clone(),
+          descending: false,
+        }),
+        Some(_) => None,
+        None => Some(first()),
+      },
+    };
+    self.sort_items(selected_id, filtered_ids);
+  }
+
+  fn toggle_mark(&mut self) {
+    if let Some(id) = self.selected_sort_id() {
+      if !self.marked.remove(&id) {
+        self.marked.insert(id);
+      }
+    }
+  }
+
+  fn toggle_mark_all(&mut self) {
+    let visible = self.visible_sort_ids();
+    if visible.iter().all(|id| self.marked.contains(id)) {
+      for id in &visible {
+        self.marked.remove(id);
+      }
+    } else {
+      self.marked.extend(visible);
+    }
+  }
+
+  fn clear_marks(&mut self) -> bool {
+    let had_marks = !self.marked.is_empty();
+    self.marked.clear();
+    had_marks
+  }
+
+  fn marked_count(&self) -> usize {
+    match self.sorter {
+      Some(sorter) if !self.marked.is_empty() => self
+        .items
+        .iter()
+        .filter(|item| self.marked.contains(&(sorter.id)(item)))
+        .count(),
+      _ => 0,
+    }
+  }
 }
 
 impl<T> Scrollable for StatefulTable<T> {
@@ -230,6 +666,19 @@ impl<T> Scrollable for StatefulTable<T> {
 }
 
 impl<T: Clone> StatefulTable<T> {
+  /// Clones of the marked rows still present in the table, in table order.
+  pub fn marked_items(&self) -> Vec<T> {
+    match self.sorter {
+      Some(sorter) if !self.marked.is_empty() => self
+        .items
+        .iter()
+        .filter(|item| self.marked.contains(&(sorter.id)(item)))
+        .cloned()
+        .collect(),
+      _ => vec![],
+    }
+  }
+
   /// A clone of the currently selected item.
   /// When a filter is active, maps the visual index through `filtered_indices`
   /// so the correct item is returned regardless of filtering.
@@ -355,20 +804,97 @@ impl Scrollable for ScrollableTxt {
   }
 }
 
-// TODO implement line buffer to avoid gathering too much data in memory
-const MAX_LOG_RECORDS: usize = 10_000;
+/// One log line and what the pane derives from it.
+#[derive(Debug, Clone)]
+struct LogRecord {
+  text: String,
+  /// `text` rendered from its JSON / logfmt fields, when it has any.
+  structured: Option<StructuredLine>,
+  /// Severity, from the structured level or detected in the text. Indented
+  /// continuation lines take the level of the line before them.
+  level: Option<LogLevel>,
+  /// Where the detected level sits in `text`, for colouring raw lines.
+  level_span: Option<Range<usize>>,
+  /// Rows of the shown line wrapped at a width, cached until the width changes.
+  wrapped: Option<(Vec<String>, u16)>,
+  /// Hidden by the include/exclude filter; kept so clearing it restores the line.
+  hidden: bool,
+}
+
+impl LogRecord {
+  fn new(text: String, fields: &LogFieldNames, previous: Option<LogLevel>) -> LogRecord {
+    let structured = logs::parse_structured(&text, fields);
+    let (detected, level_span) = logs::detect_level(&text).unzip();
+    let continuation = text.starts_with([' ', '\t']);
+    let level = structured
+      .as_ref()
+      .and_then(|structured| structured.level.as_ref().map(|(level, _)| *level))
+      .or(detected)
+      .or(previous.filter(|_| continuation));
+    LogRecord {
+      text,
+      structured,
+      level,
+      level_span,
+      wrapped: None,
+      hidden: false,
+    }
+  }
+
+  /// The part of the shown line to colour by level.
+  fn level_region(&self, raw: bool) -> Option<(LogLevel, Range<usize>)> {
+    match &self.structured {
+      Some(structured) if !raw => structured.level.clone(),
+      _ => self.level.zip(self.level_span.clone()),
+    }
+  }
+
+  /// Bytes held for the buffer's byte cap.
+  fn size(&self) -> usize {
+    self.text.len()
+      + self
+        .structured
+        .as_ref()
+        .map_or(0, |structured| structured.line.len())
+  }
+
+  /// The line as displayed: the structured rendering unless `raw` is set.
+  fn shown(&self, raw: bool) -> &str {
+    match &self.structured {
+      Some(structured) if !raw => &structured.line,
+      _ => &self.text,
+    }
+  }
+}
 
 #[derive(Debug, Clone)]
 pub struct LogsState {
   /// Stores the log messages to be displayed
-  ///
-  /// (original_message, (wrapped_message, wrapped_at_width))
-  #[allow(clippy::type_complexity)]
-  records: VecDeque<(String, Option<(Vec<ListItem<'static>>, u16)>)>,
+  records: VecDeque<LogRecord>,
   wrapped_length: usize,
   viewport_height: usize,
   pub state: ListState,
   pub id: String,
+  pub search: LogSearch,
+  filter: LogFilter,
+  /// Field names structured lines are parsed with.
+  fields: LogFieldNames,
+  /// Show records as received instead of their structured rendering.
+  raw: bool,
+  limits: LogLimits,
+  /// Bytes held by `records`, per [`LogRecord::size`].
+  bytes: usize,
+  /// Records dropped from the front to stay within `limits`.
+  evicted: usize,
+  /// Whether the last render wrapped lines, i.e. whether a record takes its
+  /// cached wrapped rows or a single row in the list.
+  wrapped_render: bool,
+  /// File new records are appended to while tee is on.
+  tee: Option<LogTee>,
+  /// Why the tee stopped on its own, until reported.
+  tee_error: Option<String>,
+  /// Record to select on the next render, once its row is known.
+  jump_to: Option<usize>,
 }
 
 impl LogsState {
@@ -379,14 +905,49 @@ impl LogsState {
       wrapped_length: 0,
       viewport_height: 0,
       id,
+      search: LogSearch::default(),
+      filter: LogFilter::default(),
+      fields: LogFieldNames::default(),
+      raw: false,
+      limits: LogLimits::default(),
+      bytes: 0,
+      evicted: 0,
+      wrapped_render: false,
+      tee: None,
+      tee_error: None,
+      jump_to: None,
     }
   }
 
+  /// Parse structured lines with `fields`, starting in raw mode if `raw`.
+  pub fn with_format(mut self, fields: LogFieldNames, raw: bool) -> LogsState {
+    self.fields = fields;
+    self.raw = raw;
+    self
+  }
+
+  /// Cap the buffer at `limits` instead of the default line count.
+  pub fn with_limits(mut self, limits: LogLimits) -> LogsState {
+    self.limits = limits;
+    self
+  }
+
+  /// An empty buffer for the same stream restarted with new options, keeping
+  /// the search query and filters.
+  pub fn restarted(&self) -> LogsState {
+    let mut logs = LogsState::new(self.id.clone())
+      .with_format(self.fields.clone(), self.raw)
+      .with_limits(self.limits);
+    logs.search.query = self.search.query.clone();
+    logs.filter = self.filter.clone();
+    logs
+  }
+
   /// get a plain text version of the logs
   pub fn get_plain_text(&self) -> String {
-    self.records.iter().fold(String::new(), |mut acc, v| {
+    self.visible().fold(String::new(), |mut acc, (_, text)| {
       acc.push('\n');
-      acc.push_str(v.0.as_str());
+      acc.push_str(text);
       acc
     })
   }
@@ -397,20 +958,73 @@ impl LogsState {
     f: &mut Frame<'_>,
     logs_area: Rect,
     block: Block<'_>,
-    style: Style,
+    styles: LogStyles,
     follow: bool,
     wrap: bool,
   ) {
     let available_lines = logs_area.height as usize;
     self.viewport_height = available_lines;
     let wrap_width = logs_area.width.max(1);
-    let mut items = if wrap {
-      self.wrapped_items(wrap_width, style)
-    } else {
-      self.unwrapped_items(style)
-    };
+    self.wrapped_render = wrap;
+    let jump_to = self.jump_to.take();
+    let aggregate = self.id.starts_with("agg:");
+    let mut jump_row = None;
+    let mut items = Vec::with_capacity(self.records.len());
+    for (index, record) in self.records.iter_mut().enumerate() {
+      if record.hidden {
+        continue;
+      }
+      if jump_to == Some(index) {
+        jump_row = Some(items.len());
+      }
+      let shown = match &record.structured {
+        Some(structured) if !self.raw => &structured.line,
+        _ => &record.text,
+      };
+      let mut regions = vec![];
+      if aggregate {
+        if let Some((range, pod)) = logs::pod_prefix(shown) {
+          regions.push((range, styles.prefix(pod)));
+        }
+      }
+      if let Some((level, range)) = record.level_region(self.raw) {
+        regions.push((range, styles.level(level)));
+      }
+      if self.search.is_match(index) {
+        regions.extend(
+          logs::match_ranges(shown, &self.search.query)
+            .into_iter()
+            .map(|range| (range, styles.search_match)),
+        );
+      }
+      if !wrap {
+        items.push(ListItem::new(logs::highlight_line(
+          shown,
+          0,
+          &regions,
+          styles.text,
+        )));
+        continue;
+      }
+      let rows = Self::wrapped_rows(shown, &mut record.wrapped, wrap_width);
+      if regions.is_empty() {
+        items.extend(
+          rows
+            .iter()
+            .map(|row| ListItem::new(Span::styled(row.clone(), styles.text))),
+        );
+      } else {
+        let offsets = logs::wrapped_offsets(shown, rows);
+        items.extend(rows.iter().zip(offsets).map(|(row, offset)| {
+          ListItem::new(logs::highlight_line(row, offset, &regions, styles.text))
+        }));
+      }
+    }
     self.wrapped_length = items.len();
 
+    if let Some(row) = jump_row {
+      self.state.select(Some(row));
+    }
     if follow {
       self.unselect();
       let wrapped_lines_to_skip = items.len().saturating_sub(available_lines);
@@ -430,84 +1044,254 @@ impl LogsState {
   /// Add a record to be displayed
   #[cfg(test)]
   pub fn add_record(&mut self, record: String) {
-    self.records.push_back((record, None));
-    while self.records.len() > MAX_LOG_RECORDS {
-      self.records.pop_front();
-    }
+    self.add_records(vec![record]);
   }
 
   /// Add multiple records in a batch
   pub fn add_records(&mut self, records: Vec<String>) {
-    for record in records {
-      self.records.push_back((record, None));
+    let start = self.records.len();
+    for text in records {
+      let previous = self.records.back().and_then(|record| record.level);
+      let mut record = LogRecord::new(text, &self.fields, previous);
+      record.hidden = !self.filter.allows(&record.text, record.level);
+      self.bytes += record.size();
+      self.records.push_back(record);
     }
-    while self.records.len() > MAX_LOG_RECORDS {
-      self.records.pop_front();
+    if let Some(tee) = &self.tee {
+      let appended = tee.append(
+        self
+          .records
+          .range(start..)
+          .map(|record| record.text.as_str()),
+      );
+      if let Err(err) = appended {
+        self.tee_error = Some(format!(
+          "Stopped writing logs to {}: {}",
+          tee.path.display(),
+          err
+        ));
+        self.tee = None;
+      }
+    }
+    let raw = self.raw;
+    self.search.extend(
+      self
+        .records
+        .range(start..)
+        .enumerate()
+        .filter(|(_, record)| !record.hidden)
+        .map(|(i, record)| (start + i, record.shown(raw))),
+    );
+    self.evict();
+  }
+
+  /// Drop the oldest records until the buffer is within its limits, keeping at
+  /// least the newest one. Indices held by the search and a pending jump, and
+  /// the row a paused view is scrolled to, shift with the records.
+  fn evict(&mut self) {
+    let mut evicted = 0;
+    let mut evicted_rows = 0;
+    while self.records.len() > 1 && self.limits.exceeded(self.records.len(), self.bytes) {
+      let Some(record) = self.records.pop_front() else {
+        break;
+      };
+      self.bytes -= record.size();
+      evicted += 1;
+      evicted_rows += match &record.wrapped {
+        _ if record.hidden => 0,
+        Some((rows, _)) if self.wrapped_render => rows.len(),
+        _ => 1,
+      };
     }
+    if evicted == 0 {
+      return;
+    }
+    self.evicted += evicted;
+    self.search.evict(evicted);
+    self.jump_to = self.jump_to.and_then(|index| index.checked_sub(evicted));
+    if let Some(row) = self.state.selected() {
+      self.state.select(Some(row.saturating_sub(evicted_rows)));
+      let offset = self.state.offset_mut();
+      *offset = offset.saturating_sub(evicted_rows);
+    }
+  }
+
+  /// How many of the oldest records were dropped to stay within the limits.
+  pub fn evicted(&self) -> usize {
+    self.evicted
   }
 
-  /// Get the last n raw log lines (for dedup on reconnect)
-  pub fn last_n_records(&self, n: usize) -> Vec<&str> {
+  /// `(index, shown line)` of the records the filter lets through.
+  fn visible(&self) -> impl Iterator<Item = (usize, &str)> {
     self
       .records
       .iter()
-      .rev()
-      .take(n)
-      .map(|(s, _)| s.as_str())
-      .collect()
+      .enumerate()
+      .filter(|(_, record)| !record.hidden)
+      .map(|(i, record)| (i, record.shown(self.raw)))
   }
 
-  fn unselect(&mut self) {
-    self.state.select(None);
+  /// Records shown / held, for the filter counter in the title.
+  pub fn visible_count(&self) -> (usize, usize) {
+    (self.visible().count(), self.records.len())
   }
 
-  pub fn freeze_follow_position(&mut self) {
-    if self.state.selected().is_none() {
-      let offset = self.wrapped_length.saturating_sub(self.viewport_height);
-      self.state.select(Some(offset));
-    }
+  /// Re-run the search after its query changed and jump to the newest match.
+  pub fn update_search(&mut self) {
+    let mut search = std::mem::take(&mut self.search);
+    search.rebuild(self.visible());
+    self.search = search;
+    self.jump_to = self.search.current_record();
   }
 
-  /// One ListItem per record, unwrapped. The List widget truncates lines wider
-  /// than the viewport, so long lines stay on a single row instead of reflowing.
-  fn unwrapped_items(&self, style: Style) -> Vec<ListItem<'static>> {
-    self
+  /// Jump to the next (or previous) search match. Returns whether there was
+  /// one to jump to.
+  pub fn jump_to_match(&mut self, forward: bool) -> bool {
+    let record = if forward {
+      self.search.next()
+    } else {
+      self.search.prev()
+    };
+    self.jump_to = record;
+    record.is_some()
+  }
+
+  /// Jump to the first shown record logged at or after `time`, going by the
+  /// Kubernetes timestamps. Returns whether there was one.
+  pub fn jump_to_time(&mut self, time: DateTime<Utc>) -> bool {
+    let record = self
       .records
       .iter()
-      .map(|record| ListItem::new(Span::styled(record.0.clone(), style)))
-      .collect()
+      .enumerate()
+      .filter(|(_, record)| !record.hidden)
+      .find(|(_, record)| logs::record_time(&record.text).is_some_and(|t| t >= time))
+      .map(|(i, _)| i);
+    self.jump_to = record;
+    record.is_some()
+  }
+
+  pub fn filter(&self) -> &LogFilter {
+    &self.filter
+  }
+
+  /// Replace the include (or exclude) filter and re-evaluate which records are
+  /// shown. A paused view is moved back to the bottom since row positions change.
+  pub fn set_filter(&mut self, exclude: bool, regex: Option<Regex>) {
+    if exclude {
+      self.filter.exclude = regex;
+    } else {
+      self.filter.include = regex;
+    }
+    self.refilter();
+  }
+
+  /// Write every record as received (unfiltered, unformatted) to a new file
+  /// at `path`. Returns how many lines were written.
+  pub fn save_to(&self, path: PathBuf) -> std::io::Result<usize> {
+    LogTee::create(path, self.raw_lines())?;
+    Ok(self.records.len())
+  }
+
+  /// Write the buffer to a new file at `path` and keep appending records to it
+  /// as they arrive.
+  pub fn start_tee(&mut self, path: PathBuf) -> std::io::Result<()> {
+    self.tee = Some(LogTee::create(path, self.raw_lines())?);
+    Ok(())
+  }
+
+  /// Stop appending. Returns the file that was being written, if any.
+  pub fn stop_tee(&mut self) -> Option<PathBuf> {
+    self.tee.take().map(|tee| tee.path)
+  }
+
+  pub fn tee_path(&self) -> Option<&std::path::Path> {
+    self.tee.as_ref().map(|tee| tee.path.as_path())
+  }
+
+  /// The error that stopped the tee, once.
+  pub fn take_tee_error(&mut self) -> Option<String> {
+    self.tee_error.take()
   }
 
-  fn wrapped_items(&mut self, width: u16, style: Style) -> Vec<ListItem<'static>> {
-    let logs_area_width = width as usize;
+  fn raw_lines(&self) -> impl Iterator<Item = &str> {
+    self.records.iter().map(|record| record.text.as_str())
+  }
+
+  /// Set (or clear) the minimum level records must have to be shown.
+  pub fn set_min_level(&mut self, level: Option<LogLevel>) {
+    self.filter.min_level = level;
+    self.refilter();
+  }
+
+  /// Drop every filter. Returns whether any was set.
+  pub fn clear_filter(&mut self) -> bool {
+    if !self.filter.is_active() {
+      return false;
+    }
+    self.filter = LogFilter::default();
+    self.refilter();
+    true
+  }
+
+  /// Switch between raw and structured lines. The search runs over what is
+  /// shown, so it is re-run too.
+  pub fn set_raw(&mut self, raw: bool) {
+    if self.raw == raw {
+      return;
+    }
+    self.raw = raw;
+    for record in &mut self.records {
+      record.wrapped = None;
+    }
+    self.unselect();
+    let mut search = std::mem::take(&mut self.search);
+    search.rebuild(self.visible());
+    self.search = search;
+  }
 
+  /// Whether any record has a structured rendering the raw toggle applies to.
+  pub fn has_structured(&self) -> bool {
     self
       .records
-      .iter_mut()
-      .flat_map(|record| {
-        if let Some(wrapped) = &record.1 {
-          if wrapped.1 == width {
-            return wrapped.0.clone();
-          }
-        }
+      .iter()
+      .any(|record| record.structured.is_some())
+  }
 
-        record.1 = Some((
-          textwrap::wrap(record.0.as_ref(), logs_area_width)
-            .into_iter()
-            .map(|line| line.to_string())
-            .map(|line| Span::styled(line, style))
-            .map(ListItem::new)
-            .collect::<Vec<ListItem<'_>>>(),
-          width,
-        ));
+  fn refilter(&mut self) {
+    for record in &mut self.records {
+      record.hidden = !self.filter.allows(&record.text, record.level);
+    }
+    self.unselect();
+    let mut search = std::mem::take(&mut self.search);
+    search.rebuild(self.visible());
+    self.search = search;
+  }
 
-        record
-          .1
-          .as_ref()
-          .map(|wrapped| wrapped.0.clone())
-          .unwrap_or_default()
-      })
-      .collect()
+  fn unselect(&mut self) {
+    self.state.select(None);
+  }
+
+  pub fn freeze_follow_position(&mut self) {
+    if self.state.selected().is_none() {
+      let offset = self.wrapped_length.saturating_sub(self.viewport_height);
+      self.state.select(Some(offset));
+    }
+  }
+
+  /// A record's rows wrapped to `width`, cached until the width changes.
+  fn wrapped_rows<'a>(
+    text: &str,
+    cache: &'a mut Option<(Vec<String>, u16)>,
+    width: u16,
+  ) -> &'a [String] {
+    if !matches!(cache, Some((_, cached)) if *cached == width) {
+      let rows = textwrap::wrap(text, width as usize)
+        .into_iter()
+        .map(|line| line.into_owned())
+        .collect();
+      *cache = Some((rows, width));
+    }
+    cache.as_ref().map_or(&[], |(rows, _)| rows.as_slice())
   }
 }
 
@@ -533,7 +1317,10 @@ mod tests {
   use ratatui::{backend::TestBackend, buffer::Buffer, layout::Position, Terminal};
 
   use super::*;
-  use crate::app::{ns::KubeNs, ActiveBlock, RouteId};
+  use crate::{
+    app::{ns::KubeNs, ActiveBlock, RouteId},
+    config::DEFAULT_LOG_MAX_LINES,
+  };
 
   #[test]
   fn test_kube_resource() {
@@ -615,6 +1402,189 @@ mod tests {
     assert_eq!(sft2.state.selected(), Some(0));
   }
 
+  #[derive(Clone, Debug, PartialEq)]
+  struct SortRow {
+    name: String,
+    restarts: i32,
+    age: String,
+  }
+
+  impl Named for SortRow {
+    fn get_name(&self) -> &String {
+      &self.name
+    }
+  }
+
+  impl Sortable for SortRow {
+    fn sort_value(&self, column: &str) -> SortValue {
+      match column {
+        "Name" => SortValue::text(&self.name),
+        "Restarts" => SortValue::number(self.restarts),
+        "Age" => SortValue::age(&self.age),
+        _ => SortValue::Missing,
+      }
+    }
+  }
+
+  fn sort_row(name: &str, restarts: i32, age: &str) -> SortRow {
+    SortRow {
+      name: name.into(),
+      restarts,
+      age: age.into(),
+    }
+  }
+
+  fn row_names(table: &StatefulTable<SortRow>) -> Vec<&str> {
+    table.items.iter().map(|r| r.name.as_str()).collect()
+  }
+
+  #[test]
+  fn test_cycle_sort_compares_typed_columns() {
+    let mut table = StatefulTable::with_items(vec![
+      sort_row("web-10", 2, "1d"),
+      sort_row("web-2", 10, "5m"),
+      sort_row("api", 0, "2h"),
+    ]);
+    table.set_sort_columns(&["Name", "Restarts", "Age"]);
+
+    table.cycle_sort();
+    assert_eq!(row_names(&table), vec!["api", "web-2", "web-10"]);
+    table.cycle_sort();
+    assert_eq!(row_names(&table), vec!["web-10", "web-2", "api"]);
+    table.cycle_sort();
+    assert_eq!(
+      table.sort,
+      Some(TableSort {
+        column: "Restarts".into(),
+        descending: false
+      })
+    );
+    assert_eq!(row_names(&table), vec!["api", "web-10", "web-2"]);
+    table.cycle_sort();
+    table.cycle_sort();
+    assert_eq!(row_names(&table), vec!["web-2", "api", "web-10"]);
+    table.cycle_sort();
+    assert_eq!(row_names(&table), vec!["web-10", "api", "web-2"]);
+    table.cycle_sort();
+    assert_eq!(table.sort, None);
+  }
+
+  #[test]
+  fn test_sort_survives_refresh_and_keeps_selected_object() {
+    let mut table = StatefulTable::with_items(vec![
+      sort_row("c", 0, "1m"),
+      sort_row("a", 0, "1m"),
+      sort_row("b", 0, "1m"),
+    ]);
+    table.set_sort_columns(&["Name"]);
+    table.state.select(Some(0));
+    table.cycle_sort();
+    assert_eq!(row_names(&table), vec!["a", "b", "c"]);
+    // the selection followed "c" to its sorted position
+    assert_eq!(table.state.selected(), Some(2));
+
+    table.state.select(Some(1));
+    table.set_items(vec![
+      sort_row("d", 0, "1m"),
+      sort_row("b", 3, "1m"),
+      sort_row("aa", 0, "1m"),
+      sort_row("a", 0, "1m"),
+    ]);
+    assert_eq!(row_names(&table), vec!["a", "aa", "b", "d"]);
+    assert_eq!(table.get_selected_item_copy().unwrap().restarts, 3);
+  }
+
+  #[test]
+  fn test_extend_items_appends_a_page_and_keeps_the_selection() {
+    let mut table = StatefulTable::new();
+    table.extend_items(vec![sort_row("c", 0, "1m"), sort_row("a", 0, "1m")]);
+    assert_eq!(table.state.selected(), Some(0));
+    table.state.select(Some(1));
+    table.extend_items(vec![sort_row("b", 0, "1m")]);
+    assert_eq!(row_names(&table), vec!["c", "a", "b"]);
+    assert_eq!(table.get_selected_item_copy().unwrap().name, "a");
+
+    table.set_sort_columns(&["Name"]);
+    table.cycle_sort();
+    table.extend_items(vec![sort_row("aa", 0, "1m")]);
+    assert_eq!(row_names(&table), vec!["a", "aa", "b", "c"]);
+    assert_eq!(table.get_selected_item_copy().unwrap().name, "a");
+  }
+
+  #[test]
+  fn test_sort_keeps_selection_within_filtered_rows() {
+    let mut table = StatefulTable::with_items(vec![
+      sort_row("web-3", 0, "1m"),
+      sort_row("api", 0, "1m"),
+      sort_row("web-1", 0, "1m"),
+    ]);
+    table.set_sort_columns(&["Name"]);
+    table.filter = "web".into();
+    table.filtered_indices = vec![0, 2];
+    table.state.select(Some(0));
+
+    table.cycle_sort();
+    assert_eq!(row_names(&table), vec!["api", "web-1", "web-3"]);
+    assert_eq!(table.filtered_indices, vec![1, 2]);
+    assert_eq!(table.get_selected_item_copy().unwrap().name, "web-3");
+  }
+
+  #[test]
+  fn test_marks_follow_rows_across_sort_and_refresh() {
+    let mut table = StatefulTable::with_items(vec![
+      sort_row("web-3", 0, "1m"),
+      sort_row("api", 0, "1m"),
+      sort_row("web-1", 0, "1m"),
+    ]);
+    table.set_sort_columns(&["Name"]);
+    table.toggle_mark();
+    table.state.select(Some(2));
+    table.toggle_mark();
+    assert_eq!(table.marked_count(), 2);
+
+    table.cycle_sort();
+    table.set_items(vec![sort_row("web-1", 0, "1m"), sort_row("web-3", 0, "1m")]);
+    let names: Vec<_> = table.marked_items().into_iter().map(|r| r.name).collect();
+    assert_eq!(names, vec!["web-1", "web-3"]);
+
+    table.state.select(Some(0));
+    table.toggle_mark();
+    assert_eq!(table.marked_count(), 1);
+    assert!(table.clear_marks());
+    assert!(!table.clear_marks());
+    assert!(table.marked_items().is_empty());
+  }
+
+  #[test]
+  fn test_toggle_mark_all_uses_filtered_rows() {
+    let mut table = StatefulTable::with_items(vec![
+      sort_row("web-3", 0, "1m"),
+      sort_row("api", 0, "1m"),
+      sort_row("web-1", 0, "1m"),
+    ]);
+    table.set_sort_columns(&["Name"]);
+    table.filter = "web".into();
+    table.filtered_indices = vec![0, 2];
+
+    table.toggle_mark_all();
+    let names: Vec<_> = table.marked_items().into_iter().map(|r| r.name).collect();
+    assert_eq!(names, vec!["web-3", "web-1"]);
+
+    table.toggle_mark_all();
+    assert_eq!(table.marked_count(), 0);
+  }
+
+  #[test]
+  fn test_sort_value_missing_sorts_last() {
+    let missing = SortValue::Missing;
+    let value = SortValue::number(1);
+    assert_eq!(missing.compare(&value, false), Ordering::Greater);
+    assert_eq!(missing.compare(&value, true), Ordering::Greater);
+    assert_eq!(SortValue::age("3d"), SortValue::Number(259200.0));
+    assert_eq!(SortValue::quantity("512Mi"), SortValue::Number(536870912.0));
+    assert_eq!(SortValue::text(""), SortValue::Missing);
+  }
+
   #[test]
   fn test_filtered_selection_returns_correct_item() {
     let mut sft: StatefulTable<&str> = StatefulTable::new();
@@ -665,6 +1635,77 @@ mod tests {
     assert_eq!(sft.get_selected_item_copy(), Some("alpha"));
   }
 
+  #[test]
+  fn test_list_selector_parse() {
+    assert_eq!(ListSelector::parse("web"), None);
+    assert_eq!(ListSelector::parse("app=api"), None);
+    assert_eq!(
+      ListSelector::parse("l:app=api,tier!=db"),
+      Some(ListSelector {
+        labels: Some("app=api,tier!=db".into()),
+        fields: None,
+      })
+    );
+    let selector = ListSelector::parse("f:status.phase=Running l:app=api l:tier=web").unwrap();
+    assert_eq!(selector.labels.as_deref(), Some("app=api,tier=web"));
+    assert_eq!(selector.fields.as_deref(), Some("status.phase=Running"));
+    assert_eq!(
+      selector.label(),
+      "l:app=api,tier=web f:status.phase=Running"
+    );
+    // only text starting with a prefix is a selector
+    assert_eq!(ListSelector::parse("web l:app=api"), None);
+    // set-based requirements span words up to the next prefix
+    assert_eq!(
+      ListSelector::parse("l:env in (prod, stage) l:tier notin (db) f:status.phase=Running"),
+      Some(ListSelector {
+        labels: Some("env in (prod, stage),tier notin (db)".into()),
+        fields: Some("status.phase=Running".into()),
+      })
+    );
+    assert_eq!(
+      ListSelector::parse("l: app=api").unwrap().labels.as_deref(),
+      Some("app=api")
+    );
+    assert!(ListSelector::parse("l:").unwrap().is_empty());
+    assert_eq!(ListSelector::parse(""), None);
+    // prefixes match in any case, values keep theirs
+    assert_eq!(
+      ListSelector::parse("L:app=Web F:spec.nodeName=Node-1"),
+      Some(ListSelector {
+        labels: Some("app=Web".into()),
+        fields: Some("spec.nodeName=Node-1".into()),
+      })
+    );
+  }
+
+  #[test]
+  fn test_commit_and_clear_selector_filter() {
+    let mut sft: StatefulTable<&str> = StatefulTable::new();
+    sft.filter = "web".into();
+    sft.filter_active = true;
+    assert!(!sft.commit_selector_filter());
+    assert!(sft.filter_active);
+
+    sft.filter = "l:app=api".into();
+    assert!(sft.commit_selector_filter());
+    assert!(!sft.filter_active);
+    assert!(sft.filter.is_empty());
+    assert_eq!(
+      sft.selector.as_ref().and_then(|s| s.labels.as_deref()),
+      Some("app=api")
+    );
+
+    // an empty selector clears the one on the tab
+    sft.filter = "l:".into();
+    assert!(sft.commit_selector_filter());
+    assert_eq!(sft.selector, None);
+
+    sft.selector = ListSelector::parse("f:spec.nodeName=a");
+    assert!(sft.clear_selector());
+    assert!(!sft.clear_selector());
+  }
+
   #[test]
   fn test_handle_table_scroll() {
     let mut item: StatefulTable<&str> = StatefulTable::new();
@@ -871,7 +1912,16 @@ mod tests {
     log.add_record("record 8".into());
 
     terminal
-      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), true, true))
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          true,
+          true,
+        )
+      })
       .unwrap();
 
     let expected = Buffer::with_lines(vec![
@@ -887,7 +1937,16 @@ mod tests {
     terminal.backend().assert_buffer(&expected);
 
     terminal
-      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), false, true))
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          false,
+          true,
+        )
+      })
       .unwrap();
 
     let expected2 = Buffer::with_lines(vec![
@@ -907,7 +1966,16 @@ mod tests {
     log.add_record("record 11".into());
     // enabling follow should scroll back to bottom
     terminal
-      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), true, true))
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          true,
+          true,
+        )
+      })
       .unwrap();
 
     let expected3 = Buffer::with_lines(vec![
@@ -923,7 +1991,16 @@ mod tests {
     terminal.backend().assert_buffer(&expected3);
 
     terminal
-      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), false, true))
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          false,
+          true,
+        )
+      })
       .unwrap();
 
     let expected4 = Buffer::with_lines(vec![
@@ -942,7 +2019,16 @@ mod tests {
     log.handle_scroll(ScrollEvent::Relative(11));
 
     terminal
-      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), false, true))
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          false,
+          true,
+        )
+      })
       .unwrap();
 
     let mut expected5 = Buffer::with_lines(vec![
@@ -970,19 +2056,19 @@ mod tests {
   fn test_logs_state_bounded() {
     let mut log = LogsState::new("bounded".into());
 
-    // Add more than MAX_LOG_RECORDS entries
-    for i in 0..MAX_LOG_RECORDS + 100 {
+    // Add more than DEFAULT_LOG_MAX_LINES entries
+    for i in 0..DEFAULT_LOG_MAX_LINES + 100 {
       log.add_record(format!("record {}", i));
     }
 
-    // Should be capped at MAX_LOG_RECORDS
-    assert_eq!(log.records.len(), MAX_LOG_RECORDS);
+    // Should be capped at DEFAULT_LOG_MAX_LINES
+    assert_eq!(log.records.len(), DEFAULT_LOG_MAX_LINES);
 
     // Oldest records should have been evicted — first record should be 100
-    assert_eq!(log.records.front().unwrap().0, "record 100");
+    assert_eq!(log.records.front().unwrap().text, "record 100");
     assert_eq!(
-      log.records.back().unwrap().0,
-      format!("record {}", MAX_LOG_RECORDS + 99)
+      log.records.back().unwrap().text,
+      format!("record {}", DEFAULT_LOG_MAX_LINES + 99)
     );
   }
 
@@ -990,16 +2076,16 @@ mod tests {
   fn test_logs_state_bounded_exactly_at_limit() {
     let mut log = LogsState::new("exact".into());
 
-    // Add exactly MAX_LOG_RECORDS entries — no eviction should occur
-    for i in 0..MAX_LOG_RECORDS {
+    // Add exactly DEFAULT_LOG_MAX_LINES entries — no eviction should occur
+    for i in 0..DEFAULT_LOG_MAX_LINES {
       log.add_record(format!("record {}", i));
     }
 
-    assert_eq!(log.records.len(), MAX_LOG_RECORDS);
-    assert_eq!(log.records.front().unwrap().0, "record 0");
+    assert_eq!(log.records.len(), DEFAULT_LOG_MAX_LINES);
+    assert_eq!(log.records.front().unwrap().text, "record 0");
     assert_eq!(
-      log.records.back().unwrap().0,
-      format!("record {}", MAX_LOG_RECORDS - 1)
+      log.records.back().unwrap().text,
+      format!("record {}", DEFAULT_LOG_MAX_LINES - 1)
     );
   }
 
@@ -1007,16 +2093,16 @@ mod tests {
   fn test_logs_state_bounded_one_over() {
     let mut log = LogsState::new("one_over".into());
 
-    for i in 0..MAX_LOG_RECORDS + 1 {
+    for i in 0..DEFAULT_LOG_MAX_LINES + 1 {
       log.add_record(format!("record {}", i));
     }
 
-    assert_eq!(log.records.len(), MAX_LOG_RECORDS);
+    assert_eq!(log.records.len(), DEFAULT_LOG_MAX_LINES);
     // First record should be evicted
-    assert_eq!(log.records.front().unwrap().0, "record 1");
+    assert_eq!(log.records.front().unwrap().text, "record 1");
     assert_eq!(
-      log.records.back().unwrap().0,
-      format!("record {}", MAX_LOG_RECORDS)
+      log.records.back().unwrap().text,
+      format!("record {}", DEFAULT_LOG_MAX_LINES)
     );
   }
 
@@ -1049,7 +2135,16 @@ mod tests {
     log.add_record("gamma delta epsilon".into());
 
     terminal
-      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), true, true))
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          true,
+          true,
+        )
+      })
       .unwrap();
 
     let expected_initial = Buffer::with_lines(vec![
@@ -1063,7 +2158,16 @@ mod tests {
     log.add_record("zeta eta theta".into());
 
     terminal
-      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), true, true))
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          true,
+          true,
+        )
+      })
       .unwrap();
 
     let expected_after_append = Buffer::with_lines(vec![
@@ -1087,11 +2191,238 @@ mod tests {
     log.add_record("zeta eta theta".into());
 
     terminal
-      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), true, true))
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          true,
+          true,
+        )
+      })
       .unwrap();
 
     log.freeze_follow_position();
 
     assert_eq!(log.state.selected(), Some(2));
   }
+
+  #[test]
+  fn test_logs_state_search_jumps_to_wrapped_row_and_highlights() {
+    let mut log = LogsState::new("search".into());
+    let backend = TestBackend::new(12, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let styles = LogStyles {
+      search_match: Style::default().add_modifier(Modifier::REVERSED),
+      ..LogStyles::default()
+    };
+
+    log.add_record("gamma delta epsilon".into());
+    log.add_record("alpha".into());
+    log.search.query = "delta eps".into();
+    log.update_search();
+    // Matches in records appended while streaming are counted too.
+    log.add_record("delta epsilon again".into());
+    assert_eq!(log.search.match_count(), 2);
+    assert!(log.jump_to_match(true));
+    assert!(log.jump_to_match(true));
+
+    terminal
+      .draw(|f| log.render_list(f, f.area(), Block::default(), styles, false, true))
+      .unwrap();
+
+    // "gamma delta" / "epsilon" / "alpha" / "delta" / ... — the first match
+    // starts on row 0 and continues onto row 1.
+    assert_eq!(log.state.selected(), Some(0));
+    let buffer = terminal.backend().buffer();
+    assert!(!buffer[(0, 0)].modifier.contains(Modifier::REVERSED));
+    assert!(buffer[(6, 0)].modifier.contains(Modifier::REVERSED));
+    assert!(buffer[(0, 1)].modifier.contains(Modifier::REVERSED));
+    assert!(!buffer[(0, 2)].modifier.contains(Modifier::REVERSED));
+  }
+
+  #[test]
+  fn test_logs_state_jump_to_time_skips_undated_and_hidden_records() {
+    let mut log = LogsState::new("time".into());
+    log.add_records(vec![
+      "2024-05-01T10:00:00Z starting".into(),
+      "  continued".into(),
+      "2024-05-01T10:00:05Z GET /healthz".into(),
+      "2024-05-01T10:00:09Z ready".into(),
+    ]);
+    log.set_filter(true, Some(Regex::new("healthz").unwrap()));
+    let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+
+    assert!(log.jump_to_time(at("2024-05-01T10:00:01Z")));
+    assert_eq!(log.jump_to, Some(3));
+    assert!(log.jump_to_time(at("2024-05-01T09:00:00Z")));
+    assert_eq!(log.jump_to, Some(0));
+    assert!(!log.jump_to_time(at("2024-05-01T10:00:10Z")));
+    assert_eq!(log.jump_to, None);
+  }
+
+  #[test]
+  fn test_logs_state_filter_hides_and_restores_records() {
+    let mut log = LogsState::new("filter".into());
+    let backend = TestBackend::new(20, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    log.add_records(vec![
+      "GET /healthz".into(),
+      "error: db".into(),
+      "info: up".into(),
+    ]);
+    log.search.query = "e".into();
+    log.update_search();
+    assert_eq!(log.search.match_count(), 2);
+
+    log.set_filter(false, Regex::new("error|info").ok());
+    log.set_filter(true, Regex::new("^info").ok());
+    // Lines streamed in after the filter was set are filtered too.
+    log.add_record("error: again".into());
+    assert_eq!(log.visible_count(), (2, 4));
+    assert_eq!(log.get_plain_text(), "\nerror: db\nerror: again");
+    assert_eq!(log.search.match_count(), 2);
+    assert!(log.search.is_match(1) && log.search.is_match(3));
+
+    terminal
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          true,
+          true,
+        )
+      })
+      .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+      "error: db           ",
+      "error: again        ",
+      "                    ",
+    ]));
+
+    assert!(log.clear_filter());
+    assert!(!log.clear_filter());
+    assert_eq!(log.visible_count(), (4, 4));
+    assert_eq!(log.search.match_count(), 3);
+  }
+
+  #[test]
+  fn test_logs_state_renders_structured_lines_until_raw() {
+    let mut log = LogsState::new("json".into());
+    let backend = TestBackend::new(30, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let styles = LogStyles {
+      error: Style::default().add_modifier(Modifier::BOLD),
+      ..LogStyles::default()
+    };
+    log.add_records(vec![
+      r#"{"level":"error","msg":"db down"}"#.into(),
+      "plain line".into(),
+    ]);
+    log.search.query = "level".into();
+    log.update_search();
+    assert!(log.has_structured());
+    // The search runs over the shown line, which has no "level" in it.
+    assert_eq!(log.search.match_count(), 0);
+
+    terminal
+      .draw(|f| log.render_list(f, f.area(), Block::default(), styles, false, true))
+      .unwrap();
+    let buffer = terminal.backend().buffer();
+    assert!(buffer[(0, 0)].modifier.contains(Modifier::BOLD));
+    assert!(!buffer[(6, 0)].modifier.contains(Modifier::BOLD));
+    assert_eq!(log.get_plain_text(), "\nERROR db down\nplain line");
+
+    log.set_raw(true);
+    assert_eq!(log.search.match_count(), 1);
+    terminal
+      .draw(|f| log.render_list(f, f.area(), Block::default(), styles, false, true))
+      .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+      r#"{"level":"error","msg":"db    "#,
+      "down\"}                        ",
+    ]));
+  }
+
+  #[test]
+  fn test_logs_state_min_level_keeps_stack_frames_with_their_error() {
+    let mut log = LogsState::new("agg:web".into());
+    log.add_records(vec![
+      "[web-1] INFO started".into(),
+      "[web-2] ERROR request failed".into(),
+      "    retrying in 1s".into(),
+      "[web-1] plain line".into(),
+      "[web-2] WARN slow".into(),
+    ]);
+
+    log.set_min_level(Some(LogLevel::Warn));
+    assert_eq!(
+      log.get_plain_text(),
+      "\n[web-2] ERROR request failed\n    retrying in 1s\n[web-2] WARN slow"
+    );
+    assert_eq!(log.filter().label(), ">=WARN");
+    log.set_min_level(None);
+    assert_eq!(log.visible_count(), (5, 5));
+
+    let styles = LogStyles {
+      warn: Style::default().add_modifier(Modifier::BOLD),
+      prefixes: [Style::default().add_modifier(Modifier::ITALIC); 5],
+      ..LogStyles::default()
+    };
+    let backend = TestBackend::new(20, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+      .draw(|f| log.render_list(f, f.area(), Block::default(), styles, true, true))
+      .unwrap();
+    // Last row: "[web-2] WARN slow" — italic prefix, bold level, plain text.
+    let buffer = terminal.backend().buffer();
+    assert!(buffer[(0, 4)].modifier.contains(Modifier::ITALIC));
+    assert!(buffer[(8, 4)].modifier.contains(Modifier::BOLD));
+    assert!(buffer[(13, 4)].modifier.is_empty());
+  }
+
+  #[test]
+  fn test_logs_state_byte_limit_evicts_oldest_and_keeps_paused_view() {
+    let mut log = LogsState::new("bytes".into()).with_limits(LogLimits {
+      max_lines: None,
+      max_bytes: Some(40),
+    });
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    // 11 + 10 + 10 bytes; the first wraps onto two rows at width 10.
+    log.add_records(vec![
+      "aaaaa bbbbb".into(),
+      "line 1 ..1".into(),
+      "line 2 ..2".into(),
+    ]);
+    terminal
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          LogStyles::default(),
+          false,
+          true,
+        )
+      })
+      .unwrap();
+    assert_eq!(log.evicted(), 0);
+
+    // Pause on "line 2", then push the first record out.
+    log.state.select(Some(3));
+    log.add_records(vec!["line 3 ..3".into(), "line 4 ..4".into()]);
+    assert_eq!(log.evicted(), 1);
+    assert_eq!(log.bytes, 40);
+    assert_eq!(log.state.selected(), Some(1));
+    assert_eq!(log.raw_lines().last(), Some("line 4 ..4"));
+
+    // A single oversized record is kept rather than leaving the buffer empty.
+    log.add_record("x".repeat(100));
+    assert_eq!(log.records.len(), 1);
+    assert_eq!(log.evicted(), 5);
+  }
 }
@@ -10,7 +10,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils, ActiveBlock, App,
 };
 use crate::{
@@ -72,6 +72,19 @@ impl Named for KubeNetworkPolicy {
   }
 }
 
+impl Sortable for KubeNetworkPolicy {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Pod Selector" => SortValue::text(&self.pod_selector),
+      "Policy Types" => SortValue::text(&self.policy_types),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<NetworkPolicy> for KubeNetworkPolicy {
   fn get_k8s_obj(&self) -> &NetworkPolicy {
     &self.k8s_obj
@@ -98,10 +111,8 @@ impl AppResource for NetworkPolicyResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeNetworkPolicy> = nw.get_namespaced_resources(NetworkPolicy::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.network_policies.set_items(items);
+    nw.load_namespaced_resources(NetworkPolicy::into, |data| &mut data.network_policies)
+      .await;
   }
 }
 
@@ -2,11 +2,7 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::api::core::v1::{Node, Pod};
-use kube::{
-  api::{ListParams, ObjectList, TypeMeta},
-  core::ListMeta,
-  Api,
-};
+use kube::{api::ListParams, Api};
 use ratatui::{
   layout::Rect,
   widgets::{Cell, Row},
@@ -16,13 +12,13 @@ use tokio::sync::MutexGuard;
 
 use super::{
   metrics::{self, KubeNodeMetrics},
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self, UNKNOWN},
   ActiveBlock, App,
 };
 use crate::{
   app::key_binding::DEFAULT_KEYBINDING,
-  network::Network,
+  network::{watch::WatchScope, Network},
   ui::utils::{
     action_hint, copy_and_escape_title_line, describe_and_yaml_hint, draw_describe_block,
     draw_resource_block, draw_yaml_block, get_cluster_wide_resource_title, get_describe_active,
@@ -53,11 +49,7 @@ static NODE_LABEL_ROLE: &str = "kubernetes.io/role";
 static NONE_ROLE: &str = "<none>";
 
 impl KubeNode {
-  pub fn from_api_with_pods(
-    node: &Node,
-    pods_list: &ObjectList<Pod>,
-    app: &mut MutexGuard<'_, App>,
-  ) -> Self {
+  pub fn from_api_with_pods(node: &Node, pods_list: &[Pod], app: &mut MutexGuard<'_, App>) -> Self {
     let node_name = node.metadata.name.clone().unwrap_or_default();
     let unschedulable = &node
       .spec
@@ -178,6 +170,26 @@ impl Named for KubeNode {
   }
 }
 
+impl Sortable for KubeNode {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Name" => SortValue::text(&self.name),
+      "Status" => SortValue::text(&self.status),
+      "Roles" => SortValue::text(&self.role),
+      "Version" => SortValue::text(&self.version),
+      "Pods" => SortValue::number(self.pods),
+      "CPU" => SortValue::quantity(&self.cpu),
+      "Mem" => SortValue::quantity(&self.mem),
+      "CPU %" => SortValue::quantity(&self.cpu_percent),
+      "Mem %" => SortValue::quantity(&self.mem_percent),
+      "CPU/A" => SortValue::quantity(&self.cpu_a),
+      "Mem/A" => SortValue::quantity(&self.mem_a),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Node> for KubeNode {
   fn get_k8s_obj(&self) -> &Node {
     &self.k8s_obj
@@ -223,46 +235,57 @@ impl AppResource for NodeResource {
       ActiveBlock::Pods => crate::app::pods::draw_block_as_sub(f, app, area),
       ActiveBlock::Containers => crate::app::pods::draw_containers_block(f, app, area),
       ActiveBlock::Logs => crate::app::pods::draw_logs_block(f, app, area),
+      ActiveBlock::Shell => crate::app::exec::draw_exec_block(f, app, area),
       ActiveBlock::Namespaces => Self::render(app.get_prev_route().active_block, f, app, area),
       _ => draw_block(f, app, area),
     };
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let lp = ListParams::default();
-    let api_pods: Api<Pod> = Api::all(nw.client.clone());
-    let api_nodes: Api<Node> = Api::all(nw.client.clone());
-
-    match api_nodes.list(&lp).await {
-      Ok(node_list) => {
-        get_node_metrics(nw).await;
-
-        let pods_list = match api_pods.list(&lp).await {
-          Ok(list) => list,
-          Err(_) => ObjectList {
-            types: TypeMeta::default(),
-            metadata: ListMeta::default(),
-            items: vec![],
-          },
-        };
+    if let Err(e) = load_nodes(nw).await {
+      nw.handle_error(anyhow!("Failed to get nodes. {}", e)).await;
+    }
+  }
+}
 
-        let mut app = nw.app.lock().await;
+/// List nodes, with their pod counts and metrics, into `app.data.nodes`.
+async fn load_nodes(nw: &Network<'_>) -> kube::Result<()> {
+  let api_pods: Api<Pod> = Api::all(nw.client.clone());
+  let api_nodes: Api<Node> = Api::all(nw.client.clone());
+
+  let node_scope = WatchScope {
+    namespace: None,
+    selector: nw
+      .app
+      .lock()
+      .await
+      .data
+      .nodes
+      .selector
+      .clone()
+      .unwrap_or_default(),
+  };
+  let node_list = nw.list_watched(api_nodes, node_scope).await?;
+  get_node_metrics(nw).await;
 
-        let items = node_list
-          .iter()
-          .map(|node| KubeNode::from_api_with_pods(node, &pods_list, &mut app))
-          .collect::<Vec<_>>();
+  let pods_list = nw
+    .list_watched(api_pods, WatchScope::default())
+    .await
+    .unwrap_or_default();
 
-        app.data.nodes.set_items(items);
-      }
-      Err(e) => {
-        nw.handle_error(anyhow!("Failed to get nodes. {}", e)).await;
-      }
-    }
-  }
+  let mut app = nw.app.lock().await;
+
+  let items = node_list
+    .iter()
+    .map(|node| KubeNode::from_api_with_pods(node, &pods_list, &mut app))
+    .collect::<Vec<_>>();
+
+  app.data.nodes.set_items(items);
+  Ok(())
 }
 
-async fn get_node_metrics(nw: &Network<'_>) {
+/// Refresh `app.data.node_metrics` from metrics-server, when installed.
+pub(crate) async fn get_node_metrics(nw: &Network<'_>) {
   let api_node_metrics: Api<metrics::NodeMetrics> = Api::all(nw.client.clone());
 
   match api_node_metrics.list(&ListParams::default()).await {
@@ -381,7 +404,7 @@ mod tests {
 
     let nodes = nodes
       .iter()
-      .map(|it| KubeNode::from_api_with_pods(it, &pods_list, &mut app))
+      .map(|it| KubeNode::from_api_with_pods(it, &pods_list.items, &mut app))
       .collect::<Vec<_>>();
 
     assert_eq!(nodes.len(), 1);
@@ -5,28 +5,31 @@ use k8s_openapi::api::core::v1::{
   PodStatus,
 };
 use ratatui::{
-  layout::Rect,
+  layout::{Constraint, Rect},
   style::Style,
+  text::Line,
   widgets::{Cell, Row},
   Frame,
 };
 
 use super::{
   key_binding::DEFAULT_KEYBINDING,
-  models::{AppResource, KubeResource, Named},
+  logs::{LogSearch, LogStyles},
+  models::{AppResource, KubeResource, LogsState, Named, SortValue, Sortable},
   utils::{self, UNKNOWN},
   ActiveBlock, App,
 };
 use crate::{
-  network::Network,
+  network::{stream::IoStreamEvent, Network},
   ui::theme::Palette,
   ui::utils::{
-    action_hint, copy_and_escape_title_line, copy_scroll_and_escape_title_line,
+    action_hint, copy_and_escape_title_line, copy_scroll_and_escape_title_line, default_part,
     describe_yaml_and_logs_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, help_part, layout_block_top_border,
-    loading, mixed_bold_line, responsive_columns, style_caution, style_failure, style_help,
-    style_success, style_text, title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps,
-    ViewTier,
+    get_describe_active, get_resource_title, help_bold_line, help_part, horizontal_chunks,
+    layout_block_active_line, layout_block_default_line, layout_block_top_border, loading,
+    mixed_bold_line, responsive_columns, style_caution, style_failure, style_help,
+    style_search_match, style_success, style_text, style_warning, title_with_dual_style, wide_hint,
+    ColumnDef, LinePart, ResourceTableProps, ViewTier,
   },
 };
 
@@ -68,6 +71,26 @@ impl Named for KubeContainer {
   }
 }
 
+impl Sortable for KubeContainer {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Name" => SortValue::text(&self.name),
+      "Image" => SortValue::text(&self.image),
+      "Init" => SortValue::text(&self.init.to_string()),
+      "Ready" => SortValue::text(&self.ready),
+      "State" => SortValue::text(&self.status),
+      "Restarts" => SortValue::number(self.restarts),
+      "Probes(L/R)" => SortValue::text(&format!(
+        "{}/{}",
+        self.liveliness_probe, self.readiness_probe
+      )),
+      "Ports" => SortValue::text(&self.ports),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Option<Container>> for KubeContainer {
   fn get_k8s_obj(&self) -> &Option<Container> {
     &None
@@ -186,6 +209,22 @@ impl Named for KubePod {
   }
 }
 
+impl Sortable for KubePod {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Ready" => SortValue::text(&format!("{}/{}", self.ready.0, self.ready.1)),
+      "Status" => SortValue::text(&self.status),
+      "Restarts" => SortValue::number(self.restarts),
+      "Node" => SortValue::text(&self.node),
+      "IP" => SortValue::text(&self.ip),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Pod> for KubePod {
   fn get_k8s_obj(&self) -> &Pod {
     &self.k8s_obj
@@ -231,28 +270,29 @@ impl AppResource for PodResource {
         ),
       ),
       ActiveBlock::Logs => draw_logs_block(f, app, area),
+      ActiveBlock::Shell => super::exec::draw_exec_block(f, app, area),
       ActiveBlock::Namespaces => Self::render(app.get_prev_route().active_block, f, app, area),
       _ => draw_block(f, app, area),
     }
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubePod> = nw.get_namespaced_resources(Pod::into).await;
+    nw.load_namespaced_resources(Pod::into, |data| &mut data.pods)
+      .await;
 
     let mut app = nw.app.lock().await;
-    if app.data.selected.pod.is_some() {
-      let containers = &items.iter().find_map(|pod| {
-        if pod.name == app.data.selected.pod.clone().unwrap() {
-          Some(&pod.containers)
-        } else {
-          None
-        }
-      });
-      if containers.is_some() {
-        app.data.containers.set_items(containers.unwrap().clone());
+    if let Some(selected) = app.data.selected.pod.clone() {
+      let containers = app
+        .data
+        .pods
+        .items
+        .iter()
+        .find(|pod| pod.name == selected)
+        .map(|pod| pod.containers.clone());
+      if let Some(containers) = containers {
+        app.data.containers.set_items(containers);
       }
     }
-    app.data.pods.set_items(items);
   }
 }
 
@@ -448,14 +488,23 @@ fn get_container_title<S: AsRef<str>>(app: &App, container_len: usize, suffix: S
 pub(crate) fn draw_logs_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   let is_aggregate = app.data.logs.id.starts_with("agg:");
 
+  let focused_follow = match &app.data.log_split {
+    Some(log_split) if log_split.focus_previous => log_split.follow,
+    _ => app.log_auto_scroll,
+  };
+
   let (title, hint) = if is_aggregate {
-    let resource = app
-      .data
-      .selected
-      .pod_selector_resource
-      .as_deref()
-      .map(capitalize_first)
-      .unwrap_or_default();
+    let resource = if matches!(app.active_log_stream, Some(IoStreamEvent::TailLogs(_))) {
+      "Tail".to_owned()
+    } else {
+      app
+        .data
+        .selected
+        .pod_selector_resource
+        .as_deref()
+        .map(capitalize_first)
+        .unwrap_or_default()
+    };
     let agg_name = app.data.logs.id.strip_prefix("agg:").unwrap_or_default();
     (
       format!(" {} -> Logs ({}) ", resource, agg_name),
@@ -481,15 +530,49 @@ pub(crate) fn draw_logs_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
     let container_name = selected_container.unwrap_or_default();
     let logs_label = if app.log_previous {
       format!("-> Logs ({} · previous) ", container_name)
+    } else if app.data.log_split.is_some() {
+      format!("-> Logs ({} · previous | current) ", container_name)
     } else {
       format!("-> Logs ({}) ", container_name)
     };
     (
       get_container_title(app, app.data.containers.items.len(), logs_label),
-      copy_scroll_and_escape_title_line("Containers", app.log_auto_scroll, app.palette),
+      copy_scroll_and_escape_title_line("Containers", focused_follow, app.palette),
     )
   };
 
+  let mut hint = hint;
+  let mut parts = vec![];
+  if let Some(since) = app.log_since {
+    parts.push(default_part(format!("[since {}]", since.label())));
+    parts.push(help_part(" · "));
+  }
+  if app.data.logs.tee_path().is_some() {
+    parts.push(default_part("[tee]"));
+    parts.push(help_part(format!(
+      " {}:stop · ",
+      DEFAULT_KEYBINDING.tee_logs.key.symbol()
+    )));
+  }
+  if app.data.logs.evicted() > 0 {
+    parts.push(default_part(format!(
+      "[{} oldest dropped]",
+      app.data.logs.evicted()
+    )));
+    parts.push(help_part(" · "));
+  }
+  parts.extend(log_filter_parts(&app.data.logs));
+  if app.data.logs.has_structured() {
+    let label = if app.log_raw { "structured" } else { "raw" };
+    parts.push(help_part(format!(
+      "{} · ",
+      action_hint(label, DEFAULT_KEYBINDING.toggle_log_raw.key)
+    )));
+  }
+  parts.extend(log_search_parts(&app.focused_logs().search));
+  let mut spans = mixed_bold_line(parts, app.palette).spans;
+  spans.append(&mut hint.spans);
+  hint.spans = spans;
   let title = title_with_dual_style(title, hint, app.palette);
 
   let block = layout_block_top_border(title, app.palette);
@@ -498,19 +581,128 @@ pub(crate) fn draw_logs_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   let container_name = selected_container.unwrap_or_default();
 
   if container_name == app.data.logs.id || is_aggregate {
-    app.data.logs.render_list(
-      f,
-      area,
-      block,
-      style_text(app.palette),
-      app.log_auto_scroll,
-      app.log_wrap,
-    );
+    let styles = LogStyles {
+      text: style_text(app.palette),
+      search_match: style_search_match(app.palette),
+      error: style_failure(app.palette),
+      warn: style_warning(app.palette),
+      info: style_text(app.palette),
+      debug: style_help(app.palette),
+      prefixes: [
+        app.palette.accent,
+        app.palette.secondary,
+        app.palette.label,
+        app.palette.success,
+        app.palette.highlight,
+      ]
+      .map(|colour| Style::default().fg(colour)),
+    };
+    if let Some(log_split) = app.data.log_split.as_mut() {
+      f.render_widget(block.clone(), area);
+      let panes = horizontal_chunks(
+        vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+        block.inner(area),
+      );
+      let previous_title = match &log_split.terminated {
+        Some(terminated) => format!(" Previous ({}) ", terminated),
+        None => " Previous ".to_owned(),
+      };
+      let pane_block = |label: String, focused: bool| {
+        let pane_title = title_with_dual_style(label, Line::default(), app.palette);
+        if focused {
+          layout_block_active_line(pane_title, app.palette)
+        } else {
+          layout_block_default_line(pane_title, app.palette)
+        }
+      };
+      let focus_previous = log_split.focus_previous;
+      log_split.previous.render_list(
+        f,
+        panes[0],
+        pane_block(previous_title, focus_previous),
+        styles,
+        log_split.follow,
+        app.log_wrap,
+      );
+      app.data.logs.render_list(
+        f,
+        panes[1],
+        pane_block(" Current ".to_owned(), !focus_previous),
+        styles,
+        app.log_auto_scroll,
+        app.log_wrap,
+      );
+    } else {
+      app
+        .data
+        .logs
+        .render_list(f, area, block, styles, app.log_auto_scroll, app.log_wrap);
+    }
   } else {
     loading(f, block, area, app.is_loading(), app.palette);
   }
 }
 
+/// Title parts for the include/exclude and level filters: the active ones with
+/// a `shown/held` line count while any is set, else the hint to set them.
+fn log_filter_parts(logs: &LogsState) -> Vec<LinePart<'static>> {
+  let keys = format!(
+    "{}/{}",
+    DEFAULT_KEYBINDING.log_filter_include.key.symbol(),
+    DEFAULT_KEYBINDING.log_filter_exclude.key.symbol()
+  );
+  let level = action_hint("level", DEFAULT_KEYBINDING.log_min_level.key);
+  if logs.filter().is_active() {
+    let (visible, total) = logs.visible_count();
+    vec![
+      default_part(format!("[{} {}/{}]", logs.filter().label(), visible, total)),
+      help_part(format!(" {}:edit · {} · ", keys, level)),
+    ]
+  } else {
+    vec![help_part(format!(
+      "{}:include/exclude · {} · ",
+      keys, level
+    ))]
+  }
+}
+
+/// Title parts for the log search: the query being typed, or the committed
+/// query with its `[i/N]` match counter, or the hint to start one.
+fn log_search_parts(search: &LogSearch) -> Vec<LinePart<'static>> {
+  let esc = DEFAULT_KEYBINDING.esc.key.symbol();
+  if search.input_active {
+    vec![
+      default_part(format!("[/{}_]", search.query)),
+      help_part(format!(
+        " {}:done · {}:cancel · ",
+        DEFAULT_KEYBINDING.submit.key.symbol(),
+        esc
+      )),
+    ]
+  } else if search.is_active() {
+    let position = search.current_position().unwrap_or(0);
+    vec![
+      default_part(format!(
+        "[/{} {}/{}]",
+        search.query,
+        position,
+        search.match_count()
+      )),
+      help_part(format!(
+        " {}/{}:next/prev · {}:clear · ",
+        DEFAULT_KEYBINDING.log_search_next.key.symbol(),
+        DEFAULT_KEYBINDING.log_search_prev.key.symbol(),
+        esc
+      )),
+    ]
+  } else {
+    vec![help_part(format!(
+      "{} · ",
+      action_hint("search", DEFAULT_KEYBINDING.log_search.key)
+    ))]
+  }
+}
+
 fn get_resource_row_style(status: &str, ready: (i32, i32), palette: Palette) -> Style {
   if status == "Running" && ready.0 == ready.1 {
     style_success(palette)
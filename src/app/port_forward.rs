@@ -1,20 +1,25 @@
-//! Active `kubectl port-forward` children tracked by the app.
+//! Active port-forwards tracked by the app.
 //!
-//! KDash shells out to `kubectl port-forward` (already a hard dependency) and
-//! holds each background [`Child`] so it can be listed and stopped. Readiness is
-//! detected by the network-stream task reading kubectl's stdout; see
-//! `network::stream`.
+//! Each forward is an in-process tokio task (see `network::port_forward`) that
+//! owns a local listener and tunnels every accepted connection over the pod
+//! `portforward` websocket. The app holds the task handle so a forward can be
+//! listed and stopped, plus live traffic counters shared with the task.
 
-use tokio::process::Child;
+use std::sync::{
+  atomic::{AtomicU64, Ordering},
+  Arc, Mutex,
+};
+
+use tokio::task::JoinHandle;
 
 /// Lifecycle of a single forward.
 #[derive(Debug)]
 pub enum PortForwardStatus {
-  /// Spawned; waiting for kubectl's "Forwarding from …" line.
+  /// Binding the local port and resolving the backing pod.
   Starting,
-  /// kubectl reported the local listener is up.
+  /// The local listener is up and connections are forwarded.
   Active,
-  /// kubectl exited before/while forwarding; carries a short reason.
+  /// The forward stopped on its own; carries a short reason.
   Failed(String),
 }
 
@@ -28,20 +33,115 @@ impl PortForwardStatus {
   }
 }
 
-/// A tracked `kubectl port-forward` child plus the metadata needed to render and
-/// stop it. Not `Clone`/`Eq` because it owns the [`Child`] handle.
+/// Traffic counters updated by the forwarding task and read by the overlay.
+#[derive(Debug, Default)]
+pub struct PortForwardStats {
+  /// Bytes sent from local clients to the pod.
+  pub bytes_sent: AtomicU64,
+  /// Bytes received from the pod.
+  pub bytes_received: AtomicU64,
+  pub open_connections: AtomicU64,
+  pub total_connections: AtomicU64,
+  /// The most recent per-connection error reported by the pod (e.g. nothing
+  /// listening on the remote port). Does not stop the forward.
+  last_error: Mutex<Option<String>>,
+}
+
+impl PortForwardStats {
+  pub fn connection_opened(&self) {
+    self.open_connections.fetch_add(1, Ordering::Relaxed);
+    self.total_connections.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn connection_closed(&self) {
+    self.open_connections.fetch_sub(1, Ordering::Relaxed);
+  }
+
+  pub fn set_last_error(&self, error: String) {
+    if let Ok(mut last) = self.last_error.lock() {
+      *last = Some(error);
+    }
+  }
+
+  pub fn last_error(&self) -> Option<String> {
+    self.last_error.lock().ok().and_then(|last| last.clone())
+  }
+
+  /// One-line summary for the forwards overlay, e.g.
+  /// `1 open · 4 total · ↑1.2 KiB ↓35.0 KiB`.
+  pub fn summary(&self) -> String {
+    format!(
+      "{} open · {} total · ↑{} ↓{}",
+      self.open_connections.load(Ordering::Relaxed),
+      self.total_connections.load(Ordering::Relaxed),
+      format_bytes(self.bytes_sent.load(Ordering::Relaxed)),
+      format_bytes(self.bytes_received.load(Ordering::Relaxed)),
+    )
+  }
+}
+
+/// A tracked forward plus the metadata needed to render and stop it. Not
+/// `Clone`/`Eq` because it owns the task handle.
 #[derive(Debug)]
 pub struct PortForward {
   /// Stable id used to target stop/status updates from the network thread.
   pub id: u64,
-  /// kubectl resource type (`pods` / `services`).
+  /// Resource type (`pods` / `services`).
   pub kind: String,
   pub namespace: String,
   pub name: String,
   pub local_port: u16,
   pub remote_port: u16,
+  /// The pod actually forwarded to, once resolved (differs from `name` for
+  /// services).
+  pub pod: Option<String>,
   pub status: PortForwardStatus,
-  /// The running child. `take`n when the forward is stopped so the owning
-  /// runtime can kill+reap it.
-  pub child: Option<Child>,
+  pub stats: Arc<PortForwardStats>,
+  /// The forwarding task. `take`n when the forward is stopped so it can be
+  /// aborted, which also drops its listener and open connections.
+  pub task: Option<JoinHandle<()>>,
+}
+
+/// Human-readable byte count using binary units.
+pub fn format_bytes(bytes: u64) -> String {
+  const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+  if bytes < 1024 {
+    return format!("{bytes} B");
+  }
+  let mut value = bytes as f64 / 1024.0;
+  let mut unit = 0;
+  while value >= 1024.0 && unit < UNITS.len() - 1 {
+    value /= 1024.0;
+    unit += 1;
+  }
+  format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_bytes() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1024), "1.0 KiB");
+    assert_eq!(format_bytes(1536), "1.5 KiB");
+    assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+  }
+
+  #[test]
+  fn test_port_forward_stats_summary() {
+    let stats = PortForwardStats::default();
+    stats.connection_opened();
+    stats.connection_opened();
+    stats.connection_closed();
+    stats.bytes_sent.fetch_add(100, Ordering::Relaxed);
+    stats.bytes_received.fetch_add(2048, Ordering::Relaxed);
+
+    assert_eq!(stats.summary(), "1 open · 2 total · ↑100 B ↓2.0 KiB");
+    assert_eq!(stats.last_error(), None);
+    stats.set_last_error("connection refused".into());
+    assert_eq!(stats.last_error().as_deref(), Some("connection refused"));
+  }
 }
@@ -10,7 +10,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self},
   ActiveBlock, App,
 };
@@ -89,6 +89,22 @@ impl Named for KubePVC {
   }
 }
 
+impl Sortable for KubePVC {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Status" => SortValue::text(&self.status),
+      "Volume" => SortValue::text(&self.volume),
+      "Capacity" => SortValue::quantity(&self.capacity),
+      "Access Modes" => SortValue::text(&self.access_modes),
+      "Storage Class" => SortValue::text(&self.storage_class),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<PersistentVolumeClaim> for KubePVC {
   fn get_k8s_obj(&self) -> &PersistentVolumeClaim {
     &self.k8s_obj
@@ -115,12 +131,10 @@ impl AppResource for PvcResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubePVC> = nw
-      .get_namespaced_resources(PersistentVolumeClaim::into)
-      .await;
-
-    let mut app = nw.app.lock().await;
-    app.data.persistent_volume_claims.set_items(items);
+    nw.load_namespaced_resources(PersistentVolumeClaim::into, |data| {
+      &mut data.persistent_volume_claims
+    })
+    .await;
   }
 }
 
@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self},
   ActiveBlock, App,
 };
@@ -106,6 +106,23 @@ impl Named for KubePV {
   }
 }
 
+impl Sortable for KubePV {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Name" => SortValue::text(&self.name),
+      "Capacity" => SortValue::quantity(&self.capacity),
+      "Access Modes" => SortValue::text(&self.access_modes),
+      "Reclaim Policy" => SortValue::text(&self.reclaim_policy),
+      "Status" => SortValue::text(&self.status),
+      "Claim" => SortValue::text(&self.claim),
+      "Storage Class" => SortValue::text(&self.storage_class),
+      "Reason" => SortValue::text(&self.reason),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<PersistentVolume> for KubePV {
   fn get_k8s_obj(&self) -> &PersistentVolume {
     &self.k8s_obj
@@ -132,10 +149,8 @@ impl AppResource for PvResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubePV> = nw.get_resources(PersistentVolume::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.persistent_volumes.set_items(items);
+    nw.load_resources(PersistentVolume::into, |data| &mut data.persistent_volumes)
+      .await;
   }
 }
 
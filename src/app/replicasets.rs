@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{self, AppResource, KubeResource, Named},
+  models::{self, AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self},
   ActiveBlock, App,
 };
@@ -62,6 +62,20 @@ impl Named for KubeReplicaSet {
   }
 }
 
+impl Sortable for KubeReplicaSet {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Desired" => SortValue::number(self.desired),
+      "Current" => SortValue::number(self.current),
+      "Ready" => SortValue::number(self.ready),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<ReplicaSet> for KubeReplicaSet {
   fn get_k8s_obj(&self) -> &ReplicaSet {
     &self.k8s_obj
@@ -100,10 +114,8 @@ impl AppResource for ReplicaSetResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeReplicaSet> = nw.get_namespaced_resources(ReplicaSet::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.replica_sets.set_items(items);
+    nw.load_namespaced_resources(ReplicaSet::into, |data| &mut data.replica_sets)
+      .await;
   }
 }
 
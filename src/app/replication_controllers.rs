@@ -10,7 +10,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{self, AppResource, KubeResource, Named},
+  models::{self, AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self},
   ActiveBlock, App,
 };
@@ -102,6 +102,23 @@ impl Named for KubeReplicationController {
   }
 }
 
+impl Sortable for KubeReplicationController {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Desired" => SortValue::number(self.desired),
+      "Current" => SortValue::number(self.current),
+      "Ready" => SortValue::number(self.ready),
+      "Containers" => SortValue::text(&self.containers),
+      "Images" => SortValue::text(&self.images),
+      "Selector" => SortValue::text(&self.selector),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<ReplicationController> for KubeReplicationController {
   fn get_k8s_obj(&self) -> &ReplicationController {
     &self.k8s_obj
@@ -140,12 +157,10 @@ impl AppResource for ReplicationControllerResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeReplicationController> = nw
-      .get_namespaced_resources(ReplicationController::into)
-      .await;
-
-    let mut app = nw.app.lock().await;
-    app.data.replication_controllers.set_items(items);
+    nw.load_namespaced_resources(ReplicationController::into, |data| {
+      &mut data.replication_controllers
+    })
+    .await;
   }
 }
 
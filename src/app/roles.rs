@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self},
   ActiveBlock, App,
 };
@@ -71,6 +71,17 @@ impl Named for KubeRole {
   }
 }
 
+impl Sortable for KubeRole {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Role> for KubeRole {
   fn get_k8s_obj(&self) -> &Role {
     &self.k8s_obj
@@ -97,10 +108,8 @@ impl AppResource for RoleResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeRole> = nw.get_namespaced_resources(Role::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.roles.set_items(items);
+    nw.load_namespaced_resources(Role::into, |data| &mut data.roles)
+      .await;
   }
 }
 
@@ -158,6 +167,16 @@ impl Named for KubeClusterRole {
   }
 }
 
+impl Sortable for KubeClusterRole {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Name" => SortValue::text(&self.name),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<ClusterRole> for KubeClusterRole {
   fn get_k8s_obj(&self) -> &ClusterRole {
     &self.k8s_obj
@@ -184,10 +203,8 @@ impl AppResource for ClusterRoleResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeClusterRole> = nw.get_resources(ClusterRole::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.cluster_roles.set_items(items);
+    nw.load_resources(ClusterRole::into, |data| &mut data.cluster_roles)
+      .await;
   }
 }
 
@@ -249,6 +266,18 @@ impl Named for KubeRoleBinding {
   }
 }
 
+impl Sortable for KubeRoleBinding {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Role" => SortValue::text(&self.role),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<RoleBinding> for KubeRoleBinding {
   fn get_k8s_obj(&self) -> &RoleBinding {
     &self.k8s_obj
@@ -275,10 +304,8 @@ impl AppResource for RoleBindingResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeRoleBinding> = nw.get_namespaced_resources(RoleBinding::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.role_bindings.set_items(items);
+    nw.load_namespaced_resources(RoleBinding::into, |data| &mut data.role_bindings)
+      .await;
   }
 }
 
@@ -341,6 +368,17 @@ impl Named for KubeClusterRoleBinding {
   }
 }
 
+impl Sortable for KubeClusterRoleBinding {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Name" => SortValue::text(&self.name),
+      "Role" => SortValue::text(&self.role),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<ClusterRoleBinding> for KubeClusterRoleBinding {
   fn get_k8s_obj(&self) -> &ClusterRoleBinding {
     &self.k8s_obj
@@ -367,10 +405,10 @@ impl AppResource for ClusterRoleBindingResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeClusterRoleBinding> = nw.get_resources(ClusterRoleBinding::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.cluster_role_bindings.set_items(items);
+    nw.load_resources(ClusterRoleBinding::into, |data| {
+      &mut data.cluster_role_bindings
+    })
+    .await;
   }
 }
 
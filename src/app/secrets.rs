@@ -10,7 +10,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self},
   ActiveBlock, App,
 };
@@ -70,6 +70,19 @@ impl Named for KubeSecret {
   }
 }
 
+impl Sortable for KubeSecret {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Type" => SortValue::text(&self.type_),
+      "Data" => SortValue::number(self.data.len() as f64),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Secret> for KubeSecret {
   fn get_k8s_obj(&self) -> &Secret {
     &self.k8s_obj
@@ -96,10 +109,8 @@ impl AppResource for SecretResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeSecret> = nw.get_namespaced_resources(Secret::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.secrets.set_items(items);
+    nw.load_namespaced_resources(Secret::into, |data| &mut data.secrets)
+      .await;
   }
 }
 
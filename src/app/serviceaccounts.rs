@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self},
   ActiveBlock, App,
 };
@@ -50,6 +50,18 @@ impl Named for KubeSvcAcct {
   }
 }
 
+impl Sortable for KubeSvcAcct {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Secrets" => SortValue::number(self.secrets),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<ServiceAccount> for KubeSvcAcct {
   fn get_k8s_obj(&self) -> &ServiceAccount {
     &self.k8s_obj
@@ -76,10 +88,8 @@ impl AppResource for SvcAcctResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeSvcAcct> = nw.get_namespaced_resources(ServiceAccount::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.service_accounts.set_items(items);
+    nw.load_namespaced_resources(ServiceAccount::into, |data| &mut data.service_accounts)
+      .await;
   }
 }
 
@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{self, AppResource, KubeResource, Named},
+  models::{self, AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self},
   ActiveBlock, App,
 };
@@ -75,6 +75,21 @@ impl Named for KubeStatefulSet {
   }
 }
 
+impl Sortable for KubeStatefulSet {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Ready" => SortValue::text(&self.ready),
+      "Service" => SortValue::text(&self.service),
+      "Update Strategy" => SortValue::text(&self.update_strategy),
+      "Replicas" => SortValue::text(&self.replicas),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<StatefulSet> for KubeStatefulSet {
   fn get_k8s_obj(&self) -> &StatefulSet {
     &self.k8s_obj
@@ -113,10 +128,8 @@ impl AppResource for StatefulSetResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeStatefulSet> = nw.get_namespaced_resources(StatefulSet::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.stateful_sets.set_items(items);
+    nw.load_namespaced_resources(StatefulSet::into, |data| &mut data.stateful_sets)
+      .await;
   }
 }
 
@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self},
   ActiveBlock, App,
 };
@@ -59,6 +59,20 @@ impl Named for KubeStorageClass {
   }
 }
 
+impl Sortable for KubeStorageClass {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Name" => SortValue::text(&self.name),
+      "Provisioner" => SortValue::text(&self.provisioner),
+      "Reclaim Policy" => SortValue::text(&self.reclaim_policy),
+      "Volume Binding Mode" => SortValue::text(&self.volume_binding_mode),
+      "Allow Volume Expansion" => SortValue::text(&self.allow_volume_expansion.to_string()),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<StorageClass> for KubeStorageClass {
   fn get_k8s_obj(&self) -> &StorageClass {
     &self.k8s_obj
@@ -85,10 +99,8 @@ impl AppResource for StorageClassResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeStorageClass> = nw.get_resources(StorageClass::into).await;
-
-    let mut app = nw.app.lock().await;
-    app.data.storage_classes.set_items(items);
+    nw.load_resources(StorageClass::into, |data| &mut data.storage_classes)
+      .await;
   }
 }
 
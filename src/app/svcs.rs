@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, KubeResource, Named, SortValue, Sortable},
   utils::{self, UNKNOWN},
   ActiveBlock, App,
 };
@@ -100,6 +100,23 @@ impl Named for KubeSvc {
   }
 }
 
+impl Sortable for KubeSvc {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Namespace" => SortValue::text(&self.namespace),
+      "Name" => SortValue::text(&self.name),
+      "Type" => SortValue::text(&self.type_),
+      "Cluster IP" => SortValue::text(&self.cluster_ip),
+      "External IP" => SortValue::text(&self.external_ip),
+      "Ports" => SortValue::text(&self.ports),
+      "Selector" => SortValue::text(&self.selector),
+      "Session Affinity" => SortValue::text(&self.session_affinity),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+}
+
 impl KubeResource<Service> for KubeSvc {
   fn get_k8s_obj(&self) -> &Service {
     &self.k8s_obj
@@ -126,9 +143,8 @@ impl AppResource for SvcResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubeSvc> = nw.get_namespaced_resources(Service::into).await;
-    let mut app = nw.app.lock().await;
-    app.data.services.set_items(items);
+    nw.load_namespaced_resources(Service::into, |data| &mut data.services)
+      .await;
   }
 }
 
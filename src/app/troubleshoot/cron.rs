@@ -0,0 +1,302 @@
+//! Minimal CronJob schedule parser, enough to work out when runs were due.
+//!
+//! Accepts what the CronJob controller accepts: five fields (minute, hour,
+//! day of month, month, day of week) with `*`, `?`, lists, ranges, steps and
+//! month/weekday names, the `@hourly`-style macros and `@every <duration>`.
+//! A `CRON_TZ=`/`TZ=` prefix and `spec.timeZone` are ignored, so schedules
+//! are evaluated in UTC.
+//!
+//! References:
+//! - <https://kubernetes.io/docs/concepts/workloads/controllers/cron-jobs/#schedule-syntax>
+//! - <https://pkg.go.dev/github.com/robfig/cron/v3#hdr-CRON_Expression_Format>
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Timelike, Utc};
+
+const MONTHS: [&str; 12] = [
+  "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAYS: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// How far ahead to look for the next run before giving up on schedules that
+/// never fire (e.g. `0 0 30 2 *`).
+const SEARCH_YEARS: i64 = 5;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Schedule {
+  Fields(Fields),
+  /// `@every <duration>`, counted from the previous run.
+  Every(TimeDelta),
+}
+
+/// Bit sets of the allowed values for each field of a five-field schedule.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fields {
+  minutes: u64,
+  hours: u64,
+  days: u64,
+  months: u64,
+  weekdays: u64,
+  /// A `*` day field makes days match on both day of month and weekday;
+  /// otherwise either is enough.
+  days_star: bool,
+  weekdays_star: bool,
+}
+
+impl Schedule {
+  /// Parse a `spec.schedule` value, or `None` if it is not valid.
+  pub fn parse(schedule: &str) -> Option<Self> {
+    let mut spec = schedule.trim();
+    if spec.starts_with("CRON_TZ=") || spec.starts_with("TZ=") {
+      spec = spec.split_once(char::is_whitespace)?.1.trim_start();
+    }
+    let expanded = match spec {
+      "@yearly" | "@annually" => "0 0 1 1 *",
+      "@monthly" => "0 0 1 * *",
+      "@weekly" => "0 0 * * 0",
+      "@daily" | "@midnight" => "0 0 * * *",
+      "@hourly" => "0 * * * *",
+      _ => match spec.strip_prefix("@every ") {
+        Some(duration) => return parse_duration(duration.trim()).map(Schedule::Every),
+        None => spec,
+      },
+    };
+
+    let fields: Vec<&str> = expanded.split_whitespace().collect();
+    let [minute, hour, day, month, weekday] = fields.as_slice() else {
+      return None;
+    };
+    let (days, days_star) = parse_field(day, 1, 31, &[])?;
+    let (weekdays, weekdays_star) = parse_field(weekday, 0, 6, &WEEKDAYS)?;
+    Some(Schedule::Fields(Fields {
+      minutes: parse_field(minute, 0, 59, &[])?.0,
+      hours: parse_field(hour, 0, 23, &[])?.0,
+      days,
+      months: parse_field(month, 1, 12, &MONTHS)?.0,
+      weekdays,
+      days_star,
+      weekdays_star,
+    }))
+  }
+
+  /// The first run strictly after `after`.
+  pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    match self {
+      Schedule::Fields(fields) => fields.next_after(after),
+      Schedule::Every(every) => after.checked_add_signed(*every),
+    }
+  }
+
+  /// Count the runs due in `(from, to]`, stopping at `limit`.
+  pub fn runs_between(&self, from: DateTime<Utc>, to: DateTime<Utc>, limit: usize) -> usize {
+    let mut count = 0;
+    let mut t = from;
+    while count < limit {
+      match self.next_after(t) {
+        Some(next) if next <= to => {
+          count += 1;
+          t = next;
+        }
+        _ => break,
+      }
+    }
+    count
+  }
+}
+
+impl Fields {
+  /// Walk forward from the next whole minute, skipping a month, day or hour
+  /// at a time when that field does not match.
+  fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let has = |set: u64, value: u32| set & (1 << value) != 0;
+
+    let limit = after + TimeDelta::days(366 * SEARCH_YEARS);
+    let mut t = after.with_second(0)?.with_nanosecond(0)? + TimeDelta::minutes(1);
+    while t < limit {
+      if !has(self.months, t.month()) {
+        let (year, month) = match t.month() {
+          12 => (t.year() + 1, 1),
+          month => (t.year(), month + 1),
+        };
+        t = NaiveDate::from_ymd_opt(year, month, 1)?
+          .and_hms_opt(0, 0, 0)?
+          .and_utc();
+        continue;
+      }
+      let day_of_month = has(self.days, t.day());
+      let day_of_week = has(self.weekdays, t.weekday().num_days_from_sunday());
+      let day_matches = if self.days_star || self.weekdays_star {
+        day_of_month && day_of_week
+      } else {
+        day_of_month || day_of_week
+      };
+      if !day_matches {
+        t = (t.date_naive() + TimeDelta::days(1))
+          .and_hms_opt(0, 0, 0)?
+          .and_utc();
+        continue;
+      }
+      if !has(self.hours, t.hour()) {
+        t = t.with_minute(0)? + TimeDelta::hours(1);
+        continue;
+      }
+      if !has(self.minutes, t.minute()) {
+        t += TimeDelta::minutes(1);
+        continue;
+      }
+      return Some(t);
+    }
+    None
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Parse one field into a bit set of allowed values, plus whether it was a
+/// bare `*` or `?`.
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Option<(u64, bool)> {
+  let mut set = 0u64;
+  let mut star = false;
+  for part in field.split(',') {
+    let (range, step) = match part.split_once('/') {
+      Some((range, step)) => (range, Some(step.parse::<u32>().ok().filter(|s| *s > 0)?)),
+      None => (part, None),
+    };
+    let (lo, hi) = if range == "*" || range == "?" {
+      star |= step.unwrap_or(1) == 1;
+      (min, max)
+    } else if let Some((lo, hi)) = range.split_once('-') {
+      (parse_value(lo, min, names)?, parse_value(hi, min, names)?)
+    } else {
+      let value = parse_value(range, min, names)?;
+      // `5/15` means from 5 to the end of the range.
+      (value, if step.is_some() { max } else { value })
+    };
+    if lo < min || hi > max || lo > hi {
+      return None;
+    }
+    for value in (lo..=hi).step_by(step.unwrap_or(1) as usize) {
+      set |= 1 << value;
+    }
+  }
+  Some((set, star))
+}
+
+fn parse_value(value: &str, min: u32, names: &[&str]) -> Option<u32> {
+  value.parse().ok().or_else(|| {
+    names
+      .iter()
+      .position(|name| name.eq_ignore_ascii_case(value))
+      .map(|index| index as u32 + min)
+  })
+}
+
+/// Parse a Go duration made of whole `h`, `m` and `s` units, e.g. `1h30m`.
+fn parse_duration(value: &str) -> Option<TimeDelta> {
+  let mut total = TimeDelta::zero();
+  let mut rest = value;
+  while !rest.is_empty() {
+    let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+    let amount: i64 = rest[..digits].parse().ok()?;
+    let unit = rest[digits..].chars().next()?;
+    total += match unit {
+      'h' => TimeDelta::hours(amount),
+      'm' => TimeDelta::minutes(amount),
+      's' => TimeDelta::seconds(amount),
+      _ => return None,
+    };
+    rest = &rest[digits + 1..];
+  }
+  (total > TimeDelta::zero()).then_some(total)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn at(time: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(time).unwrap().to_utc()
+  }
+
+  fn next(schedule: &str, after: &str) -> DateTime<Utc> {
+    Schedule::parse(schedule)
+      .unwrap()
+      .next_after(at(after))
+      .unwrap()
+  }
+
+  #[test]
+  fn test_parse_rejects_invalid_schedules() {
+    for schedule in [
+      "",
+      "* * * *",
+      "60 * * * *",
+      "* 24 * * *",
+      "* * 0 * *",
+      "* * * 13 *",
+      "* * * * 7",
+      "5-1 * * * *",
+      "*/0 * * * *",
+      "@every",
+      "@every 1d",
+      "@fortnightly",
+    ] {
+      assert_eq!(Schedule::parse(schedule), None, "{}", schedule);
+    }
+  }
+
+  #[test]
+  fn test_next_after_fields() {
+    assert_eq!(
+      next("*/15 * * * *", "2024-01-01T10:07:30Z"),
+      at("2024-01-01T10:15:00Z")
+    );
+    assert_eq!(
+      next("30 2 * * *", "2024-01-01T02:30:00Z"),
+      at("2024-01-02T02:30:00Z")
+    );
+    assert_eq!(
+      next("0 9 * * mon-fri", "2024-01-05T10:00:00Z"),
+      at("2024-01-08T09:00:00Z")
+    );
+    assert_eq!(
+      next("0 0 1 jan,jul *", "2024-02-10T00:00:00Z"),
+      at("2024-07-01T00:00:00Z")
+    );
+    assert_eq!(
+      next("CRON_TZ=Europe/Paris @hourly", "2024-01-01T10:00:00Z"),
+      at("2024-01-01T11:00:00Z")
+    );
+  }
+
+  #[test]
+  fn test_restricted_day_fields_match_either() {
+    // The 13th, or any Friday.
+    assert_eq!(
+      next("0 0 13 * 5", "2024-01-01T00:00:00Z"),
+      at("2024-01-05T00:00:00Z")
+    );
+    assert_eq!(
+      next("0 0 13 * 5", "2024-01-12T00:00:00Z"),
+      at("2024-01-13T00:00:00Z")
+    );
+  }
+
+  #[test]
+  fn test_impossible_date_never_runs() {
+    let schedule = Schedule::parse("0 0 30 2 *").unwrap();
+    assert_eq!(schedule.next_after(at("2024-01-01T00:00:00Z")), None);
+  }
+
+  #[test]
+  fn test_every_and_runs_between() {
+    let schedule = Schedule::parse("@every 1h30m").unwrap();
+    assert_eq!(schedule, Schedule::Every(TimeDelta::minutes(90)));
+
+    let from = at("2024-01-01T00:00:00Z");
+    let to = at("2024-01-01T06:00:00Z");
+    assert_eq!(schedule.runs_between(from, to, 10), 4);
+    assert_eq!(schedule.runs_between(from, to, 2), 2);
+  }
+}
@@ -0,0 +1,226 @@
+//! CronJob troubleshoot checks for schedules without recent successful runs,
+//! suspended CronJobs and runs piling up.
+//!
+//! References:
+//! - <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.35/#cronjobstatus-v1-batch>
+//! - <https://kubernetes.io/docs/concepts/workloads/controllers/cron-jobs/>
+
+use chrono::{DateTime, Utc};
+
+use crate::app::{
+  cronjobs::KubeCronJob,
+  models::KubeResource,
+  utils::{duration_to_age, time_to_chrono},
+};
+
+use super::{cron::Schedule, DisplayFinding, ResourceKind, Severity};
+
+/// Scheduled runs without a success before a CronJob is flagged. Counting
+/// runs rather than multiples of one interval copes with uneven schedules
+/// such as weekdays only, and leaves slack for the ignored time zone.
+const MISSED_RUNS_THRESHOLD: usize = 3;
+
+/// Active Jobs from one CronJob before it is flagged as piling up.
+const ACTIVE_JOBS_THRESHOLD: usize = 3;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn finding(
+  cronjob: &KubeCronJob,
+  severity: Severity,
+  reason: String,
+  message: String,
+) -> DisplayFinding {
+  DisplayFinding {
+    severity,
+    reason,
+    resource_kind: ResourceKind::CronJob,
+    namespace: Some(cronjob.namespace.clone()),
+    resource_name: cronjob.name.clone(),
+    message,
+    age: cronjob.age.clone(),
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Individual CronJob checks
+// ---------------------------------------------------------------------------
+
+fn check_suspended(cronjob: &KubeCronJob) -> Option<DisplayFinding> {
+  if !cronjob.suspend {
+    return None;
+  }
+
+  Some(finding(
+    cronjob,
+    Severity::Info,
+    "Suspended".into(),
+    "no new jobs are scheduled while suspended".into(),
+  ))
+}
+
+/// Flag a CronJob with several scheduled runs since its last success, or
+/// since it was created if it never succeeded.
+fn check_last_success(cronjob: &KubeCronJob, now: DateTime<Utc>) -> Option<DisplayFinding> {
+  if cronjob.suspend {
+    return None;
+  }
+  let schedule = Schedule::parse(&cronjob.schedule)?;
+  let obj = cronjob.get_k8s_obj();
+  let last_success = obj
+    .status
+    .as_ref()
+    .and_then(|s| s.last_successful_time.as_ref())
+    .map(time_to_chrono);
+  let since =
+    last_success.or_else(|| obj.metadata.creation_timestamp.as_ref().map(time_to_chrono))?;
+  if schedule.runs_between(since, now, MISSED_RUNS_THRESHOLD) < MISSED_RUNS_THRESHOLD {
+    return None;
+  }
+  let first = schedule.next_after(since)?;
+  let interval = duration_to_age(schedule.next_after(first)? - first, false);
+  let ago = duration_to_age(now - since, false);
+
+  Some(finding(
+    cronjob,
+    Severity::Warn,
+    "No recent success".into(),
+    match last_success {
+      Some(_) => format!(
+        "last successful run {} ago, scheduled every {}",
+        ago, interval
+      ),
+      None => format!(
+        "no successful run since it was created {} ago, scheduled every {}",
+        ago, interval
+      ),
+    },
+  ))
+}
+
+/// Flag Jobs piling up, usually runs outlasting the schedule under the
+/// `Allow` concurrency policy.
+fn check_active(cronjob: &KubeCronJob) -> Option<DisplayFinding> {
+  if cronjob.active < ACTIVE_JOBS_THRESHOLD {
+    return None;
+  }
+
+  Some(finding(
+    cronjob,
+    Severity::Warn,
+    "Too many active jobs".into(),
+    format!(
+      "{} jobs running at once (concurrencyPolicy {})",
+      cronjob.active, cronjob.concurrency_policy
+    ),
+  ))
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation entry point
+// ---------------------------------------------------------------------------
+
+/// Run all CronJob checks as of `now` and collect findings.
+pub fn evaluate(items: &[KubeCronJob], now: DateTime<Utc>) -> Vec<DisplayFinding> {
+  items
+    .iter()
+    .flat_map(|cronjob| {
+      [
+        check_suspended(cronjob),
+        check_last_success(cronjob, now),
+        check_active(cronjob),
+      ]
+    })
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use k8s_openapi::api::batch::v1::{CronJob, CronJobSpec, CronJobStatus};
+  use k8s_openapi::api::core::v1::ObjectReference;
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, Time};
+
+  fn at(time: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(time).unwrap().to_utc()
+  }
+
+  fn time(value: &str) -> Time {
+    Time(k8s_openapi::jiff::Timestamp::from_second(at(value).timestamp()).unwrap())
+  }
+
+  fn build_cronjob(
+    schedule: &str,
+    suspend: bool,
+    last_success: Option<&str>,
+    active: usize,
+  ) -> KubeCronJob {
+    KubeCronJob::from(CronJob {
+      metadata: ObjectMeta {
+        name: Some("backup".into()),
+        namespace: Some("ns-1".into()),
+        creation_timestamp: Some(time("2024-01-01T00:00:00Z")),
+        ..Default::default()
+      },
+      spec: Some(CronJobSpec {
+        schedule: schedule.into(),
+        suspend: Some(suspend),
+        ..Default::default()
+      }),
+      status: Some(CronJobStatus {
+        last_successful_time: last_success.map(time),
+        active: Some(vec![ObjectReference::default(); active]),
+        ..Default::default()
+      }),
+    })
+  }
+
+  #[test]
+  fn test_last_success_flagged_after_missed_runs() {
+    let cronjob = build_cronjob("0 * * * *", false, Some("2024-01-02T10:00:00Z"), 0);
+
+    assert!(check_last_success(&cronjob, at("2024-01-02T12:30:00Z")).is_none());
+    let finding = check_last_success(&cronjob, at("2024-01-02T13:00:00Z")).unwrap();
+    assert_eq!(finding.severity, Severity::Warn);
+    assert_eq!(
+      finding.message,
+      "last successful run 3h ago, scheduled every 1h"
+    );
+  }
+
+  #[test]
+  fn test_never_successful_counts_from_creation() {
+    let cronjob = build_cronjob("@daily", false, None, 0);
+
+    assert_eq!(
+      check_last_success(&cronjob, at("2024-01-04T12:00:00Z"))
+        .unwrap()
+        .message,
+      "no successful run since it was created 3d12h ago, scheduled every 1d"
+    );
+  }
+
+  #[test]
+  fn test_suspended_cronjob_is_only_noted() {
+    let cronjob = build_cronjob("0 * * * *", true, None, 0);
+
+    let findings = evaluate(&[cronjob], at("2024-02-01T00:00:00Z"));
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].severity, Severity::Info);
+    assert_eq!(findings[0].reason, "Suspended");
+  }
+
+  #[test]
+  fn test_check_active() {
+    assert!(check_active(&build_cronjob("* * * * *", false, None, 2)).is_none());
+    assert_eq!(
+      check_active(&build_cronjob("* * * * *", false, None, 3))
+        .unwrap()
+        .message,
+      "3 jobs running at once (concurrencyPolicy Allow)"
+    );
+  }
+}
@@ -0,0 +1,172 @@
+//! Deployment troubleshoot checks for stalled rollouts and missing replicas.
+//!
+//! References:
+//! - <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.35/#deploymentstatus-v1-apps>
+//! - <https://kubernetes.io/docs/concepts/workloads/controllers/deployment/#failed-deployment>
+
+use crate::app::{deployments::KubeDeployment, models::KubeResource};
+
+use super::{DisplayFinding, ResourceKind, Severity};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn finding(
+  deploy: &KubeDeployment,
+  severity: Severity,
+  reason: String,
+  message: String,
+) -> DisplayFinding {
+  DisplayFinding {
+    severity,
+    reason,
+    resource_kind: ResourceKind::Deployment,
+    namespace: Some(deploy.namespace.clone()),
+    resource_name: deploy.name.clone(),
+    message,
+    age: deploy.age.clone(),
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Individual deployment checks
+// ---------------------------------------------------------------------------
+
+/// Flag a rollout past `progressDeadlineSeconds`, which the controller reports
+/// as `Progressing=False` (reason `ProgressDeadlineExceeded`).
+fn check_progress(deploy: &KubeDeployment) -> Option<DisplayFinding> {
+  let condition = deploy
+    .get_k8s_obj()
+    .status
+    .as_ref()?
+    .conditions
+    .as_ref()?
+    .iter()
+    .find(|c| c.type_ == "Progressing" && c.status == "False")?;
+
+  Some(finding(
+    deploy,
+    Severity::Error,
+    condition
+      .reason
+      .clone()
+      .unwrap_or_else(|| "ProgressDeadlineExceeded".into()),
+    condition
+      .message
+      .clone()
+      .unwrap_or_else(|| "rollout is not progressing".into()),
+  ))
+}
+
+/// Flag replicas the deployment wants but cannot make available.
+fn check_unavailable(deploy: &KubeDeployment) -> Option<DisplayFinding> {
+  let obj = deploy.get_k8s_obj();
+  let unavailable = obj
+    .status
+    .as_ref()?
+    .unavailable_replicas
+    .unwrap_or_default();
+  if unavailable <= 0 {
+    return None;
+  }
+  let desired = obj.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+
+  Some(finding(
+    deploy,
+    Severity::Warn,
+    "Unavailable replicas".into(),
+    format!("{} of {} replicas unavailable", unavailable, desired),
+  ))
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation entry point
+// ---------------------------------------------------------------------------
+
+/// Run all deployment checks and collect findings.
+pub fn evaluate(items: &[KubeDeployment]) -> Vec<DisplayFinding> {
+  items
+    .iter()
+    .flat_map(|deploy| [check_progress(deploy), check_unavailable(deploy)])
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use k8s_openapi::api::apps::v1::{
+    Deployment, DeploymentCondition, DeploymentSpec, DeploymentStatus,
+  };
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+  fn build_deploy(status: DeploymentStatus) -> KubeDeployment {
+    KubeDeployment::from(Deployment {
+      metadata: ObjectMeta {
+        name: Some("deploy-1".into()),
+        namespace: Some("ns-1".into()),
+        ..Default::default()
+      },
+      spec: Some(DeploymentSpec {
+        replicas: Some(3),
+        ..Default::default()
+      }),
+      status: Some(status),
+    })
+  }
+
+  #[test]
+  fn test_check_progress_flags_exceeded_deadline() {
+    let deploy = build_deploy(DeploymentStatus {
+      conditions: Some(vec![
+        DeploymentCondition {
+          type_: "Available".into(),
+          status: "True".into(),
+          ..Default::default()
+        },
+        DeploymentCondition {
+          type_: "Progressing".into(),
+          status: "False".into(),
+          reason: Some("ProgressDeadlineExceeded".into()),
+          message: Some("ReplicaSet \"deploy-1-abc\" has timed out progressing.".into()),
+          ..Default::default()
+        },
+      ]),
+      ..Default::default()
+    });
+
+    let finding = check_progress(&deploy).expect("stalled rollout is flagged");
+    assert_eq!(finding.severity, Severity::Error);
+    assert_eq!(finding.reason, "ProgressDeadlineExceeded");
+    assert_eq!(finding.resource_kind, ResourceKind::Deployment);
+  }
+
+  #[test]
+  fn test_check_progress_ignores_progressing_rollout() {
+    let deploy = build_deploy(DeploymentStatus {
+      conditions: Some(vec![DeploymentCondition {
+        type_: "Progressing".into(),
+        status: "True".into(),
+        reason: Some("NewReplicaSetAvailable".into()),
+        ..Default::default()
+      }]),
+      ..Default::default()
+    });
+
+    assert!(check_progress(&deploy).is_none());
+  }
+
+  #[test]
+  fn test_check_unavailable_counts_against_desired() {
+    let deploy = build_deploy(DeploymentStatus {
+      unavailable_replicas: Some(2),
+      ..Default::default()
+    });
+    let finding = check_unavailable(&deploy).expect("unavailable replicas are flagged");
+    assert_eq!(finding.message, "2 of 3 replicas unavailable");
+
+    let healthy = build_deploy(DeploymentStatus::default());
+    assert!(check_unavailable(&healthy).is_none());
+  }
+}
@@ -0,0 +1,166 @@
+//! DaemonSet troubleshoot checks for pods on the wrong nodes, nodes missing
+//! their pod, and unavailable pods.
+//!
+//! References:
+//! - <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.35/#daemonsetstatus-v1-apps>
+
+use k8s_openapi::api::apps::v1::DaemonSetStatus;
+
+use crate::app::{daemonsets::KubeDaemonSet, models::KubeResource};
+
+use super::{DisplayFinding, ResourceKind, Severity};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn finding(
+  ds: &KubeDaemonSet,
+  severity: Severity,
+  reason: String,
+  message: String,
+) -> DisplayFinding {
+  DisplayFinding {
+    severity,
+    reason,
+    resource_kind: ResourceKind::DaemonSet,
+    namespace: Some(ds.namespace.clone()),
+    resource_name: ds.name.clone(),
+    message,
+    age: ds.age.clone(),
+  }
+}
+
+fn status(ds: &KubeDaemonSet) -> Option<&DaemonSetStatus> {
+  ds.get_k8s_obj().status.as_ref()
+}
+
+// ---------------------------------------------------------------------------
+// Individual DS checks
+// ---------------------------------------------------------------------------
+
+/// Flag daemon pods running on nodes they should not run on.
+fn check_misscheduled(ds: &KubeDaemonSet) -> Option<DisplayFinding> {
+  let misscheduled = status(ds)?.number_misscheduled;
+  if misscheduled <= 0 {
+    return None;
+  }
+
+  Some(finding(
+    ds,
+    Severity::Warn,
+    "Misscheduled".into(),
+    format!(
+      "daemon pods on nodes they should not run on (misscheduled={})",
+      misscheduled
+    ),
+  ))
+}
+
+/// Flag eligible nodes that have no daemon pod scheduled.
+fn check_unscheduled(ds: &KubeDaemonSet) -> Option<DisplayFinding> {
+  let status = status(ds)?;
+  let missing = status.desired_number_scheduled - status.current_number_scheduled;
+  if missing <= 0 {
+    return None;
+  }
+
+  Some(finding(
+    ds,
+    Severity::Warn,
+    "Unscheduled pods".into(),
+    format!(
+      "{} of {} nodes have no daemon pod scheduled",
+      missing, status.desired_number_scheduled
+    ),
+  ))
+}
+
+/// Flag scheduled daemon pods that are not available.
+fn check_unavailable(ds: &KubeDaemonSet) -> Option<DisplayFinding> {
+  let status = status(ds)?;
+  let unavailable = status.number_unavailable.unwrap_or_default();
+  if unavailable <= 0 {
+    return None;
+  }
+
+  Some(finding(
+    ds,
+    Severity::Warn,
+    "Unavailable pods".into(),
+    format!(
+      "{} of {} daemon pods unavailable",
+      unavailable, status.desired_number_scheduled
+    ),
+  ))
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation entry point
+// ---------------------------------------------------------------------------
+
+/// Run all DS checks and collect findings.
+pub fn evaluate(items: &[KubeDaemonSet]) -> Vec<DisplayFinding> {
+  items
+    .iter()
+    .flat_map(|ds| {
+      [
+        check_misscheduled(ds),
+        check_unscheduled(ds),
+        check_unavailable(ds),
+      ]
+    })
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use k8s_openapi::api::apps::v1::DaemonSet;
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+  fn build_ds(status: DaemonSetStatus) -> KubeDaemonSet {
+    KubeDaemonSet::from(DaemonSet {
+      metadata: ObjectMeta {
+        name: Some("ds-1".into()),
+        namespace: Some("ns-1".into()),
+        ..Default::default()
+      },
+      status: Some(status),
+      ..Default::default()
+    })
+  }
+
+  #[test]
+  fn test_healthy_ds_has_no_findings() {
+    let ds = build_ds(DaemonSetStatus {
+      desired_number_scheduled: 3,
+      current_number_scheduled: 3,
+      number_ready: 3,
+      ..Default::default()
+    });
+    assert!(evaluate(&[ds]).is_empty());
+  }
+
+  #[test]
+  fn test_ds_flags_misscheduled_unscheduled_and_unavailable() {
+    let ds = build_ds(DaemonSetStatus {
+      desired_number_scheduled: 3,
+      current_number_scheduled: 2,
+      number_misscheduled: 1,
+      number_unavailable: Some(1),
+      ..Default::default()
+    });
+
+    let reasons: Vec<String> = evaluate(&[ds]).into_iter().map(|f| f.message).collect();
+    assert_eq!(
+      reasons,
+      vec![
+        "daemon pods on nodes they should not run on (misscheduled=1)",
+        "1 of 3 nodes have no daemon pod scheduled",
+        "1 of 3 daemon pods unavailable",
+      ]
+    );
+  }
+}
@@ -0,0 +1,295 @@
+//! Ingress troubleshoot checks for backends pointing at a missing Service or
+//! port, and TLS entries naming a missing Secret.
+//!
+//! References:
+//! - <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.35/#ingressspec-v1-networking-k8s-io>
+//! - <https://kubernetes.io/docs/concepts/services-networking/ingress/#tls>
+
+use k8s_openapi::api::networking::v1::{IngressServiceBackend, ServiceBackendPort};
+
+use crate::app::{ingress::KubeIngress, models::KubeResource, svcs::KubeSvc};
+
+use super::{DisplayFinding, ResourceKind, ServiceRefs, Severity};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn finding(
+  ingress: &KubeIngress,
+  severity: Severity,
+  reason: String,
+  message: String,
+) -> DisplayFinding {
+  DisplayFinding {
+    severity,
+    reason,
+    resource_kind: ResourceKind::Ingress,
+    namespace: Some(ingress.namespace.clone()),
+    resource_name: ingress.name.clone(),
+    message,
+    age: ingress.age.clone(),
+  }
+}
+
+/// Every Service backend: the default one, then each rule path's.
+fn service_backends(ingress: &KubeIngress) -> Vec<&IngressServiceBackend> {
+  let Some(spec) = ingress.get_k8s_obj().spec.as_ref() else {
+    return vec![];
+  };
+  let default = spec
+    .default_backend
+    .as_ref()
+    .and_then(|b| b.service.as_ref());
+  let paths = spec
+    .rules
+    .iter()
+    .flatten()
+    .filter_map(|rule| rule.http.as_ref())
+    .flat_map(|http| &http.paths)
+    .filter_map(|path| path.backend.service.as_ref());
+  default.into_iter().chain(paths).collect()
+}
+
+fn format_port(port: &ServiceBackendPort) -> String {
+  match (&port.name, port.number) {
+    (Some(name), _) => name.clone(),
+    (None, Some(number)) => number.to_string(),
+    (None, None) => "<unset>".into(),
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Individual Ingress checks
+// ---------------------------------------------------------------------------
+
+/// Flag backends whose Service does not exist or does not expose the port.
+/// Each distinct backend is reported once.
+fn check_backends(ingress: &KubeIngress, services: &[KubeSvc]) -> Vec<DisplayFinding> {
+  let mut findings: Vec<DisplayFinding> = vec![];
+  for backend in service_backends(ingress) {
+    let svc = services
+      .iter()
+      .find(|s| s.namespace == ingress.namespace && s.name == backend.name);
+    let found = match svc {
+      None => finding(
+        ingress,
+        Severity::Error,
+        "Missing service".into(),
+        format!("backend service {} does not exist", backend.name),
+      ),
+      Some(svc) => {
+        let Some(port) = backend.port.as_ref() else {
+          continue;
+        };
+        let ports = svc
+          .get_k8s_obj()
+          .spec
+          .as_ref()
+          .and_then(|s| s.ports.as_deref())
+          .unwrap_or_default();
+        let exposed = ports.iter().any(|p| match &port.name {
+          Some(name) => p.name.as_ref() == Some(name),
+          None => Some(p.port) == port.number,
+        });
+        if exposed {
+          continue;
+        }
+        finding(
+          ingress,
+          Severity::Error,
+          "Missing port".into(),
+          format!(
+            "backend service {} has no port {}",
+            backend.name,
+            format_port(port)
+          ),
+        )
+      }
+    };
+    if !findings.contains(&found) {
+      findings.push(found);
+    }
+  }
+  findings
+}
+
+/// Flag TLS entries whose certificate Secret does not exist. Controllers then
+/// serve their default certificate, so this is a warning.
+fn check_tls(ingress: &KubeIngress, refs: &ServiceRefs) -> Vec<DisplayFinding> {
+  let (Some(secrets), Some(spec)) = (&refs.secrets, &ingress.get_k8s_obj().spec) else {
+    return vec![];
+  };
+  spec
+    .tls
+    .iter()
+    .flatten()
+    .filter_map(|tls| tls.secret_name.as_deref())
+    .filter(|name| !secrets.contains(&format!("{}/{}", ingress.namespace, name)))
+    .map(|name| {
+      finding(
+        ingress,
+        Severity::Warn,
+        "Missing TLS secret".into(),
+        format!("TLS secret {} does not exist", name),
+      )
+    })
+    .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation entry point
+// ---------------------------------------------------------------------------
+
+/// Run all Ingress checks and collect findings.
+pub fn evaluate(
+  items: &[KubeIngress],
+  services: &[KubeSvc],
+  refs: &ServiceRefs,
+) -> Vec<DisplayFinding> {
+  items
+    .iter()
+    .flat_map(|ingress| {
+      check_backends(ingress, services)
+        .into_iter()
+        .chain(check_tls(ingress, refs))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashSet;
+
+  use super::*;
+  use k8s_openapi::api::core::v1::{Service, ServicePort, ServiceSpec};
+  use k8s_openapi::api::networking::v1::{
+    HTTPIngressPath, HTTPIngressRuleValue, Ingress, IngressBackend, IngressRule, IngressSpec,
+    IngressTLS,
+  };
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+  fn meta(name: &str) -> ObjectMeta {
+    ObjectMeta {
+      name: Some(name.into()),
+      namespace: Some("ns-1".into()),
+      ..Default::default()
+    }
+  }
+
+  fn backend(service: &str, port: ServiceBackendPort) -> IngressBackend {
+    IngressBackend {
+      service: Some(IngressServiceBackend {
+        name: service.into(),
+        port: Some(port),
+      }),
+      ..Default::default()
+    }
+  }
+
+  fn number(port: i32) -> ServiceBackendPort {
+    ServiceBackendPort {
+      number: Some(port),
+      ..Default::default()
+    }
+  }
+
+  fn build_ingress(backends: Vec<IngressBackend>, tls_secret: Option<&str>) -> KubeIngress {
+    KubeIngress::from(Ingress {
+      metadata: meta("web"),
+      spec: Some(IngressSpec {
+        rules: Some(vec![IngressRule {
+          http: Some(HTTPIngressRuleValue {
+            paths: backends
+              .into_iter()
+              .map(|backend| HTTPIngressPath {
+                backend,
+                path: Some("/".into()),
+                path_type: "Prefix".into(),
+              })
+              .collect(),
+          }),
+          ..Default::default()
+        }]),
+        tls: tls_secret.map(|name| {
+          vec![IngressTLS {
+            secret_name: Some(name.into()),
+            ..Default::default()
+          }]
+        }),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+  }
+
+  fn build_svc(name: &str, ports: &[(Option<&str>, i32)]) -> KubeSvc {
+    KubeSvc::from(Service {
+      metadata: meta(name),
+      spec: Some(ServiceSpec {
+        ports: Some(
+          ports
+            .iter()
+            .map(|(port_name, port)| ServicePort {
+              name: port_name.map(str::to_string),
+              port: *port,
+              ..Default::default()
+            })
+            .collect(),
+        ),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+  }
+
+  fn messages(findings: Vec<DisplayFinding>) -> Vec<String> {
+    findings.into_iter().map(|f| f.message).collect()
+  }
+
+  #[test]
+  fn test_backends_need_existing_service_and_port() {
+    let ingress = build_ingress(
+      vec![
+        backend("api", number(80)),
+        backend("api", number(8080)),
+        backend(
+          "api",
+          ServiceBackendPort {
+            name: Some("grpc".into()),
+            ..Default::default()
+          },
+        ),
+        backend("gone", number(80)),
+        backend("gone", number(80)),
+      ],
+      None,
+    );
+    let services = [build_svc("api", &[(Some("http"), 80)])];
+
+    assert_eq!(
+      messages(check_backends(&ingress, &services)),
+      vec![
+        "backend service api has no port 8080",
+        "backend service api has no port grpc",
+        "backend service gone does not exist",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_tls_secret_must_exist_when_secrets_are_known() {
+    let ingress = build_ingress(vec![], Some("web-tls"));
+
+    assert!(check_tls(&ingress, &ServiceRefs::default()).is_empty());
+    let with_secret = |name: &str| ServiceRefs {
+      secrets: Some(HashSet::from([format!("ns-1/{}", name)])),
+      ..Default::default()
+    };
+    assert_eq!(
+      messages(check_tls(&ingress, &with_secret("other"))),
+      vec!["TLS secret web-tls does not exist"]
+    );
+    assert!(check_tls(&ingress, &with_secret("web-tls")).is_empty());
+  }
+}
@@ -0,0 +1,246 @@
+//! Job troubleshoot checks for failed Jobs, exhausted retries and Jobs running
+//! past their deadline.
+//!
+//! References:
+//! - <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.35/#jobstatus-v1-batch>
+//! - <https://kubernetes.io/docs/concepts/workloads/controllers/job/#job-termination-and-cleanup>
+
+use chrono::{DateTime, TimeDelta, Utc};
+use k8s_openapi::api::batch::v1::JobCondition;
+
+use crate::app::{
+  jobs::KubeJob,
+  models::KubeResource,
+  utils::{duration_to_age, time_to_chrono},
+};
+
+use super::{DisplayFinding, ResourceKind, Severity};
+
+/// The API default for `spec.backoffLimit`.
+const DEFAULT_BACKOFF_LIMIT: i32 = 6;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn finding(job: &KubeJob, severity: Severity, reason: String, message: String) -> DisplayFinding {
+  DisplayFinding {
+    severity,
+    reason,
+    resource_kind: ResourceKind::Job,
+    namespace: Some(job.namespace.clone()),
+    resource_name: job.name.clone(),
+    message,
+    age: job.age.clone(),
+  }
+}
+
+fn condition<'a>(job: &'a KubeJob, type_: &str) -> Option<&'a JobCondition> {
+  job
+    .get_k8s_obj()
+    .status
+    .as_ref()?
+    .conditions
+    .as_ref()?
+    .iter()
+    .find(|c| c.type_ == type_ && c.status == "True")
+}
+
+fn is_finished(job: &KubeJob) -> bool {
+  condition(job, "Complete").is_some() || condition(job, "Failed").is_some()
+}
+
+// ---------------------------------------------------------------------------
+// Individual Job checks
+// ---------------------------------------------------------------------------
+
+/// Flag a Job marked `Failed`, with the controller's reason (usually
+/// `BackoffLimitExceeded` or `DeadlineExceeded`).
+fn check_failed(job: &KubeJob) -> Option<DisplayFinding> {
+  let condition = condition(job, "Failed")?;
+
+  Some(finding(
+    job,
+    Severity::Error,
+    condition.reason.clone().unwrap_or_else(|| "Failed".into()),
+    condition
+      .message
+      .clone()
+      .unwrap_or_else(|| "job failed".into()),
+  ))
+}
+
+/// Flag a Job whose failed pods have used up `backoffLimit` before the
+/// controller has marked it failed.
+fn check_backoff(job: &KubeJob) -> Option<DisplayFinding> {
+  if is_finished(job) {
+    return None;
+  }
+  let obj = job.get_k8s_obj();
+  let failed = obj.status.as_ref()?.failed.unwrap_or_default();
+  let limit = obj
+    .spec
+    .as_ref()
+    .and_then(|s| s.backoff_limit)
+    .unwrap_or(DEFAULT_BACKOFF_LIMIT);
+  if failed <= limit {
+    return None;
+  }
+
+  Some(finding(
+    job,
+    Severity::Error,
+    "BackoffLimitExceeded".into(),
+    format!("{} pods failed, backoffLimit is {}", failed, limit),
+  ))
+}
+
+/// Flag an unfinished Job that has run past `activeDeadlineSeconds`.
+fn check_deadline(job: &KubeJob, now: DateTime<Utc>) -> Option<DisplayFinding> {
+  let obj = job.get_k8s_obj();
+  let spec = obj.spec.as_ref()?;
+  if spec.suspend == Some(true) || is_finished(job) {
+    return None;
+  }
+  let deadline = TimeDelta::seconds(spec.active_deadline_seconds?);
+  let started = time_to_chrono(obj.status.as_ref()?.start_time.as_ref()?);
+  let running = now - started;
+  if running <= deadline {
+    return None;
+  }
+
+  Some(finding(
+    job,
+    Severity::Error,
+    "DeadlineExceeded".into(),
+    format!(
+      "running for {}, past activeDeadlineSeconds of {}",
+      duration_to_age(running, true),
+      duration_to_age(deadline, true)
+    ),
+  ))
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation entry point
+// ---------------------------------------------------------------------------
+
+/// Run all Job checks as of `now` and collect findings.
+pub fn evaluate(items: &[KubeJob], now: DateTime<Utc>) -> Vec<DisplayFinding> {
+  items
+    .iter()
+    .filter_map(|job| {
+      check_failed(job)
+        .or_else(|| check_backoff(job))
+        .or_else(|| check_deadline(job, now))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use k8s_openapi::api::batch::v1::{Job, JobSpec, JobStatus};
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, Time};
+
+  fn at(time: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(time).unwrap().to_utc()
+  }
+
+  fn build_job(spec: JobSpec, status: JobStatus) -> KubeJob {
+    KubeJob::from(Job {
+      metadata: ObjectMeta {
+        name: Some("backup".into()),
+        namespace: Some("ns-1".into()),
+        ..Default::default()
+      },
+      spec: Some(spec),
+      status: Some(status),
+    })
+  }
+
+  fn failed_condition(reason: &str) -> Option<Vec<JobCondition>> {
+    Some(vec![JobCondition {
+      type_: "Failed".into(),
+      status: "True".into(),
+      reason: Some(reason.into()),
+      message: Some("Job has reached the specified backoff limit".into()),
+      ..Default::default()
+    }])
+  }
+
+  #[test]
+  fn test_failed_condition_is_reported_once() {
+    let job = build_job(
+      JobSpec {
+        backoff_limit: Some(1),
+        ..Default::default()
+      },
+      JobStatus {
+        failed: Some(2),
+        conditions: failed_condition("BackoffLimitExceeded"),
+        ..Default::default()
+      },
+    );
+
+    let findings = evaluate(&[job], Utc::now());
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].severity, Severity::Error);
+    assert_eq!(findings[0].reason, "BackoffLimitExceeded");
+    assert_eq!(
+      findings[0].message,
+      "Job has reached the specified backoff limit"
+    );
+  }
+
+  #[test]
+  fn test_backoff_exhausted_before_failed_condition() {
+    let status = |failed: i32| JobStatus {
+      failed: Some(failed),
+      ..Default::default()
+    };
+    let job = |failed: i32| build_job(JobSpec::default(), status(failed));
+
+    assert!(check_backoff(&job(6)).is_none());
+    assert_eq!(
+      check_backoff(&job(7)).unwrap().message,
+      "7 pods failed, backoffLimit is 6"
+    );
+  }
+
+  #[test]
+  fn test_deadline_only_applies_to_running_jobs() {
+    let spec = JobSpec {
+      active_deadline_seconds: Some(600),
+      ..Default::default()
+    };
+    let started = JobStatus {
+      start_time: Some(Time(
+        k8s_openapi::jiff::Timestamp::from_second(at("2024-01-01T10:00:00Z").timestamp()).unwrap(),
+      )),
+      ..Default::default()
+    };
+    let job = build_job(spec.clone(), started.clone());
+
+    assert!(check_deadline(&job, at("2024-01-01T10:09:00Z")).is_none());
+    assert_eq!(
+      check_deadline(&job, at("2024-01-01T10:15:00Z"))
+        .unwrap()
+        .message,
+      "running for 15m, past activeDeadlineSeconds of 10m"
+    );
+
+    let complete = build_job(
+      spec,
+      JobStatus {
+        conditions: Some(vec![JobCondition {
+          type_: "Complete".into(),
+          status: "True".into(),
+          ..Default::default()
+        }]),
+        ..started
+      },
+    );
+    assert!(check_deadline(&complete, at("2024-01-01T10:15:00Z")).is_none());
+  }
+}
@@ -21,10 +21,12 @@
 //!    - `pub fn evaluate(items: &[KubeT]) -> Vec<DisplayFinding>`.
 //! 3. In this file (`mod.rs`):
 //!    - Add `mod <resource>;`
-//!    - Add `findings.extend(<resource>::evaluate(&data.<table>.items));`
+//!    - Add a field for the resource to [`TroubleshootInputs`].
+//!    - Add `findings.extend(<resource>::evaluate(&inputs.<field>));`
 //!      in `evaluate_findings`.
-//! 4. In `TroubleshootResource::get_resource`, fetch and store the new
-//!    resource type alongside the existing `tokio::join!` calls.
+//! 4. In `TroubleshootResource::get_resource`, fetch the new resource type
+//!    alongside the existing `tokio::join!` calls and store it in
+//!    [`TroubleshootInputs`], not in the resource's tab table.
 //! 5. Run `cargo test troubleshoot` and verify the new `ResourceKind`
 //!    is handled by the new module and contributes findings through
 //!    `evaluate_findings`.
@@ -32,14 +34,28 @@
 use async_trait::async_trait;
 use ratatui::layout::Rect;
 use ratatui::Frame;
+use std::collections::HashSet;
+
+use chrono::{TimeDelta, Utc};
+use log::warn;
 
 use super::{
-  models::AppResource, pods::KubePod, pvcs::KubePVC, replicasets::KubeReplicaSet, ActiveBlock, App,
-  Data,
+  cronjobs::KubeCronJob, daemonsets::KubeDaemonSet, deployments::KubeDeployment,
+  ingress::KubeIngress, jobs::KubeJob, models::AppResource, nodes::KubeNode, pods::KubePod,
+  pvcs::KubePVC, replicasets::KubeReplicaSet, statefulsets::KubeStatefulSet, svcs::KubeSvc,
+  ActiveBlock, App, Data,
 };
-use k8s_openapi::api::apps::v1::ReplicaSet;
-use k8s_openapi::api::core::v1::{PersistentVolumeClaim, Pod};
-
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{Endpoints, Node, PersistentVolumeClaim, Pod, Secret, Service};
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::NamespaceResourceScope;
+use kube::{api::ListParams, Api};
+use serde::de::DeserializeOwned;
+
+use crate::config::TroubleshootConfig;
+use crate::network::watch::WatchScope;
 use crate::ui::utils::{
   copy_and_escape_title_line, draw_describe_block, draw_yaml_block, get_describe_active,
   get_resource_title, title_with_dual_style,
@@ -48,25 +64,74 @@ use crate::ui::utils::{
 mod render;
 mod types;
 
+pub use node::NodeRefs;
 pub use render::render_troubleshoot;
+pub use sts::RevisionTracker;
+pub use svc::ServiceRefs;
 pub use types::{DisplayFinding, ResourceKind, Severity};
 
+/// Resources listed for the checks, kept apart from their tabs so a tab's
+/// selector and `max_items_per_tab` cap are left alone.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TroubleshootInputs {
+  pub deployments: Vec<KubeDeployment>,
+  pub stateful_sets: Vec<KubeStatefulSet>,
+  pub daemon_sets: Vec<KubeDaemonSet>,
+  pub services: Vec<KubeSvc>,
+  pub ingresses: Vec<KubeIngress>,
+  pub jobs: Vec<KubeJob>,
+  pub cronjobs: Vec<KubeCronJob>,
+}
+
+mod cron;
+mod cronjob;
+mod deploy;
+mod ds;
+mod ingress;
+mod job;
+mod node;
 mod pod;
 mod pvc;
 mod rs;
+mod sts;
+mod svc;
 
 // ---------------------------------------------------------------------------
 // Evaluation orchestrator
 // ---------------------------------------------------------------------------
 
-pub fn evaluate_findings(data: &Data) -> Vec<DisplayFinding> {
+pub fn evaluate_findings(data: &Data, config: &TroubleshootConfig) -> Vec<DisplayFinding> {
   let mut findings: Vec<DisplayFinding> = Vec::new();
+  let now = Utc::now();
 
-  findings.extend(pod::evaluate(&data.pods.items));
+  findings.extend(pod::evaluate(&data.pods.items, config, now));
   findings.extend(pvc::evaluate(&data.persistent_volume_claims.items));
   findings.extend(rs::evaluate(&data.replica_sets.items));
-
-  // Future: add node/deployment checks.
+  let inputs = &data.troubleshoot_inputs;
+  findings.extend(deploy::evaluate(&inputs.deployments));
+  findings.extend(sts::evaluate(
+    &inputs.stateful_sets,
+    &data.troubleshoot_revisions,
+    TimeDelta::seconds(config.rollout_threshold_secs.into()),
+  ));
+  findings.extend(ds::evaluate(&inputs.daemon_sets));
+  findings.extend(node::evaluate(
+    &data.troubleshoot_nodes,
+    data.server_version.as_deref(),
+    config,
+  ));
+  findings.extend(svc::evaluate(
+    &inputs.services,
+    &data.pods.items,
+    &data.troubleshoot_refs,
+  ));
+  findings.extend(ingress::evaluate(
+    &inputs.ingresses,
+    &inputs.services,
+    &data.troubleshoot_refs,
+  ));
+  findings.extend(job::evaluate(&inputs.jobs, now));
+  findings.extend(cronjob::evaluate(&inputs.cronjobs, now));
 
   findings.sort_unstable_by(|a, b| {
     a.severity
@@ -80,6 +145,142 @@ pub fn evaluate_findings(data: &Data) -> Vec<DisplayFinding> {
   findings
 }
 
+/// List a resource only the checks need, in the selected namespace, through
+/// the watch cache. Failures (usually RBAC) are logged and return `None` so
+/// dependent checks are skipped.
+async fn list_for_checks<K>(network: &crate::network::Network<'_>) -> Option<Vec<K>>
+where
+  <K as kube::Resource>::DynamicType: Default,
+  K: kube::Resource<Scope = NamespaceResourceScope>,
+  K: Clone + DeserializeOwned + std::fmt::Debug + Send + Sync + 'static,
+{
+  let api = network.get_namespaced_api::<K>().await;
+  let scope = WatchScope {
+    namespace: network.app.lock().await.data.selected.ns.clone(),
+    ..WatchScope::default()
+  };
+  match network.list_all_watched(api, scope).await {
+    Ok(items) => Some(items),
+    Err(e) => {
+      warn!(
+        "Troubleshoot could not list {}: {}",
+        K::plural(&Default::default()),
+        e
+      );
+      None
+    }
+  }
+}
+
+/// `namespace/name` of every Secret in the selected namespace, listed as
+/// metadata only, in pages of `list_page_size`.
+async fn list_secret_names(network: &crate::network::Network<'_>) -> kube::Result<HashSet<String>> {
+  let api = network.get_namespaced_api::<Secret>().await;
+  let page_size = network.app.lock().await.config.list_page_size();
+  let mut names = HashSet::new();
+  let mut lp = ListParams::default().limit(page_size);
+  loop {
+    let page = api.list_metadata(&lp).await?;
+    names.extend(page.items.into_iter().map(|s| {
+      format!(
+        "{}/{}",
+        s.metadata.namespace.unwrap_or_default(),
+        s.metadata.name.unwrap_or_default()
+      )
+    }));
+    match page.metadata.continue_.filter(|t| !t.is_empty()) {
+      Some(token) => lp = lp.continue_token(&token),
+      None => return Ok(names),
+    }
+  }
+}
+
+/// List every node, unaffected by the Nodes tab's selector, and every pod for
+/// the cordon check. Node metrics are only fetched when a utilisation check is
+/// on. Without access to nodes there are just no node findings.
+async fn load_node_refs(
+  network: &crate::network::Network<'_>,
+  config: &TroubleshootConfig,
+) -> NodeRefs {
+  let (nodes, pods) = tokio::join!(
+    network.list_all_watched(
+      Api::<Node>::all(network.client.clone()),
+      WatchScope::default()
+    ),
+    network.list_all_watched(
+      Api::<Pod>::all(network.client.clone()),
+      WatchScope::default()
+    ),
+  );
+  let nodes = match nodes {
+    Ok(nodes) => nodes,
+    Err(e) => {
+      warn!("Troubleshoot could not list nodes: {}", e);
+      return NodeRefs::default();
+    }
+  };
+  let pods = match pods {
+    Ok(pods) => Some(pods),
+    Err(e) => {
+      warn!("Troubleshoot could not list pods in all namespaces: {}", e);
+      None
+    }
+  };
+  if config.node_cpu_threshold > 0 || config.node_mem_threshold > 0 {
+    super::nodes::get_node_metrics(network).await;
+  }
+
+  let mut app = network.app.lock().await;
+  let nodes = nodes
+    .iter()
+    .map(|node| KubeNode::from_api_with_pods(node, pods.as_deref().unwrap_or_default(), &mut app))
+    .collect();
+  NodeRefs { nodes, pods }
+}
+
+/// The API server's git version, fetched once per context.
+async fn load_server_version(network: &crate::network::Network<'_>) -> Option<String> {
+  if let Some(version) = network.app.lock().await.data.server_version.clone() {
+    return Some(version);
+  }
+  match network.client.apiserver_version().await {
+    Ok(info) => Some(info.git_version),
+    Err(e) => {
+      warn!("Troubleshoot could not read the server version: {}", e);
+      None
+    }
+  }
+}
+
+/// Collect ready endpoint counts and Secret names for [`ServiceRefs`]. Only
+/// Secret metadata is fetched, so no Secret data is read.
+async fn load_service_refs(network: &crate::network::Network<'_>) -> ServiceRefs {
+  let (slices, endpoints, secrets) = tokio::join!(
+    list_for_checks::<EndpointSlice>(network),
+    list_for_checks::<Endpoints>(network),
+    list_secret_names(network),
+  );
+  let secrets = match secrets {
+    Ok(names) => Some(names),
+    Err(e) => {
+      warn!("Troubleshoot could not list secrets: {}", e);
+      None
+    }
+  };
+  // Slices alone are enough on current clusters; Endpoints only fill gaps.
+  let ready_endpoints = match (slices, endpoints) {
+    (None, None) => None,
+    (slices, endpoints) => Some(ServiceRefs::ready_endpoints(
+      &slices.unwrap_or_default(),
+      &endpoints.unwrap_or_default(),
+    )),
+  };
+  ServiceRefs {
+    ready_endpoints,
+    secrets,
+  }
+}
+
 // ---------------------------------------------------------------------------
 // AppResource impl
 // ---------------------------------------------------------------------------
@@ -92,6 +293,7 @@ impl AppResource for TroubleshootResource {
     match block {
       ActiveBlock::Containers => super::pods::draw_containers_block(f, app, area),
       ActiveBlock::Logs => super::pods::draw_logs_block(f, app, area),
+      ActiveBlock::Shell => super::exec::draw_exec_block(f, app, area),
       ActiveBlock::Describe => draw_describe_block(
         f,
         app,
@@ -127,17 +329,48 @@ impl AppResource for TroubleshootResource {
   }
 
   async fn get_resource(network: &crate::network::Network<'_>) {
-    let (pods, pvcs, replica_sets) = tokio::join!(
+    let (pods, pvcs, replica_sets, deployments, stateful_sets, daemon_sets) = tokio::join!(
       network.get_namespaced_resources::<Pod, KubePod, _>(KubePod::from),
       network.get_namespaced_resources::<PersistentVolumeClaim, KubePVC, _>(KubePVC::from),
       network.get_namespaced_resources::<ReplicaSet, KubeReplicaSet, _>(KubeReplicaSet::from),
+      network.get_namespaced_resources::<Deployment, KubeDeployment, _>(KubeDeployment::from),
+      network.get_namespaced_resources::<StatefulSet, KubeStatefulSet, _>(KubeStatefulSet::from),
+      network.get_namespaced_resources::<DaemonSet, KubeDaemonSet, _>(KubeDaemonSet::from),
+    );
+    let (services, ingresses, refs, jobs, cronjobs) = tokio::join!(
+      network.get_namespaced_resources::<Service, KubeSvc, _>(KubeSvc::from),
+      network.get_namespaced_resources::<Ingress, KubeIngress, _>(KubeIngress::from),
+      load_service_refs(network),
+      network.get_namespaced_resources::<Job, KubeJob, _>(KubeJob::from),
+      network.get_namespaced_resources::<CronJob, KubeCronJob, _>(KubeCronJob::from),
+    );
+    let config = network.app.lock().await.config.troubleshoot.clone();
+    let (node_refs, server_version) = tokio::join!(
+      load_node_refs(network, &config),
+      load_server_version(network),
     );
 
     let mut app = network.app.lock().await;
+    app.data.server_version = server_version;
+    app.data.troubleshoot_nodes = node_refs;
     app.data.pods.set_items(pods);
     app.data.persistent_volume_claims.set_items(pvcs);
     app.data.replica_sets.set_items(replica_sets);
-    let findings = evaluate_findings(&app.data);
+    app.data.troubleshoot_refs = refs;
+    let data = &mut app.data;
+    data
+      .troubleshoot_revisions
+      .observe(&stateful_sets, &data.pods.items, Utc::now());
+    data.troubleshoot_inputs = TroubleshootInputs {
+      deployments,
+      stateful_sets,
+      daemon_sets,
+      services,
+      ingresses,
+      jobs,
+      cronjobs,
+    };
+    let findings = evaluate_findings(&app.data, &app.config.troubleshoot);
     app.data.troubleshoot_findings.set_items(findings);
   }
 }
@@ -246,7 +479,7 @@ mod tests {
 
     let app = build_app_with_resources(pod, pvc, rs);
 
-    let findings = evaluate_findings(&app.data);
+    let findings = evaluate_findings(&app.data, &TroubleshootConfig::default());
 
     // Order: severity (Error->Warn->Info), then name.
     assert_eq!(findings.len(), 3);
@@ -258,6 +491,58 @@ mod tests {
     assert_eq!(findings[2].resource_name, "b-pvc");
   }
 
+  #[test]
+  fn test_evaluate_findings_includes_workload_controllers() {
+    use k8s_openapi::api::apps::v1::{
+      DaemonSet, DaemonSetStatus, Deployment, DeploymentStatus, StatefulSet, StatefulSetStatus,
+    };
+    let meta = |name: &str| ObjectMeta {
+      name: Some(name.into()),
+      namespace: Some("ns-1".into()),
+      ..Default::default()
+    };
+    let data = Data {
+      troubleshoot_inputs: TroubleshootInputs {
+        deployments: vec![KubeDeployment::from(Deployment {
+          metadata: meta("deploy-a"),
+          status: Some(DeploymentStatus {
+            unavailable_replicas: Some(1),
+            ..Default::default()
+          }),
+          ..Default::default()
+        })],
+        stateful_sets: vec![KubeStatefulSet::from(StatefulSet {
+          metadata: meta("sts-a"),
+          status: Some(StatefulSetStatus::default()),
+          ..Default::default()
+        })],
+        daemon_sets: vec![KubeDaemonSet::from(DaemonSet {
+          metadata: meta("ds-a"),
+          status: Some(DaemonSetStatus {
+            number_misscheduled: 1,
+            ..Default::default()
+          }),
+          ..Default::default()
+        })],
+        ..TroubleshootInputs::default()
+      },
+      ..Data::default()
+    };
+
+    let findings = evaluate_findings(&data, &TroubleshootConfig::default());
+
+    let kinds: Vec<ResourceKind> = findings.iter().map(|f| f.resource_kind).collect();
+    assert_eq!(
+      kinds,
+      vec![
+        ResourceKind::Deployment,
+        ResourceKind::DaemonSet,
+        ResourceKind::StatefulSet
+      ]
+    );
+    assert_eq!(findings[0].describe_target().0, "deploy");
+  }
+
   #[test]
   fn test_evaluate_findings_tie_breaks_same_name() {
     let mut data = Data::default();
@@ -279,7 +564,7 @@ mod tests {
         KubePod::from(pod)
       }]);
 
-    let findings = evaluate_findings(&data);
+    let findings = evaluate_findings(&data, &TroubleshootConfig::default());
 
     assert_eq!(findings.len(), 2);
     assert_eq!(findings[0].namespace.as_deref(), Some("ns-1"));
@@ -0,0 +1,439 @@
+//! Node troubleshoot checks for readiness, pressure conditions, cordoned nodes
+//! still running workloads, kubelet version skew and high utilisation.
+//!
+//! References:
+//! - <https://kubernetes.io/docs/reference/node/node-status/#condition>
+//! - <https://kubernetes.io/releases/version-skew-policy/#kubelet>
+
+use k8s_openapi::api::core::v1::{NodeCondition, Pod};
+
+use crate::{
+  app::{models::KubeResource, nodes::KubeNode},
+  config::TroubleshootConfig,
+};
+
+use super::{DisplayFinding, ResourceKind, Severity};
+
+/// Minor versions a kubelet may trail the API server by.
+const MAX_KUBELET_MINOR_SKEW: u32 = 3;
+
+/// Nodes listed for the checks, independent of the Nodes tab and its
+/// selector, with pods from every namespace for the cordon check. `pods` is
+/// `None` when they could not be listed cluster-wide.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeRefs {
+  pub nodes: Vec<KubeNode>,
+  pub pods: Option<Vec<Pod>>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn finding(node: &KubeNode, severity: Severity, reason: String, message: String) -> DisplayFinding {
+  DisplayFinding {
+    severity,
+    reason,
+    resource_kind: ResourceKind::Node,
+    namespace: None,
+    resource_name: node.name.clone(),
+    message,
+    age: node.age.clone(),
+  }
+}
+
+fn conditions(node: &KubeNode) -> &[NodeCondition] {
+  node
+    .get_k8s_obj()
+    .status
+    .as_ref()
+    .and_then(|s| s.conditions.as_deref())
+    .unwrap_or_default()
+}
+
+fn condition_message(condition: &NodeCondition, fallback: &str) -> String {
+  condition
+    .message
+    .clone()
+    .filter(|m| !m.is_empty())
+    .unwrap_or_else(|| fallback.into())
+}
+
+/// `(major, minor)` from a version like `v1.30.2-eks-1234`.
+pub(super) fn parse_version(version: &str) -> Option<(u32, u32)> {
+  let mut parts = version.trim_start_matches('v').split('.');
+  let major = parts.next()?.parse().ok()?;
+  let minor = parts
+    .next()?
+    .trim_end_matches(|c: char| !c.is_ascii_digit())
+    .parse()
+    .ok()?;
+  Some((major, minor))
+}
+
+// ---------------------------------------------------------------------------
+// Individual node checks
+// ---------------------------------------------------------------------------
+
+/// Flag a `Ready` condition that is `False`, or `Unknown` because the kubelet
+/// stopped reporting.
+fn check_ready(node: &KubeNode) -> Option<DisplayFinding> {
+  let ready = conditions(node).iter().find(|c| c.type_ == "Ready")?;
+  let reason = match ready.status.as_str() {
+    "False" => "NotReady",
+    "Unknown" => "NodeStatusUnknown",
+    _ => return None,
+  };
+  Some(finding(
+    node,
+    Severity::Error,
+    reason.into(),
+    condition_message(ready, "node is not ready"),
+  ))
+}
+
+/// Flag resource pressure and an unconfigured network.
+fn check_pressure(node: &KubeNode) -> Vec<DisplayFinding> {
+  conditions(node)
+    .iter()
+    .filter(|c| c.status == "True")
+    .filter_map(|c| {
+      let severity = match c.type_.as_str() {
+        "MemoryPressure" | "DiskPressure" | "PIDPressure" => Severity::Warn,
+        "NetworkUnavailable" => Severity::Error,
+        _ => return None,
+      };
+      Some(finding(
+        node,
+        severity,
+        c.type_.clone(),
+        condition_message(c, &format!("node reports {}", c.type_)),
+      ))
+    })
+    .collect()
+}
+
+/// Flag a cordoned node that still runs pods other than DaemonSet pods, which
+/// stay on cordoned nodes by design. `pods` must cover every namespace.
+fn check_cordoned(node: &KubeNode, pods: &[Pod]) -> Option<DisplayFinding> {
+  let unschedulable = node
+    .get_k8s_obj()
+    .spec
+    .as_ref()
+    .and_then(|s| s.unschedulable)
+    .unwrap_or_default();
+  if !unschedulable {
+    return None;
+  }
+  let workloads = pods
+    .iter()
+    .filter(|pod| {
+      pod.spec.as_ref().and_then(|s| s.node_name.as_deref()) == Some(node.name.as_str())
+        && !matches!(
+          pod.status.as_ref().and_then(|s| s.phase.as_deref()),
+          Some("Succeeded" | "Failed")
+        )
+        && !pod
+          .metadata
+          .owner_references
+          .as_ref()
+          .is_some_and(|owners| owners.iter().any(|o| o.kind == "DaemonSet"))
+    })
+    .count();
+  if workloads == 0 {
+    return None;
+  }
+
+  Some(finding(
+    node,
+    Severity::Warn,
+    "Cordoned".into(),
+    format!(
+      "node is cordoned but still runs {} workload pods",
+      workloads
+    ),
+  ))
+}
+
+/// Flag a kubelet on a different minor version from the API server: a warning
+/// outside the supported skew (newer, or more than three minors older), a note
+/// otherwise.
+fn check_version_skew(node: &KubeNode, server_version: Option<&str>) -> Option<DisplayFinding> {
+  let (server_major, server_minor) = parse_version(server_version?)?;
+  let (major, minor) = parse_version(&node.version)?;
+  if (major, minor) == (server_major, server_minor) {
+    return None;
+  }
+  let supported =
+    major == server_major && minor < server_minor && server_minor - minor <= MAX_KUBELET_MINOR_SKEW;
+
+  Some(finding(
+    node,
+    if supported {
+      Severity::Info
+    } else {
+      Severity::Warn
+    },
+    "Version skew".into(),
+    format!(
+      "kubelet {} differs from control plane {}{}",
+      node.version,
+      server_version?,
+      if supported { "" } else { " (unsupported)" }
+    ),
+  ))
+}
+
+/// Flag CPU or memory use above the configured share of allocatable. Nodes
+/// without metrics report 0% and are skipped.
+fn check_utilisation(node: &KubeNode, config: &TroubleshootConfig) -> Vec<DisplayFinding> {
+  [
+    ("CPU", &node.cpu_percent, config.node_cpu_threshold),
+    ("memory", &node.mem_percent, config.node_mem_threshold),
+  ]
+  .into_iter()
+  .filter_map(|(resource, percent, threshold)| {
+    let percent: f64 = percent.parse().ok()?;
+    if threshold == 0 || percent <= f64::from(threshold) {
+      return None;
+    }
+    Some(finding(
+      node,
+      Severity::Warn,
+      format!("High {} usage", resource),
+      format!(
+        "{} usage at {:.0}% of allocatable (threshold {}%)",
+        resource, percent, threshold
+      ),
+    ))
+  })
+  .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation entry point
+// ---------------------------------------------------------------------------
+
+/// Run all node checks and collect findings. `server_version` is the API
+/// server's git version, when known. The cordon check is skipped without pods.
+pub fn evaluate(
+  refs: &NodeRefs,
+  server_version: Option<&str>,
+  config: &TroubleshootConfig,
+) -> Vec<DisplayFinding> {
+  refs
+    .nodes
+    .iter()
+    .flat_map(|node| {
+      check_ready(node)
+        .into_iter()
+        .chain(check_pressure(node))
+        .chain(
+          refs
+            .pods
+            .as_deref()
+            .and_then(|pods| check_cordoned(node, pods)),
+        )
+        .chain(check_version_skew(node, server_version))
+        .chain(check_utilisation(node, config))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use super::*;
+  use k8s_openapi::api::core::v1::{
+    Node, NodeSpec, NodeStatus, NodeSystemInfo, Pod, PodSpec, PodStatus,
+  };
+  use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+  use tokio::sync::Mutex;
+
+  use crate::app::{metrics::KubeNodeMetrics, App};
+
+  fn condition(type_: &str, status: &str) -> NodeCondition {
+    NodeCondition {
+      type_: type_.into(),
+      status: status.into(),
+      ..Default::default()
+    }
+  }
+
+  /// A `v1.30.1` node with 1 CPU and 1000Mi allocatable, using `usage`.
+  async fn build_node(
+    conditions: Vec<NodeCondition>,
+    unschedulable: bool,
+    usage: Option<(&str, &str)>,
+  ) -> KubeNode {
+    let node = Node {
+      metadata: ObjectMeta {
+        name: Some("node-1".into()),
+        ..Default::default()
+      },
+      spec: Some(NodeSpec {
+        unschedulable: Some(unschedulable),
+        ..Default::default()
+      }),
+      status: Some(NodeStatus {
+        conditions: Some(conditions),
+        allocatable: Some(BTreeMap::from([
+          ("cpu".to_string(), Quantity("1".into())),
+          ("memory".to_string(), Quantity("1000Mi".into())),
+        ])),
+        node_info: Some(NodeSystemInfo {
+          kubelet_version: "v1.30.1".into(),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }),
+    };
+    let mut app = App::default();
+    if let Some((cpu, mem)) = usage {
+      app.data.node_metrics = vec![KubeNodeMetrics {
+        name: "node-1".into(),
+        cpu: cpu.into(),
+        mem: mem.into(),
+        ..Default::default()
+      }];
+    }
+    let app = Mutex::new(app);
+    let node = KubeNode::from_api_with_pods(&node, &[], &mut app.lock().await);
+    node
+  }
+
+  fn build_pod(name: &str, owner_kind: &str) -> Pod {
+    Pod {
+      metadata: ObjectMeta {
+        name: Some(name.into()),
+        namespace: Some("ns-1".into()),
+        owner_references: Some(vec![OwnerReference {
+          kind: owner_kind.into(),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      },
+      spec: Some(PodSpec {
+        node_name: Some("node-1".into()),
+        ..Default::default()
+      }),
+      status: Some(PodStatus {
+        phase: Some("Running".into()),
+        ..Default::default()
+      }),
+    }
+  }
+
+  fn reasons(findings: &[DisplayFinding]) -> Vec<(Severity, &str)> {
+    findings
+      .iter()
+      .map(|f| (f.severity, f.reason.as_str()))
+      .collect()
+  }
+
+  #[tokio::test]
+  async fn test_node_conditions() {
+    let node = build_node(
+      vec![
+        condition("Ready", "Unknown"),
+        condition("MemoryPressure", "True"),
+        condition("DiskPressure", "False"),
+        condition("PIDPressure", "True"),
+        condition("NetworkUnavailable", "True"),
+      ],
+      false,
+      None,
+    )
+    .await;
+
+    let refs = NodeRefs {
+      nodes: vec![node],
+      pods: None,
+    };
+    let findings = evaluate(&refs, None, &TroubleshootConfig::default());
+
+    assert_eq!(
+      reasons(&findings),
+      vec![
+        (Severity::Error, "NodeStatusUnknown"),
+        (Severity::Warn, "MemoryPressure"),
+        (Severity::Warn, "PIDPressure"),
+        (Severity::Error, "NetworkUnavailable"),
+      ]
+    );
+    assert_eq!(findings[0].namespace, None);
+  }
+
+  #[tokio::test]
+  async fn test_cordoned_node_ignores_daemonset_pods() {
+    let node = build_node(vec![condition("Ready", "True")], true, None).await;
+    let daemon = build_pod("agent", "DaemonSet");
+    let workload = build_pod("api", "ReplicaSet");
+
+    assert!(check_cordoned(&node, std::slice::from_ref(&daemon)).is_none());
+    let finding = check_cordoned(&node, &[daemon, workload]).expect("workload is flagged");
+    assert_eq!(
+      finding.message,
+      "node is cordoned but still runs 1 workload pods"
+    );
+
+    // without a cluster-wide pod list the check is skipped, not guessed
+    let refs = NodeRefs {
+      nodes: vec![node],
+      pods: None,
+    };
+    assert!(evaluate(&refs, None, &TroubleshootConfig::default()).is_empty());
+  }
+
+  #[test]
+  fn test_parse_version() {
+    assert_eq!(parse_version("v1.30.2"), Some((1, 30)));
+    assert_eq!(parse_version("v1.27.3-eks-a5565ad"), Some((1, 27)));
+    assert_eq!(parse_version("1.29+"), Some((1, 29)));
+    assert_eq!(parse_version("unknown"), None);
+  }
+
+  #[tokio::test]
+  async fn test_version_skew() {
+    let node = build_node(vec![], false, None).await;
+
+    assert!(check_version_skew(&node, Some("v1.30.4")).is_none());
+    assert!(check_version_skew(&node, None).is_none());
+    let older = check_version_skew(&node, Some("v1.32.0")).unwrap();
+    assert_eq!(older.severity, Severity::Info);
+    let too_old = check_version_skew(&node, Some("v1.34.0")).unwrap();
+    assert_eq!(too_old.severity, Severity::Warn);
+    let newer = check_version_skew(&node, Some("v1.29.0")).unwrap();
+    assert_eq!(
+      newer.message,
+      "kubelet v1.30.1 differs from control plane v1.29.0 (unsupported)"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_utilisation_thresholds() {
+    let node = build_node(vec![], false, Some(("950m", "100Mi"))).await;
+
+    let findings = check_utilisation(&node, &TroubleshootConfig::default());
+    assert_eq!(reasons(&findings), vec![(Severity::Warn, "High CPU usage")]);
+    assert_eq!(
+      findings[0].message,
+      "CPU usage at 95% of allocatable (threshold 90%)"
+    );
+
+    let off = TroubleshootConfig {
+      node_cpu_threshold: 0,
+      node_mem_threshold: 5,
+      ..TroubleshootConfig::default()
+    };
+    assert_eq!(
+      reasons(&check_utilisation(&node, &off)),
+      vec![(Severity::Warn, "High memory usage")]
+    );
+
+    let no_metrics = build_node(vec![], false, None).await;
+    assert!(check_utilisation(&no_metrics, &TroubleshootConfig::default()).is_empty());
+  }
+}
@@ -1,9 +1,17 @@
-//! Pod troubleshoot checks for unhealthy phases.
+//! Pod troubleshoot checks for unhealthy phases and failing containers.
 //! Ref: <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.35/#podstatus-v1-core>
 
-use k8s_openapi::api::core::v1::PodCondition;
+use chrono::{DateTime, TimeDelta, Utc};
+use k8s_openapi::api::core::v1::{ContainerStatus, PodCondition};
 
-use crate::app::{models::KubeResource, pods::KubePod};
+use crate::{
+  app::{
+    models::KubeResource,
+    pods::KubePod,
+    utils::{duration_to_age, time_to_chrono},
+  },
+  config::TroubleshootConfig,
+};
 
 use super::{DisplayFinding, ResourceKind, Severity};
 
@@ -74,19 +82,158 @@ fn check_phase(pod: &KubePod) -> Option<DisplayFinding> {
   Some(finding(pod, severity, reason, message))
 }
 
+/// Waiting reasons that keep a container from running until someone acts.
+/// Ref: <https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle/#container-state-waiting>
+const FAILED_WAITING_REASONS: [&str; 4] = [
+  "CrashLoopBackOff",
+  "ImagePullBackOff",
+  "ErrImagePull",
+  "CreateContainerConfigError",
+];
+
+/// Init containers first, labelled so findings say which kind failed.
+fn container_statuses(pod: &KubePod) -> impl Iterator<Item = (&'static str, &ContainerStatus)> {
+  let status = pod.get_k8s_obj().status.as_ref();
+  let init = status
+    .and_then(|s| s.init_container_statuses.as_deref())
+    .unwrap_or_default()
+    .iter()
+    .map(|c| ("init container", c));
+  let main = status
+    .and_then(|s| s.container_statuses.as_deref())
+    .unwrap_or_default()
+    .iter()
+    .map(|c| ("container", c));
+  init.chain(main)
+}
+
+/// Flag containers stuck waiting on a crash loop, an image pull or bad config.
+fn check_waiting(pod: &KubePod, label: &str, status: &ContainerStatus) -> Option<DisplayFinding> {
+  let waiting = status.state.as_ref()?.waiting.as_ref()?;
+  let reason = waiting.reason.as_deref()?;
+  if !FAILED_WAITING_REASONS.contains(&reason) {
+    return None;
+  }
+  let message = match waiting.message.as_deref().filter(|m| !m.is_empty()) {
+    Some(detail) => format!("{} {}: {}", label, status.name, detail),
+    None => format!("{} {} is in {}", label, status.name, reason),
+  };
+  Some(finding(pod, Severity::Error, reason.into(), message))
+}
+
+/// Flag a previous instance that was OOM-killed or exited with an error.
+/// `lastState` stays set until the next termination, so once the container is
+/// running and ready again a termination older than `window` is only `Info`.
+fn check_last_terminated(
+  pod: &KubePod,
+  label: &str,
+  status: &ContainerStatus,
+  now: DateTime<Utc>,
+  window: TimeDelta,
+) -> Option<DisplayFinding> {
+  let terminated = status.last_state.as_ref()?.terminated.as_ref()?;
+  let (severity, reason, message) = match terminated.reason.as_deref() {
+    Some("OOMKilled") => (
+      Severity::Error,
+      "OOMKilled",
+      format!(
+        "{} {} was killed for running out of memory (exit {})",
+        label, status.name, terminated.exit_code
+      ),
+    ),
+    Some("Error") => (
+      Severity::Warn,
+      "Error",
+      format!(
+        "{} {} last exited with code {}",
+        label, status.name, terminated.exit_code
+      ),
+    ),
+    _ => return None,
+  };
+
+  let running = status.ready && status.state.as_ref().is_some_and(|s| s.running.is_some());
+  let since = terminated
+    .finished_at
+    .as_ref()
+    .map(|finished| now - time_to_chrono(finished));
+  if !running || since.is_some_and(|since| since <= window) {
+    return Some(finding(pod, severity, reason.into(), message));
+  }
+  let message = match since {
+    Some(since) => format!("{}, {} ago", message, duration_to_age(since, false)),
+    None => message,
+  };
+  Some(finding(pod, Severity::Info, reason.into(), message))
+}
+
+/// Flag containers restarted more than `threshold` times; `0` skips the check.
+fn check_restarts(
+  pod: &KubePod,
+  label: &str,
+  status: &ContainerStatus,
+  threshold: i32,
+) -> Option<DisplayFinding> {
+  if threshold <= 0 || status.restart_count <= threshold {
+    return None;
+  }
+  Some(finding(
+    pod,
+    Severity::Warn,
+    "HighRestartCount".into(),
+    format!(
+      "{} {} restarted {} times",
+      label, status.name, status.restart_count
+    ),
+  ))
+}
+
+/// Run the container checks over every init and app container.
+fn check_containers(
+  pod: &KubePod,
+  config: &TroubleshootConfig,
+  now: DateTime<Utc>,
+) -> Vec<DisplayFinding> {
+  let window = TimeDelta::seconds(config.termination_window_secs.into());
+  container_statuses(pod)
+    .flat_map(|(label, status)| {
+      [
+        check_waiting(pod, label, status),
+        check_last_terminated(pod, label, status, now, window),
+        check_restarts(pod, label, status, config.restart_threshold),
+      ]
+    })
+    .flatten()
+    .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Evaluation entry point
 // ---------------------------------------------------------------------------
 
-/// Run all pod checks and collect findings.
-pub fn evaluate(items: &[KubePod]) -> Vec<DisplayFinding> {
-  items.iter().filter_map(check_phase).collect()
+/// Run all pod checks as of `now` and collect findings.
+pub fn evaluate(
+  items: &[KubePod],
+  config: &TroubleshootConfig,
+  now: DateTime<Utc>,
+) -> Vec<DisplayFinding> {
+  items
+    .iter()
+    .flat_map(|pod| {
+      check_phase(pod)
+        .into_iter()
+        .chain(check_containers(pod, config, now))
+    })
+    .collect()
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use k8s_openapi::api::core::v1::{Pod, PodCondition, PodStatus};
+  use k8s_openapi::api::core::v1::{
+    ContainerState, ContainerStateRunning, ContainerStateTerminated, ContainerStateWaiting, Pod,
+    PodCondition, PodStatus,
+  };
   use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 
   use crate::app::test_utils::get_time;
@@ -114,6 +261,200 @@ mod tests {
     KubePod::from(pod)
   }
 
+  fn build_pod_with_statuses(init: Vec<ContainerStatus>, main: Vec<ContainerStatus>) -> KubePod {
+    let mut pod = build_pod(Some("Running"), vec![]).get_k8s_obj().clone();
+    let status = pod.status.as_mut().unwrap();
+    status.init_container_statuses = Some(init);
+    status.container_statuses = Some(main);
+    KubePod::from(pod)
+  }
+
+  fn waiting(name: &str, reason: &str, message: Option<&str>) -> ContainerStatus {
+    ContainerStatus {
+      name: name.into(),
+      state: Some(ContainerState {
+        waiting: Some(ContainerStateWaiting {
+          reason: Some(reason.into()),
+          message: message.map(str::to_string),
+        }),
+        ..Default::default()
+      }),
+      ..Default::default()
+    }
+  }
+
+  fn last_terminated(name: &str, reason: &str, exit_code: i32, restarts: i32) -> ContainerStatus {
+    ContainerStatus {
+      name: name.into(),
+      restart_count: restarts,
+      last_state: Some(ContainerState {
+        terminated: Some(ContainerStateTerminated {
+          reason: Some(reason.into()),
+          exit_code,
+          ..Default::default()
+        }),
+        ..Default::default()
+      }),
+      ..Default::default()
+    }
+  }
+
+  fn now() -> DateTime<Utc> {
+    time_to_chrono(&get_time("2026-03-01T12:00:00Z"))
+  }
+
+  fn summary(findings: &[DisplayFinding]) -> Vec<(Severity, &str, &str)> {
+    findings
+      .iter()
+      .map(|f| (f.severity, f.reason.as_str(), f.message.as_str()))
+      .collect()
+  }
+
+  #[test]
+  fn test_running_pod_in_crash_loop_gets_container_findings() {
+    let mut crashing = last_terminated("app", "OOMKilled", 137, 7);
+    crashing.state = waiting("app", "CrashLoopBackOff", Some("back-off 5m0s")).state;
+    let pod = build_pod_with_statuses(vec![], vec![crashing]);
+
+    let findings = evaluate(&[pod], &TroubleshootConfig::default(), now());
+
+    assert_eq!(
+      summary(&findings),
+      vec![
+        (
+          Severity::Error,
+          "CrashLoopBackOff",
+          "container app: back-off 5m0s"
+        ),
+        (
+          Severity::Error,
+          "OOMKilled",
+          "container app was killed for running out of memory (exit 137)"
+        ),
+        (
+          Severity::Warn,
+          "HighRestartCount",
+          "container app restarted 7 times"
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_init_container_failures_are_labelled() {
+    let pod = build_pod_with_statuses(
+      vec![
+        waiting("migrate", "ImagePullBackOff", None),
+        last_terminated("setup", "Error", 2, 1),
+      ],
+      vec![
+        waiting("app", "ContainerCreating", None),
+        waiting(
+          "sidecar",
+          "CreateContainerConfigError",
+          Some("secret \"db\" not found"),
+        ),
+      ],
+    );
+
+    let findings = evaluate(&[pod], &TroubleshootConfig::default(), now());
+
+    assert_eq!(
+      summary(&findings),
+      vec![
+        (
+          Severity::Error,
+          "ImagePullBackOff",
+          "init container migrate is in ImagePullBackOff"
+        ),
+        (
+          Severity::Warn,
+          "Error",
+          "init container setup last exited with code 2"
+        ),
+        (
+          Severity::Error,
+          "CreateContainerConfigError",
+          "container sidecar: secret \"db\" not found"
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_old_termination_of_a_running_ready_container_is_info() {
+    let terminated_at = |finished: &str| {
+      let mut status = last_terminated("app", "OOMKilled", 137, 1);
+      status.ready = true;
+      status.state = Some(ContainerState {
+        running: Some(ContainerStateRunning::default()),
+        ..Default::default()
+      });
+      let last = status
+        .last_state
+        .as_mut()
+        .unwrap()
+        .terminated
+        .as_mut()
+        .unwrap();
+      last.finished_at = Some(get_time(finished));
+      build_pod_with_statuses(vec![], vec![status])
+    };
+
+    let recent = evaluate(
+      &[terminated_at("2026-03-01T11:30:00Z")],
+      &TroubleshootConfig::default(),
+      now(),
+    );
+    assert_eq!(
+      summary(&recent),
+      vec![(
+        Severity::Error,
+        "OOMKilled",
+        "container app was killed for running out of memory (exit 137)"
+      )]
+    );
+
+    let old = evaluate(
+      &[terminated_at("2026-02-20T12:00:00Z")],
+      &TroubleshootConfig::default(),
+      now(),
+    );
+    assert_eq!(
+      summary(&old),
+      vec![(
+        Severity::Info,
+        "OOMKilled",
+        "container app was killed for running out of memory (exit 137), 1w2d ago"
+      )]
+    );
+  }
+
+  #[test]
+  fn test_restart_threshold_is_configurable() {
+    let pod = build_pod_with_statuses(vec![], vec![last_terminated("app", "Completed", 0, 3)]);
+
+    let strict = TroubleshootConfig {
+      restart_threshold: 2,
+      ..TroubleshootConfig::default()
+    };
+    assert_eq!(
+      evaluate(std::slice::from_ref(&pod), &strict, now()).len(),
+      1
+    );
+    assert!(evaluate(
+      std::slice::from_ref(&pod),
+      &TroubleshootConfig::default(),
+      now()
+    )
+    .is_empty());
+    let off = TroubleshootConfig {
+      restart_threshold: 0,
+      ..TroubleshootConfig::default()
+    };
+    assert!(evaluate(&[pod], &off, now()).is_empty());
+  }
+
   #[test]
   fn test_pod_phase_fallback_and_value() {
     let pod_unknown = build_pod(None, vec![]);
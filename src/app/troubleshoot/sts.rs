@@ -0,0 +1,351 @@
+//! StatefulSet troubleshoot checks for unavailable replicas and updates that
+//! do not finish.
+//!
+//! A StatefulSet reports no time for when an update started, so
+//! [`RevisionTracker`] works it out: the oldest pod already on the new
+//! revision, or else when the pending update was first seen.
+//!
+//! References:
+//! - <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.35/#statefulsetstatus-v1-apps>
+//! - <https://kubernetes.io/docs/concepts/workloads/controllers/statefulset/#update-strategies>
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::app::{
+  models::KubeResource,
+  pods::KubePod,
+  statefulsets::KubeStatefulSet,
+  utils::{duration_to_age, time_to_chrono},
+};
+
+use super::{DisplayFinding, ResourceKind, Severity};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn finding(
+  sts: &KubeStatefulSet,
+  severity: Severity,
+  reason: String,
+  message: String,
+) -> DisplayFinding {
+  DisplayFinding {
+    severity,
+    reason,
+    resource_kind: ResourceKind::StatefulSet,
+    namespace: Some(sts.namespace.clone()),
+    resource_name: sts.name.clone(),
+    message,
+    age: sts.age.clone(),
+  }
+}
+
+fn key(sts: &KubeStatefulSet) -> String {
+  format!("{}/{}", sts.namespace, sts.name)
+}
+
+/// The revision pods are being moved to, while it differs from the current one.
+fn pending_revision(sts: &KubeStatefulSet) -> Option<&str> {
+  let status = sts.get_k8s_obj().status.as_ref()?;
+  let update = status.update_revision.as_deref()?;
+  (status.current_revision.as_deref() != Some(update)).then_some(update)
+}
+
+/// Creation time of the oldest pod of `sts` already on `revision`.
+fn oldest_updated_pod(
+  sts: &KubeStatefulSet,
+  revision: &str,
+  pods: &[KubePod],
+) -> Option<DateTime<Utc>> {
+  pods
+    .iter()
+    .map(|pod| &pod.get_k8s_obj().metadata)
+    .filter(|meta| {
+      meta.namespace.as_deref() == Some(sts.namespace.as_str())
+        && meta
+          .labels
+          .as_ref()
+          .and_then(|labels| labels.get("controller-revision-hash"))
+          .is_some_and(|hash| hash == revision)
+        && meta.owner_references.as_ref().is_some_and(|owners| {
+          owners
+            .iter()
+            .any(|owner| owner.kind == "StatefulSet" && owner.name == sts.name)
+        })
+    })
+    .filter_map(|meta| meta.creation_timestamp.as_ref().map(time_to_chrono))
+    .min()
+}
+
+/// When each StatefulSet's pending update started, keyed by `namespace/name`,
+/// so the revision check can tell a rollout in progress from a stuck one.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RevisionTracker {
+  pending: HashMap<String, (String, DateTime<Utc>)>,
+  observed_at: Option<DateTime<Utc>>,
+}
+
+impl RevisionTracker {
+  /// Record the pending updates in `items` as of `now`. An update keeps the
+  /// start it was first given; finished ones are forgotten.
+  pub fn observe(&mut self, items: &[KubeStatefulSet], pods: &[KubePod], now: DateTime<Utc>) {
+    let mut pending = HashMap::new();
+    for sts in items {
+      let Some(revision) = pending_revision(sts) else {
+        continue;
+      };
+      let key = key(sts);
+      let started = match self.pending.remove(&key) {
+        Some((seen, started)) if seen == revision => started,
+        _ => oldest_updated_pod(sts, revision, pods).map_or(now, |created| created.min(now)),
+      };
+      pending.insert(key, (revision.to_owned(), started));
+    }
+    self.pending = pending;
+    self.observed_at = Some(now);
+  }
+
+  /// How long the pending update of `sts` has been running, if it has one.
+  fn pending_for(&self, sts: &KubeStatefulSet) -> Option<TimeDelta> {
+    let (_, started) = self.pending.get(&key(sts))?;
+    Some(self.observed_at? - *started)
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Individual STS checks
+// ---------------------------------------------------------------------------
+
+/// Flag replicas the StatefulSet wants but cannot make available.
+fn check_unavailable(sts: &KubeStatefulSet) -> Option<DisplayFinding> {
+  let obj = sts.get_k8s_obj();
+  let status = obj.status.as_ref()?;
+  let desired = obj.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+  let available = status
+    .available_replicas
+    .or(status.ready_replicas)
+    .unwrap_or_default();
+  if available >= desired {
+    return None;
+  }
+
+  Some(finding(
+    sts,
+    Severity::Warn,
+    "Unavailable replicas".into(),
+    format!(
+      "{} of {} replicas unavailable",
+      desired - available,
+      desired
+    ),
+  ))
+}
+
+/// Flag an update that has not reached every pod within `threshold`. With the
+/// `OnDelete` strategy pods only update once deleted, so that is just noted.
+fn check_revision(
+  sts: &KubeStatefulSet,
+  tracker: &RevisionTracker,
+  threshold: TimeDelta,
+) -> Option<DisplayFinding> {
+  let revision = pending_revision(sts)?;
+  let pending_for = tracker.pending_for(sts)?;
+  if pending_for <= threshold {
+    return None;
+  }
+  let obj = sts.get_k8s_obj();
+  let updated = obj
+    .status
+    .as_ref()
+    .and_then(|s| s.updated_replicas)
+    .unwrap_or_default();
+  let desired = obj.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+  let on_delete = obj
+    .spec
+    .as_ref()
+    .and_then(|s| s.update_strategy.as_ref())
+    .and_then(|s| s.type_.as_deref())
+    == Some("OnDelete");
+
+  let message = format!(
+    "update to {} not finished after {} ({} of {} replicas updated)",
+    revision,
+    duration_to_age(pending_for, false),
+    updated,
+    desired
+  );
+  Some(if on_delete {
+    finding(
+      sts,
+      Severity::Info,
+      "Update pending".into(),
+      format!("{}; OnDelete strategy: delete pods to update them", message),
+    )
+  } else {
+    finding(sts, Severity::Warn, "Update stuck".into(), message)
+  })
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation entry point
+// ---------------------------------------------------------------------------
+
+/// Run all STS checks and collect findings. Updates pending for longer than
+/// `threshold` are flagged.
+pub fn evaluate(
+  items: &[KubeStatefulSet],
+  tracker: &RevisionTracker,
+  threshold: TimeDelta,
+) -> Vec<DisplayFinding> {
+  items
+    .iter()
+    .flat_map(|sts| {
+      [
+        check_unavailable(sts),
+        check_revision(sts, tracker, threshold),
+      ]
+    })
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use super::*;
+  use k8s_openapi::api::apps::v1::{
+    StatefulSet, StatefulSetSpec, StatefulSetStatus, StatefulSetUpdateStrategy,
+  };
+  use k8s_openapi::api::core::v1::Pod;
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+
+  use crate::app::test_utils::get_time;
+
+  fn build_sts(current: &str, update: &str, strategy: Option<&str>) -> KubeStatefulSet {
+    KubeStatefulSet::from(StatefulSet {
+      metadata: ObjectMeta {
+        name: Some("web".into()),
+        namespace: Some("ns-1".into()),
+        ..Default::default()
+      },
+      spec: Some(StatefulSetSpec {
+        replicas: Some(3),
+        update_strategy: strategy.map(|s| StatefulSetUpdateStrategy {
+          type_: Some(s.into()),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }),
+      status: Some(StatefulSetStatus {
+        replicas: 3,
+        available_replicas: Some(3),
+        updated_replicas: Some(1),
+        current_revision: Some(current.into()),
+        update_revision: Some(update.into()),
+        ..Default::default()
+      }),
+    })
+  }
+
+  fn build_pod(revision: &str, created: &str) -> KubePod {
+    KubePod::from(Pod {
+      metadata: ObjectMeta {
+        name: Some("web-2".into()),
+        namespace: Some("ns-1".into()),
+        creation_timestamp: Some(get_time(created)),
+        labels: Some(BTreeMap::from([(
+          "controller-revision-hash".to_string(),
+          revision.to_string(),
+        )])),
+        owner_references: Some(vec![OwnerReference {
+          kind: "StatefulSet".into(),
+          name: "web".into(),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      },
+      ..Default::default()
+    })
+  }
+
+  fn at(time: &str) -> DateTime<Utc> {
+    time_to_chrono(&get_time(time))
+  }
+
+  #[test]
+  fn test_revision_flagged_once_pending_past_threshold() {
+    let sts = build_sts("web-1", "web-2", None);
+    let threshold = TimeDelta::minutes(10);
+    let mut tracker = RevisionTracker::default();
+
+    tracker.observe(std::slice::from_ref(&sts), &[], at("2026-01-01T00:00:00Z"));
+    assert!(check_revision(&sts, &tracker, threshold).is_none());
+
+    tracker.observe(std::slice::from_ref(&sts), &[], at("2026-01-01T00:15:00Z"));
+    let finding = check_revision(&sts, &tracker, threshold).expect("stuck update is flagged");
+    assert_eq!(finding.severity, Severity::Warn);
+    assert_eq!(
+      finding.message,
+      "update to web-2 not finished after 15m (1 of 3 replicas updated)"
+    );
+  }
+
+  #[test]
+  fn test_revision_start_taken_from_oldest_updated_pod() {
+    let sts = build_sts("web-1", "web-2", None);
+    let pods = [
+      build_pod("web-2", "2026-01-01T00:00:00Z"),
+      build_pod("web-1", "2025-12-01T00:00:00Z"),
+    ];
+    let mut tracker = RevisionTracker::default();
+
+    tracker.observe(
+      std::slice::from_ref(&sts),
+      &pods,
+      at("2026-01-01T01:00:00Z"),
+    );
+
+    assert_eq!(tracker.pending_for(&sts), Some(TimeDelta::hours(1)));
+  }
+
+  #[test]
+  fn test_finished_update_is_forgotten() {
+    let mut tracker = RevisionTracker::default();
+    tracker.observe(
+      &[build_sts("web-1", "web-2", None)],
+      &[],
+      at("2026-01-01T00:00:00Z"),
+    );
+
+    let done = build_sts("web-2", "web-2", None);
+    tracker.observe(std::slice::from_ref(&done), &[], at("2026-01-01T01:00:00Z"));
+
+    assert!(tracker.pending.is_empty());
+    assert!(check_revision(&done, &tracker, TimeDelta::zero()).is_none());
+  }
+
+  #[test]
+  fn test_on_delete_update_is_only_noted() {
+    let sts = build_sts("web-1", "web-2", Some("OnDelete"));
+    let mut tracker = RevisionTracker::default();
+    tracker.observe(std::slice::from_ref(&sts), &[], at("2026-01-01T00:00:00Z"));
+    tracker.observe(std::slice::from_ref(&sts), &[], at("2026-01-02T00:00:00Z"));
+
+    let finding = check_revision(&sts, &tracker, TimeDelta::minutes(10)).unwrap();
+    assert_eq!(finding.severity, Severity::Info);
+  }
+
+  #[test]
+  fn test_check_unavailable() {
+    let mut sts = build_sts("web-1", "web-1", None).get_k8s_obj().clone();
+    sts.status.as_mut().unwrap().available_replicas = Some(1);
+    let finding = check_unavailable(&KubeStatefulSet::from(sts)).unwrap();
+    assert_eq!(finding.message, "2 of 3 replicas unavailable");
+
+    assert!(check_unavailable(&build_sts("web-1", "web-1", None)).is_none());
+  }
+}
@@ -0,0 +1,276 @@
+//! Service troubleshoot checks for selectors that match no pods and Services
+//! with no ready endpoints.
+//!
+//! Endpoints are not kept in a table, so [`ServiceRefs`] carries what the
+//! Service and Ingress checks need from lists fetched for troubleshoot.
+//!
+//! References:
+//! - <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.35/#servicespec-v1-core>
+//! - <https://kubernetes.io/docs/concepts/services-networking/endpoint-slices/>
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use k8s_openapi::api::{core::v1::Endpoints, discovery::v1::EndpointSlice};
+
+use crate::app::{models::KubeResource, pods::KubePod, svcs::KubeSvc};
+
+use super::{DisplayFinding, ResourceKind, Severity};
+
+/// Label linking an EndpointSlice to its Service.
+const SERVICE_NAME_LABEL: &str = "kubernetes.io/service-name";
+
+/// What the Service and Ingress checks need beyond the resource tables. A
+/// `None` list could not be fetched, so the checks that need it are skipped
+/// instead of reporting everything missing.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServiceRefs {
+  /// Ready endpoints per `namespace/service`.
+  pub ready_endpoints: Option<HashMap<String, usize>>,
+  /// `namespace/name` of every Secret.
+  pub secrets: Option<HashSet<String>>,
+}
+
+impl ServiceRefs {
+  /// Count ready endpoints per Service from its EndpointSlices, falling back
+  /// to the legacy Endpoints object for Services that have no slice.
+  pub fn ready_endpoints(
+    slices: &[EndpointSlice],
+    endpoints: &[Endpoints],
+  ) -> HashMap<String, usize> {
+    let mut ready = HashMap::new();
+    for slice in slices {
+      let Some(service) = slice
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(SERVICE_NAME_LABEL))
+      else {
+        continue;
+      };
+      // An unset `ready` means unknown, which consumers treat as ready.
+      let count = slice
+        .endpoints
+        .iter()
+        .filter(|e| e.conditions.as_ref().and_then(|c| c.ready) != Some(false))
+        .count();
+      let ns = slice.metadata.namespace.as_deref().unwrap_or_default();
+      *ready.entry(format!("{}/{}", ns, service)).or_default() += count;
+    }
+    for ep in endpoints {
+      let key = format!(
+        "{}/{}",
+        ep.metadata.namespace.as_deref().unwrap_or_default(),
+        ep.metadata.name.as_deref().unwrap_or_default()
+      );
+      let count = ep
+        .subsets
+        .iter()
+        .flatten()
+        .map(|s| s.addresses.as_ref().map_or(0, Vec::len))
+        .sum();
+      ready.entry(key).or_insert(count);
+    }
+    ready
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn finding(svc: &KubeSvc, severity: Severity, reason: String, message: String) -> DisplayFinding {
+  DisplayFinding {
+    severity,
+    reason,
+    resource_kind: ResourceKind::Service,
+    namespace: Some(svc.namespace.clone()),
+    resource_name: svc.name.clone(),
+    message,
+    age: svc.age.clone(),
+  }
+}
+
+/// The pod selector, unless the Service has none (its endpoints are managed
+/// by hand) or is an `ExternalName` alias.
+fn selector(svc: &KubeSvc) -> Option<&BTreeMap<String, String>> {
+  let spec = svc.get_k8s_obj().spec.as_ref()?;
+  if spec.type_.as_deref() == Some("ExternalName") {
+    return None;
+  }
+  spec.selector.as_ref().filter(|s| !s.is_empty())
+}
+
+fn matches_selector(pod: &KubePod, svc: &KubeSvc, selector: &BTreeMap<String, String>) -> bool {
+  let meta = &pod.get_k8s_obj().metadata;
+  pod.namespace == svc.namespace
+    && meta.labels.as_ref().is_some_and(|labels| {
+      selector
+        .iter()
+        .all(|(key, value)| labels.get(key) == Some(value))
+    })
+}
+
+fn format_selector(selector: &BTreeMap<String, String>) -> String {
+  selector
+    .iter()
+    .map(|(key, value)| format!("{}={}", key, value))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+// ---------------------------------------------------------------------------
+// Individual Service checks
+// ---------------------------------------------------------------------------
+
+/// Flag a selector no pod carries, then a Service whose pods are not ready.
+/// Only `pods` (the namespaces in view) are matched.
+fn check_endpoints(svc: &KubeSvc, pods: &[KubePod], refs: &ServiceRefs) -> Option<DisplayFinding> {
+  let selector = selector(svc)?;
+  if !pods.iter().any(|pod| matches_selector(pod, svc, selector)) {
+    return Some(finding(
+      svc,
+      Severity::Error,
+      "No matching pods".into(),
+      format!("selector {} matches no pods", format_selector(selector)),
+    ));
+  }
+  let key = format!("{}/{}", svc.namespace, svc.name);
+  let ready = *refs.ready_endpoints.as_ref()?.get(&key)?;
+  if ready > 0 {
+    return None;
+  }
+
+  Some(finding(
+    svc,
+    Severity::Error,
+    "No ready endpoints".into(),
+    "pods match the selector but none are ready".into(),
+  ))
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation entry point
+// ---------------------------------------------------------------------------
+
+/// Run all Service checks and collect findings.
+pub fn evaluate(items: &[KubeSvc], pods: &[KubePod], refs: &ServiceRefs) -> Vec<DisplayFinding> {
+  items
+    .iter()
+    .filter_map(|svc| check_endpoints(svc, pods, refs))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use k8s_openapi::api::core::v1::{EndpointAddress, EndpointSubset, Pod, Service, ServiceSpec};
+  use k8s_openapi::api::discovery::v1::{Endpoint, EndpointConditions};
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+  fn labels(pairs: &[(&str, &str)]) -> Option<BTreeMap<String, String>> {
+    Some(
+      pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect(),
+    )
+  }
+
+  fn meta(name: &str, labels: Option<BTreeMap<String, String>>) -> ObjectMeta {
+    ObjectMeta {
+      name: Some(name.into()),
+      namespace: Some("ns-1".into()),
+      labels,
+      ..Default::default()
+    }
+  }
+
+  fn build_svc(selector: &[(&str, &str)]) -> KubeSvc {
+    KubeSvc::from(Service {
+      metadata: meta("api", None),
+      spec: Some(ServiceSpec {
+        selector: labels(selector),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+  }
+
+  fn build_pod(pod_labels: &[(&str, &str)]) -> KubePod {
+    KubePod::from(Pod {
+      metadata: meta("api-0", labels(pod_labels)),
+      ..Default::default()
+    })
+  }
+
+  fn slice(ready: &[Option<bool>]) -> EndpointSlice {
+    EndpointSlice {
+      metadata: meta("api-abc", labels(&[(SERVICE_NAME_LABEL, "api")])),
+      endpoints: ready
+        .iter()
+        .map(|ready| Endpoint {
+          conditions: Some(EndpointConditions {
+            ready: *ready,
+            ..Default::default()
+          }),
+          ..Default::default()
+        })
+        .collect(),
+      ..Default::default()
+    }
+  }
+
+  fn refs(ready: usize) -> ServiceRefs {
+    ServiceRefs {
+      ready_endpoints: Some(HashMap::from([("ns-1/api".to_string(), ready)])),
+      secrets: None,
+    }
+  }
+
+  #[test]
+  fn test_ready_endpoints_prefers_slices_over_endpoints() {
+    let legacy = |name: &str, addresses: usize| Endpoints {
+      metadata: meta(name, None),
+      subsets: Some(vec![EndpointSubset {
+        addresses: Some(vec![EndpointAddress::default(); addresses]),
+        ..Default::default()
+      }]),
+    };
+
+    let ready = ServiceRefs::ready_endpoints(
+      &[slice(&[Some(true), None, Some(false)])],
+      &[legacy("api", 5), legacy("db", 1)],
+    );
+
+    assert_eq!(ready.get("ns-1/api"), Some(&2));
+    assert_eq!(ready.get("ns-1/db"), Some(&1));
+  }
+
+  #[test]
+  fn test_selector_matching_no_pods_is_flagged_first() {
+    let svc = build_svc(&[("app", "api")]);
+    let other = build_pod(&[("app", "web")]);
+
+    let finding = check_endpoints(&svc, &[other], &refs(0)).unwrap();
+    assert_eq!(finding.reason, "No matching pods");
+    assert_eq!(finding.message, "selector app=api matches no pods");
+  }
+
+  #[test]
+  fn test_matching_pods_without_ready_endpoints() {
+    let svc = build_svc(&[("app", "api")]);
+    let pod = build_pod(&[("app", "api"), ("tier", "backend")]);
+
+    let finding = check_endpoints(&svc, std::slice::from_ref(&pod), &refs(0)).unwrap();
+    assert_eq!(finding.reason, "No ready endpoints");
+    assert!(check_endpoints(&svc, std::slice::from_ref(&pod), &refs(2)).is_none());
+    // Unknown endpoints are not reported as missing.
+    assert!(check_endpoints(&svc, &[pod], &ServiceRefs::default()).is_none());
+  }
+
+  #[test]
+  fn test_selectorless_service_is_skipped() {
+    let svc = build_svc(&[]);
+    assert!(check_endpoints(&svc, &[], &refs(0)).is_none());
+  }
+}
@@ -4,7 +4,7 @@ use std::cmp::Ordering;
 
 use strum::{Display, EnumIter};
 
-use crate::app::models::Named;
+use crate::app::models::{Named, SortValue, Sortable};
 
 /// Severity-tagged finding.
 ///
@@ -57,6 +57,22 @@ pub enum ResourceKind {
   Pvc,
   #[strum(serialize = "rs")]
   ReplicaSet,
+  #[strum(serialize = "deploy")]
+  Deployment,
+  #[strum(serialize = "sts")]
+  StatefulSet,
+  #[strum(serialize = "ds")]
+  DaemonSet,
+  #[strum(serialize = "node")]
+  Node,
+  #[strum(serialize = "svc")]
+  Service,
+  #[strum(serialize = "ing")]
+  Ingress,
+  #[strum(serialize = "job")]
+  Job,
+  #[strum(serialize = "cj")]
+  CronJob,
 }
 
 // ---------------------------------------------------------------------------
@@ -97,3 +113,26 @@ impl Named for DisplayFinding {
     &self.resource_name
   }
 }
+
+impl Sortable for DisplayFinding {
+  fn sort_value(&self, column: &str) -> SortValue {
+    match column {
+      "Severity" => SortValue::number(self.severity.rank()),
+      "Type" => SortValue::text(&self.resource_kind.to_string()),
+      "Reason" => SortValue::text(&self.reason),
+      "Resource" => SortValue::text(&self.resource_ref()),
+      "Message" => SortValue::text(&self.message),
+      "Age" => SortValue::age(&self.age),
+      _ => SortValue::Missing,
+    }
+  }
+
+  fn sort_id(&self) -> String {
+    format!(
+      "{}/{}/{}",
+      self.resource_kind,
+      self.resource_ref(),
+      self.reason
+    )
+  }
+}
@@ -10,7 +10,7 @@ pub fn sanitize_obj<K: Resource>(mut obj: K) -> K {
 pub static UNKNOWN: &str = "Unknown";
 
 /// Convert a k8s-openapi `Time` (jiff::Timestamp) to a chrono `DateTime<Utc>`.
-fn time_to_chrono(time: &Time) -> DateTime<Utc> {
+pub(crate) fn time_to_chrono(time: &Time) -> DateTime<Utc> {
   DateTime::from_timestamp(time.0.as_second(), time.0.subsec_nanosecond() as u32)
     .unwrap_or_default()
 }
@@ -119,6 +119,112 @@ pub fn convert_to_f64(s: &str) -> f64 {
   s.parse().unwrap_or(0f64)
 }
 
+/// Parse an age produced by [`duration_to_age`] (e.g. `2w3d`, `5h12m`, `45s`)
+/// back into seconds.
+pub fn age_to_secs(age: &str) -> Option<i64> {
+  let age = age.trim();
+  if age.is_empty() {
+    return None;
+  }
+  let mut total = 0i64;
+  let mut digits = String::new();
+  for c in age.chars() {
+    if c.is_ascii_digit() || c == '-' {
+      digits.push(c);
+      continue;
+    }
+    let unit = match c {
+      'w' => 7 * 24 * 3600,
+      'd' => 24 * 3600,
+      'h' => 3600,
+      'm' => 60,
+      's' => 1,
+      _ => return None,
+    };
+    total += digits.parse::<i64>().ok()? * unit;
+    digits.clear();
+  }
+  digits.is_empty().then_some(total)
+}
+
+/// Parse a Kubernetes quantity (`250m`, `1.5Gi`, `2k`, `100`) into base units.
+/// A trailing `%` is ignored so percentage cells compare as plain numbers.
+pub fn quantity_to_f64(quantity: &str) -> Option<f64> {
+  let quantity = quantity.trim().trim_end_matches('%');
+  let split = quantity
+    .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+    .unwrap_or(quantity.len());
+  let (number, suffix) = quantity.split_at(split);
+  let (multiplier, divisor) = match suffix {
+    "n" => (1f64, 1e9),
+    "u" => (1f64, 1e6),
+    "m" => (1f64, 1e3),
+    _ => (decimal_or_binary_multiplier(suffix)?, 1f64),
+  };
+  number.parse::<f64>().ok().map(|n| n * multiplier / divisor)
+}
+
+fn decimal_or_binary_multiplier(suffix: &str) -> Option<f64> {
+  let multiplier = match suffix {
+    "" => 1f64,
+    "k" => 1e3,
+    "M" => 1e6,
+    "G" => 1e9,
+    "T" => 1e12,
+    "P" => 1e15,
+    "E" => 1e18,
+    "Ki" => 1024f64,
+    "Mi" => 1024f64.powi(2),
+    "Gi" => 1024f64.powi(3),
+    "Ti" => 1024f64.powi(4),
+    "Pi" => 1024f64.powi(5),
+    "Ei" => 1024f64.powi(6),
+    _ => return None,
+  };
+  Some(multiplier)
+}
+
+/// Compare strings case-insensitively, ordering runs of digits by their
+/// numeric value so `pod-2` sorts before `pod-10`.
+pub fn natural_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+  use std::cmp::Ordering;
+
+  let mut a = a.chars().peekable();
+  let mut b = b.chars().peekable();
+  loop {
+    match (a.peek().copied(), b.peek().copied()) {
+      (None, None) => return Ordering::Equal,
+      (None, Some(_)) => return Ordering::Less,
+      (Some(_), None) => return Ordering::Greater,
+      (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+        let take_run = |it: &mut std::iter::Peekable<std::str::Chars<'_>>| {
+          let mut run = String::new();
+          while let Some(c) = it.next_if(char::is_ascii_digit) {
+            run.push(c);
+          }
+          run.trim_start_matches('0').to_string()
+        };
+        let (run_a, run_b) = (take_run(&mut a), take_run(&mut b));
+        let ord = run_a
+          .len()
+          .cmp(&run_b.len())
+          .then_with(|| run_a.cmp(&run_b));
+        if ord != Ordering::Equal {
+          return ord;
+        }
+      }
+      (Some(x), Some(y)) => {
+        let ord = x.to_lowercase().cmp(y.to_lowercase());
+        if ord != Ordering::Equal {
+          return ord;
+        }
+        a.next();
+        b.next();
+      }
+    }
+  }
+}
+
 /// Extract a human-friendly type name from a full Rust type path.
 /// e.g. `kdash::app::pods::KubePod` → `Pod`, `kdash::app::ns::KubeNs` → `Namespace`
 pub fn friendly_type_name<T>() -> String {
@@ -272,6 +378,39 @@ mod tests {
     assert_eq!(cpu_to_milli(String::from("8")), String::from("8000m"));
     assert_eq!(cpu_to_milli(String::from("0")), String::from("0m"));
   }
+  #[test]
+  fn test_age_to_secs() {
+    use super::age_to_secs;
+    assert_eq!(age_to_secs("45s"), Some(45));
+    assert_eq!(age_to_secs("5h12m"), Some(5 * 3600 + 12 * 60));
+    assert_eq!(age_to_secs("2w3d"), Some(17 * 24 * 3600));
+    assert_eq!(age_to_secs("0m"), Some(0));
+    assert_eq!(age_to_secs(""), None);
+    assert_eq!(age_to_secs("<none>"), None);
+    assert_eq!(age_to_secs("12"), None);
+  }
+  #[test]
+  fn test_quantity_to_f64() {
+    use super::quantity_to_f64;
+    assert_eq!(quantity_to_f64("250m"), Some(0.25));
+    assert_eq!(quantity_to_f64("2"), Some(2.0));
+    assert_eq!(quantity_to_f64("1Gi"), Some(1024f64 * 1024f64 * 1024f64));
+    assert_eq!(quantity_to_f64("1.5k"), Some(1500.0));
+    assert_eq!(quantity_to_f64("37%"), Some(37.0));
+    assert_eq!(quantity_to_f64("lots"), None);
+    assert!(quantity_to_f64("512Mi") < quantity_to_f64("1Gi"));
+  }
+  #[test]
+  fn test_natural_cmp() {
+    use std::cmp::Ordering;
+
+    use super::natural_cmp;
+    assert_eq!(natural_cmp("pod-2", "pod-10"), Ordering::Less);
+    assert_eq!(natural_cmp("Web", "api"), Ordering::Greater);
+    assert_eq!(natural_cmp("web-007", "web-7"), Ordering::Equal);
+    assert_eq!(natural_cmp("2/3", "10/10"), Ordering::Less);
+    assert_eq!(natural_cmp("abc", "abcd"), Ordering::Less);
+  }
 
   fn chrono_to_jiff(dt: DateTime<Utc>) -> k8s_openapi::jiff::Timestamp {
     k8s_openapi::jiff::Timestamp::from_second(dt.timestamp()).unwrap()
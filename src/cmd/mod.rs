@@ -227,7 +227,8 @@ impl<'a> CmdRunner<'a> {
     app.data.clis = clis;
   }
 
-  // TODO temp solution, should build this from API response
+  /// `kubectl describe` fallback for kinds without a native renderer, or for
+  /// every kind when `describe_with_kubectl` is set.
   async fn get_describe(&self, kind: String, value: String, ns: Option<String>) {
     if !is_valid_kubectl_arg(&kind) || !is_valid_kubectl_arg(&value) {
       self
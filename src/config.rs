@@ -11,6 +11,21 @@ use log::warn;
 use regex::Regex;
 use serde::Deserialize;
 
+/// Default page size for paginated list calls.
+pub const DEFAULT_LIST_PAGE_SIZE: u32 = 500;
+/// Default number of lines the log view keeps.
+pub const DEFAULT_LOG_MAX_LINES: usize = 10_000;
+/// Default container restart count above which troubleshoot flags a container.
+pub const DEFAULT_RESTART_THRESHOLD: i32 = 5;
+/// Default seconds a StatefulSet update may take before troubleshoot flags it.
+pub const DEFAULT_ROLLOUT_THRESHOLD_SECS: u32 = 600;
+/// Default seconds a container's last termination stays an error or warning
+/// once it is running and ready again.
+pub const DEFAULT_TERMINATION_WINDOW_SECS: u32 = 3600;
+/// Default node CPU / memory use, in percent of allocatable, that troubleshoot
+/// flags.
+pub const DEFAULT_NODE_UTILISATION_THRESHOLD: u8 = 90;
+
 #[derive(Clone, Debug, Default, Deserialize, PartialEq)]
 #[serde(default)]
 pub struct KdashConfig {
@@ -25,11 +40,113 @@ pub struct KdashConfig {
   /// Full user-defined palette that joins the `t` / `Alt+t` theme cycle.
   pub custom_theme: Option<crate::ui::theme::CustomThemeConfig>,
   pub log_tail_lines: Option<u32>,
+  /// Lines the log view keeps before dropping the oldest. Defaults to
+  /// [`DEFAULT_LOG_MAX_LINES`]; `0` removes the line cap.
+  pub log_max_lines: Option<usize>,
+  /// Bytes of log text the log view keeps before dropping the oldest lines.
+  /// Uncapped by default.
+  pub log_max_bytes: Option<usize>,
+  /// Field names read from JSON / logfmt log lines.
+  pub log_fields: LogFieldNames,
+  /// Page size (`limit`) for list calls; large collections are fetched with
+  /// continue tokens. Defaults to [`DEFAULT_LIST_PAGE_SIZE`].
+  pub list_page_size: Option<u32>,
+  /// Stop listing a tab's resources after this many items. Capped kinds are
+  /// polled instead of watched. Unlimited by default.
+  pub max_items_per_tab: Option<usize>,
+  /// Thresholds for the troubleshoot view's checks.
+  pub troubleshoot: TroubleshootConfig,
   pub cli_info: Option<CliInfoConfig>,
+  /// Run `kubectl describe` instead of the built-in renderer. Kinds without a
+  /// native renderer always use kubectl.
+  pub describe_with_kubectl: bool,
+  /// Directory the capture action writes snapshots into; defaults to the
+  /// current directory.
+  pub snapshot_dir: Option<String>,
+  /// Replace Secret values in captured snapshots.
+  pub snapshot_redact_secrets: bool,
   pub hide_logo: bool,
   pub hide_info_on_start: bool,
 }
 
+impl KdashConfig {
+  /// Effective list page size; `0` falls back to the default.
+  pub fn list_page_size(&self) -> u32 {
+    self
+      .list_page_size
+      .filter(|size| *size > 0)
+      .unwrap_or(DEFAULT_LIST_PAGE_SIZE)
+  }
+
+  /// Effective log line cap; `None` when it was set to `0`.
+  pub fn log_max_lines(&self) -> Option<usize> {
+    match self.log_max_lines {
+      None => Some(DEFAULT_LOG_MAX_LINES),
+      Some(0) => None,
+      lines => lines,
+    }
+  }
+
+  /// Effective log byte cap; `0` counts as unset.
+  pub fn log_max_bytes(&self) -> Option<usize> {
+    self.log_max_bytes.filter(|bytes| *bytes > 0)
+  }
+}
+
+/// Candidate field names for the parts of a structured log line, tried in
+/// order; the first one present is used.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct LogFieldNames {
+  pub time: Vec<String>,
+  pub level: Vec<String>,
+  pub message: Vec<String>,
+}
+
+impl Default for LogFieldNames {
+  fn default() -> Self {
+    let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+    Self {
+      time: names(&["time", "ts", "timestamp", "@timestamp"]),
+      level: names(&["level", "lvl", "severity", "@level"]),
+      message: names(&["msg", "message", "@message"]),
+    }
+  }
+}
+
+/// Thresholds the troubleshoot checks flag resources at.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct TroubleshootConfig {
+  /// Flag containers restarted more than this many times; `0` turns the check
+  /// off.
+  pub restart_threshold: i32,
+  /// Flag StatefulSet updates that have not reached every pod after this many
+  /// seconds.
+  pub rollout_threshold_secs: u32,
+  /// Keep flagging a container's OOM kill or error exit for this many seconds
+  /// after it finished; past that, a running and ready container only gets
+  /// an info finding.
+  pub termination_window_secs: u32,
+  /// Flag nodes using more than this percentage of allocatable CPU; `0` turns
+  /// the check off. Needs metrics-server.
+  pub node_cpu_threshold: u8,
+  /// Same as `node_cpu_threshold`, for memory.
+  pub node_mem_threshold: u8,
+}
+
+impl Default for TroubleshootConfig {
+  fn default() -> Self {
+    Self {
+      restart_threshold: DEFAULT_RESTART_THRESHOLD,
+      rollout_threshold_secs: DEFAULT_ROLLOUT_THRESHOLD_SECS,
+      termination_window_secs: DEFAULT_TERMINATION_WINDOW_SECS,
+      node_cpu_threshold: DEFAULT_NODE_UTILISATION_THRESHOLD,
+      node_mem_threshold: DEFAULT_NODE_UTILISATION_THRESHOLD,
+    }
+  }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
 pub struct KeybindingOverrides {
   #[serde(flatten)]
@@ -377,6 +494,78 @@ mod tests {
     assert!(config.hide_info_on_start);
   }
 
+  #[test]
+  fn test_describe_with_kubectl_defaults_to_native() {
+    let config: KdashConfig = serde_saphyr::from_str("").expect("empty config should parse");
+    assert!(!config.describe_with_kubectl);
+
+    let config: KdashConfig =
+      serde_saphyr::from_str("describe_with_kubectl: true\n").expect("config should parse");
+    assert!(config.describe_with_kubectl);
+  }
+
+  #[test]
+  fn test_list_paging_config() {
+    let config: KdashConfig = serde_saphyr::from_str("").expect("empty config should parse");
+    assert_eq!(config.list_page_size(), DEFAULT_LIST_PAGE_SIZE);
+    assert_eq!(config.max_items_per_tab, None);
+
+    let config: KdashConfig =
+      serde_saphyr::from_str("list_page_size: 100\nmax_items_per_tab: 2000\n")
+        .expect("config should parse");
+    assert_eq!(config.list_page_size(), 100);
+    assert_eq!(config.max_items_per_tab, Some(2000));
+
+    let config: KdashConfig =
+      serde_saphyr::from_str("list_page_size: 0\n").expect("config should parse");
+    assert_eq!(config.list_page_size(), DEFAULT_LIST_PAGE_SIZE);
+  }
+
+  #[test]
+  fn test_log_buffer_limits() {
+    let config: KdashConfig = serde_saphyr::from_str("").expect("empty config should parse");
+    assert_eq!(config.log_max_lines(), Some(DEFAULT_LOG_MAX_LINES));
+    assert_eq!(config.log_max_bytes(), None);
+
+    let config: KdashConfig = serde_saphyr::from_str("log_max_lines: 0\nlog_max_bytes: 1048576\n")
+      .expect("config should parse");
+    assert_eq!(config.log_max_lines(), None);
+    assert_eq!(config.log_max_bytes(), Some(1_048_576));
+  }
+
+  #[test]
+  fn test_troubleshoot_thresholds() {
+    let config: KdashConfig = serde_saphyr::from_str("").expect("empty config should parse");
+    assert_eq!(
+      config.troubleshoot.restart_threshold,
+      DEFAULT_RESTART_THRESHOLD
+    );
+
+    let config: KdashConfig = serde_saphyr::from_str("troubleshoot:\n  restart_threshold: 0\n")
+      .expect("config should parse");
+    assert_eq!(config.troubleshoot.restart_threshold, 0);
+    assert_eq!(
+      config.troubleshoot.rollout_threshold_secs,
+      DEFAULT_ROLLOUT_THRESHOLD_SECS
+    );
+    assert_eq!(
+      config.troubleshoot.termination_window_secs,
+      DEFAULT_TERMINATION_WINDOW_SECS
+    );
+    assert_eq!(
+      config.troubleshoot.node_mem_threshold,
+      DEFAULT_NODE_UTILISATION_THRESHOLD
+    );
+  }
+
+  #[test]
+  fn test_log_fields_override_only_the_given_names() {
+    let config: KdashConfig =
+      serde_saphyr::from_str("log_fields:\n  message: [event]\n").expect("config should parse");
+    assert_eq!(config.log_fields.message, vec!["event".to_string()]);
+    assert_eq!(config.log_fields.level, LogFieldNames::default().level);
+  }
+
   #[test]
   fn test_cli_info_custom_regex_defaults_to_none() {
     let config: KdashConfig =
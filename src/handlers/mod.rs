@@ -1,5 +1,7 @@
 use anyhow::anyhow;
+use chrono::Local;
 use crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
+use kube::Resource;
 use kubectl_view_allocations::GroupBy;
 use serde::Serialize;
 use std::{
@@ -10,17 +12,20 @@ use std::{
 use crate::{
   app::{
     actions::{InputAction, InputModal, InputSubmit, Modal, ResourceAction},
+    exec::key_event_bytes,
     key_binding::DEFAULT_KEYBINDING,
+    logs::{self, LogLevel},
     models::{
       HasPodSelector, KubeResource, Scrollable, ScrollableTxt, StatefulList, StatefulTable,
     },
+    nodes::KubeNode,
     secrets::KubeSecret,
-    troubleshoot::ResourceKind,
-    ActiveBlock, App, PendingEdit, PendingShellExec, Route, RouteId,
+    troubleshoot::{DisplayFinding, ResourceKind},
+    ActiveBlock, App, PendingEdit, Route, RouteId,
   },
-  cmd::IoCmdEvent,
+  cmd::{shell::ShellExecTarget, IoCmdEvent},
   event::Key,
-  network::{stream::IoStreamEvent, IoEvent, ResourcePatch},
+  network::{stream::IoStreamEvent, BulkOp, BulkTarget, IoEvent, ResourcePatch},
 };
 
 /// Handles Enter/`o` key on a workload resource: describe/yaml, drill-down to pods, or aggregate logs.
@@ -122,9 +127,20 @@ macro_rules! handle_resource_scroll {
 }
 
 pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
-  let _ = key_event;
+  // A live shell pane owns the keyboard; only its close key stays with kdash.
+  if app.get_current_route().active_block == ActiveBlock::Shell
+    && handle_shell_key(key, key_event, app)
+  {
+    return;
+  }
 
   // Overlay modals and the action menu consume input before anything else.
+  if app.bulk_summary.is_some() {
+    if key == DEFAULT_KEYBINDING.esc.key || key == DEFAULT_KEYBINDING.submit.key {
+      app.bulk_summary = None;
+    }
+    return;
+  }
   if app.modal.is_some() {
     handle_modal_key(key, app).await;
     return;
@@ -154,6 +170,11 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
     app.menu_filter_active = true;
   } else if resource_filter_active && handle_resource_filter_key(key, app) {
     // Resource filter captured the key — done
+  } else if app.get_current_route().active_block == ActiveBlock::Logs
+    && app.data.logs.search.input_active
+    && handle_log_search_key(key, app)
+  {
+    // Log search input captured the key — done
   } else if app.get_current_route().active_block == ActiveBlock::Namespaces
     && app.ns_filter_active
     && handle_namespace_filter_key(key, app)
@@ -207,6 +228,87 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
       {
         app.log_wrap = !app.log_wrap;
       }
+      _ if key == DEFAULT_KEYBINDING.toggle_log_raw.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        app.log_raw = !app.log_raw;
+        app.data.logs.set_raw(app.log_raw);
+        if let Some(log_split) = app.data.log_split.as_mut() {
+          log_split.previous.set_raw(app.log_raw);
+        }
+      }
+      _ if key == DEFAULT_KEYBINDING.log_search.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        app.data.logs.search.begin_input();
+      }
+      _ if (key == DEFAULT_KEYBINDING.log_search_next.key
+        || key == DEFAULT_KEYBINDING.log_search_prev.key)
+        && app.get_current_route().active_block == ActiveBlock::Logs
+        && app.focused_logs().search.is_active() =>
+      {
+        let forward = key == DEFAULT_KEYBINDING.log_search_next.key;
+        if app.focused_logs_mut().jump_to_match(forward) {
+          *app.focused_log_follow_mut() = false;
+        }
+      }
+      _ if (key == DEFAULT_KEYBINDING.log_filter_include.key
+        || key == DEFAULT_KEYBINDING.log_filter_exclude.key)
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        open_log_filter_input(app, key == DEFAULT_KEYBINDING.log_filter_exclude.key);
+      }
+      _ if key == DEFAULT_KEYBINDING.save_logs.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        save_logs(app, None);
+      }
+      _ if key == DEFAULT_KEYBINDING.tee_logs.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        toggle_log_tee(app, None);
+      }
+      _ if key == DEFAULT_KEYBINDING.log_min_level.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        let level = LogLevel::cycle_min(app.data.logs.filter().min_level);
+        app.data.logs.set_min_level(level);
+        if let Some(log_split) = app.data.log_split.as_mut() {
+          log_split.previous.set_min_level(level);
+        }
+      }
+      _ if key == DEFAULT_KEYBINDING.log_since.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        open_log_since_input(app);
+      }
+      _ if key == DEFAULT_KEYBINDING.log_jump_to_time.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        open_log_jump_input(app);
+      }
+      _ if key == DEFAULT_KEYBINDING.log_split_previous.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        app.toggle_log_split().await;
+      }
+      // Left/Right move focus between the split's panes
+      _ if (key == DEFAULT_KEYBINDING.left.key
+        || key == DEFAULT_KEYBINDING.left.alt.unwrap()
+        || key == Key::Left
+        || key == DEFAULT_KEYBINDING.right.key
+        || key == DEFAULT_KEYBINDING.right.alt.unwrap()
+        || key == Key::Right)
+        && app.get_current_route().active_block == ActiveBlock::Logs
+        && app.data.log_split.is_some() =>
+      {
+        let previous = key == DEFAULT_KEYBINDING.left.key
+          || key == DEFAULT_KEYBINDING.left.alt.unwrap()
+          || key == Key::Left;
+        if let Some(log_split) = app.data.log_split.as_mut() {
+          log_split.focus_previous = previous;
+        }
+      }
       _ if key == DEFAULT_KEYBINDING.toggle_theme.key => {
         app.cycle_theme();
         app.set_status_message(format!("Theme: {}", app.palette.name));
@@ -218,12 +320,34 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
       _ if key == DEFAULT_KEYBINDING.toggle_wide_columns.key => {
         app.wide_columns = !app.wide_columns;
       }
+      _ if key == DEFAULT_KEYBINDING.sort_column.key => {
+        if let Some(table) = app.resource_table_mut(app.get_current_route().active_block) {
+          table.cycle_sort();
+        }
+      }
+      _ if key == DEFAULT_KEYBINDING.toggle_mark.key => {
+        if let Some(table) = app.resource_table_mut(app.get_current_route().active_block) {
+          table.toggle_mark();
+          handle_block_scroll(app, ScrollEvent::down(), false).await;
+        }
+      }
+      _ if key == DEFAULT_KEYBINDING.toggle_mark_all.key => {
+        if let Some(table) = app.resource_table_mut(app.get_current_route().active_block) {
+          table.toggle_mark_all();
+        }
+      }
       _ if key == DEFAULT_KEYBINDING.refresh.key => {
         app.refresh();
       }
       _ if key == DEFAULT_KEYBINDING.dump_error_log.key => {
         dump_error_history(app, None);
       }
+      _ if key == DEFAULT_KEYBINDING.capture_snapshot.key => {
+        app.capture_snapshot().await;
+      }
+      _ if key == DEFAULT_KEYBINDING.tail_logs.key => {
+        open_log_tail_input(app);
+      }
       _ if key == DEFAULT_KEYBINDING.help.key => {
         if app.get_current_route().active_block != ActiveBlock::Help {
           app.help_scroll = 0;
@@ -267,6 +391,12 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
 async fn handle_modal_key(key: Key, app: &mut App) {
   if key == Key::Char('y') || key == DEFAULT_KEYBINDING.submit.key {
     if let Some(modal) = app.modal.take() {
+      // A confirmed bulk action consumes the marks it was built from.
+      if let IoEvent::BulkAction { block, .. } = &modal.on_confirm {
+        if let Some(table) = app.resource_table_mut(*block) {
+          table.clear_marks();
+        }
+      }
       app.dispatch(modal.on_confirm).await;
       // Refresh the affected view promptly after a write.
       app.tick_count = 0;
@@ -299,6 +429,38 @@ async fn handle_input_modal_key(key: Key, app: &mut App) {
           .start_port_forward(kind, namespace, name, local_port, remote_port)
           .await;
       }
+      Some(Ok(InputSubmit::SetLogFilter { exclude, pattern })) => {
+        app.close_input_modal();
+        // Already validated, so this only fails for a blank pattern (None).
+        let regex = logs::parse_filter(&pattern).ok().flatten();
+        if let Some(log_split) = app.data.log_split.as_mut() {
+          log_split.previous.set_filter(exclude, regex.clone());
+        }
+        app.data.logs.set_filter(exclude, regex);
+      }
+      Some(Ok(InputSubmit::SetLogSince(since))) => {
+        app.close_input_modal();
+        if app.log_since != since {
+          app.log_since = since;
+          app.restream_logs().await;
+        }
+      }
+      Some(Ok(InputSubmit::StartTailLogs(query))) => {
+        app.close_input_modal();
+        let route_id = app.get_current_route().id.clone();
+        app.dispatch_tail_logs(query, route_id).await;
+      }
+      Some(Ok(InputSubmit::JumpToLogTime(time))) => {
+        app.close_input_modal();
+        if app.focused_logs_mut().jump_to_time(time) {
+          *app.focused_log_follow_mut() = false;
+        } else {
+          app.set_status_message(format!(
+            "No log line at or after {}",
+            time.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")
+          ));
+        }
+      }
       Some(Err(err)) => {
         if let Some(input) = app.input_modal.as_mut() {
           input.error = Some(err);
@@ -325,22 +487,46 @@ async fn handle_input_modal_key(key: Key, app: &mut App) {
 
 /// Resolve the `(name, namespace)` of the selected row for a mutable block.
 fn selected_target(app: &App, block: ActiveBlock) -> Option<(String, Option<String>)> {
+  block_targets(app, block, false)
+    .into_iter()
+    .next()
+    .map(|target| (target.name, target.namespace))
+}
+
+/// The marked rows of a mutable block, in table order. Empty when nothing is
+/// marked, in which case actions fall back to the selected row.
+fn marked_targets(app: &App, block: ActiveBlock) -> Vec<BulkTarget> {
+  block_targets(app, block, true)
+}
+
+fn block_targets(app: &App, block: ActiveBlock, marked: bool) -> Vec<BulkTarget> {
+  fn rows<T: Clone>(table: &StatefulTable<T>, marked: bool) -> Vec<T> {
+    if marked {
+      table.marked_items()
+    } else {
+      table.get_selected_item_copy().into_iter().collect()
+    }
+  }
   macro_rules! namespaced {
     ($field:ident) => {
-      app
-        .data
-        .$field
-        .get_selected_item_copy()
-        .map(|res| (res.name.clone(), Some(res.namespace.clone())))
+      rows(&app.data.$field, marked)
+        .into_iter()
+        .map(|res| BulkTarget {
+          name: res.name,
+          namespace: Some(res.namespace),
+        })
+        .collect()
     };
   }
   macro_rules! cluster {
     ($field:ident) => {
-      app
-        .data
-        .$field
-        .get_selected_item_copy()
-        .map(|res| (res.name.clone(), None))
+      rows(&app.data.$field, marked)
+        .into_iter()
+        .map(|res| BulkTarget {
+          name: res.name,
+          namespace: None,
+        })
+        .collect()
     };
   }
 
@@ -368,15 +554,32 @@ fn selected_target(app: &App, block: ActiveBlock) -> Option<(String, Option<Stri
     ActiveBlock::StorageClasses => cluster!(storage_classes),
     ActiveBlock::ClusterRoles => cluster!(cluster_roles),
     ActiveBlock::ClusterRoleBindings => cluster!(cluster_role_bindings),
-    ActiveBlock::DynamicResource => app
-      .data
-      .dynamic_resources
-      .get_selected_item_copy()
-      .map(|res| (res.name.clone(), res.namespace.clone())),
-    _ => None,
+    ActiveBlock::DynamicResource => rows(&app.data.dynamic_resources, marked)
+      .into_iter()
+      .map(|res| BulkTarget {
+        name: res.name,
+        namespace: res.namespace,
+      })
+      .collect(),
+    _ => vec![],
   }
 }
 
+/// Open one confirmation listing every marked target for a bulk action.
+fn open_bulk_confirm(
+  app: &mut App,
+  block: ActiveBlock,
+  title: &str,
+  prompt: String,
+  op: BulkOp,
+  targets: Vec<BulkTarget>,
+) {
+  let labels = targets.iter().map(BulkTarget::label).collect();
+  app.open_modal(
+    Modal::confirm(title, prompt, IoEvent::BulkAction { block, op, targets }).with_targets(labels),
+  );
+}
+
 /// Human-readable kind label for confirmation prompts.
 fn resource_kind_label(app: &App, block: ActiveBlock) -> String {
   let label = match block {
@@ -417,9 +620,20 @@ fn resource_kind_label(app: &App, block: ActiveBlock) -> String {
   label.to_owned()
 }
 
-/// Open a delete-confirmation modal for the selected row in the current block.
+/// Open a delete-confirmation modal for the marked rows, or the selected row
+/// when nothing is marked.
 async fn handle_delete_resource(app: &mut App) {
   let block = app.get_current_route().active_block;
+  let marked = marked_targets(app, block);
+  if !marked.is_empty() {
+    let prompt = format!(
+      "Delete {} {}(s)? This cannot be undone.",
+      marked.len(),
+      resource_kind_label(app, block)
+    );
+    open_bulk_confirm(app, block, "Confirm delete", prompt, BulkOp::Delete, marked);
+    return;
+  }
   let Some((name, namespace)) = selected_target(app, block) else {
     return;
   };
@@ -442,8 +656,8 @@ async fn handle_delete_resource(app: &mut App) {
   ));
 }
 
-/// Open a restart-confirmation modal for the selected workload. Only offered for
-/// the rollout-capable kinds (deployments, statefulsets, daemonsets).
+/// Open a restart-confirmation modal for the marked or selected workloads. Only
+/// offered for the rollout-capable kinds (deployments, statefulsets, daemonsets).
 async fn handle_restart_resource(app: &mut App) {
   let block = app.get_current_route().active_block;
   if !matches!(
@@ -452,6 +666,17 @@ async fn handle_restart_resource(app: &mut App) {
   ) {
     return;
   }
+  let marked = marked_targets(app, block);
+  if !marked.is_empty() {
+    let prompt = format!(
+      "Rollout restart {} {}(s)?",
+      marked.len(),
+      resource_kind_label(app, block)
+    );
+    let op = BulkOp::Patch(ResourcePatch::RolloutRestart);
+    open_bulk_confirm(app, block, "Confirm restart", prompt, op, marked);
+    return;
+  }
   let Some((name, namespace)) = selected_target(app, block) else {
     return;
   };
@@ -512,12 +737,29 @@ fn current_replicas(app: &App, block: ActiveBlock) -> Option<i32> {
 }
 
 /// Open the scale input modal for the selected workload, prefilled with the
-/// current replica count. Menu-only (no hotkey); only offered for scalable kinds.
+/// current replica count, or for every marked workload. Menu-only (no hotkey);
+/// only offered for scalable kinds.
 fn handle_scale_resource(app: &mut App) {
   let block = app.get_current_route().active_block;
   if !is_scalable(block) {
     return;
   }
+  let marked = marked_targets(app, block);
+  if !marked.is_empty() {
+    let kind = resource_kind_label(app, block);
+    app.open_input_modal(InputModal {
+      title: "Scale".to_owned(),
+      prompt: format!("New replica count for {} {}(s):", marked.len(), kind),
+      buffer: String::new(),
+      error: None,
+      action: InputAction::BulkScale {
+        block,
+        targets: marked,
+        kind,
+      },
+    });
+    return;
+  }
   let Some((name, namespace)) = selected_target(app, block) else {
     return;
   };
@@ -593,8 +835,26 @@ async fn execute_resource_action(action: ResourceAction, app: &mut App) {
 }
 
 /// Open a suspend/resume confirmation for the selected cronjob. The direction is
-/// derived from the cronjob's current `spec.suspend` state.
+/// derived from the cronjob's current `spec.suspend` state; for marked cronjobs
+/// they are all suspended unless every one already is.
 async fn handle_cronjob_suspend_toggle(app: &mut App) {
+  let marked = app.data.cronjobs.marked_items();
+  if !marked.is_empty() {
+    let suspend = marked.iter().any(|cronjob| !cronjob.suspend);
+    let verb = if suspend { "Suspend" } else { "Resume" };
+    let prompt = format!("{} {} cronjob(s)?", verb, marked.len());
+    let targets = marked_targets(app, ActiveBlock::CronJobs);
+    let op = BulkOp::Patch(ResourcePatch::SetSuspend(suspend));
+    open_bulk_confirm(
+      app,
+      ActiveBlock::CronJobs,
+      "Confirm suspend",
+      prompt,
+      op,
+      targets,
+    );
+    return;
+  }
   let Some(cronjob) = app.data.cronjobs.get_selected_item_copy() else {
     return;
   };
@@ -615,8 +875,25 @@ async fn handle_cronjob_suspend_toggle(app: &mut App) {
   ));
 }
 
-/// Open a confirmation to trigger an immediate run of the selected cronjob.
+/// Open a confirmation to trigger an immediate run of the marked or selected
+/// cronjobs.
 async fn handle_cronjob_trigger(app: &mut App) {
+  let marked = marked_targets(app, ActiveBlock::CronJobs);
+  if !marked.is_empty() {
+    let prompt = format!(
+      "Trigger {} cronjob(s) now? This creates a one-off Job for each.",
+      marked.len()
+    );
+    open_bulk_confirm(
+      app,
+      ActiveBlock::CronJobs,
+      "Confirm trigger",
+      prompt,
+      BulkOp::TriggerCronJob,
+      marked,
+    );
+    return;
+  }
   let Some(cronjob) = app.data.cronjobs.get_selected_item_copy() else {
     return;
   };
@@ -634,18 +911,38 @@ async fn handle_cronjob_trigger(app: &mut App) {
 }
 
 /// Open a cordon/uncordon confirmation for the selected node. The direction is
-/// derived from the node's current `spec.unschedulable` state.
+/// derived from the node's current `spec.unschedulable` state; for marked nodes
+/// they are all cordoned unless every one already is.
 async fn handle_cordon_toggle(app: &mut App) {
+  let is_unschedulable = |node: &KubeNode| {
+    node
+      .get_k8s_obj()
+      .spec
+      .as_ref()
+      .and_then(|spec| spec.unschedulable)
+      .unwrap_or(false)
+  };
+  let marked = app.data.nodes.marked_items();
+  if !marked.is_empty() {
+    let cordon = marked.iter().any(|node| !is_unschedulable(node));
+    let verb = if cordon { "Cordon" } else { "Uncordon" };
+    let prompt = format!("{} {} node(s)?", verb, marked.len());
+    let targets = marked_targets(app, ActiveBlock::Nodes);
+    let op = BulkOp::Patch(ResourcePatch::SetUnschedulable(cordon));
+    open_bulk_confirm(
+      app,
+      ActiveBlock::Nodes,
+      "Confirm cordon",
+      prompt,
+      op,
+      targets,
+    );
+    return;
+  }
   let Some(node) = app.data.nodes.get_selected_item_copy() else {
     return;
   };
-  let currently_unschedulable = node
-    .get_k8s_obj()
-    .spec
-    .as_ref()
-    .and_then(|spec| spec.unschedulable)
-    .unwrap_or(false);
-  let cordon = !currently_unschedulable;
+  let cordon = !is_unschedulable(&node);
   let verb = if cordon { "Cordon" } else { "Uncordon" };
   app.open_modal(Modal::confirm(
     "Confirm cordon",
@@ -695,6 +992,39 @@ fn handle_escape(app: &mut App) {
     }
   }
 
+  // A committed log search, then the log filters, are cleared before Esc
+  // leaves the log view
+  let block = app.get_current_route().active_block;
+  if block == ActiveBlock::Logs && app.data.logs.search.is_active() {
+    app.data.logs.search.clear();
+    sync_split_search(app);
+    return;
+  }
+  if block == ActiveBlock::Logs && app.data.logs.clear_filter() {
+    if let Some(log_split) = app.data.log_split.as_mut() {
+      log_split.previous.clear_filter();
+    }
+    return;
+  }
+
+  // Marks go before the selector so one Esc never drops both
+
+  if app
+    .resource_table_mut(block)
+    .is_some_and(|table| table.clear_marks())
+  {
+    return;
+  }
+
+  // A server-side selector stays on the tab until Esc clears it
+  if app
+    .resource_table_mut(block)
+    .is_some_and(|table| table.clear_selector())
+  {
+    app.tick_count = 0;
+    return;
+  }
+
   // Clear menu filter state on any menu exit
   if app.is_menu_active() {
     app.menu_filter.clear();
@@ -719,9 +1049,14 @@ fn handle_escape(app: &mut App) {
         app.data.selected.pod_selector_resource = None;
         app.pop_navigation_stack();
       }
+      ActiveBlock::Shell => {
+        app.close_exec_session();
+        app.pop_navigation_stack();
+      }
       ActiveBlock::Logs => {
         app.cancel_log_stream();
         app.log_previous = false;
+        app.data.log_split = None;
         // Clear resource context when leaving aggregate logs
         if app.data.selected.pod_selector.is_none() {
           app.data.selected.pod_selector_resource = None;
@@ -744,6 +1079,23 @@ fn handle_escape(app: &mut App) {
   }
 }
 
+/// Forward a key to the running shell, or close the pane on the close key.
+/// Returns false once the session has ended so Esc and friends work as usual.
+fn handle_shell_key(key: Key, key_event: KeyEvent, app: &mut App) -> bool {
+  if key == DEFAULT_KEYBINDING.close_shell.key {
+    app.close_exec_session();
+    app.pop_navigation_stack();
+    return true;
+  }
+  if !app.is_exec_input_active() {
+    return false;
+  }
+  if let (Some(session), Some(data)) = (app.exec_session.as_mut(), key_event_bytes(key_event)) {
+    session.send_input(data);
+  }
+  true
+}
+
 /// Handle character/backspace keys for menu filter input.
 /// Returns true if the key was consumed, false to let it pass through.
 fn handle_menu_filter_key(key: Key, app: &mut App) -> bool {
@@ -779,6 +1131,155 @@ fn handle_filter_text_key(filter: &mut String, key: Key) -> bool {
   }
 }
 
+/// Prompt for the log include (or exclude) regex, prefilled with the current one
+/// so it can be refined or blanked to clear it.
+fn open_log_filter_input(app: &mut App, exclude: bool) {
+  let filter = app.data.logs.filter();
+  let current = if exclude {
+    &filter.exclude
+  } else {
+    &filter.include
+  };
+  let (title, prompt) = if exclude {
+    (
+      "Exclude",
+      "Hide log lines matching (regex, blank to clear):",
+    )
+  } else {
+    (
+      "Include",
+      "Show only log lines matching (regex, blank to clear):",
+    )
+  };
+  app.open_input_modal(InputModal {
+    title: title.to_owned(),
+    prompt: prompt.to_owned(),
+    buffer: current
+      .as_ref()
+      .map_or_else(String::new, |re| re.as_str().to_owned()),
+    error: None,
+    action: InputAction::LogFilter { exclude },
+  });
+}
+
+/// Prompt for a stern-style multi-pod tail query. Without `-n`/`-A` it covers
+/// the selected namespace (all of them when none is selected).
+fn open_log_tail_input(app: &mut App) {
+  app.open_input_modal(InputModal {
+    title: "Tail logs".to_owned(),
+    prompt: "Tail pods matching <pod-regex> [-c <container-regex>] [-n <namespace> | -A]:"
+      .to_owned(),
+    buffer: String::new(),
+    error: None,
+    action: InputAction::TailLogs {
+      namespace: app.data.selected.ns.clone(),
+    },
+  });
+}
+
+/// Prompt for the log window, prefilled with the current one.
+fn open_log_since_input(app: &mut App) {
+  app.open_input_modal(InputModal {
+    title: "Since".to_owned(),
+    prompt: "Fetch logs since a duration (15m, 1h30m) or time (14:02, 2024-05-01 14:02), blank for the last lines:".to_owned(),
+    buffer: app.log_since.map_or_else(String::new, |since| since.input()),
+    error: None,
+    action: InputAction::LogSince,
+  });
+}
+
+/// Prompt for a time to jump to. Lines are only dated when they carry the
+/// Kubernetes timestamps, so ask for those first.
+fn open_log_jump_input(app: &mut App) {
+  if !app.log_timestamps {
+    app.set_status_message(format!(
+      "Jumping to a time needs timestamps; press '{}' to show them",
+      DEFAULT_KEYBINDING.toggle_log_timestamps.key
+    ));
+    return;
+  }
+  app.open_input_modal(InputModal {
+    title: "Jump to time".to_owned(),
+    prompt: "Jump to the first line at or after (14:02, 2024-05-01 14:02, or 10m ago as 10m):"
+      .to_owned(),
+    buffer: String::new(),
+    error: None,
+    action: InputAction::LogJumpToTime,
+  });
+}
+
+/// Edit the log search query while it is being typed; each edit re-runs the
+/// search and jumps to the newest match. `Enter` keeps the query for `n`/`N`,
+/// `Esc` drops it.
+fn handle_log_search_key(key: Key, app: &mut App) -> bool {
+  let search = &mut app.data.logs.search;
+  if key == DEFAULT_KEYBINDING.submit.key {
+    search.input_active = false;
+    return true;
+  }
+  if key == DEFAULT_KEYBINDING.esc.key {
+    search.clear();
+    sync_split_search(app);
+    return true;
+  }
+  if !handle_filter_text_key(&mut search.query, key) {
+    return false;
+  }
+  app.data.logs.update_search();
+  if app.data.logs.search.current_record().is_some() {
+    app.log_auto_scroll = false;
+  }
+  sync_split_search(app);
+  true
+}
+
+/// YAML of the resource a troubleshoot finding points at, or empty when it is
+/// no longer listed.
+fn finding_yaml<K, T>(items: &[T], finding: &DisplayFinding) -> String
+where
+  K: Resource + Serialize,
+  T: KubeResource<K>,
+{
+  items
+    .iter()
+    .find(|item| {
+      item.get_name() == &finding.resource_name
+        && item.get_k8s_obj().meta().namespace == finding.namespace
+    })
+    .map(|item| item.resource_to_yaml())
+    .unwrap_or_default()
+}
+
+/// Mirror the live pane's search into the split's previous pane so one query
+/// highlights both.
+fn sync_split_search(app: &mut App) {
+  let Some(log_split) = app.data.log_split.as_mut() else {
+    return;
+  };
+  let previous = &mut log_split.previous;
+  if previous.search.query == app.data.logs.search.query {
+    return;
+  }
+  if app.data.logs.search.query.is_empty() {
+    previous.search.clear();
+    return;
+  }
+  previous.search.query = app.data.logs.search.query.clone();
+  previous.update_search();
+  if previous.search.current_record().is_some() {
+    log_split.follow = false;
+  }
+}
+
+/// `s` in the log view: stop or resume following new lines in the focused pane.
+fn toggle_log_auto_scroll(app: &mut App) {
+  if *app.focused_log_follow_mut() {
+    app.focused_logs_mut().freeze_follow_position();
+  }
+  let follow = app.focused_log_follow_mut();
+  *follow = !*follow;
+}
+
 fn clear_or_deactivate_filter(filter: &mut String, active: &mut bool) {
   if filter.is_empty() {
     *active = false;
@@ -788,6 +1289,9 @@ fn clear_or_deactivate_filter(filter: &mut String, active: &mut bool) {
 }
 
 fn handle_resource_filter_key(key: Key, app: &mut App) -> bool {
+  if key == DEFAULT_KEYBINDING.submit.key && commit_selector_filter(app) {
+    return true;
+  }
   if let Some((filter, _, state)) = app.current_resource_filter_mut() {
     let handled = handle_filter_text_key(filter, key);
     if handled {
@@ -799,6 +1303,24 @@ fn handle_resource_filter_key(key: Key, app: &mut App) -> bool {
   }
 }
 
+/// Turn `l:` / `f:` filter text into the tab's server-side selector and force
+/// a refetch. Returns false for plain name filters or tabs not listed from the
+/// API, leaving Enter to its usual action.
+fn commit_selector_filter(app: &mut App) -> bool {
+  let block = app.get_current_route().active_block;
+  if !app.supports_list_selector(block) {
+    return false;
+  }
+  let committed = app
+    .resource_table_mut(block)
+    .is_some_and(|table| table.commit_selector_filter());
+  if committed {
+    // to force network request
+    app.tick_count = 0;
+  }
+  committed
+}
+
 fn handle_namespace_filter_key(key: Key, app: &mut App) -> bool {
   let handled = handle_filter_text_key(&mut app.ns_filter, key);
   if handled {
@@ -846,7 +1368,7 @@ where
   if key == DEFAULT_KEYBINDING.describe_resource.key {
     app.data.describe_out = ScrollableTxt::new();
     app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
-    app.dispatch_cmd(action).await;
+    app.dispatch_describe(action).await;
     true
   } else if key == DEFAULT_KEYBINDING.resource_yaml.key {
     let yaml = res.resource_to_yaml();
@@ -898,7 +1420,7 @@ async fn dispatch_describe_on_submit(
   if !handled && key == DEFAULT_KEYBINDING.submit.key {
     app.data.describe_out = ScrollableTxt::new();
     app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
-    app.dispatch_cmd(describe_action).await;
+    app.dispatch_describe(describe_action).await;
   }
 }
 
@@ -1115,7 +1637,7 @@ async fn handle_route_events(key: Key, app: &mut App) {
           }
           ActiveBlock::Containers => {
             if key == DEFAULT_KEYBINDING.shell_exec.key {
-              queue_selected_container_shell_exec(app);
+              open_selected_container_shell(app, RouteId::Home).await;
             } else if key == DEFAULT_KEYBINDING.previous_logs.key {
               handle_previous_logs_for_container(app, RouteId::Home).await;
             } else if let Some(c) = handle_block_action(key, &app.data.containers) {
@@ -1125,12 +1647,9 @@ async fn handle_route_events(key: Key, app: &mut App) {
           }
           ActiveBlock::Logs => {
             if key == DEFAULT_KEYBINDING.log_auto_scroll.key {
-              if app.log_auto_scroll {
-                app.data.logs.freeze_follow_position();
-              }
-              app.log_auto_scroll = !app.log_auto_scroll;
+              toggle_log_auto_scroll(app);
             } else if key == DEFAULT_KEYBINDING.copy_to_clipboard.key {
-              copy_to_clipboard(app.data.logs.get_plain_text(), app);
+              copy_to_clipboard(app.focused_logs().get_plain_text(), app);
             }
           }
           ActiveBlock::Describe | ActiveBlock::Yaml => {
@@ -1200,7 +1719,7 @@ async fn handle_route_events(key: Key, app: &mut App) {
               }
             }
           }
-          ActiveBlock::Contexts | ActiveBlock::Utilization | ActiveBlock::Troubleshoot | ActiveBlock::Help => { /* Do nothing */ }
+          ActiveBlock::Contexts | ActiveBlock::Utilization | ActiveBlock::Troubleshoot | ActiveBlock::Help | ActiveBlock::Shell => { /* Do nothing */ }
         }
       )
     }
@@ -1247,7 +1766,7 @@ async fn handle_route_events(key: Key, app: &mut App) {
       match app.get_current_route().active_block {
         ActiveBlock::Containers => {
           if key == DEFAULT_KEYBINDING.shell_exec.key {
-            queue_selected_container_shell_exec(app);
+            open_selected_container_shell(app, RouteId::Troubleshoot).await;
           } else if key == DEFAULT_KEYBINDING.previous_logs.key {
             handle_previous_logs_for_container(app, RouteId::Troubleshoot).await;
           } else if let Some(c) = handle_block_action(key, &app.data.containers) {
@@ -1259,12 +1778,9 @@ async fn handle_route_events(key: Key, app: &mut App) {
         }
         ActiveBlock::Logs => {
           if key == DEFAULT_KEYBINDING.log_auto_scroll.key {
-            if app.log_auto_scroll {
-              app.data.logs.freeze_follow_position();
-            }
-            app.log_auto_scroll = !app.log_auto_scroll;
+            toggle_log_auto_scroll(app);
           } else if key == DEFAULT_KEYBINDING.copy_to_clipboard.key {
-            copy_to_clipboard(app.data.logs.get_plain_text(), app);
+            copy_to_clipboard(app.focused_logs().get_plain_text(), app);
           }
         }
         ActiveBlock::Troubleshoot => {
@@ -1291,7 +1807,7 @@ async fn handle_route_events(key: Key, app: &mut App) {
                 app.data.describe_out = ScrollableTxt::new();
                 app.push_navigation_stack(RouteId::Troubleshoot, ActiveBlock::Describe);
                 app
-                  .dispatch_cmd(IoCmdEvent::GetDescribe {
+                  .dispatch_describe(IoCmdEvent::GetDescribe {
                     kind,
                     value: value.to_owned(),
                     ns: ns.map(str::to_owned),
@@ -1305,7 +1821,7 @@ async fn handle_route_events(key: Key, app: &mut App) {
               app.data.describe_out = ScrollableTxt::new();
               app.push_navigation_stack(RouteId::Troubleshoot, ActiveBlock::Describe);
               app
-                .dispatch_cmd(IoCmdEvent::GetDescribe {
+                .dispatch_describe(IoCmdEvent::GetDescribe {
                   kind,
                   value: value.to_owned(),
                   ns: ns.map(str::to_owned),
@@ -1314,49 +1830,27 @@ async fn handle_route_events(key: Key, app: &mut App) {
             }
           } else if key == DEFAULT_KEYBINDING.resource_yaml.key {
             if let Some(finding) = handle_block_action(key, &app.data.troubleshoot_findings) {
+              let data = &app.data;
               let yaml = match finding.resource_kind {
-                ResourceKind::Pod => app
-                  .data
-                  .pods
-                  .items
-                  .iter()
-                  .find(|p| {
-                    p.name == finding.resource_name
-                      && finding
-                        .namespace
-                        .as_deref()
-                        .is_some_and(|ns| p.namespace == ns)
-                  })
-                  .map(|p| p.resource_to_yaml())
-                  .unwrap_or_default(),
-                ResourceKind::Pvc => app
-                  .data
-                  .persistent_volume_claims
-                  .items
-                  .iter()
-                  .find(|pvc| {
-                    pvc.name == finding.resource_name
-                      && finding
-                        .namespace
-                        .as_deref()
-                        .is_some_and(|ns| pvc.namespace == ns)
-                  })
-                  .map(|pvc| pvc.resource_to_yaml())
-                  .unwrap_or_default(),
-                ResourceKind::ReplicaSet => app
-                  .data
-                  .replica_sets
-                  .items
-                  .iter()
-                  .find(|rs| {
-                    rs.name == finding.resource_name
-                      && finding
-                        .namespace
-                        .as_deref()
-                        .is_some_and(|ns| rs.namespace == ns)
-                  })
-                  .map(|rs| rs.resource_to_yaml())
-                  .unwrap_or_default(),
+                ResourceKind::Pod => finding_yaml(&data.pods.items, &finding),
+                ResourceKind::Pvc => finding_yaml(&data.persistent_volume_claims.items, &finding),
+                ResourceKind::ReplicaSet => finding_yaml(&data.replica_sets.items, &finding),
+                ResourceKind::Deployment => {
+                  finding_yaml(&data.troubleshoot_inputs.deployments, &finding)
+                }
+                ResourceKind::StatefulSet => {
+                  finding_yaml(&data.troubleshoot_inputs.stateful_sets, &finding)
+                }
+                ResourceKind::DaemonSet => {
+                  finding_yaml(&data.troubleshoot_inputs.daemon_sets, &finding)
+                }
+                ResourceKind::Node => finding_yaml(&data.troubleshoot_nodes.nodes, &finding),
+                ResourceKind::Service => finding_yaml(&data.troubleshoot_inputs.services, &finding),
+                ResourceKind::Ingress => {
+                  finding_yaml(&data.troubleshoot_inputs.ingresses, &finding)
+                }
+                ResourceKind::Job => finding_yaml(&data.troubleshoot_inputs.jobs, &finding),
+                ResourceKind::CronJob => finding_yaml(&data.troubleshoot_inputs.cronjobs, &finding),
               };
               app.data.describe_out = ScrollableTxt::with_string(yaml);
               app.push_navigation_stack(RouteId::Troubleshoot, ActiveBlock::Yaml);
@@ -1503,7 +1997,7 @@ fn move_list_selection(state: &mut ratatui::widgets::ListState, len: usize, delt
   state.select(Some(next));
 }
 
-fn queue_selected_container_shell_exec(app: &mut App) {
+async fn open_selected_container_shell(app: &mut App, route_id: RouteId) {
   let Some(pod) = app.data.pods.get_selected_item_copy() else {
     app.handle_error(anyhow!("No pod selected for shell exec"));
     return;
@@ -1517,11 +2011,16 @@ fn queue_selected_container_shell_exec(app: &mut App) {
     return;
   };
 
-  app.queue_shell_exec(PendingShellExec {
-    namespace: pod.namespace,
-    pod: pod.name,
-    container: container.name,
-  });
+  app
+    .open_exec_session(
+      ShellExecTarget {
+        namespace: pod.namespace,
+        pod: pod.name,
+        container: container.name,
+      },
+      route_id,
+    )
+    .await;
 }
 
 fn resolve_shell_container(
@@ -1622,6 +2121,8 @@ async fn handle_block_scroll(app: &mut App, event: ScrollEvent, is_mouse: bool)
       ActiveBlock::Contexts => app.data.contexts.handle_scroll(event),
       ActiveBlock::Utilization => app.data.metrics.handle_scroll(event),
       ActiveBlock::Troubleshoot => app.data.troubleshoot_findings.handle_scroll(event),
+      // the remote TTY has no local scrollback
+      ActiveBlock::Shell => {}
       ActiveBlock::Help => {
         // Grouped help is a scrolled paragraph; the offset is clamped to the
         // content height at render time.
@@ -1643,11 +2144,13 @@ async fn handle_block_scroll(app: &mut App, event: ScrollEvent, is_mouse: bool)
         handle_menu_scroll(&mut app.dynamic_resources_menu, event, filtered_len);
       }
       ActiveBlock::Logs => {
-        if app.log_auto_scroll {
-          app.data.logs.freeze_follow_position();
-          app.log_auto_scroll = false;
+        if *app.focused_log_follow_mut() {
+          app.focused_logs_mut().freeze_follow_position();
+          *app.focused_log_follow_mut() = false;
         }
-        app.data.logs.handle_scroll(inverse_dir(event, is_mouse));
+        app
+          .focused_logs_mut()
+          .handle_scroll(inverse_dir(event, is_mouse));
       }
       ActiveBlock::Describe | ActiveBlock::Yaml => app
         .data
@@ -1702,6 +2205,45 @@ fn dump_error_history(app: &mut App, output_dir: Option<&Path>) {
   }
 }
 
+/// Write the log buffer to a timestamped file named after the stream.
+fn save_logs(app: &mut App, output_dir: Option<&Path>) {
+  let saved = log_file_path(app, output_dir).and_then(|path| {
+    let lines = app.data.logs.save_to(path.clone())?;
+    Ok((path, lines))
+  });
+  match saved {
+    Ok((path, lines)) => {
+      app.set_status_message(format!("Saved {} log lines to {}", lines, path.display()))
+    }
+    Err(error) => app.handle_error(anyhow!("Unable to write logs: {}", error)),
+  }
+}
+
+/// Start writing the log buffer, then each new line, to a timestamped file; or
+/// stop if already writing.
+fn toggle_log_tee(app: &mut App, output_dir: Option<&Path>) {
+  if let Some(path) = app.data.logs.stop_tee() {
+    app.set_status_message(format!("Stopped writing logs to {}", path.display()));
+    return;
+  }
+  let started = log_file_path(app, output_dir).and_then(|path| {
+    app.data.logs.start_tee(path.clone())?;
+    Ok(path)
+  });
+  match started {
+    Ok(path) => app.set_status_message(format!("Writing logs to {}", path.display())),
+    Err(error) => app.handle_error(anyhow!("Unable to write logs: {}", error)),
+  }
+}
+
+fn log_file_path(app: &App, output_dir: Option<&Path>) -> std::io::Result<PathBuf> {
+  let dir = match output_dir {
+    Some(path) => path.to_path_buf(),
+    None => std::env::current_dir()?,
+  };
+  Ok(logs::log_file_path(&dir, &app.log_file_name()))
+}
+
 fn write_error_history_file(
   history: &std::collections::VecDeque<crate::app::ErrorRecord>,
   output_dir: Option<&Path>,
@@ -1713,7 +2255,7 @@ fn write_error_history_file(
 
   let path = dir.join(format!(
     "kdash-errors-{}.log",
-    chrono::Local::now().format("%Y%m%d%H%M%S")
+    Local::now().format("%Y%m%d%H%M%S")
   ));
 
   fs::write(&path, format_error_history(history))?;
@@ -1749,7 +2291,7 @@ mod tests {
     time::{SystemTime, UNIX_EPOCH},
   };
 
-  use crossterm::event::KeyCode;
+  use crossterm::event::{KeyCode, KeyModifiers};
   use k8s_openapi::ByteString;
   use kube::{
     api::ObjectMeta,
@@ -1762,8 +2304,9 @@ mod tests {
   use crate::app::{
     contexts::KubeContext,
     dynamic::{dynamic_cache_key, KubeDynamicKind, KubeDynamicResource},
+    exec::ExecInput,
+    models::FilterableTable,
     pods::{KubeContainer, KubePod},
-    PendingShellExec,
   };
 
   #[test]
@@ -1796,6 +2339,54 @@ mod tests {
     assert!(contents.contains("second error"));
   }
 
+  #[test]
+  fn test_save_logs_writes_raw_buffer_named_after_container() {
+    let dir = temp_test_dir("save-logs");
+    let mut app = App::default();
+    app.data.selected.pod = Some("web-1".into());
+    app.data.logs = crate::app::models::LogsState::new("app".into());
+    app.data.logs.add_records(vec![
+      r#"{"level":"info","msg":"up"}"#.into(),
+      "plain".into(),
+    ]);
+    app.data.logs.set_min_level(Some(LogLevel::Error));
+
+    save_logs(&mut app, Some(&dir));
+
+    let entry = fs::read_dir(&dir).unwrap().next().unwrap().unwrap();
+    let name = entry.file_name().into_string().unwrap();
+    assert!(name.starts_with("kdash-logs-web-1-app-"), "{}", name);
+    // Every record as received, regardless of filters and formatting.
+    assert_eq!(
+      fs::read_to_string(entry.path()).unwrap(),
+      "{\"level\":\"info\",\"msg\":\"up\"}\nplain\n"
+    );
+    assert!(app.status_message.text().contains(&name));
+  }
+
+  #[test]
+  fn test_log_tee_appends_streamed_records_until_stopped() {
+    let dir = temp_test_dir("tee-logs");
+    let mut app = App::default();
+    app.data.logs = crate::app::models::LogsState::new("agg:my-deploy".into());
+    app.data.logs.add_record("before".into());
+
+    toggle_log_tee(&mut app, Some(&dir));
+    let path = app.data.logs.tee_path().unwrap().to_path_buf();
+    assert!(path
+      .file_name()
+      .unwrap()
+      .to_str()
+      .unwrap()
+      .starts_with("kdash-logs-my-deploy-"));
+    app.data.logs.add_records(vec!["one".into(), "two".into()]);
+
+    toggle_log_tee(&mut app, Some(&dir));
+    assert!(app.data.logs.tee_path().is_none());
+    app.data.logs.add_record("after".into());
+    assert_eq!(fs::read_to_string(path).unwrap(), "before\none\ntwo\n");
+  }
+
   #[test]
   fn test_write_error_history_file_writes_empty_message_when_no_errors() {
     let dir = temp_test_dir("empty-error-dump");
@@ -1855,16 +2446,59 @@ mod tests {
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
     assert_eq!(
-      app.pending_shell_exec(),
-      Some(&PendingShellExec {
+      app.exec_target(),
+      Some(&ShellExecTarget {
         namespace: "team-a".into(),
         pod: "pod-1".into(),
         container: "app".into(),
       })
     );
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Shell);
     assert!(app.api_error.is_empty());
   }
 
+  #[tokio::test]
+  async fn test_shell_pane_forwards_keys_and_closes() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+    app
+      .open_exec_session(
+        ShellExecTarget {
+          namespace: "team-a".into(),
+          pod: "pod-1".into(),
+          container: "app".into(),
+        },
+        RouteId::Home,
+      )
+      .await;
+    let id = app.exec_session.as_ref().unwrap().id;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    app
+      .exec_session_mut(id)
+      .unwrap()
+      .attach("/bin/sh".into(), tx);
+
+    // kdash keys like `q` and Esc go to the remote shell
+    for code in [KeyCode::Char('q'), KeyCode::Esc, KeyCode::Enter] {
+      let key_evt = KeyEvent::from(code);
+      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    }
+    assert!(!app.should_quit);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Shell);
+    assert_eq!(rx.try_recv(), Ok(ExecInput::Data(b"q".to_vec())));
+    assert_eq!(rx.try_recv(), Ok(ExecInput::Data(vec![0x1b])));
+    assert_eq!(rx.try_recv(), Ok(ExecInput::Data(b"\r".to_vec())));
+
+    let key_evt = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.exec_session.is_none());
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Containers
+    );
+  }
+
   #[tokio::test]
   async fn test_shell_exec_key_in_containers_requires_selected_container() {
     let mut app = App::default();
@@ -1878,7 +2512,7 @@ mod tests {
     let key_evt = KeyEvent::from(KeyCode::Char('s'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
-    assert_eq!(app.pending_shell_exec(), None);
+    assert_eq!(app.exec_target(), None);
     assert_eq!(
       app.api_error,
       "No container selected for shell exec on pod pod-1"
@@ -1908,8 +2542,8 @@ mod tests {
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
     assert_eq!(
-      app.pending_shell_exec(),
-      Some(&PendingShellExec {
+      app.exec_target(),
+      Some(&ShellExecTarget {
         namespace: "team-a".into(),
         pod: "pod-1".into(),
         container: "app".into(),
@@ -1941,8 +2575,8 @@ mod tests {
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
     assert_eq!(
-      app.pending_shell_exec(),
-      Some(&PendingShellExec {
+      app.exec_target(),
+      Some(&ShellExecTarget {
         namespace: "team-a".into(),
         pod: "pod-1".into(),
         container: "app".into(),
@@ -1972,7 +2606,7 @@ mod tests {
     let key_evt = KeyEvent::from(KeyCode::Char('s'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
-    assert_eq!(app.pending_shell_exec(), None);
+    assert_eq!(app.exec_target(), None);
     assert_eq!(
       app.api_error,
       "No container selected for shell exec on pod pod-1"
@@ -1991,7 +2625,7 @@ mod tests {
     let key_evt = KeyEvent::from(KeyCode::Char('s'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
-    assert_eq!(app.pending_shell_exec(), None);
+    assert_eq!(app.exec_target(), None);
     assert_eq!(app.api_error, "No pod selected for shell exec");
   }
 
@@ -2006,7 +2640,7 @@ mod tests {
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
     assert!(!app.log_auto_scroll);
-    assert_eq!(app.pending_shell_exec(), None);
+    assert_eq!(app.exec_target(), None);
   }
 
   #[tokio::test]
@@ -2028,8 +2662,8 @@ mod tests {
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
     assert_eq!(
-      app.pending_shell_exec(),
-      Some(&PendingShellExec {
+      app.exec_target(),
+      Some(&ShellExecTarget {
         namespace: "team-a".into(),
         pod: "pod-1".into(),
         container: "app".into(),
@@ -2069,6 +2703,51 @@ mod tests {
     assert!(!app.data.pods.filter_active);
   }
 
+  #[tokio::test]
+  async fn test_resource_selector_filter_key_flow() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    app.tick_count = 5;
+
+    let key_evt = KeyEvent::from(KeyCode::Char('/'));
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    for c in "l:app=api".chars() {
+      let key_evt = KeyEvent::from(KeyCode::Char(c));
+      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    }
+    let key_evt = KeyEvent::from(KeyCode::Enter);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+
+    assert!(!app.data.deployments.filter_active);
+    assert!(app.data.deployments.filter.is_empty());
+    assert_eq!(
+      app
+        .data
+        .deployments
+        .selector
+        .as_ref()
+        .and_then(|s| s.labels.as_deref()),
+      Some("app=api")
+    );
+    assert_eq!(app.tick_count, 0);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Deployments
+    );
+
+    // Esc clears the selector before navigating anywhere
+    app.tick_count = 5;
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert_eq!(app.data.deployments.selector, None);
+    assert_eq!(app.tick_count, 0);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Deployments
+    );
+  }
+
   #[tokio::test]
   async fn test_containers_filter_key_flow() {
     let mut app = App::default();
@@ -2193,8 +2872,8 @@ mod tests {
 
     assert!(app.action_menu.is_none());
     assert_eq!(
-      app.pending_shell_exec(),
-      Some(&PendingShellExec {
+      app.exec_target(),
+      Some(&ShellExecTarget {
         namespace: "team-a".into(),
         pod: "pod-1".into(),
         container: "app".into(),
@@ -2239,7 +2918,7 @@ mod tests {
     };
     let seed = tokio::sync::Mutex::new(App::default());
     let mut guard = seed.try_lock().expect("uncontended lock");
-    crate::app::nodes::KubeNode::from_api_with_pods(&node, &pods, &mut guard)
+    crate::app::nodes::KubeNode::from_api_with_pods(&node, &pods.items, &mut guard)
   }
 
   /// A `Shift`+char key event (crossterm only sets `Key::Shift(..)` when the
@@ -2351,6 +3030,122 @@ mod tests {
     assert!(modal.prompt.contains("team-a"));
   }
 
+  fn pods_with_names(app: &mut App, names: &[&str]) {
+    let pods = names
+      .iter()
+      .map(|name| {
+        let mut pod = KubePod::default();
+        pod.namespace = "team-a".into();
+        pod.name = (*name).into();
+        pod
+      })
+      .collect();
+    app.data.pods.set_items(pods);
+    // Row ids are captured on draw; tests skip drawing.
+    app.data.pods.set_sort_columns(&["Name"]);
+  }
+
+  #[tokio::test]
+  async fn test_marked_rows_delete_opens_one_bulk_confirm_and_clears_marks() {
+    let mut app = App::default();
+    app.route_home();
+    pods_with_names(&mut app, &["pod-1", "pod-2", "pod-3"]);
+
+    // Space marks and moves down: mark pod-1, skip pod-2, mark pod-3.
+    send_keys(
+      &mut app,
+      &[KeyCode::Char(' '), KeyCode::Down, KeyCode::Char(' ')],
+    )
+    .await;
+    assert_eq!(app.data.pods.marked_count(), 2);
+
+    let ctrl_d = ctrl_key('d');
+    handle_key_events(Key::from(ctrl_d), ctrl_d, &mut app).await;
+
+    let modal = app.modal.as_ref().expect("bulk delete should open a modal");
+    assert!(modal.prompt.contains("Delete 2 pod(s)"));
+    assert_eq!(modal.targets, vec!["team-a/pod-1", "team-a/pod-3"]);
+    assert_eq!(
+      modal.on_confirm,
+      IoEvent::BulkAction {
+        block: ActiveBlock::Pods,
+        op: BulkOp::Delete,
+        targets: vec![
+          BulkTarget {
+            name: "pod-1".into(),
+            namespace: Some("team-a".into()),
+          },
+          BulkTarget {
+            name: "pod-3".into(),
+            namespace: Some("team-a".into()),
+          },
+        ],
+      }
+    );
+
+    send_keys(&mut app, &[KeyCode::Char('y')]).await;
+    assert!(app.modal.is_none());
+    assert_eq!(app.data.pods.marked_count(), 0);
+  }
+
+  #[tokio::test]
+  async fn test_mark_all_then_esc_clears_marks_first() {
+    let mut app = App::default();
+    app.route_home();
+    pods_with_names(&mut app, &["pod-1", "pod-2"]);
+
+    let ctrl_a = ctrl_key('a');
+    handle_key_events(Key::from(ctrl_a), ctrl_a, &mut app).await;
+    assert_eq!(app.data.pods.marked_count(), 2);
+
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert_eq!(app.data.pods.marked_count(), 0);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+  }
+
+  #[tokio::test]
+  async fn test_menu_cordon_on_marked_nodes_cordons_all_unless_all_cordoned() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Nodes);
+    app
+      .data
+      .nodes
+      .set_items(vec![make_node("n1", true), make_node("n2", false)]);
+    app.data.nodes.set_sort_columns(&["Name"]);
+    app.data.nodes.toggle_mark_all();
+
+    open_menu_and_select(&mut app, 3).await;
+
+    let modal = app.modal.as_ref().expect("cordon should open a modal");
+    assert!(modal.prompt.contains("Cordon 2 node(s)"));
+    assert!(matches!(
+      &modal.on_confirm,
+      IoEvent::BulkAction {
+        op: BulkOp::Patch(ResourcePatch::SetUnschedulable(true)),
+        targets,
+        ..
+      } if targets.len() == 2
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_bulk_summary_consumes_keys_until_dismissed() {
+    let mut app = App::default();
+    app.route_home();
+    app.bulk_summary = Some(crate::app::actions::BulkSummary {
+      title: "Delete results".into(),
+      results: vec![("team-a/pod-1".into(), Ok("Deleting pod-1".into()))],
+    });
+
+    send_keys(&mut app, &[KeyCode::Char('q')]).await;
+    assert!(!app.should_quit);
+    assert!(app.bulk_summary.is_some());
+
+    send_keys(&mut app, &[KeyCode::Enter]).await;
+    assert!(app.bulk_summary.is_none());
+  }
+
   #[tokio::test]
   async fn test_delete_key_is_noop_without_selected_row() {
     let mut app = App::default();
@@ -2516,6 +3311,176 @@ mod tests {
     let w = KeyEvent::from(KeyCode::Char('w'));
     handle_key_events(Key::from(w), w, &mut app).await;
     assert!(!app.log_wrap, "'w' toggles wrap in the log view");
+
+    app
+      .data
+      .logs
+      .add_record(r#"{"level":"info","msg":"up"}"#.into());
+    send_keys(&mut app, &[KeyCode::Char('r')]).await;
+    assert!(app.log_raw, "'r' shows raw structured lines");
+    assert_eq!(
+      app.data.logs.get_plain_text(),
+      "\n{\"level\":\"info\",\"msg\":\"up\"}"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_log_min_level_key_cycles_and_wraps_off() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+
+    let mut seen = vec![];
+    for _ in 0..5 {
+      send_keys(&mut app, &[KeyCode::Char('v')]).await;
+      seen.push(app.data.logs.filter().min_level);
+    }
+    assert_eq!(
+      seen,
+      vec![
+        Some(LogLevel::Debug),
+        Some(LogLevel::Info),
+        Some(LogLevel::Warn),
+        Some(LogLevel::Error),
+        None
+      ]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_log_jump_to_time_needs_timestamps_then_pauses_at_line() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+    app.data.logs.add_records(vec![
+      "2024-05-01T10:00:00Z starting".into(),
+      "2024-05-01T10:00:09Z ready".into(),
+    ]);
+
+    app.log_timestamps = false;
+    send_keys(&mut app, &[KeyCode::Char('g')]).await;
+    assert!(app.input_modal.is_none());
+    assert!(app.status_message.text().contains("needs timestamps"));
+
+    app.log_timestamps = true;
+    send_keys(&mut app, &[KeyCode::Char('g')]).await;
+    let keys: Vec<KeyCode> = "2024-05-01T10:00:05Z"
+      .chars()
+      .map(KeyCode::Char)
+      .chain([KeyCode::Enter])
+      .collect();
+    send_keys(&mut app, &keys).await;
+    assert!(app.input_modal.is_none());
+    assert!(!app.log_auto_scroll, "a jump pauses following");
+
+    app.log_auto_scroll = true;
+    send_keys(&mut app, &[KeyCode::Char('g')]).await;
+    let keys: Vec<KeyCode> = "2024-05-01T11:00:00Z"
+      .chars()
+      .map(KeyCode::Char)
+      .chain([KeyCode::Enter])
+      .collect();
+    send_keys(&mut app, &keys).await;
+    assert!(app.log_auto_scroll);
+    assert!(app
+      .status_message
+      .text()
+      .starts_with("No log line at or after"));
+  }
+
+  #[tokio::test]
+  async fn test_log_search_types_query_then_navigates_matches() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+    app.data.logs.add_records(vec![
+      "starting".into(),
+      "error: first".into(),
+      "ok".into(),
+      "ERROR: second".into(),
+    ]);
+    assert!(app.log_auto_scroll);
+
+    // Typed chars go to the query, not to the `t`/`w` log toggles.
+    send_keys(
+      &mut app,
+      &[
+        KeyCode::Char('/'),
+        KeyCode::Char('e'),
+        KeyCode::Char('r'),
+        KeyCode::Char('r'),
+        KeyCode::Enter,
+      ],
+    )
+    .await;
+    let search = &app.data.logs.search;
+    assert_eq!(search.query, "err");
+    assert!(!search.input_active);
+    assert_eq!(search.match_count(), 2);
+    assert_eq!(search.current_record(), Some(3));
+    assert!(!app.log_auto_scroll, "jumping to a match stops following");
+
+    send_keys(&mut app, &[KeyCode::Char('n')]).await;
+    assert_eq!(app.data.logs.search.current_record(), Some(1));
+    let shift_n = shift_char('N');
+    handle_key_events(Key::from(shift_n), shift_n, &mut app).await;
+    assert_eq!(app.data.logs.search.current_record(), Some(3));
+
+    // Esc clears the search before leaving the log view.
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert!(!app.data.logs.search.is_active());
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
+  }
+
+  #[tokio::test]
+  async fn test_log_filter_keys_set_and_esc_clears_filters() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+    app.data.logs.add_records(vec![
+      "error: first".into(),
+      "GET /healthz".into(),
+      "error: /healthz".into(),
+    ]);
+
+    send_keys(&mut app, &[KeyCode::Char('f')]).await;
+    assert_eq!(
+      app.input_modal.as_ref().map(|input| &input.action),
+      Some(&InputAction::LogFilter { exclude: false })
+    );
+    for c in "err".chars() {
+      send_keys(&mut app, &[KeyCode::Char(c)]).await;
+    }
+    send_keys(&mut app, &[KeyCode::Enter]).await;
+    assert!(app.input_modal.is_none());
+
+    // An invalid exclude pattern keeps the input open with an error.
+    send_keys(
+      &mut app,
+      &[KeyCode::Char('x'), KeyCode::Char('('), KeyCode::Enter],
+    )
+    .await;
+    assert!(app
+      .input_modal
+      .as_ref()
+      .is_some_and(|input| input.error.is_some()));
+    send_keys(
+      &mut app,
+      &[KeyCode::Backspace, KeyCode::Char('z'), KeyCode::Enter],
+    )
+    .await;
+    assert_eq!(app.data.logs.filter().label(), "+err -z");
+    assert_eq!(app.data.logs.visible_count(), (1, 3));
+
+    // Reopening prefills the current pattern.
+    send_keys(&mut app, &[KeyCode::Char('f')]).await;
+    assert_eq!(app.input_modal.as_ref().unwrap().buffer, "err");
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert!(!app.data.logs.filter().is_active());
+    assert_eq!(app.data.logs.visible_count(), (3, 3));
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
   }
 
   #[tokio::test]
@@ -2816,8 +3781,10 @@ mod tests {
         name: format!("web-{id}"),
         local_port: port,
         remote_port: 80,
+        pod: None,
         status: PortForwardStatus::Active,
-        child: None,
+        stats: Default::default(),
+        task: None,
       });
     }
 
@@ -3087,6 +4054,45 @@ mod tests {
     assert!(!app.data.troubleshoot_findings.filter_active);
   }
 
+  #[tokio::test]
+  async fn test_troubleshoot_yaml_key_shows_workload_finding() {
+    use crate::app::{deployments::KubeDeployment, troubleshoot::Severity};
+    use k8s_openapi::api::apps::v1::Deployment;
+
+    let mut app = App::default();
+    app.route_troubleshoot();
+    app.data.troubleshoot_inputs.deployments = ["ns-1", "ns-2"]
+      .map(|ns| {
+        KubeDeployment::from(Deployment {
+          metadata: ObjectMeta {
+            name: Some("api".into()),
+            namespace: Some(ns.into()),
+            ..Default::default()
+          },
+          ..Default::default()
+        })
+      })
+      .into();
+    app
+      .data
+      .troubleshoot_findings
+      .set_items(vec![DisplayFinding {
+        severity: Severity::Warn,
+        reason: "Unavailable replicas".into(),
+        resource_kind: ResourceKind::Deployment,
+        namespace: Some("ns-2".into()),
+        resource_name: "api".into(),
+        message: "1 of 2 replicas unavailable".into(),
+        age: "1m".into(),
+      }]);
+
+    let key_evt = KeyEvent::from(KeyCode::Char('y'));
+    handle_key_events(DEFAULT_KEYBINDING.resource_yaml.key, key_evt, &mut app).await;
+
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Yaml);
+    assert!(app.data.describe_out.get_txt().contains("namespace: ns-2"));
+  }
+
   #[tokio::test]
   async fn test_help_scroll_keys_adjust_offset() {
     let mut app = App::default();
@@ -3571,6 +4577,100 @@ mod tests {
     assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
   }
 
+  #[tokio::test]
+  async fn test_tail_logs_key_takes_query_and_opens_log_view() {
+    let mut app = App::default();
+    app.route_home();
+    app.data.selected.ns = Some("prod".into());
+
+    let p = shift_char('P');
+    handle_key_events(Key::from(p), p, &mut app).await;
+    assert!(matches!(
+      app.input_modal.as_ref().map(|input| &input.action),
+      Some(InputAction::TailLogs { namespace }) if namespace.as_deref() == Some("prod")
+    ));
+    let keys: Vec<KeyCode> = "api -c app"
+      .chars()
+      .map(KeyCode::Char)
+      .chain([KeyCode::Enter])
+      .collect();
+    send_keys(&mut app, &keys).await;
+
+    assert!(app.input_modal.is_none());
+    assert_eq!(app.data.logs.id, "agg:api -c app -n prod");
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
+  }
+
+  #[tokio::test]
+  async fn test_log_split_shares_search_and_scrolls_the_focused_pane() {
+    let mut app = App::default();
+    app
+      .dispatch_container_logs("app".into(), RouteId::Home)
+      .await;
+    app
+      .data
+      .logs
+      .add_records(vec!["ok".into(), "error now".into(), "ok".into()]);
+
+    send_keys(&mut app, &[KeyCode::Char('p')]).await;
+    let log_split = app.data.log_split.as_mut().expect("split open");
+    assert!(!log_split.focus_previous);
+    log_split
+      .previous
+      .add_records(vec!["error then".into(), "ok".into()]);
+
+    send_keys(
+      &mut app,
+      &[
+        KeyCode::Char('/'),
+        KeyCode::Char('e'),
+        KeyCode::Char('r'),
+        KeyCode::Char('r'),
+        KeyCode::Enter,
+      ],
+    )
+    .await;
+    let log_split = app.data.log_split.as_ref().unwrap();
+    assert_eq!(log_split.previous.search.query, "err");
+    assert_eq!(log_split.previous.search.current_record(), Some(0));
+
+    // Left focuses the previous instance; scrolling leaves the live pane alone.
+    app.log_auto_scroll = true;
+    send_keys(&mut app, &[KeyCode::Left, KeyCode::Up]).await;
+    let log_split = app.data.log_split.as_ref().unwrap();
+    assert!(log_split.focus_previous);
+    assert!(!log_split.follow);
+    assert!(app.log_auto_scroll);
+
+    // Esc clears the shared search, then leaves the view and the split.
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert!(app
+      .data
+      .log_split
+      .as_ref()
+      .unwrap()
+      .previous
+      .search
+      .query
+      .is_empty());
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert!(app.data.log_split.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_log_split_needs_a_single_container() {
+    let mut app = App::default();
+    app.dispatch_pod_logs("api".into(), RouteId::Home).await;
+
+    send_keys(&mut app, &[KeyCode::Char('p')]).await;
+
+    assert!(app.data.log_split.is_none());
+    assert_eq!(
+      app.status_message.text(),
+      "Side-by-side view needs a single container's live logs"
+    );
+  }
+
   #[tokio::test]
   async fn test_escape_from_more_selected_resource_returns_to_more_not_dynamic() {
     let mut app = App::default();
@@ -13,6 +13,7 @@ use std::{
   fs::File,
   io::{self, stdout, Stdout},
   panic::{self, PanicHookInfo},
+  path::PathBuf,
   sync::Arc,
 };
 
@@ -20,10 +21,9 @@ use anyhow::{anyhow, Result};
 use app::{key_binding::initialize_keybindings, App, DEFAULT_LOG_TAIL_LINES};
 use banner::BANNER;
 use chrono::{self};
-use clap::{builder::PossibleValuesParser, Parser};
+use clap::{builder::PossibleValuesParser, Parser, Subcommand};
 use cmd::{
   edit::{prepare_edit, run_edit, EditTarget},
-  shell::{prepare_shell_exec, run_shell_exec, ShellExecTarget},
   CmdRunner, IoCmdEvent,
 };
 use config::load_config;
@@ -36,7 +36,9 @@ use event::Key;
 use log::{info, warn, LevelFilter, SetLoggerError};
 use network::{
   get_client,
+  snapshot::{capture_snapshot, snapshot_client, SnapshotStore},
   stream::{IoStreamEvent, NetworkStream},
+  watch::WatchCache,
   IoEvent, Network,
 };
 use ratatui::{
@@ -83,6 +85,26 @@ pub struct Cli {
   /// Pre-select a kubeconfig context on startup (same as picking it from the Contexts view).
   #[arg(short = 'c', long, value_parser)]
   pub context: Option<String>,
+  /// Browse a directory of saved YAML/JSON manifests offline instead of a live cluster.
+  /// Everything is read-only in this mode.
+  #[arg(long, value_parser, value_name = "DIR", conflicts_with = "context")]
+  pub snapshot: Option<PathBuf>,
+  #[command(subcommand)]
+  pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+  /// Write every resource kind KDash knows about to `<out>/<namespace>/<kind>/<name>.yaml`
+  /// and exit. Honours `--context` and `--namespace`; the result can be browsed with `--snapshot`.
+  Snapshot {
+    /// Directory to write the snapshot into.
+    #[arg(long, value_parser, value_name = "DIR")]
+    out: PathBuf,
+    /// Replace the values of every Secret with a placeholder.
+    #[arg(long)]
+    redact_secrets: bool,
+  },
 }
 
 #[tokio::main]
@@ -113,6 +135,26 @@ async fn main() -> Result<()> {
     panic!("Poll rate must be multiple of tick-rate");
   }
 
+  // load the snapshot up front so a bad directory fails before the UI starts
+  let snapshot = match &cli.snapshot {
+    Some(dir) => Some(Arc::new(SnapshotStore::load(dir)?)),
+    None => None,
+  };
+
+  if let Some(Command::Snapshot {
+    out,
+    redact_secrets,
+  }) = &cli.command
+  {
+    let client = connect(cli.context.clone(), snapshot).await?;
+    let summary = capture_snapshot(&client, out, cli.namespace.as_deref(), *redact_secrets).await?;
+    for skipped in &summary.skipped {
+      eprintln!("skipped {skipped}");
+    }
+    println!("{}", summary.describe(out));
+    return Ok(());
+  }
+
   // channels for communication between network/cmd threads & UI thread
   let (sync_io_tx, sync_io_rx) = mpsc::channel::<IoEvent>(500);
   let (sync_io_stream_tx, sync_io_stream_rx) = mpsc::channel::<IoStreamEvent>(500);
@@ -142,6 +184,7 @@ async fn main() -> Result<()> {
     // Seed startup scoping from CLI flags so the first client connection and the
     // UI reflect the requested namespace/context (#545).
     seed_startup_selection(&mut app, cli.namespace.clone(), cli.context.clone());
+    app.snapshot = cli.snapshot.clone();
     if app.config.keybindings.is_some() || app.config.theme.is_some() {
       info!("Loaded config overrides from file");
     }
@@ -159,6 +202,10 @@ async fn main() -> Result<()> {
   let app_stream = Arc::clone(&app);
   let app_cli = Arc::clone(&app);
   let network_context = cli.context.clone();
+  let stream_snapshot = snapshot.clone();
+  // one set of watches serves both network tasks
+  let watches = WatchCache::default();
+  let stream_watches = watches.clone();
 
   std::thread::spawn(move || {
     let rt = tokio::runtime::Builder::new_multi_thread()
@@ -171,12 +218,19 @@ async fn main() -> Result<()> {
       let stream_context = network_context.clone();
       tokio::spawn(async move {
         info!("Starting network task");
-        start_network(sync_io_rx, &app_nw, network_context).await;
+        start_network(sync_io_rx, &app_nw, network_context, snapshot, watches).await;
       });
 
       tokio::spawn(async move {
         info!("Starting network stream task");
-        start_stream_network(sync_io_stream_rx, &app_stream, stream_context).await;
+        start_stream_network(
+          sync_io_stream_rx,
+          &app_stream,
+          stream_context,
+          stream_snapshot,
+          stream_watches,
+        )
+        .await;
       });
 
       tokio::spawn(async move {
@@ -199,14 +253,29 @@ async fn main() -> Result<()> {
   Ok(())
 }
 
+/// The client the network tasks talk to: the live cluster, or the in-process
+/// server backed by `--snapshot`.
+async fn connect(
+  context: Option<String>,
+  snapshot: Option<Arc<SnapshotStore>>,
+) -> Result<kube::Client> {
+  match snapshot {
+    Some(store) => Ok(snapshot_client(store)),
+    None => get_client(context).await,
+  }
+}
+
 async fn start_network(
   mut io_rx: mpsc::Receiver<IoEvent>,
   app: &Arc<Mutex<App>>,
   context: Option<String>,
+  snapshot: Option<Arc<SnapshotStore>>,
+  watches: WatchCache,
 ) {
-  match get_client(context).await {
+  match connect(context, snapshot).await {
     Ok(client) => {
       let mut network = Network::new(client, app);
+      network.watches = watches;
 
       while let Some(io_event) = io_rx.recv().await {
         info!("Network event received: {:?}", io_event);
@@ -238,10 +307,13 @@ async fn start_stream_network(
   mut io_rx: mpsc::Receiver<IoStreamEvent>,
   app: &Arc<Mutex<App>>,
   context: Option<String>,
+  snapshot: Option<Arc<SnapshotStore>>,
+  watches: WatchCache,
 ) {
-  match get_client(context).await {
+  match connect(context, snapshot).await {
     Ok(client) => {
       let mut network = NetworkStream::new(client, app);
+      network.watches = watches;
 
       while let Some(io_event) = io_rx.recv().await {
         info!("Network stream event received: {:?}", io_event);
@@ -274,7 +346,8 @@ async fn process_event(
   match ev {
     event::Event::Input(key_event) => {
       let key = Key::from(key_event);
-      if key == Key::Ctrl('c') {
+      // Ctrl+C belongs to the remote process while a shell pane is live.
+      if key == Key::Ctrl('c') && !app.is_exec_input_active() {
         true
       } else {
         handlers::handle_key_events(key, key_event, app).await;
@@ -393,7 +466,7 @@ async fn start_ui(cli: Cli, app: &Arc<Mutex<App>>) -> Result<()> {
     }
   }
 
-  // Tear down any active port-forwards so kubectl children don't outlive the UI.
+  // Tear down any active port-forwards so their listeners close with the UI.
   {
     let mut app = app.lock().await;
     app.kill_all_port_forwards();
@@ -443,42 +516,18 @@ impl ShellTerminal for Terminal<CrosstermBackend<Stdout>> {
   }
 }
 
-/// Run a queued terminal action (shell-exec or `kubectl edit`): both suspend the
-/// TUI, run an interactive child inheriting stdio, then restore the TUI.
+/// Run a queued terminal action (`kubectl edit`): suspend the TUI, run an
+/// interactive child inheriting stdio, then restore the TUI.
 async fn execute_pending_terminal_action(
   app: &Arc<Mutex<App>>,
   terminal: &mut Terminal<CrosstermBackend<Stdout>>,
   action: app::PendingTerminalAction,
 ) -> Result<()> {
   match action {
-    app::PendingTerminalAction::Shell(request) => {
-      execute_pending_shell_exec(app, terminal, request).await
-    }
     app::PendingTerminalAction::Edit(request) => execute_pending_edit(app, terminal, request).await,
   }
 }
 
-async fn execute_pending_shell_exec(
-  app: &Arc<Mutex<App>>,
-  terminal: &mut Terminal<CrosstermBackend<Stdout>>,
-  request: app::PendingShellExec,
-) -> Result<()> {
-  let context = app.lock().await.data.selected.context.clone();
-  execute_pending_shell_exec_with(app, terminal, request, move |request| {
-    let target = ShellExecTarget {
-      namespace: request.namespace,
-      pod: request.pod,
-      container: request.container,
-      context,
-    };
-    let command = prepare_shell_exec(&target).map_err(|error| anyhow!(error.to_string()))?;
-    let shell = command.shell.clone();
-    run_shell_exec(&command).map_err(|error| anyhow!(error.to_string()))?;
-    Ok(shell)
-  })
-  .await
-}
-
 async fn execute_pending_edit(
   app: &Arc<Mutex<App>>,
   terminal: &mut Terminal<CrosstermBackend<Stdout>>,
@@ -499,50 +548,6 @@ async fn execute_pending_edit(
   .await
 }
 
-async fn execute_pending_shell_exec_with<F, T>(
-  app: &Arc<Mutex<App>>,
-  terminal: &mut T,
-  request: app::PendingShellExec,
-  run_shell: F,
-) -> Result<()>
-where
-  F: FnOnce(app::PendingShellExec) -> Result<String>,
-  T: ShellTerminal,
-{
-  terminal.suspend()?;
-  let shell_result = run_shell(request.clone());
-  let restore_result = terminal.restore();
-
-  let mut app = app.lock().await;
-
-  if let Err(error) = restore_result {
-    app.handle_error(anyhow!(
-      "Unable to restore terminal after shell exec: {}",
-      error
-    ));
-    return Err(error);
-  }
-
-  match shell_result {
-    Ok(shell) => {
-      app.set_status_message(format!(
-        "Closed {} shell for {}/{}",
-        shell, request.pod, request.container
-      ));
-      Ok(())
-    }
-    Err(error) => {
-      app.handle_error(anyhow!(
-        "Unable to open shell for {}/{}: {}",
-        request.pod,
-        request.container,
-        error
-      ));
-      Ok(())
-    }
-  }
-}
-
 async fn execute_pending_edit_with<F, T>(
   app: &Arc<Mutex<App>>,
   terminal: &mut T,
@@ -676,28 +681,10 @@ fn get_panic_info(info: &PanicHookInfo<'_>) -> (String, String) {
 
 #[cfg(test)]
 mod tests {
-  use super::{
-    execute_pending_shell_exec_with, process_event, resolve_log_tail_lines, seed_startup_selection,
-    Cli,
-  };
+  use super::{process_event, resolve_log_tail_lines, seed_startup_selection, Cli};
   use crate::{app::App, config::KdashConfig, event};
-  use anyhow::anyhow;
   use clap::Parser;
   use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-  use std::sync::Arc;
-  use tokio::sync::Mutex;
-
-  struct StubTerminal;
-
-  impl super::ShellTerminal for StubTerminal {
-    fn suspend(&mut self) -> anyhow::Result<()> {
-      Ok(())
-    }
-
-    fn restore(&mut self) -> anyhow::Result<()> {
-      Ok(())
-    }
-  }
 
   #[test]
   fn test_resolve_log_tail_lines_uses_default() {
@@ -765,61 +752,6 @@ mod tests {
     assert_eq!(app.data.selected.context, None);
   }
 
-  #[tokio::test]
-  async fn test_execute_pending_shell_exec_with_sets_success_status_and_clears_request() {
-    let app = Arc::new(Mutex::new(App::default()));
-    let mut terminal = StubTerminal;
-
-    let result = execute_pending_shell_exec_with(
-      &app,
-      &mut terminal,
-      crate::app::PendingShellExec {
-        namespace: "default".into(),
-        pod: "api-123".into(),
-        container: "web".into(),
-      },
-      |_| Ok("/bin/sh".into()),
-    )
-    .await;
-
-    assert!(result.is_ok());
-
-    let app = app.lock().await;
-    assert!(app.api_error.is_empty());
-    assert_eq!(
-      app.status_message.text(),
-      "Closed /bin/sh shell for api-123/web"
-    );
-    assert!(app.pending_shell_exec().is_none());
-  }
-
-  #[tokio::test]
-  async fn test_execute_pending_shell_exec_with_reports_shell_errors_after_restoring_terminal() {
-    let app = Arc::new(Mutex::new(App::default()));
-    let mut terminal = StubTerminal;
-
-    let result = execute_pending_shell_exec_with(
-      &app,
-      &mut terminal,
-      crate::app::PendingShellExec {
-        namespace: "default".into(),
-        pod: "api-123".into(),
-        container: "web".into(),
-      },
-      |_| Err(anyhow!("probe failed")),
-    )
-    .await;
-
-    assert!(result.is_ok());
-
-    let app = app.lock().await;
-    assert_eq!(
-      app.api_error,
-      "Unable to open shell for api-123/web: probe failed"
-    );
-    assert!(app.status_message.is_empty());
-  }
-
   #[tokio::test]
   async fn test_process_event_tick_advances_state_and_sets_tick_seen() {
     let mut app = App::default();
@@ -0,0 +1,263 @@
+//! Fetching for native describe: the object comes from the tab's cached
+//! `k8s_obj` when present (falling back to a GET), and its events are listed
+//! with an `involvedObject` field selector.
+
+use std::fmt::Debug;
+
+use anyhow::anyhow;
+use k8s_openapi::{
+  api::{
+    apps::v1::{DaemonSet, Deployment, StatefulSet},
+    batch::v1::{CronJob, Job},
+    core::v1::{Event, Node, PersistentVolumeClaim, Pod, Service},
+  },
+  NamespaceResourceScope,
+};
+use kube::{api::ListParams, Api, Resource, ResourceExt};
+use log::warn;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::Network;
+use crate::app::{
+  describe::{self, DescribeKind},
+  models::{KubeResource, ScrollableTxt},
+  Data,
+};
+
+impl Network<'_> {
+  /// Render `kubectl describe`-style output for one object into the describe
+  /// pane without shelling out to kubectl.
+  pub async fn describe_resource(&self, kind: &str, name: &str, namespace: Option<&str>) {
+    let Some(describe_kind) = DescribeKind::parse(kind) else {
+      self
+        .handle_error(anyhow!("Native describe is not supported for {}", kind))
+        .await;
+      return;
+    };
+    let result = match describe_kind {
+      DescribeKind::Pod => {
+        self
+          .describe_namespaced::<Pod, _>(
+            describe_kind,
+            |data| data.pods.items.as_slice(),
+            name,
+            namespace,
+            describe::describe_pod,
+          )
+          .await
+      }
+      DescribeKind::Deployment => {
+        self
+          .describe_namespaced::<Deployment, _>(
+            describe_kind,
+            |data| data.deployments.items.as_slice(),
+            name,
+            namespace,
+            describe::describe_deployment,
+          )
+          .await
+      }
+      DescribeKind::StatefulSet => {
+        self
+          .describe_namespaced::<StatefulSet, _>(
+            describe_kind,
+            |data| data.stateful_sets.items.as_slice(),
+            name,
+            namespace,
+            describe::describe_statefulset,
+          )
+          .await
+      }
+      DescribeKind::DaemonSet => {
+        self
+          .describe_namespaced::<DaemonSet, _>(
+            describe_kind,
+            |data| data.daemon_sets.items.as_slice(),
+            name,
+            namespace,
+            describe::describe_daemonset,
+          )
+          .await
+      }
+      DescribeKind::Service => {
+        self
+          .describe_namespaced::<Service, _>(
+            describe_kind,
+            |data| data.services.items.as_slice(),
+            name,
+            namespace,
+            describe::describe_service,
+          )
+          .await
+      }
+      DescribeKind::PersistentVolumeClaim => {
+        self
+          .describe_namespaced::<PersistentVolumeClaim, _>(
+            describe_kind,
+            |data| data.persistent_volume_claims.items.as_slice(),
+            name,
+            namespace,
+            describe::describe_pvc,
+          )
+          .await
+      }
+      DescribeKind::Job => {
+        self
+          .describe_namespaced::<Job, _>(
+            describe_kind,
+            |data| data.jobs.items.as_slice(),
+            name,
+            namespace,
+            describe::describe_job,
+          )
+          .await
+      }
+      DescribeKind::CronJob => {
+        self
+          .describe_namespaced::<CronJob, _>(
+            describe_kind,
+            |data| data.cronjobs.items.as_slice(),
+            name,
+            namespace,
+            describe::describe_cronjob,
+          )
+          .await
+      }
+      DescribeKind::Node => self.describe_node(name).await,
+    };
+
+    match result {
+      Ok(out) => {
+        let mut app = self.app.lock().await;
+        app.data.describe_out = ScrollableTxt::with_string(out);
+      }
+      Err(e) => {
+        self
+          .handle_error(anyhow!("Error running {} describe: {}", kind, e))
+          .await;
+      }
+    }
+  }
+
+  async fn describe_namespaced<K, T>(
+    &self,
+    kind: DescribeKind,
+    items: fn(&Data) -> &[T],
+    name: &str,
+    namespace: Option<&str>,
+    render: fn(&K, &[Event]) -> String,
+  ) -> anyhow::Result<String>
+  where
+    K: Resource<DynamicType = (), Scope = NamespaceResourceScope>
+      + Clone
+      + DeserializeOwned
+      + Debug
+      + Serialize,
+    T: KubeResource<K>,
+  {
+    let namespace = namespace.ok_or_else(|| anyhow!("no namespace given"))?;
+    let cached = {
+      let app = self.app.lock().await;
+      find_cached(items(&app.data), name, Some(namespace))
+    };
+    let obj = match cached {
+      Some(obj) => obj,
+      None => {
+        let api: Api<K> = Api::namespaced(self.client.clone(), namespace);
+        api.get(name).await?
+      }
+    };
+    let events = self.related_events(kind, name, Some(namespace)).await;
+    Ok(render(&obj, &events))
+  }
+
+  async fn describe_node(&self, name: &str) -> anyhow::Result<String> {
+    let cached = {
+      let app = self.app.lock().await;
+      find_cached(&app.data.nodes.items, name, None)
+    };
+    let node = match cached {
+      Some(node) => node,
+      None => {
+        let api: Api<Node> = Api::all(self.client.clone());
+        api.get(name).await?
+      }
+    };
+    let events = self.related_events(DescribeKind::Node, name, None).await;
+    Ok(describe::describe_node(&node, &events))
+  }
+
+  /// Events about one object. A failed list (e.g. no RBAC on events) is logged
+  /// and rendered as no events rather than failing the whole describe.
+  async fn related_events(
+    &self,
+    kind: DescribeKind,
+    name: &str,
+    namespace: Option<&str>,
+  ) -> Vec<Event> {
+    let api: Api<Event> = match namespace.filter(|_| kind.is_namespaced()) {
+      Some(ns) => Api::namespaced(self.client.clone(), ns),
+      None => Api::all(self.client.clone()),
+    };
+    let params =
+      ListParams::default().fields(&describe::events_field_selector(kind, name, namespace));
+    match api.list(&params).await {
+      Ok(list) => list.items,
+      Err(e) => {
+        warn!(
+          "Unable to list events for {}/{}: {}",
+          kind.event_kind(),
+          name,
+          e
+        );
+        vec![]
+      }
+    }
+  }
+}
+
+/// The cached object for `name` (and `namespace`, when namespaced) from a tab's
+/// items.
+fn find_cached<K, T>(items: &[T], name: &str, namespace: Option<&str>) -> Option<K>
+where
+  K: Resource + Clone + Serialize,
+  T: KubeResource<K>,
+{
+  items
+    .iter()
+    .map(KubeResource::get_k8s_obj)
+    .find(|obj| obj.name_any() == name && obj.namespace().as_deref() == namespace)
+    .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+  use super::*;
+  use crate::app::pods::KubePod;
+
+  fn pod(name: &str, namespace: &str) -> KubePod {
+    KubePod::from(Pod {
+      metadata: ObjectMeta {
+        name: Some(name.into()),
+        namespace: Some(namespace.into()),
+        ..Default::default()
+      },
+      ..Default::default()
+    })
+  }
+
+  #[test]
+  fn test_find_cached_matches_name_and_namespace() {
+    let items = vec![pod("web", "default"), pod("web", "staging")];
+
+    let found: Option<Pod> = find_cached(&items, "web", Some("staging"));
+    assert_eq!(
+      found.and_then(|p| p.metadata.namespace),
+      Some("staging".into())
+    );
+    assert!(find_cached::<Pod, _>(&items, "web", Some("prod")).is_none());
+    assert!(find_cached::<Pod, _>(&items, "api", Some("default")).is_none());
+  }
+}
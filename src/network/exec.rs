@@ -0,0 +1,256 @@
+//! In-process container shell over the pod `exec` websocket.
+//!
+//! Output is fed into the app's [`ExecSession`](crate::app::exec::ExecSession)
+//! screen; keys and pane resizes come back through the session's input channel.
+//! Closing the pane drops that channel, which aborts the remote process.
+
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use futures::{channel::mpsc::Sender, SinkExt};
+use k8s_openapi::{api::core::v1::Pod, apimachinery::pkg::apis::meta::v1::Status};
+use kube::{
+  api::{AttachParams, TerminalSize},
+  Api, Client,
+};
+use log::error;
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  sync::{mpsc, Mutex},
+};
+
+use crate::{
+  app::{exec::ExecInput, App},
+  cmd::shell::{detect_shell, is_missing_shell_error, ShellExecTarget, ShellProbeResult},
+};
+
+const READ_BUF_SIZE: usize = 8192;
+
+/// Run a shell session for `target` until it exits or its pane is closed.
+pub async fn run_exec_session(
+  client: Client,
+  app: Arc<Mutex<App>>,
+  id: u64,
+  target: ShellExecTarget,
+) {
+  let api: Api<Pod> = Api::namespaced(client, &target.namespace);
+  let result = exec_shell(&api, &app, id, &target).await;
+
+  let mut app = app.lock().await;
+  match result {
+    Ok((shell, reason)) => {
+      if let Some(session) = app.exec_session_mut(id) {
+        session.finish(reason);
+      }
+      app.set_status_message(format!(
+        "Closed {} shell for {}/{}",
+        shell, target.pod, target.container
+      ));
+    }
+    Err(e) => {
+      error!("{:?}", e);
+      if let Some(session) = app.exec_session_mut(id) {
+        session.finish("failed");
+      }
+      app.handle_error(anyhow!(
+        "Unable to open shell for {}/{}: {}",
+        target.pod,
+        target.container,
+        e
+      ));
+    }
+  }
+}
+
+/// Detect a shell, attach a TTY and pump bytes until either side ends. Returns
+/// the shell used and a short exit reason.
+async fn exec_shell(
+  api: &Api<Pod>,
+  app: &Arc<Mutex<App>>,
+  id: u64,
+  target: &ShellExecTarget,
+) -> anyhow::Result<(String, String)> {
+  let shell = detect_shell(target, |shell| probe_shell(api, target, shell))
+    .await
+    .map_err(|e| anyhow!(e.to_string()))?;
+
+  let params = AttachParams::interactive_tty().container(target.container.as_str());
+  let mut attached = api.exec(&target.pod, [shell.as_str()], &params).await?;
+  let (Some(mut stdin), Some(mut stdout)) = (attached.stdin(), attached.stdout()) else {
+    return Err(anyhow!("exec session has no TTY streams"));
+  };
+  let mut resize_tx = attached.terminal_size();
+  let status = attached.take_status();
+
+  let (tx, mut rx) = mpsc::unbounded_channel();
+  let size = {
+    let mut app = app.lock().await;
+    match app.exec_session_mut(id) {
+      Some(session) => session.attach(shell.clone(), tx),
+      None => {
+        attached.abort();
+        return Ok((shell, "closed".into()));
+      }
+    }
+  };
+  send_resize(&mut resize_tx, size).await;
+
+  let mut buf = vec![0u8; READ_BUF_SIZE];
+  loop {
+    tokio::select! {
+      read = stdout.read(&mut buf) => match read {
+        Ok(0) | Err(_) => break,
+        Ok(n) => {
+          let mut app = app.lock().await;
+          match app.exec_session_mut(id) {
+            Some(session) => session.screen.feed(&buf[..n]),
+            None => break,
+          }
+        }
+      },
+      input = rx.recv() => match input {
+        Some(ExecInput::Data(data)) => {
+          if stdin.write_all(&data).await.is_err() || stdin.flush().await.is_err() {
+            break;
+          }
+        }
+        Some(ExecInput::Resize { cols, rows }) => send_resize(&mut resize_tx, (cols, rows)).await,
+        None => {
+          attached.abort();
+          return Ok((shell, "closed".into()));
+        }
+      },
+    }
+  }
+
+  drop(stdin);
+  let reason = match status {
+    Some(status) => status
+      .await
+      .map_or_else(|| "exited".into(), |s| exit_reason(&s)),
+    None => "exited".into(),
+  };
+  Ok((shell, reason))
+}
+
+async fn send_resize(tx: &mut Option<Sender<TerminalSize>>, (width, height): (u16, u16)) {
+  if let Some(tx) = tx {
+    let _ = tx.send(TerminalSize { width, height }).await;
+  }
+}
+
+/// Run `<shell> -c exit` in the container to see whether the shell exists.
+async fn probe_shell(
+  api: &Api<Pod>,
+  target: &ShellExecTarget,
+  shell: &'static str,
+) -> ShellProbeResult {
+  let params = AttachParams::default()
+    .container(target.container.as_str())
+    .stderr(false);
+  let mut attached = match api.exec(&target.pod, [shell, "-c", "exit"], &params).await {
+    Ok(attached) => attached,
+    Err(e) => return ShellProbeResult::Failed(e.to_string()),
+  };
+  match attached.take_status() {
+    Some(status) => classify_probe(status.await, shell),
+    None => ShellProbeResult::Failed("no exit status from exec probe".into()),
+  }
+}
+
+fn classify_probe(status: Option<Status>, shell: &str) -> ShellProbeResult {
+  let Some(status) = status else {
+    return ShellProbeResult::Failed("no exit status from exec probe".into());
+  };
+  if status.status.as_deref() == Some("Success") {
+    return ShellProbeResult::Supported;
+  }
+  let message = status.message.unwrap_or_default();
+  if is_missing_shell_error(&message, shell) {
+    ShellProbeResult::Unsupported
+  } else {
+    ShellProbeResult::Failed(message)
+  }
+}
+
+/// Short reason for the pane title, e.g. `exit code 130`.
+fn exit_reason(status: &Status) -> String {
+  if status.status.as_deref() == Some("Success") {
+    return "exited".into();
+  }
+  status
+    .details
+    .as_ref()
+    .and_then(|details| details.causes.as_ref())
+    .and_then(|causes| {
+      causes
+        .iter()
+        .find(|cause| cause.reason.as_deref() == Some("ExitCode"))
+    })
+    .and_then(|cause| cause.message.as_ref())
+    .map(|code| format!("exit code {code}"))
+    .or_else(|| status.message.clone())
+    .unwrap_or_else(|| "exited with error".into())
+}
+
+#[cfg(test)]
+mod tests {
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::{StatusCause, StatusDetails};
+
+  use super::*;
+
+  fn failure(message: &str) -> Status {
+    Status {
+      status: Some("Failure".into()),
+      message: Some(message.into()),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_classify_probe() {
+    let success = Status {
+      status: Some("Success".into()),
+      ..Default::default()
+    };
+    assert_eq!(
+      classify_probe(Some(success), "/bin/bash"),
+      ShellProbeResult::Supported
+    );
+    assert_eq!(
+      classify_probe(
+        Some(failure(
+          "exec: \"/bin/bash\": stat /bin/bash: no such file or directory: unknown"
+        )),
+        "/bin/bash"
+      ),
+      ShellProbeResult::Unsupported
+    );
+    assert_eq!(
+      classify_probe(Some(failure("container not running")), "/bin/bash"),
+      ShellProbeResult::Failed("container not running".into())
+    );
+    assert!(matches!(
+      classify_probe(None, "/bin/sh"),
+      ShellProbeResult::Failed(_)
+    ));
+  }
+
+  #[test]
+  fn test_exit_reason_prefers_exit_code_cause() {
+    let mut status = failure("command terminated with non-zero exit code");
+    assert_eq!(
+      exit_reason(&status),
+      "command terminated with non-zero exit code"
+    );
+    status.details = Some(StatusDetails {
+      causes: Some(vec![StatusCause {
+        reason: Some("ExitCode".into()),
+        message: Some("130".into()),
+        ..Default::default()
+      }]),
+      ..Default::default()
+    });
+    assert_eq!(exit_reason(&status), "exit code 130");
+  }
+}
@@ -1,4 +1,9 @@
+pub(crate) mod describe;
+pub(crate) mod exec;
+pub(crate) mod port_forward;
+pub(crate) mod snapshot;
 pub(crate) mod stream;
+pub(crate) mod watch;
 
 use core::convert::TryFrom;
 use std::{
@@ -26,7 +31,9 @@ use log::{debug, error, info, warn};
 use serde::de::DeserializeOwned;
 use tokio::{process::Command, sync::Mutex, time::timeout};
 
+use self::watch::{WatchCache, WatchScope};
 use crate::app::{
+  actions::BulkSummary,
   configmaps::ConfigMapResource,
   contexts,
   cronjobs::CronJobResource,
@@ -37,7 +44,7 @@ use crate::app::{
   ingress::IngressResource,
   jobs::JobResource,
   metrics::UtilizationResource,
-  models::{AppResource, StatefulList},
+  models::{AppResource, ListSelector, StatefulList, StatefulTable},
   network_policies::NetworkPolicyResource,
   nodes::NodeResource,
   ns::NamespaceResource,
@@ -53,7 +60,7 @@ use crate::app::{
   storageclass::StorageClassResource,
   svcs::SvcResource,
   troubleshoot::TroubleshootResource,
-  ActiveBlock, App,
+  ActiveBlock, App, Data, ListProgress,
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -95,6 +102,8 @@ pub enum IoEvent {
   GetPodsByNode {
     node_name: String,
   },
+  /// The selected container's previous instance, for the split log view.
+  GetPreviousLogsForSplit,
   DeleteResource {
     block: ActiveBlock,
     name: String,
@@ -110,6 +119,84 @@ pub enum IoEvent {
     name: String,
     namespace: String,
   },
+  /// Apply one operation to every marked row of `block`, one target at a time,
+  /// and report each outcome in a [`crate::app::actions::BulkSummary`].
+  BulkAction {
+    block: ActiveBlock,
+    op: BulkOp,
+    targets: Vec<BulkTarget>,
+  },
+  /// Write a snapshot of the cluster to `dir`; see [`snapshot::capture_snapshot`].
+  CaptureSnapshot {
+    dir: PathBuf,
+    namespace: Option<String>,
+    redact_secrets: bool,
+  },
+  /// Native describe; see [`crate::app::describe`].
+  GetDescribe {
+    kind: String,
+    value: String,
+    ns: Option<String>,
+  },
+}
+
+impl IoEvent {
+  /// A short description of the change this event makes to the cluster, or
+  /// `None` for read-only events.
+  pub fn mutation(&self) -> Option<&'static str> {
+    match self {
+      IoEvent::DeleteResource { .. } => Some("Deleting resources"),
+      IoEvent::PatchResource { .. } => Some("Modifying resources"),
+      IoEvent::TriggerCronJob { .. } => Some("Triggering cronjobs"),
+      IoEvent::BulkAction { op, .. } => Some(match op {
+        BulkOp::Delete => "Deleting resources",
+        BulkOp::Patch(_) => "Modifying resources",
+        BulkOp::TriggerCronJob => "Triggering cronjobs",
+      }),
+      _ => None,
+    }
+  }
+}
+
+/// The operation an [`IoEvent::BulkAction`] applies to each target.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BulkOp {
+  Delete,
+  Patch(ResourcePatch),
+  TriggerCronJob,
+}
+
+impl BulkOp {
+  /// Title of the result summary.
+  pub fn label(&self) -> &'static str {
+    match self {
+      BulkOp::Delete => "Delete",
+      BulkOp::Patch(ResourcePatch::RolloutRestart) => "Restart",
+      BulkOp::Patch(ResourcePatch::SetUnschedulable(true)) => "Cordon",
+      BulkOp::Patch(ResourcePatch::SetUnschedulable(false)) => "Uncordon",
+      BulkOp::Patch(ResourcePatch::SetSuspend(true)) => "Suspend",
+      BulkOp::Patch(ResourcePatch::SetSuspend(false)) => "Resume",
+      BulkOp::Patch(ResourcePatch::SetReplicas(_)) => "Scale",
+      BulkOp::TriggerCronJob => "Trigger",
+    }
+  }
+}
+
+/// One object a bulk action touches.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BulkTarget {
+  pub name: String,
+  pub namespace: Option<String>,
+}
+
+impl BulkTarget {
+  /// `namespace/name` for namespaced objects, `name` otherwise.
+  pub fn label(&self) -> String {
+    match &self.namespace {
+      Some(ns) => format!("{}/{}", ns, self.name),
+      None => self.name.clone(),
+    }
+  }
 }
 
 /// A merge-patch a resource action applies. Kept as a small enum (rather than
@@ -403,15 +490,31 @@ pub async fn get_client(context: Option<String>) -> Result<kube::Client> {
   kube::Client::try_from(client_config).context("Failed to create Kubernetes client")
 }
 
+/// Picks the table a paged list streams into, e.g. `|data| &mut data.pods`.
+pub type TableFn<T> = fn(&mut Data) -> &mut StatefulTable<T>;
+
+/// The server-side selector set on `table`, if any.
+fn table_selector<T>(app: &mut App, table: Option<TableFn<T>>) -> ListSelector {
+  table
+    .and_then(|table| table(&mut app.data).selector.clone())
+    .unwrap_or_default()
+}
+
 #[derive(Clone)]
 pub struct Network<'a> {
   pub client: Client,
   pub app: &'a Arc<Mutex<App>>,
+  /// Watch-backed stores that serve resource lists between polls.
+  pub watches: WatchCache,
 }
 
 impl<'a> Network<'a> {
   pub fn new(client: Client, app: &'a Arc<Mutex<App>>) -> Self {
-    Network { client, app }
+    Network {
+      client,
+      app,
+      watches: WatchCache::default(),
+    }
   }
 
   pub async fn refresh_client(&mut self) {
@@ -427,9 +530,17 @@ impl<'a> Network<'a> {
       (context, ns, main_tab_index, context_tab_index, route)
     };
 
-    match refresh_kube_config(&context).await {
+    let refreshed = if self.app.lock().await.snapshot.is_some() {
+      // a snapshot has nothing to reconnect to; just reload from it
+      Ok(self.client.clone())
+    } else {
+      refresh_kube_config(&context).await
+    };
+    match refreshed {
       Ok(client) => {
         self.client = client;
+        // watches belong to the old client/context
+        self.watches.clear();
         let mut app = self.app.lock().await;
         app.reset();
         app.data.selected.context = context;
@@ -450,6 +561,11 @@ impl<'a> Network<'a> {
 
   #[allow(clippy::cognitive_complexity)]
   pub async fn handle_network_event(&mut self, io_event: IoEvent) {
+    // remember what the active tab's load read so only changes to those
+    // watches refresh it between polls
+    let is_active_tab = self.app.lock().await.active_tab_event.as_ref() == Some(&io_event);
+    self.watches.take_reads();
+
     match io_event {
       IoEvent::RefreshClient => {
         self.refresh_client().await;
@@ -550,6 +666,9 @@ impl<'a> Network<'a> {
       IoEvent::GetPodsByNode { node_name } => {
         self.get_pods_by_node(&node_name).await;
       }
+      IoEvent::GetPreviousLogsForSplit => {
+        stream::load_previous_logs(self.client.clone(), self.app, true).await;
+      }
       IoEvent::DeleteResource {
         block,
         name,
@@ -572,9 +691,26 @@ impl<'a> Network<'a> {
       IoEvent::TriggerCronJob { name, namespace } => {
         self.trigger_cronjob(&name, &namespace).await;
       }
+      IoEvent::BulkAction { block, op, targets } => {
+        self.bulk_action(block, op, targets).await;
+      }
+      IoEvent::GetDescribe { kind, value, ns } => {
+        self.describe_resource(&kind, &value, ns.as_deref()).await;
+      }
+      IoEvent::CaptureSnapshot {
+        dir,
+        namespace,
+        redact_secrets,
+      } => {
+        self.capture_snapshot(dir, namespace, redact_secrets);
+      }
     };
 
+    let reads = self.watches.take_reads();
     let mut app = self.app.lock().await;
+    if is_active_tab {
+      app.active_tab_watches = reads;
+    }
     app.loading_complete();
   }
 
@@ -585,6 +721,13 @@ impl<'a> Network<'a> {
   }
 
   pub async fn get_kube_config(&self) {
+    {
+      let mut app = self.app.lock().await;
+      if let Some(dir) = app.snapshot.clone() {
+        app.set_contexts(vec![snapshot::snapshot_context(&dir)]);
+        return;
+      }
+    }
     match load_local_kubeconfig() {
       Ok(Some(config)) => {
         info!("Using Kubeconfig");
@@ -625,18 +768,58 @@ impl<'a> Network<'a> {
     }
   }
 
-  /// calls the kubernetes API to list the given resource for either selected namespace or all namespaces
+  /// calls the kubernetes API to list the given resource for either selected namespace or all namespaces.
+  /// `max_items_per_tab` does not apply: views such as troubleshoot cross-reference these lists and
+  /// would draw wrong conclusions from a partial one.
   pub async fn get_namespaced_resources<K, T, F>(&self, map_fn: F) -> Vec<T>
   where
     <K as ApiResource>::DynamicType: Default,
     K: kube::Resource<Scope = NamespaceResourceScope>,
-    K: Clone + DeserializeOwned + fmt::Debug,
+    K: Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+    T: Clone,
+    F: Fn(K) -> T,
+  {
+    self.list_namespaced(map_fn, None, false).await
+  }
+
+  /// Same as [`Network::get_namespaced_resources`] but streams each page into
+  /// `table` as it arrives and sets the final list there.
+  pub async fn load_namespaced_resources<K, T, F>(&self, map_fn: F, table: TableFn<T>)
+  where
+    <K as ApiResource>::DynamicType: Default,
+    K: kube::Resource<Scope = NamespaceResourceScope>,
+    K: Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+    T: Clone,
+    F: Fn(K) -> T,
+  {
+    let items = self.list_namespaced(map_fn, Some(table), true).await;
+    let mut app = self.app.lock().await;
+    table(&mut app.data).set_items(items);
+  }
+
+  async fn list_namespaced<K, T, F>(
+    &self,
+    map_fn: F,
+    table: Option<TableFn<T>>,
+    capped: bool,
+  ) -> Vec<T>
+  where
+    <K as ApiResource>::DynamicType: Default,
+    K: kube::Resource<Scope = NamespaceResourceScope>,
+    K: Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+    T: Clone,
     F: Fn(K) -> T,
   {
     let api: Api<K> = self.get_namespaced_api().await;
-    let lp = ListParams::default();
-    match api.list(&lp).await {
-      Ok(list) => list.into_iter().map(map_fn).collect::<Vec<_>>(),
+    let scope = {
+      let mut app = self.app.lock().await;
+      WatchScope {
+        namespace: app.data.selected.ns.clone(),
+        selector: table_selector(&mut app, table),
+      }
+    };
+    match self.list_mapped(api, scope, map_fn, table, capped).await {
+      Ok(items) => items,
       Err(e) => {
         self
           .handle_error(anyhow!(
@@ -650,17 +833,35 @@ impl<'a> Network<'a> {
     }
   }
 
-  /// calls the kubernetes API to list the given resource for all namespaces
-  pub async fn get_resources<K, T, F>(&self, map_fn: F) -> Vec<T>
+  /// calls the kubernetes API to list the given cluster-scoped resource (or a
+  /// namespaced one across all namespaces), streaming each page into `table`
+  /// as it arrives and setting the final list there.
+  pub async fn load_resources<K, T, F>(&self, map_fn: F, table: TableFn<T>)
   where
     <K as ApiResource>::DynamicType: Default,
-    K: ApiResource + Clone + DeserializeOwned + fmt::Debug,
+    K: ApiResource + Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+    T: Clone,
+    F: Fn(K) -> T,
+  {
+    let items = self.list_cluster(map_fn, Some(table)).await;
+    let mut app = self.app.lock().await;
+    table(&mut app.data).set_items(items);
+  }
+
+  async fn list_cluster<K, T, F>(&self, map_fn: F, table: Option<TableFn<T>>) -> Vec<T>
+  where
+    <K as ApiResource>::DynamicType: Default,
+    K: ApiResource + Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+    T: Clone,
     F: Fn(K) -> T,
   {
     let api: Api<K> = Api::all(self.client.clone());
-    let lp = ListParams::default();
-    match api.list(&lp).await {
-      Ok(list) => list.into_iter().map(map_fn).collect::<Vec<_>>(),
+    let scope = WatchScope {
+      namespace: None,
+      selector: table_selector(&mut *self.app.lock().await, table),
+    };
+    match self.list_mapped(api, scope, map_fn, table, true).await {
+      Ok(items) => items,
       Err(e) => {
         self
           .handle_error(anyhow!(
@@ -674,6 +875,146 @@ impl<'a> Network<'a> {
     }
   }
 
+  /// List `K` through the watch cache without mapping; see [`Network::list_mapped`].
+  pub async fn list_watched<K>(&self, api: Api<K>, scope: WatchScope) -> kube::Result<Vec<K>>
+  where
+    K: ApiResource + Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+  {
+    self.list_mapped(api, scope, |obj| obj, None, true).await
+  }
+
+  /// Like [`Network::list_watched`] but ignoring `max_items_per_tab`, for
+  /// lists that are cross-referenced rather than shown, where a partial list
+  /// gives wrong answers (pod counts, selector matches, log tail targets).
+  pub async fn list_all_watched<K>(&self, api: Api<K>, scope: WatchScope) -> kube::Result<Vec<K>>
+  where
+    K: ApiResource + Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+  {
+    self.list_mapped(api, scope, |obj| obj, None, false).await
+  }
+
+  /// List `K` through the watch cache and map it with `map_fn`. A running
+  /// watch serves the list from its store. Otherwise the collection is listed
+  /// in pages of `list_page_size` using continue tokens; every page updates the
+  /// header progress and, when given, is appended to `table`. A complete list
+  /// seeds a new watch from its `resourceVersion` unless a watch failed
+  /// recently. When `capped`, listing stops at `max_items_per_tab`; a
+  /// truncated list is not watched, so capped kinds keep polling. `scope`
+  /// carries the namespace `api` is bound to and the tab's server-side
+  /// selector.
+  async fn list_mapped<K, T, F>(
+    &self,
+    api: Api<K>,
+    scope: WatchScope,
+    map_fn: F,
+    table: Option<TableFn<T>>,
+    capped: bool,
+  ) -> kube::Result<Vec<T>>
+  where
+    K: ApiResource + Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+    T: Clone,
+    F: Fn(K) -> T,
+  {
+    let (page_size, max_items, changes) = {
+      let app = self.app.lock().await;
+      (
+        app.config.list_page_size(),
+        app.config.max_items_per_tab.filter(|_| capped),
+        app.watch_changes.clone(),
+      )
+    };
+    self.watches.record_read::<K>(&scope);
+
+    if let Some(objects) = self.watches.snapshot::<K>(&scope) {
+      let limit = max_items.unwrap_or(usize::MAX);
+      return Ok(objects.into_iter().take(limit).map(map_fn).collect());
+    }
+
+    let start_watch = self.watches.should_start::<K>(&scope);
+    let kind = crate::app::utils::friendly_type_name::<T>();
+    let mut objects = vec![];
+    let mut items = vec![];
+    let mut lp = scope
+      .selector
+      .list_params(ListParams::default().limit(page_size));
+    let mut truncated = false;
+    let mut shown_progress = false;
+
+    let resource_version = loop {
+      let page = match api.list(&lp).await {
+        Ok(page) => page,
+        Err(e) => {
+          if shown_progress {
+            self.app.lock().await.list_progress = None;
+          }
+          return Err(e);
+        }
+      };
+      let token = page.metadata.continue_.clone().filter(|t| !t.is_empty());
+      let remaining = page.metadata.remaining_item_count;
+      let page_len = page.items.len();
+      let page_start = items.len();
+      for obj in page.items {
+        if max_items.is_some_and(|max| items.len() >= max) {
+          truncated = true;
+          break;
+        }
+        if start_watch {
+          objects.push(obj.clone());
+        }
+        items.push(map_fn(obj));
+      }
+      if max_items.is_some_and(|max| items.len() >= max) && token.is_some() {
+        truncated = true;
+      }
+
+      match token {
+        Some(token) if !truncated => {
+          debug!(
+            "Listed page of {} {} ({} so far)",
+            page_len,
+            kind,
+            items.len()
+          );
+          let mut app = self.app.lock().await;
+          app.list_progress = Some(ListProgress {
+            kind: kind.clone(),
+            loaded: items.len(),
+            total: remaining.map(|r| items.len() + r.max(0) as usize),
+          });
+          // Copy only the new page, so each object is cloned once per list.
+          if let Some(table) = table {
+            let page = items[page_start..].to_vec();
+            if shown_progress {
+              table(&mut app.data).extend_items(page);
+            } else {
+              table(&mut app.data).set_items(page);
+            }
+          }
+          shown_progress = true;
+          lp = lp.continue_token(&token);
+        }
+        _ => break page.metadata.resource_version,
+      }
+    };
+
+    if shown_progress {
+      self.app.lock().await.list_progress = None;
+    }
+    if truncated {
+      info!(
+        "Listing {} stopped at {} items (max_items_per_tab)",
+        kind,
+        items.len()
+      );
+    } else if start_watch {
+      self
+        .watches
+        .start(api, scope, objects, resource_version, page_size, changes);
+    }
+    Ok(items)
+  }
+
   pub async fn get_namespaced_api<K>(&self) -> Api<K>
   where
     <K as ApiResource>::DynamicType: Default,
@@ -758,40 +1099,33 @@ impl<'a> Network<'a> {
   /// refresh the affected view. Works for any block that maps to a mutable
   /// resource (see [`api_resource_for_block`]).
   pub async fn delete_resource(&self, block: ActiveBlock, name: &str, namespace: Option<&str>) {
-    let dynamic_kind = {
-      let app = self.app.lock().await;
-      app.data.selected.dynamic_kind.clone()
-    };
-
-    let Some((api_resource, scope)) = api_resource_for_block(block, dynamic_kind.as_ref()) else {
-      self
-        .handle_error(anyhow!("Delete is not supported for this resource."))
-        .await;
-      return;
-    };
-
-    let api: Api<DynamicObject> = match scope {
-      Scope::Cluster => Api::all_with(self.client.clone(), &api_resource),
-      Scope::Namespaced => match namespace {
-        Some(ns) => Api::namespaced_with(self.client.clone(), ns, &api_resource),
-        None => Api::all_with(self.client.clone(), &api_resource),
-      },
-    };
-
-    match api.delete(name, &DeleteParams::default()).await {
-      Ok(_) => {
+    match self.try_delete_resource(block, name, namespace).await {
+      Ok(status) => {
         let mut app = self.app.lock().await;
-        app.set_status_message(format!("Deleting {}", name));
+        app.set_status_message(status);
         app.dispatch_by_active_block(block).await;
       }
-      Err(e) => {
-        self
-          .handle_error(anyhow!("Failed to delete {}. {}", name, e))
-          .await;
-      }
+      Err(e) => self.handle_error(e).await,
     }
   }
 
+  async fn try_delete_resource(
+    &self,
+    block: ActiveBlock,
+    name: &str,
+    namespace: Option<&str>,
+  ) -> anyhow::Result<String> {
+    let api = self
+      .dynamic_api_for_block(block, namespace)
+      .await
+      .ok_or_else(|| anyhow!("Delete is not supported for this resource."))?;
+    api
+      .delete(name, &DeleteParams::default())
+      .await
+      .map_err(|e| anyhow!("Failed to delete {}. {}", name, e))?;
+    Ok(format!("Deleting {}", name))
+  }
+
   /// Apply a merge patch to the named resource for the given block via the
   /// dynamic `Api`, then refresh the affected view.
   pub async fn patch_resource(
@@ -801,66 +1135,84 @@ impl<'a> Network<'a> {
     namespace: Option<&str>,
     patch: ResourcePatch,
   ) {
+    match self
+      .try_patch_resource(block, name, namespace, &patch)
+      .await
+    {
+      Ok(status) => {
+        let mut app = self.app.lock().await;
+        app.set_status_message(status);
+        app.dispatch_by_active_block(block).await;
+      }
+      Err(e) => self.handle_error(e).await,
+    }
+  }
+
+  async fn try_patch_resource(
+    &self,
+    block: ActiveBlock,
+    name: &str,
+    namespace: Option<&str>,
+    patch: &ResourcePatch,
+  ) -> anyhow::Result<String> {
+    let api = self
+      .dynamic_api_for_block(block, namespace)
+      .await
+      .ok_or_else(|| anyhow!("This action is not supported for this resource."))?;
+    let body = patch.to_merge_patch();
+    api
+      .patch(name, &PatchParams::default(), &Patch::Merge(body))
+      .await
+      .map_err(|e| anyhow!("Failed to update {}. {}", name, e))?;
+    Ok(patch.status_message(name))
+  }
+
+  /// The dynamic `Api` for `block`, scoped to `namespace` when the resource is
+  /// namespaced, or `None` when the block has no mutable resource.
+  async fn dynamic_api_for_block(
+    &self,
+    block: ActiveBlock,
+    namespace: Option<&str>,
+  ) -> Option<Api<DynamicObject>> {
     let dynamic_kind = {
       let app = self.app.lock().await;
       app.data.selected.dynamic_kind.clone()
     };
-
-    let Some((api_resource, scope)) = api_resource_for_block(block, dynamic_kind.as_ref()) else {
-      self
-        .handle_error(anyhow!("This action is not supported for this resource."))
-        .await;
-      return;
-    };
-
-    let api: Api<DynamicObject> = match scope {
+    let (api_resource, scope) = api_resource_for_block(block, dynamic_kind.as_ref())?;
+    Some(match scope {
       Scope::Cluster => Api::all_with(self.client.clone(), &api_resource),
       Scope::Namespaced => match namespace {
         Some(ns) => Api::namespaced_with(self.client.clone(), ns, &api_resource),
         None => Api::all_with(self.client.clone(), &api_resource),
       },
-    };
+    })
+  }
 
-    let body = patch.to_merge_patch();
-    match api
-      .patch(name, &PatchParams::default(), &Patch::Merge(body))
-      .await
-    {
-      Ok(_) => {
+  /// Trigger an immediate run of a cronjob by creating a Job from its
+  /// `jobTemplate`, mirroring `kubectl create job --from=cronjob/<name>`.
+  pub async fn trigger_cronjob(&self, name: &str, namespace: &str) {
+    match self.try_trigger_cronjob(name, namespace).await {
+      Ok(status) => {
         let mut app = self.app.lock().await;
-        app.set_status_message(patch.status_message(name));
-        app.dispatch_by_active_block(block).await;
-      }
-      Err(e) => {
-        self
-          .handle_error(anyhow!("Failed to update {}. {}", name, e))
-          .await;
+        app.set_status_message(status);
+        app.dispatch_by_active_block(ActiveBlock::CronJobs).await;
       }
+      Err(e) => self.handle_error(e).await,
     }
   }
 
-  /// Trigger an immediate run of a cronjob by creating a Job from its
-  /// `jobTemplate`, mirroring `kubectl create job --from=cronjob/<name>`.
-  pub async fn trigger_cronjob(&self, name: &str, namespace: &str) {
+  async fn try_trigger_cronjob(&self, name: &str, namespace: &str) -> anyhow::Result<String> {
     use k8s_openapi::api::batch::v1::{CronJob, Job};
     use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
 
     let cj_api: Api<CronJob> = Api::namespaced(self.client.clone(), namespace);
-    let cronjob = match cj_api.get(name).await {
-      Ok(cronjob) => cronjob,
-      Err(e) => {
-        self
-          .handle_error(anyhow!("Failed to read cronjob {}. {}", name, e))
-          .await;
-        return;
-      }
-    };
+    let cronjob = cj_api
+      .get(name)
+      .await
+      .map_err(|e| anyhow!("Failed to read cronjob {}. {}", name, e))?;
 
     let Some(template) = cronjob.spec.map(|spec| spec.job_template) else {
-      self
-        .handle_error(anyhow!("CronJob {} has no job template.", name))
-        .await;
-      return;
+      return Err(anyhow!("CronJob {} has no job template.", name));
     };
 
     let mut job = Job {
@@ -890,28 +1242,82 @@ impl<'a> Network<'a> {
     }]);
 
     let job_api: Api<Job> = Api::namespaced(self.client.clone(), namespace);
-    match job_api.create(&PostParams::default(), &job).await {
-      Ok(created) => {
-        let mut app = self.app.lock().await;
-        app.set_status_message(format!(
-          "Triggered cronjob {} (created job {})",
-          name,
-          created.metadata.name.as_deref().unwrap_or("?")
-        ));
-        app.dispatch_by_active_block(ActiveBlock::CronJobs).await;
-      }
-      Err(e) => {
-        self
-          .handle_error(anyhow!("Failed to trigger cronjob {}. {}", name, e))
-          .await;
+    let created = job_api
+      .create(&PostParams::default(), &job)
+      .await
+      .map_err(|e| anyhow!("Failed to trigger cronjob {}. {}", name, e))?;
+    Ok(format!(
+      "Triggered cronjob {} (created job {})",
+      name,
+      created.metadata.name.as_deref().unwrap_or("?")
+    ))
+  }
+
+  /// Run `op` against each target in turn. One failure doesn't stop the rest;
+  /// every outcome lands in the bulk summary overlay and the view is refreshed
+  /// once at the end.
+  pub async fn bulk_action(&self, block: ActiveBlock, op: BulkOp, targets: Vec<BulkTarget>) {
+    let mut results = Vec::with_capacity(targets.len());
+    for target in targets {
+      let namespace = target.namespace.as_deref();
+      let outcome = match &op {
+        BulkOp::Delete => {
+          self
+            .try_delete_resource(block, &target.name, namespace)
+            .await
+        }
+        BulkOp::Patch(patch) => {
+          self
+            .try_patch_resource(block, &target.name, namespace, patch)
+            .await
+        }
+        BulkOp::TriggerCronJob => {
+          self
+            .try_trigger_cronjob(&target.name, namespace.unwrap_or_default())
+            .await
+        }
+      };
+      if let Err(e) = &outcome {
+        error!("{:?}", e);
       }
+      results.push((target.label(), outcome.map_err(|e| e.to_string())));
     }
+
+    let summary = BulkSummary {
+      title: format!("{} results", op.label()),
+      results,
+    };
+    let mut app = self.app.lock().await;
+    app.set_status_message(format!(
+      "{}: {} succeeded, {} failed",
+      op.label(),
+      summary.results.len() - summary.failed(),
+      summary.failed()
+    ));
+    app.bulk_summary = Some(summary);
+    app.dispatch_by_active_block(block).await;
+  }
+
+  /// Capture in the background so polling carries on; the outcome is reported
+  /// as a status message.
+  fn capture_snapshot(&self, dir: PathBuf, namespace: Option<String>, redact_secrets: bool) {
+    let client = self.client.clone();
+    let app = Arc::clone(self.app);
+    tokio::spawn(async move {
+      let result =
+        snapshot::capture_snapshot(&client, &dir, namespace.as_deref(), redact_secrets).await;
+      let mut app = app.lock().await;
+      match result {
+        Ok(summary) => app.set_status_message(summary.describe(&dir)),
+        Err(e) => app.handle_error(anyhow!("Failed to capture snapshot. {}", e)),
+      }
+    });
   }
 
   /// Discover and cache custom resources on the cluster
   pub async fn discover_dynamic_resources(&self) {
-    let api_groups = match self.client.list_api_groups().await {
-      Ok(groups) => groups.groups,
+    let dynamic_resources = match discover_dynamic_kinds(&self.client).await {
+      Ok(kinds) => kinds,
       Err(e) => {
         self
           .handle_error(anyhow!("Failed to get dynamic resources. {}", e))
@@ -919,74 +1325,81 @@ impl<'a> Network<'a> {
         return;
       }
     };
+    let mut dynamic_menu: Vec<(String, ActiveBlock)> = dynamic_resources
+      .iter()
+      .map(|kind| (kind.kind.clone(), ActiveBlock::DynamicResource))
+      .collect();
+    let mut app = self.app.lock().await;
+    // sort dynamic_menu alphabetically using the first element of the tuple
+    dynamic_menu.sort_by(|a, b| a.0.cmp(&b.0));
+    app.dynamic_resources_menu = StatefulList::with_items(dynamic_menu);
+    app.data.dynamic_kinds = dynamic_resources;
+  }
+}
 
-    let mut dynamic_resources = vec![];
-    let mut dynamic_menu = vec![];
-
-    let excluded = [
-      "Namespace",
-      "Pod",
-      "Service",
-      "Node",
-      "ConfigMap",
-      "StatefulSet",
-      "ReplicaSet",
-      "Deployment",
-      "Job",
-      "DaemonSet",
-      "CronJob",
-      "Secret",
-      "ReplicationController",
-      "PersistentVolumeClaim",
-      "PersistentVolume",
-      "StorageClass",
-      "Role",
-      "RoleBinding",
-      "ClusterRole",
-      "ClusterRoleBinding",
-      "ServiceAccount",
-      "Ingress",
-      "Event",
-      "NetworkPolicy",
-    ];
-
-    for api_group in api_groups {
-      let group_name = api_group.name.clone();
-      let Some(group_version) = preferred_group_version(&api_group) else {
-        warn!(
-          "Skipping dynamic API group '{}' because it has no preferred or parseable version",
-          group_name
-        );
-        continue;
-      };
-
-      match pinned_group(&self.client, &group_version).await {
-        Ok(group) => {
-          for (ar, caps) in group.recommended_resources() {
-            if !caps.supports_operation(verbs::LIST) || excluded.contains(&ar.kind.as_str()) {
-              continue;
-            }
+/// Listable kinds from every API group except the built-in kinds kdash has
+/// dedicated tabs for. Groups that fail discovery are skipped with a warning.
+pub async fn discover_dynamic_kinds(client: &Client) -> Result<Vec<KubeDynamicKind>> {
+  let api_groups = client.list_api_groups().await?.groups;
+  let mut dynamic_resources = vec![];
+
+  let excluded = [
+    "Namespace",
+    "Pod",
+    "Service",
+    "Node",
+    "ConfigMap",
+    "StatefulSet",
+    "ReplicaSet",
+    "Deployment",
+    "Job",
+    "DaemonSet",
+    "CronJob",
+    "Secret",
+    "ReplicationController",
+    "PersistentVolumeClaim",
+    "PersistentVolume",
+    "StorageClass",
+    "Role",
+    "RoleBinding",
+    "ClusterRole",
+    "ClusterRoleBinding",
+    "ServiceAccount",
+    "Ingress",
+    "Event",
+    "NetworkPolicy",
+  ];
+
+  for api_group in api_groups {
+    let group_name = api_group.name.clone();
+    let Some(group_version) = preferred_group_version(&api_group) else {
+      warn!(
+        "Skipping dynamic API group '{}' because it has no preferred or parseable version",
+        group_name
+      );
+      continue;
+    };
 
-            dynamic_menu.push((ar.kind.to_string(), ActiveBlock::DynamicResource));
-            dynamic_resources.push(KubeDynamicKind::new(ar, caps.scope));
+    match pinned_group(client, &group_version).await {
+      Ok(group) => {
+        for (ar, caps) in group.recommended_resources() {
+          if !caps.supports_operation(verbs::LIST) || excluded.contains(&ar.kind.as_str()) {
+            continue;
           }
-        }
-        Err(e) => {
-          warn!(
-            "Skipping dynamic API group '{}' at '{}' due to discovery error: {}",
-            group_name,
-            group_version.api_version(),
-            e
-          );
+          dynamic_resources.push(KubeDynamicKind::new(ar, caps.scope));
         }
       }
+      Err(e) => {
+        warn!(
+          "Skipping dynamic API group '{}' at '{}' due to discovery error: {}",
+          group_name,
+          group_version.api_version(),
+          e
+        );
+      }
     }
-    let mut app = self.app.lock().await;
-    // sort dynamic_menu alphabetically using the first element of the tuple
-    dynamic_menu.sort_by(|a, b| a.0.cmp(&b.0));
-    app.dynamic_resources_menu = StatefulList::with_items(dynamic_menu);
-    app.data.dynamic_kinds = dynamic_resources.clone();
   }
+  Ok(dynamic_resources)
 }
 
 fn preferred_group_version(api_group: &DiscoveryApiGroup) -> Option<GroupVersion> {
@@ -0,0 +1,381 @@
+//! In-process port-forwarding over the pod `portforward` websocket.
+//!
+//! A forward binds `127.0.0.1:<local_port>`, resolves the pod to tunnel to
+//! (services go through one ready pod matched by their selector) and opens one
+//! websocket per accepted connection, like `kubectl port-forward` does. Traffic
+//! counters are shared with the app through
+//! [`PortForwardStats`](crate::app::port_forward::PortForwardStats).
+
+use std::{
+  net::Ipv4Addr,
+  sync::{atomic::AtomicU64, atomic::Ordering, Arc},
+};
+
+use anyhow::anyhow;
+use k8s_openapi::{
+  api::core::v1::{Pod, Service},
+  apimachinery::pkg::util::intstr::IntOrString,
+};
+use kube::{api::ListParams, Api, Client};
+use log::{debug, error};
+use tokio::{
+  io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+  net::{TcpListener, TcpStream},
+  sync::Mutex,
+  task::JoinSet,
+};
+
+use crate::{
+  app::{
+    port_forward::{PortForwardStats, PortForwardStatus},
+    App,
+  },
+  cmd::port_forward::PortForwardTarget,
+};
+
+const COPY_BUF_SIZE: usize = 16 * 1024;
+
+/// Run a forward until it fails or its task is aborted by a stop. Failures are
+/// recorded on the forward's status rather than raised as app errors so the
+/// overlay keeps showing them.
+pub async fn run_port_forward(
+  client: Client,
+  app: Arc<Mutex<App>>,
+  id: u64,
+  target: PortForwardTarget,
+  stats: Arc<PortForwardStats>,
+) {
+  if let Err(e) = forward(client, &app, id, &target, &stats).await {
+    error!(
+      "Port-forward {}/{} failed: {:?}",
+      target.kind, target.name, e
+    );
+    let mut app = app.lock().await;
+    app.set_port_forward_status(id, PortForwardStatus::Failed(e.to_string()));
+  }
+}
+
+async fn forward(
+  client: Client,
+  app: &Arc<Mutex<App>>,
+  id: u64,
+  target: &PortForwardTarget,
+  stats: &Arc<PortForwardStats>,
+) -> anyhow::Result<()> {
+  let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, target.local_port))
+    .await
+    .map_err(|e| anyhow!("unable to listen on port {}: {}", target.local_port, e))?;
+
+  let pods: Api<Pod> = Api::namespaced(client.clone(), &target.namespace);
+  let (pod, port) = resolve_backend(client, &pods, target).await?;
+  app.lock().await.set_port_forward_active(id, pod.clone());
+
+  // Dropped (aborting every open connection) when the forward task is aborted.
+  let mut connections = JoinSet::new();
+  loop {
+    tokio::select! {
+      accepted = listener.accept() => {
+        let (socket, peer) = accepted?;
+        debug!("Port-forward {}: connection from {}", target.local_port, peer);
+        let api = pods.clone();
+        let pod = pod.clone();
+        let stats = Arc::clone(stats);
+        connections.spawn(async move {
+          stats.connection_opened();
+          let result = forward_connection(&api, &pod, port, socket, &stats).await;
+          stats.connection_closed();
+          result
+        });
+      }
+      Some(joined) = connections.join_next() => {
+        // Only websocket setup errors surface here (pod gone, RBAC, ...); they
+        // would fail every later connection too, so the forward stops.
+        if let Ok(Err(e)) = joined {
+          return Err(e);
+        }
+      }
+    }
+  }
+}
+
+/// Pick the pod and container port to tunnel to. Pods forward as-is; services
+/// map the requested service port to its target port on a ready backing pod.
+async fn resolve_backend(
+  client: Client,
+  pods: &Api<Pod>,
+  target: &PortForwardTarget,
+) -> anyhow::Result<(String, u16)> {
+  if target.kind != "services" {
+    return Ok((target.name.clone(), target.remote_port));
+  }
+  let services: Api<Service> = Api::namespaced(client, &target.namespace);
+  let service = services.get(&target.name).await?;
+  let selector = service
+    .spec
+    .as_ref()
+    .and_then(|spec| spec.selector.as_ref())
+    .filter(|selector| !selector.is_empty())
+    .map(|selector| {
+      selector
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(",")
+    })
+    .ok_or_else(|| anyhow!("service {} has no pod selector", target.name))?;
+  let candidates = pods.list(&ListParams::default().labels(&selector)).await?;
+  pick_service_backend(&service, &candidates.items, target.remote_port)
+}
+
+/// Choose a running, ready pod (first by name, for a stable pick) and translate
+/// `service_port` into the container port it targets.
+fn pick_service_backend(
+  service: &Service,
+  pods: &[Pod],
+  service_port: u16,
+) -> anyhow::Result<(String, u16)> {
+  let name = service.metadata.name.as_deref().unwrap_or_default();
+  let service_port_spec = service
+    .spec
+    .as_ref()
+    .and_then(|spec| spec.ports.as_ref())
+    .and_then(|ports| {
+      ports
+        .iter()
+        .find(|port| port.port == i32::from(service_port))
+    })
+    .ok_or_else(|| anyhow!("service {} does not expose port {}", name, service_port))?;
+
+  let mut ready: Vec<&Pod> = pods.iter().filter(|pod| is_pod_ready(pod)).collect();
+  ready.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+  let pod = ready
+    .first()
+    .ok_or_else(|| anyhow!("no ready pods back service {}", name))?;
+  let pod_name = pod.metadata.name.clone().unwrap_or_default();
+
+  let container_port = match &service_port_spec.target_port {
+    None => i32::from(service_port),
+    Some(IntOrString::Int(port)) => *port,
+    Some(IntOrString::String(port_name)) => named_container_port(pod, port_name)
+      .ok_or_else(|| anyhow!("pod {} has no container port named {}", pod_name, port_name))?,
+  };
+  let container_port = u16::try_from(container_port)
+    .map_err(|_| anyhow!("invalid target port {} on service {}", container_port, name))?;
+  Ok((pod_name, container_port))
+}
+
+fn is_pod_ready(pod: &Pod) -> bool {
+  if pod.metadata.deletion_timestamp.is_some() {
+    return false;
+  }
+  let Some(status) = pod.status.as_ref() else {
+    return false;
+  };
+  status.phase.as_deref() == Some("Running")
+    && status.conditions.as_ref().is_some_and(|conditions| {
+      conditions
+        .iter()
+        .any(|c| c.type_ == "Ready" && c.status == "True")
+    })
+}
+
+fn named_container_port(pod: &Pod, port_name: &str) -> Option<i32> {
+  pod
+    .spec
+    .as_ref()?
+    .containers
+    .iter()
+    .filter_map(|container| container.ports.as_ref())
+    .flatten()
+    .find(|port| port.name.as_deref() == Some(port_name))
+    .map(|port| port.container_port)
+}
+
+/// Tunnel one local connection over its own websocket until both directions
+/// finish. Errors reported by the pod (e.g. nothing listening on the port) are
+/// recorded on the stats; only failing to open the websocket is returned.
+async fn forward_connection(
+  api: &Api<Pod>,
+  pod: &str,
+  port: u16,
+  mut local: TcpStream,
+  stats: &PortForwardStats,
+) -> anyhow::Result<()> {
+  let mut forwarder = api.portforward(pod, &[port]).await?;
+  let upstream = forwarder
+    .take_stream(port)
+    .ok_or_else(|| anyhow!("port {} missing from portforward session", port))?;
+  let pod_error = forwarder.take_error(port);
+
+  let (mut local_read, mut local_write) = local.split();
+  let (mut upstream_read, mut upstream_write) = tokio::io::split(upstream);
+  let copy = async {
+    tokio::try_join!(
+      pump(&mut local_read, &mut upstream_write, &stats.bytes_sent),
+      pump(&mut upstream_read, &mut local_write, &stats.bytes_received),
+    )
+  };
+  let pod_error = async {
+    match pod_error {
+      Some(pod_error) => pod_error.await,
+      None => std::future::pending().await,
+    }
+  };
+
+  tokio::select! {
+    result = copy => {
+      if let Err(e) = result {
+        debug!("Port-forward connection to {}:{} closed: {}", pod, port, e);
+      }
+    }
+    Some(message) = pod_error => stats.set_last_error(message),
+  }
+  forwarder.abort();
+  Ok(())
+}
+
+/// Copy `from` into `to` until EOF, counting bytes, then half-close `to` so the
+/// other side sees the end of the stream.
+async fn pump<R, W>(from: &mut R, to: &mut W, counter: &AtomicU64) -> std::io::Result<()>
+where
+  R: AsyncRead + Unpin,
+  W: AsyncWrite + Unpin,
+{
+  let mut buf = vec![0u8; COPY_BUF_SIZE];
+  loop {
+    let n = from.read(&mut buf).await?;
+    if n == 0 {
+      break;
+    }
+    to.write_all(&buf[..n]).await?;
+    counter.fetch_add(n as u64, Ordering::Relaxed);
+  }
+  to.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use k8s_openapi::api::core::v1::{
+    Container, ContainerPort, PodCondition, PodSpec, PodStatus, ServicePort, ServiceSpec,
+  };
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+  use super::*;
+
+  fn pod(name: &str, phase: &str, ready: bool) -> Pod {
+    Pod {
+      metadata: ObjectMeta {
+        name: Some(name.into()),
+        ..Default::default()
+      },
+      spec: Some(PodSpec {
+        containers: vec![Container {
+          name: "web".into(),
+          ports: Some(vec![ContainerPort {
+            name: Some("http".into()),
+            container_port: 8080,
+            ..Default::default()
+          }]),
+          ..Default::default()
+        }],
+        ..Default::default()
+      }),
+      status: Some(PodStatus {
+        phase: Some(phase.into()),
+        conditions: Some(vec![PodCondition {
+          type_: "Ready".into(),
+          status: if ready { "True" } else { "False" }.into(),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+    }
+  }
+
+  fn service(target_port: Option<IntOrString>) -> Service {
+    Service {
+      metadata: ObjectMeta {
+        name: Some("web".into()),
+        ..Default::default()
+      },
+      spec: Some(ServiceSpec {
+        selector: Some(BTreeMap::from([("app".into(), "web".into())])),
+        ports: Some(vec![ServicePort {
+          port: 80,
+          target_port,
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_pick_service_backend_maps_target_port() {
+    let pods = vec![
+      pod("web-b", "Running", true),
+      pod("web-a", "Running", false),
+      pod("web-c", "Pending", false),
+      pod("web-d", "Running", true),
+    ];
+
+    assert_eq!(
+      pick_service_backend(&service(None), &pods, 80).unwrap(),
+      ("web-b".into(), 80)
+    );
+    assert_eq!(
+      pick_service_backend(&service(Some(IntOrString::Int(9090))), &pods, 80).unwrap(),
+      ("web-b".into(), 9090)
+    );
+    assert_eq!(
+      pick_service_backend(
+        &service(Some(IntOrString::String("http".into()))),
+        &pods,
+        80
+      )
+      .unwrap(),
+      ("web-b".into(), 8080)
+    );
+  }
+
+  #[test]
+  fn test_pick_service_backend_errors() {
+    let pods = vec![pod("web-a", "Running", true)];
+    let err = pick_service_backend(&service(None), &pods, 443).unwrap_err();
+    assert_eq!(err.to_string(), "service web does not expose port 443");
+
+    let err =
+      pick_service_backend(&service(None), &[pod("web-a", "Running", false)], 80).unwrap_err();
+    assert_eq!(err.to_string(), "no ready pods back service web");
+
+    let err = pick_service_backend(
+      &service(Some(IntOrString::String("grpc".into()))),
+      &pods,
+      80,
+    )
+    .unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "pod web-a has no container port named grpc"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_pump_copies_counts_and_half_closes() {
+    let (mut client, mut server) = tokio::io::duplex(64);
+    let (mut sink_writer, mut sink_reader) = tokio::io::duplex(64);
+    let counter = AtomicU64::new(0);
+
+    client.write_all(b"hello world").await.unwrap();
+    drop(client);
+    pump(&mut server, &mut sink_writer, &counter).await.unwrap();
+
+    let mut received = Vec::new();
+    sink_reader.read_to_end(&mut received).await.unwrap();
+    assert_eq!(received, b"hello world");
+    assert_eq!(counter.load(Ordering::Relaxed), 11);
+  }
+}
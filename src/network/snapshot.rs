@@ -0,0 +1,1107 @@
+//! Offline snapshot mode: browse a directory of saved manifests without a live
+//! API server.
+//!
+//! Every object found under the snapshot directory (YAML or JSON; single
+//! objects, multi-document files and `List`s) is indexed by group, version and
+//! plural resource name. [`snapshot_client`] wraps the index in an in-process
+//! [`Client`] that answers discovery, list and get requests the way the API
+//! server would, applying namespace, label and field selectors. The regular
+//! loaders, describe, troubleshoot and utilization therefore run unchanged
+//! against the snapshot. Watches stay open without events, writes are rejected
+//! with `403` and subresources (logs, exec, port-forward) answer `404`.
+//!
+//! [`capture_snapshot`] is the producer side: it writes every kind kdash knows
+//! about to `<namespace>/<kind>/<name>.yaml`, the layout [`SnapshotStore::load`]
+//! reads back.
+
+use std::{
+  collections::{BTreeMap, BTreeSet, HashSet},
+  convert::Infallible,
+  fs,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use anyhow::{anyhow, Context};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use http::{Method, Request, Response, StatusCode};
+use k8s_openapi::api::core::v1::Namespace;
+use kube::{
+  api::{DynamicObject, ListParams},
+  client::Body,
+  core::{ApiResource, GroupVersionKind, TypeMeta},
+  discovery::Scope,
+  Api, Client, ResourceExt,
+};
+use log::{info, warn};
+use serde_json::{json, Value as JValue};
+
+use super::discover_dynamic_kinds;
+use crate::app::{
+  contexts::KubeContext,
+  dynamic::{api_resource_for_block, KubeDynamicKind},
+  utils::sanitize_obj,
+  ActiveBlock,
+};
+
+/// `resourceVersion` reported for every list; watches started from it never
+/// receive events.
+const SNAPSHOT_RESOURCE_VERSION: &str = "1";
+const MANIFEST_EXTENSIONS: [&str; 3] = ["yaml", "yml", "json"];
+/// Directory cluster-scoped objects are written under.
+const CLUSTER_SCOPE_DIR: &str = "_cluster";
+/// Page size for capture list calls.
+const CAPTURE_PAGE_SIZE: u32 = 500;
+/// Value redacted secret keys are set to.
+const REDACTED: &str = "REDACTED";
+const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+/// Tabs whose kinds a capture writes, besides namespaces and dynamic kinds.
+const CAPTURED_BLOCKS: [ActiveBlock; 23] = [
+  ActiveBlock::Pods,
+  ActiveBlock::Services,
+  ActiveBlock::ConfigMaps,
+  ActiveBlock::Secrets,
+  ActiveBlock::StatefulSets,
+  ActiveBlock::ReplicaSets,
+  ActiveBlock::Deployments,
+  ActiveBlock::Jobs,
+  ActiveBlock::DaemonSets,
+  ActiveBlock::CronJobs,
+  ActiveBlock::ReplicationControllers,
+  ActiveBlock::Roles,
+  ActiveBlock::RoleBindings,
+  ActiveBlock::Ingresses,
+  ActiveBlock::PersistentVolumeClaims,
+  ActiveBlock::NetworkPolicies,
+  ActiveBlock::ServiceAccounts,
+  ActiveBlock::Events,
+  ActiveBlock::Nodes,
+  ActiveBlock::PersistentVolumes,
+  ActiveBlock::StorageClasses,
+  ActiveBlock::ClusterRoles,
+  ActiveBlock::ClusterRoleBindings,
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct ResourceKey {
+  group: String,
+  version: String,
+  plural: String,
+}
+
+#[derive(Debug)]
+struct SnapshotResource {
+  kind: String,
+  namespaced: bool,
+  /// Objects keyed by `(namespace, name)`, matching list call ordering.
+  objects: BTreeMap<(String, String), JValue>,
+}
+
+/// All objects loaded from a snapshot directory.
+#[derive(Debug, Default)]
+pub struct SnapshotStore {
+  resources: BTreeMap<ResourceKey, SnapshotResource>,
+}
+
+impl SnapshotStore {
+  /// Read every `.yaml`, `.yml` and `.json` file under `dir` (recursively).
+  /// Files that do not parse and documents that are not Kubernetes objects are
+  /// skipped with a warning; a snapshot without any object is an error.
+  pub fn load(dir: &Path) -> anyhow::Result<Self> {
+    if !dir.is_dir() {
+      return Err(anyhow!(
+        "snapshot path {} is not a directory",
+        dir.display()
+      ));
+    }
+    let mut files = vec![];
+    collect_manifest_files(dir, &mut files)?;
+    files.sort();
+
+    let mut store = SnapshotStore::default();
+    for file in files {
+      let content =
+        fs::read_to_string(&file).with_context(|| format!("Failed to read {}", file.display()))?;
+      match parse_documents(&file, &content) {
+        Ok(documents) => documents
+          .into_iter()
+          .for_each(|document| store.add_document(document, None)),
+        Err(e) => warn!("Skipping snapshot file {}: {}", file.display(), e),
+      }
+    }
+    if store.resources.is_empty() {
+      return Err(anyhow!(
+        "no Kubernetes objects found in snapshot {}",
+        dir.display()
+      ));
+    }
+    store.add_missing_namespaces();
+    info!(
+      "Loaded snapshot {} with {} objects",
+      dir.display(),
+      store.object_count()
+    );
+    Ok(store)
+  }
+
+  pub fn object_count(&self) -> usize {
+    self.resources.values().map(|r| r.objects.len()).sum()
+  }
+
+  /// Add one parsed document. Lists are flattened; items of typed lists (e.g.
+  /// a raw `PodList` API response) inherit the kind and `apiVersion` they lack.
+  fn add_document(&mut self, document: JValue, list_type: Option<(&str, &str)>) {
+    if document.is_null() {
+      return;
+    }
+    let kind = document
+      .get("kind")
+      .and_then(JValue::as_str)
+      .or(list_type.map(|(_, kind)| kind));
+    let api_version = document
+      .get("apiVersion")
+      .and_then(JValue::as_str)
+      .or(list_type.map(|(api_version, _)| api_version));
+
+    if let (Some(kind), Some(JValue::Array(items))) = (kind, document.get("items")) {
+      if let Some(item_kind) = kind.strip_suffix("List") {
+        let api_version = api_version.unwrap_or("v1").to_owned();
+        let item_kind = item_kind.to_owned();
+        let item_type =
+          (!item_kind.is_empty()).then_some((api_version.as_str(), item_kind.as_str()));
+        for item in items.clone() {
+          self.add_document(item, item_type);
+        }
+        return;
+      }
+    }
+
+    let (Some(kind), Some(api_version)) = (kind, api_version) else {
+      warn!("Skipping snapshot document without kind or apiVersion");
+      return;
+    };
+    let kind = kind.to_owned();
+    let api_version = api_version.to_owned();
+    self.add_object(document, &api_version, &kind);
+  }
+
+  fn add_object(&mut self, mut object: JValue, api_version: &str, kind: &str) {
+    let Some(name) = object
+      .pointer("/metadata/name")
+      .and_then(JValue::as_str)
+      .map(str::to_owned)
+    else {
+      warn!("Skipping {} without metadata.name in snapshot", kind);
+      return;
+    };
+    let namespace = object
+      .pointer("/metadata/namespace")
+      .and_then(JValue::as_str)
+      .map(str::to_owned);
+    if let Some(fields) = object.as_object_mut() {
+      fields.insert("apiVersion".into(), api_version.into());
+      fields.insert("kind".into(), kind.into());
+    }
+
+    let (group, version) = match api_version.split_once('/') {
+      Some((group, version)) => (group, version),
+      None => ("", api_version),
+    };
+    let plural = ApiResource::from_gvk(&GroupVersionKind::gvk(group, version, kind)).plural;
+    let resource = self
+      .resources
+      .entry(ResourceKey {
+        group: group.into(),
+        version: version.into(),
+        plural,
+      })
+      .or_insert_with(|| SnapshotResource {
+        kind: kind.into(),
+        namespaced: false,
+        objects: BTreeMap::new(),
+      });
+    resource.namespaced |= namespace.is_some();
+    // a later file wins for the same object
+    resource
+      .objects
+      .insert((namespace.unwrap_or_default(), name), object);
+  }
+
+  /// Dumps often lack the `Namespace` objects themselves; synthesize one for
+  /// every namespace an object lives in so the namespace picker works.
+  fn add_missing_namespaces(&mut self) {
+    let used: BTreeSet<String> = self
+      .resources
+      .values()
+      .flat_map(|resource| resource.objects.keys())
+      .map(|(namespace, _)| namespace.clone())
+      .filter(|namespace| !namespace.is_empty())
+      .collect();
+    for namespace in used {
+      if self
+        .find("", "v1", "namespaces", None, &namespace)
+        .is_none()
+      {
+        let object = json!({
+          "metadata": { "name": namespace },
+          "status": { "phase": "Active" },
+        });
+        self.add_object(object, "v1", "Namespace");
+      }
+    }
+  }
+
+  fn resource(&self, group: &str, version: &str, plural: &str) -> Option<&SnapshotResource> {
+    self.resources.get(&ResourceKey {
+      group: group.into(),
+      version: version.into(),
+      plural: plural.into(),
+    })
+  }
+
+  fn find(
+    &self,
+    group: &str,
+    version: &str,
+    plural: &str,
+    namespace: Option<&str>,
+    name: &str,
+  ) -> Option<&JValue> {
+    self
+      .resource(group, version, plural)?
+      .objects
+      .get(&(namespace.unwrap_or_default().to_owned(), name.to_owned()))
+  }
+
+  /// Answer one API request the way the API server would.
+  fn respond(&self, method: &Method, path: &str, query: Option<&str>) -> Response<Body> {
+    if method != Method::GET {
+      return status_response(
+        StatusCode::FORBIDDEN,
+        "Forbidden",
+        "kdash is browsing a read-only snapshot",
+      );
+    }
+    let params = query_params(query);
+    let param = |key: &str| {
+      params
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+    };
+
+    match parse_path(path) {
+      Some(ApiPath::CoreVersions) => json_response(json!({
+        "kind": "APIVersions",
+        "versions": ["v1"],
+        "serverAddressByClientCIDRs": [],
+      })),
+      Some(ApiPath::Groups) => json_response(self.api_group_list()),
+      Some(ApiPath::Resources { group, version }) => match self.api_resource_list(group, version) {
+        Some(list) => json_response(list),
+        None => not_found(&format!(
+          "the server could not find the requested resource {}",
+          api_version(group, version)
+        )),
+      },
+      Some(ApiPath::Collection {
+        group,
+        version,
+        namespace,
+        plural,
+      }) => json_response(self.list(
+        group,
+        version,
+        plural,
+        namespace,
+        param("labelSelector").unwrap_or_default(),
+        param("fieldSelector").unwrap_or_default(),
+      )),
+      Some(ApiPath::Object {
+        group,
+        version,
+        namespace,
+        plural,
+        name,
+      }) => match self.find(group, version, plural, namespace, name) {
+        Some(object) => json_response(object.clone()),
+        None => not_found(&format!("{plural} \"{name}\" not found")),
+      },
+      Some(ApiPath::Subresource {
+        plural,
+        name,
+        subresource,
+      }) => not_found(&format!(
+        "{plural}/{subresource} for \"{name}\" is not available in snapshot mode"
+      )),
+      None => not_found(&format!("{path} is not available in snapshot mode")),
+    }
+  }
+
+  fn list(
+    &self,
+    group: &str,
+    version: &str,
+    plural: &str,
+    namespace: Option<&str>,
+    label_selector: &str,
+    field_selector: &str,
+  ) -> JValue {
+    let resource = self.resource(group, version, plural);
+    let items: Vec<&JValue> = resource
+      .map(|resource| {
+        resource
+          .objects
+          .iter()
+          .filter(|((ns, _), _)| namespace.is_none_or(|namespace| ns == namespace))
+          .map(|(_, object)| object)
+          .filter(|object| {
+            matches_label_selector(label_selector, object.pointer("/metadata/labels"))
+              && matches_field_selector(field_selector, object)
+          })
+          .collect()
+      })
+      .unwrap_or_default();
+    let kind = resource.map_or("", |resource| resource.kind.as_str());
+    json!({
+      "apiVersion": api_version(group, version),
+      "kind": format!("{kind}List"),
+      "metadata": { "resourceVersion": SNAPSHOT_RESOURCE_VERSION },
+      "items": items,
+    })
+  }
+
+  fn api_group_list(&self) -> JValue {
+    let mut versions: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for key in self.resources.keys().filter(|key| !key.group.is_empty()) {
+      let group_versions = versions.entry(key.group.as_str()).or_default();
+      if !group_versions.contains(&key.version.as_str()) {
+        group_versions.push(key.version.as_str());
+      }
+    }
+    let groups: Vec<JValue> = versions
+      .into_iter()
+      .map(|(group, versions)| {
+        let versions: Vec<JValue> = versions
+          .into_iter()
+          .map(|version| json!({ "groupVersion": api_version(group, version), "version": version }))
+          .collect();
+        json!({
+          "name": group,
+          "preferredVersion": versions[0].clone(),
+          "versions": versions,
+        })
+      })
+      .collect();
+    json!({ "kind": "APIGroupList", "apiVersion": "v1", "groups": groups })
+  }
+
+  fn api_resource_list(&self, group: &str, version: &str) -> Option<JValue> {
+    let resources: Vec<JValue> = self
+      .resources
+      .iter()
+      .filter(|(key, _)| key.group == group && key.version == version)
+      .map(|(key, resource)| {
+        json!({
+          "name": key.plural,
+          "singularName": resource.kind.to_lowercase(),
+          "namespaced": resource.namespaced,
+          "kind": resource.kind,
+          "verbs": ["get", "list", "watch"],
+        })
+      })
+      .collect();
+    (!resources.is_empty() || (group.is_empty() && version == "v1")).then(|| {
+      json!({
+        "kind": "APIResourceList",
+        "apiVersion": "v1",
+        "groupVersion": api_version(group, version),
+        "resources": resources,
+      })
+    })
+  }
+}
+
+/// A kube [`Client`] served entirely from `store`.
+pub fn snapshot_client(store: Arc<SnapshotStore>) -> Client {
+  let service = tower::service_fn(move |request: Request<Body>| {
+    let store = Arc::clone(&store);
+    async move {
+      let is_watch = query_params(request.uri().query())
+        .iter()
+        .any(|(key, value)| key == "watch" && (value == "true" || value == "1"));
+      if is_watch && request.method() == Method::GET {
+        // nothing ever changes in a snapshot; keep the watch open and quiet
+        std::future::pending::<()>().await;
+      }
+      Ok::<_, Infallible>(store.respond(
+        request.method(),
+        request.uri().path(),
+        request.uri().query(),
+      ))
+    }
+  });
+  Client::new(service, "default")
+}
+
+/// The single context listed while browsing a snapshot.
+pub fn snapshot_context(dir: &Path) -> KubeContext {
+  let name = dir
+    .file_name()
+    .map(|name| name.to_string_lossy().into_owned())
+    .unwrap_or_else(|| "snapshot".into());
+  KubeContext {
+    name: format!("snapshot:{name}"),
+    cluster: dir.display().to_string(),
+    user: None,
+    namespace: None,
+    is_active: true,
+  }
+}
+
+/// Outcome of [`capture_snapshot`].
+#[derive(Debug, Default)]
+pub struct CaptureSummary {
+  pub objects: usize,
+  pub kinds: usize,
+  /// Kinds that could not be listed (e.g. forbidden), with the reason.
+  pub skipped: Vec<String>,
+}
+
+impl CaptureSummary {
+  pub fn describe(&self, dir: &Path) -> String {
+    let mut text = format!(
+      "Saved {} objects of {} kinds to {}",
+      self.objects,
+      self.kinds,
+      dir.display()
+    );
+    if !self.skipped.is_empty() {
+      text.push_str(&format!(" ({} kinds skipped)", self.skipped.len()));
+    }
+    text
+  }
+}
+
+/// Write every built-in kind kdash has a tab for, plus namespaces and the
+/// discovered dynamic kinds, to `dir/<namespace>/<kind>/<name>.yaml`
+/// (cluster-scoped objects go under `_cluster`). `namespace` limits namespaced
+/// kinds to one namespace. Objects pass through [`sanitize_obj`]; with
+/// `redact_secrets` every Secret value is replaced. Kinds that fail to list are
+/// recorded in the summary rather than failing the capture.
+pub async fn capture_snapshot(
+  client: &Client,
+  dir: &Path,
+  namespace: Option<&str>,
+  redact_secrets: bool,
+) -> anyhow::Result<CaptureSummary> {
+  fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+  let mut kinds: Vec<(ApiResource, Scope)> =
+    vec![(ApiResource::erase::<Namespace>(&()), Scope::Cluster)];
+  kinds.extend(
+    CAPTURED_BLOCKS
+      .iter()
+      .filter_map(|block| api_resource_for_block(*block, None)),
+  );
+  let mut summary = CaptureSummary::default();
+  match discover_dynamic_kinds(client).await {
+    Ok(dynamic) => kinds.extend(dynamic.into_iter().map(
+      |KubeDynamicKind {
+         api_resource,
+         scope,
+         ..
+       }| (api_resource, scope),
+    )),
+    Err(e) => summary
+      .skipped
+      .push(format!("dynamic resource discovery: {e}")),
+  }
+
+  for (ar, scope) in kinds {
+    let api: Api<DynamicObject> = match (scope, namespace) {
+      (Scope::Namespaced, Some(ns)) => Api::namespaced_with(client.clone(), ns, &ar),
+      _ => Api::all_with(client.clone(), &ar),
+    };
+    let objects = match list_all(&api).await {
+      Ok(objects) => objects,
+      Err(e) => {
+        warn!("Snapshot skipped {}: {}", kind_dir(&ar), e);
+        summary.skipped.push(format!("{}: {}", kind_dir(&ar), e));
+        continue;
+      }
+    };
+    // a single namespace capture still includes the namespace object itself
+    let objects = objects
+      .into_iter()
+      .filter(|obj| ar.kind != "Namespace" || namespace.is_none_or(|ns| obj.name_any() == ns));
+
+    let mut written = 0;
+    let mut taken = HashSet::new();
+    for obj in objects {
+      let mut obj = sanitize_obj(obj);
+      obj.types = Some(TypeMeta {
+        api_version: ar.api_version.clone(),
+        kind: ar.kind.clone(),
+      });
+      if redact_secrets && ar.group.is_empty() && ar.kind == "Secret" {
+        redact_secret(&mut obj);
+      }
+      let path = object_path(
+        &dir
+          .join(obj.namespace().as_deref().unwrap_or(CLUSTER_SCOPE_DIR))
+          .join(kind_dir(&ar)),
+        &obj.name_any(),
+        &mut taken,
+      );
+      if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+          .with_context(|| format!("Failed to create {}", parent.display()))?;
+      }
+      let yaml = serde_saphyr::to_string(&obj)?;
+      fs::write(&path, yaml).with_context(|| format!("Failed to write {}", path.display()))?;
+      written += 1;
+    }
+    if written > 0 {
+      summary.kinds += 1;
+      summary.objects += written;
+    }
+  }
+  info!("{}", summary.describe(dir));
+  Ok(summary)
+}
+
+async fn list_all(api: &Api<DynamicObject>) -> kube::Result<Vec<DynamicObject>> {
+  let mut objects = vec![];
+  let mut lp = ListParams::default().limit(CAPTURE_PAGE_SIZE);
+  loop {
+    let page = api.list(&lp).await?;
+    let token = page.metadata.continue_.clone().filter(|t| !t.is_empty());
+    objects.extend(page.items);
+    match token {
+      Some(token) => lp = lp.continue_token(&token),
+      None => return Ok(objects),
+    }
+  }
+}
+
+/// Replace every Secret value (keeping the keys) and drop the last-applied
+/// annotation, which would otherwise carry the data in plain text.
+fn redact_secret(obj: &mut DynamicObject) {
+  let redacted = STANDARD.encode(REDACTED);
+  if let Some(JValue::Object(data)) = obj.data.get_mut("data") {
+    data
+      .values_mut()
+      .for_each(|value| *value = redacted.clone().into());
+  }
+  if let Some(JValue::Object(data)) = obj.data.get_mut("stringData") {
+    data.values_mut().for_each(|value| *value = REDACTED.into());
+  }
+  obj.annotations_mut().remove(LAST_APPLIED_ANNOTATION);
+}
+
+/// Directory name for a kind: the plural resource name, qualified with the
+/// group outside the core group (`pods`, `deployments.apps`).
+fn kind_dir(ar: &ApiResource) -> String {
+  if ar.group.is_empty() {
+    ar.plural.clone()
+  } else {
+    format!("{}.{}", ar.plural, ar.group)
+  }
+}
+
+/// Object names are safe on Linux but RBAC names often contain `:`.
+fn file_name(name: &str) -> String {
+  name
+    .chars()
+    .map(|c| {
+      if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+        c
+      } else {
+        '_'
+      }
+    })
+    .collect()
+}
+
+/// The file for object `name` in `kind_dir`. Names that sanitize to a file
+/// already written in this capture (`system:foo` after `system_foo`) get a
+/// hash of the original name appended instead of overwriting it.
+fn object_path(kind_dir: &Path, name: &str, taken: &mut HashSet<PathBuf>) -> PathBuf {
+  let base = file_name(name);
+  let mut path = kind_dir.join(format!("{}.yaml", base));
+  if taken.contains(&path) {
+    path = kind_dir.join(format!("{}-{:08x}.yaml", base, fnv1a(name)));
+  }
+  taken.insert(path.clone());
+  path
+}
+
+/// 32-bit FNV-1a, stable across runs and Rust versions.
+fn fnv1a(text: &str) -> u32 {
+  text.bytes().fold(0x811c_9dc5, |hash, byte| {
+    (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+  })
+}
+
+fn collect_manifest_files(dir: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+  let entries =
+    fs::read_dir(dir).with_context(|| format!("Failed to read directory {}", dir.display()))?;
+  for entry in entries {
+    let path = entry?.path();
+    if path.is_dir() {
+      collect_manifest_files(&path, files)?;
+    } else if path
+      .extension()
+      .and_then(|ext| ext.to_str())
+      .is_some_and(|ext| MANIFEST_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+    {
+      files.push(path);
+    }
+  }
+  Ok(())
+}
+
+fn parse_documents(file: &Path, content: &str) -> anyhow::Result<Vec<JValue>> {
+  let is_json = file
+    .extension()
+    .and_then(|ext| ext.to_str())
+    .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+  if is_json {
+    Ok(vec![serde_json::from_str(content)?])
+  } else {
+    Ok(serde_saphyr::from_multiple(content)?)
+  }
+}
+
+enum ApiPath<'a> {
+  CoreVersions,
+  Groups,
+  Resources {
+    group: &'a str,
+    version: &'a str,
+  },
+  Collection {
+    group: &'a str,
+    version: &'a str,
+    namespace: Option<&'a str>,
+    plural: &'a str,
+  },
+  Object {
+    group: &'a str,
+    version: &'a str,
+    namespace: Option<&'a str>,
+    plural: &'a str,
+    name: &'a str,
+  },
+  Subresource {
+    plural: &'a str,
+    name: &'a str,
+    subresource: &'a str,
+  },
+}
+
+fn parse_path(path: &str) -> Option<ApiPath<'_>> {
+  let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+  let (group, version, rest) = match segments.as_slice() {
+    ["api"] => return Some(ApiPath::CoreVersions),
+    ["apis"] => return Some(ApiPath::Groups),
+    ["api", version, rest @ ..] => ("", *version, rest),
+    ["apis", group, version, rest @ ..] => (*group, *version, rest),
+    _ => return None,
+  };
+  let (namespace, rest) = match rest {
+    ["namespaces", namespace, rest @ ..] if !rest.is_empty() => (Some(*namespace), rest),
+    rest => (None, rest),
+  };
+  Some(match rest {
+    [] => ApiPath::Resources { group, version },
+    [plural] => ApiPath::Collection {
+      group,
+      version,
+      namespace,
+      plural,
+    },
+    [plural, name] => ApiPath::Object {
+      group,
+      version,
+      namespace,
+      plural,
+      name,
+    },
+    [plural, name, subresource, ..] => ApiPath::Subresource {
+      plural,
+      name,
+      subresource,
+    },
+  })
+}
+
+fn api_version(group: &str, version: &str) -> String {
+  if group.is_empty() {
+    version.to_owned()
+  } else {
+    format!("{group}/{version}")
+  }
+}
+
+fn query_params(query: Option<&str>) -> Vec<(String, String)> {
+  query
+    .unwrap_or_default()
+    .split('&')
+    .filter(|pair| !pair.is_empty())
+    .map(|pair| {
+      let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+      (percent_decode(key), percent_decode(value))
+    })
+    .collect()
+}
+
+fn percent_decode(value: &str) -> String {
+  let hex = |byte: u8| (byte as char).to_digit(16).map(|digit| digit as u8);
+  let bytes = value.as_bytes();
+  let mut out = Vec::with_capacity(bytes.len());
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'+' => out.push(b' '),
+      b'%' if i + 2 < bytes.len() => match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+        (Some(high), Some(low)) => {
+          out.push(high << 4 | low);
+          i += 2;
+        }
+        _ => out.push(b'%'),
+      },
+      byte => out.push(byte),
+    }
+    i += 1;
+  }
+  String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Split a selector on the commas that separate requirements, leaving the
+/// commas inside `in (a,b)` value sets alone.
+fn split_requirements(selector: &str) -> Vec<&str> {
+  let mut requirements = vec![];
+  let mut depth = 0usize;
+  let mut start = 0;
+  for (i, c) in selector.char_indices() {
+    match c {
+      '(' => depth += 1,
+      ')' => depth = depth.saturating_sub(1),
+      ',' if depth == 0 => {
+        requirements.push(selector[start..i].trim());
+        start = i + 1;
+      }
+      _ => {}
+    }
+  }
+  requirements.push(selector[start..].trim());
+  requirements.retain(|requirement| !requirement.is_empty());
+  requirements
+}
+
+/// Match a label selector (`=`, `==`, `!=`, `in`, `notin`, `key`, `!key`).
+fn matches_label_selector(selector: &str, labels: Option<&JValue>) -> bool {
+  let label = |key: &str| {
+    labels
+      .and_then(|labels| labels.get(key))
+      .and_then(JValue::as_str)
+  };
+  split_requirements(selector).into_iter().all(|requirement| {
+    if let Some((key, values)) = split_set_requirement(requirement, " notin ") {
+      return label(key).is_none_or(|value| !values.contains(&value));
+    }
+    if let Some((key, values)) = split_set_requirement(requirement, " in ") {
+      return label(key).is_some_and(|value| values.contains(&value));
+    }
+    if let Some(key) = requirement.strip_prefix('!') {
+      return label(key.trim()).is_none();
+    }
+    if let Some((key, value)) = requirement.split_once("!=") {
+      return label(key.trim()) != Some(value.trim());
+    }
+    if let Some((key, value)) = requirement
+      .split_once("==")
+      .or_else(|| requirement.split_once('='))
+    {
+      return label(key.trim()) == Some(value.trim());
+    }
+    label(requirement).is_some()
+  })
+}
+
+fn split_set_requirement<'a>(
+  requirement: &'a str,
+  operator: &str,
+) -> Option<(&'a str, Vec<&'a str>)> {
+  let (key, values) = requirement.split_once(operator)?;
+  let values = values
+    .trim()
+    .strip_prefix('(')?
+    .strip_suffix(')')?
+    .split(',')
+    .map(str::trim)
+    .collect();
+  Some((key.trim(), values))
+}
+
+/// Match a field selector (`path=value`, `path==value`, `path!=value`) against
+/// the object's JSON; missing fields compare as empty strings.
+fn matches_field_selector(selector: &str, object: &JValue) -> bool {
+  let field = |path: &str| {
+    let pointer = format!("/{}", path.trim().replace('.', "/"));
+    match object.pointer(&pointer) {
+      Some(JValue::String(value)) => value.clone(),
+      Some(JValue::Null) | None => String::new(),
+      Some(value) => value.to_string(),
+    }
+  };
+  split_requirements(selector).into_iter().all(|requirement| {
+    if let Some((path, value)) = requirement.split_once("!=") {
+      return field(path) != value.trim();
+    }
+    match requirement
+      .split_once("==")
+      .or_else(|| requirement.split_once('='))
+    {
+      Some((path, value)) => field(path) == value.trim(),
+      None => true,
+    }
+  })
+}
+
+fn json_response(body: JValue) -> Response<Body> {
+  let mut response = Response::new(Body::from(body.to_string().into_bytes()));
+  response.headers_mut().insert(
+    http::header::CONTENT_TYPE,
+    http::HeaderValue::from_static("application/json"),
+  );
+  response
+}
+
+fn status_response(code: StatusCode, reason: &str, message: &str) -> Response<Body> {
+  let mut response = json_response(json!({
+    "kind": "Status",
+    "apiVersion": "v1",
+    "metadata": {},
+    "status": "Failure",
+    "message": message,
+    "reason": reason,
+    "code": code.as_u16(),
+  }));
+  *response.status_mut() = code;
+  response
+}
+
+fn not_found(message: &str) -> Response<Body> {
+  status_response(StatusCode::NOT_FOUND, "NotFound", message)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::{SystemTime, UNIX_EPOCH};
+
+  use k8s_openapi::api::core::v1::{Node, Pod, Secret};
+  use kube::{
+    api::{DeleteParams, ListParams},
+    Api, ResourceExt,
+  };
+
+  use super::*;
+  use crate::app::{metrics::NodeMetrics, test_utils::load_resource_from_file};
+
+  fn temp_snapshot_dir(files: &[(&str, &str)]) -> PathBuf {
+    let suffix = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .expect("system time should be after epoch")
+      .as_nanos();
+    let dir = std::env::temp_dir().join(format!("kdash-snapshot-{suffix}"));
+    fs::create_dir_all(&dir).unwrap();
+    for (name, content) in files {
+      let path = dir.join(name);
+      fs::create_dir_all(path.parent().unwrap()).unwrap();
+      fs::write(path, content).unwrap();
+    }
+    dir
+  }
+
+  #[tokio::test]
+  async fn test_snapshot_client_serves_test_data() {
+    let store = SnapshotStore::load(Path::new("./test_data")).unwrap();
+    let client = snapshot_client(Arc::new(store));
+
+    let expected_nodes = load_resource_from_file::<Node>("nodes").items.len();
+    let nodes = Api::<Node>::all(client.clone())
+      .list(&ListParams::default())
+      .await
+      .unwrap();
+    assert_eq!(nodes.items.len(), expected_nodes);
+
+    let metrics = Api::<NodeMetrics>::all(client.clone())
+      .list(&ListParams::default())
+      .await
+      .unwrap();
+    assert!(!metrics.items.is_empty());
+
+    let pod = load_resource_from_file::<Pod>("pods").items.remove(0);
+    let api = Api::<Pod>::namespaced(client.clone(), &pod.namespace().unwrap());
+    assert_eq!(
+      api.get(&pod.name_any()).await.unwrap().name_any(),
+      pod.name_any()
+    );
+    assert!(api.get("missing").await.is_err());
+    assert!(api
+      .delete(&pod.name_any(), &DeleteParams::default())
+      .await
+      .is_err());
+
+    let groups = client.list_api_groups().await.unwrap();
+    assert!(groups.groups.iter().any(|g| g.name == "metrics.k8s.io"));
+  }
+
+  #[tokio::test]
+  async fn test_snapshot_flattens_lists_and_synthesizes_namespaces() {
+    let dir = temp_snapshot_dir(&[
+      (
+        "pods.json",
+        r#"{"kind":"PodList","apiVersion":"v1","items":[
+          {"metadata":{"name":"web-1","namespace":"shop","labels":{"app":"web","tier":"front"}},"spec":{"nodeName":"node-a","containers":[]}},
+          {"metadata":{"name":"db-1","namespace":"shop","labels":{"app":"db"}},"spec":{"nodeName":"node-b","containers":[]}}
+        ]}"#,
+      ),
+      (
+        "nested/more.yaml",
+        "apiVersion: v1\nkind: Pod\nmetadata:\n  name: web-2\n  namespace: blog\n  labels:\n    app: web\nspec:\n  containers: []\n---\n# empty document\n",
+      ),
+      ("broken.yaml", "kind: [unterminated"),
+      ("notes.txt", "not a manifest"),
+    ]);
+    let store = SnapshotStore::load(&dir).unwrap();
+    assert_eq!(store.object_count(), 5);
+    let client = snapshot_client(Arc::new(store));
+
+    let namespaces = Api::<Namespace>::all(client.clone())
+      .list(&ListParams::default())
+      .await
+      .unwrap();
+    let names: Vec<String> = namespaces.iter().map(ResourceExt::name_any).collect();
+    assert_eq!(names, vec!["blog", "shop"]);
+
+    let web = Api::<Pod>::all(client.clone())
+      .list(&ListParams::default().labels("app in (web,api),tier!=back"))
+      .await
+      .unwrap();
+    let names: Vec<String> = web.iter().map(ResourceExt::name_any).collect();
+    assert_eq!(names, vec!["web-2", "web-1"]);
+
+    let on_node = Api::<Pod>::namespaced(client, "shop")
+      .list(&ListParams::default().fields("spec.nodeName=node-b"))
+      .await
+      .unwrap();
+    assert_eq!(on_node.items.len(), 1);
+    assert_eq!(on_node.items[0].name_any(), "db-1");
+
+    fs::remove_dir_all(dir).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_capture_snapshot_round_trips_and_redacts_secrets() {
+    let source = SnapshotStore::load(Path::new("./test_data")).unwrap();
+    let expected = source.object_count();
+    let client = snapshot_client(Arc::new(source));
+    let dir = temp_snapshot_dir(&[]);
+
+    let summary = capture_snapshot(&client, &dir, None, true).await.unwrap();
+    assert!(summary.skipped.is_empty(), "{:?}", summary.skipped);
+    assert_eq!(summary.objects, expected);
+
+    let secret = load_resource_from_file::<Secret>("secrets").items.remove(0);
+    let path = dir
+      .join(secret.namespace().unwrap())
+      .join("secrets")
+      .join(format!("{}.yaml", secret.name_any()));
+    let written: Secret = serde_saphyr::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    assert!(written
+      .data
+      .unwrap()
+      .values()
+      .all(|value| value.0 == REDACTED.as_bytes()));
+    assert!(!written
+      .metadata
+      .annotations
+      .unwrap_or_default()
+      .contains_key(LAST_APPLIED_ANNOTATION));
+    assert!(dir.join(CLUSTER_SCOPE_DIR).join("nodes").is_dir());
+    assert!(dir
+      .join("_cluster/clusterroles.rbac.authorization.k8s.io")
+      .is_dir());
+
+    let reloaded = SnapshotStore::load(&dir).unwrap();
+    assert_eq!(reloaded.object_count(), expected);
+    fs::remove_dir_all(dir).unwrap();
+  }
+
+  #[test]
+  fn test_file_name_replaces_unsafe_characters() {
+    assert_eq!(file_name("system:controller:job"), "system_controller_job");
+    assert_eq!(file_name("web-1.v2_x"), "web-1.v2_x");
+  }
+
+  #[test]
+  fn test_object_path_keeps_colliding_names_apart() {
+    let kind_dir = Path::new("snap/_cluster/clusterroles.rbac.authorization.k8s.io");
+    let mut taken = HashSet::new();
+
+    let first = object_path(kind_dir, "system_foo", &mut taken);
+    let second = object_path(kind_dir, "system:foo", &mut taken);
+    assert_eq!(first, kind_dir.join("system_foo.yaml"));
+    assert_ne!(first, second);
+    assert_eq!(
+      second,
+      kind_dir.join(format!("system_foo-{:08x}.yaml", fnv1a("system:foo")))
+    );
+    // other kinds may reuse the plain name
+    assert_eq!(
+      object_path(Path::new("snap/_cluster/nodes"), "system:foo", &mut taken),
+      Path::new("snap/_cluster/nodes/system_foo.yaml")
+    );
+  }
+
+  #[test]
+  fn test_snapshot_load_rejects_empty_dir() {
+    let dir = temp_snapshot_dir(&[("notes.txt", "nothing here")]);
+    assert!(SnapshotStore::load(&dir).is_err());
+    fs::remove_dir_all(dir).unwrap();
+  }
+
+  #[test]
+  fn test_selector_matching() {
+    let labels = json!({ "app": "web", "tier": "front" });
+    assert!(matches_label_selector("", Some(&labels)));
+    assert!(matches_label_selector("app=web,tier", Some(&labels)));
+    assert!(matches_label_selector("app==web,!env", Some(&labels)));
+    assert!(matches_label_selector(
+      "app notin (db, cache)",
+      Some(&labels)
+    ));
+    assert!(!matches_label_selector("app in (db,cache)", Some(&labels)));
+    assert!(!matches_label_selector("tier!=front", Some(&labels)));
+    assert!(!matches_label_selector("app", None));
+
+    let event = json!({ "involvedObject": { "kind": "Pod", "name": "web-1" }, "count": 3 });
+    assert!(matches_field_selector(
+      "involvedObject.kind=Pod,involvedObject.name==web-1",
+      &event
+    ));
+    assert!(matches_field_selector(
+      "count=3,involvedObject.uid=",
+      &event
+    ));
+    assert!(!matches_field_selector(
+      "involvedObject.name!=web-1",
+      &event
+    ));
+    assert_eq!(percent_decode("app%3Dweb%2Ctier+x"), "app=web,tier x");
+  }
+}
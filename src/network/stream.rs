@@ -1,31 +1,41 @@
-use std::{collections::HashSet, process::Stdio, sync::Arc, time::Duration};
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
 use anyhow::anyhow;
+use chrono::{DateTime, Utc};
 use futures::AsyncBufReadExt;
-use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::{api::core::v1::Pod, jiff};
 use kube::{
   api::{ListParams, LogParams},
   Api, Client,
 };
 use log::{debug, error, info, warn};
-use tokio::{
-  io::{AsyncBufReadExt as TokioAsyncBufReadExt, AsyncReadExt, BufReader},
-  process::{ChildStderr, ChildStdout, Command},
-  sync::Mutex,
-  time::Instant,
-};
+use regex::Regex;
+use tokio::{sync::Mutex, time::Instant};
 use tokio_stream::StreamExt;
 
-use super::refresh_kube_config;
-use crate::app::port_forward::PortForwardStatus;
-use crate::app::App;
-use crate::cmd::port_forward::{prepare_port_forward, PortForwardTarget};
+use super::{
+  exec::run_exec_session,
+  port_forward::run_port_forward,
+  refresh_kube_config,
+  watch::{WatchCache, WatchScope},
+  Network,
+};
+use crate::app::{
+  logs::{LogSince, LogTailQuery},
+  utils::to_age,
+  App,
+};
+use crate::cmd::port_forward::{validate_port_forward, PortForwardTarget};
+use crate::cmd::shell::ShellExecTarget;
 const BATCH_SIZE: usize = 50;
 const BATCH_FLUSH_MS: u64 = 100;
-const RECONNECT_OVERLAP_SECS: i64 = 5;
+/// Consecutive failures to open a stream before giving up.
 const MAX_RECONNECT_ATTEMPTS: u32 = 10;
-const DEDUP_WINDOW: usize = 50;
+const MAX_RECONNECT_BACKOFF_SECS: u64 = 30;
 const MAX_AGGREGATE_PODS: usize = 20;
+/// How often the multi-pod tail re-lists pods to pick up new ones.
+const TAIL_POLL_SECS: u64 = 5;
+const MAX_TAIL_STREAMS: usize = 100;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IoStreamEvent {
@@ -37,6 +47,8 @@ pub enum IoStreamEvent {
     selector: String,
   },
   GetPodAllContainerLogs,
+  /// Follow every pod and container a stern-style query matches.
+  TailLogs(LogTailQuery),
   StartPortForward {
     kind: String,
     namespace: String,
@@ -47,22 +59,35 @@ pub enum IoStreamEvent {
   StopPortForward {
     id: u64,
   },
+  StartExec {
+    id: u64,
+    target: ShellExecTarget,
+  },
 }
 
 #[derive(Clone)]
 pub struct NetworkStream<'a> {
   pub client: Client,
   pub app: &'a Arc<Mutex<App>>,
+  /// Shared with [`Network`] so log tails poll pods from the same watches.
+  pub watches: WatchCache,
 }
 
 impl<'a> NetworkStream<'a> {
   pub fn new(client: Client, app: &'a Arc<Mutex<App>>) -> Self {
-    NetworkStream { client, app }
+    NetworkStream {
+      client,
+      app,
+      watches: WatchCache::default(),
+    }
   }
 
   pub async fn refresh_client(&mut self) {
     let context = {
       let app = self.app.lock().await;
+      if app.snapshot.is_some() {
+        return;
+      }
       app.data.selected.context.clone()
     };
     match refresh_kube_config(&context).await {
@@ -97,6 +122,9 @@ impl<'a> NetworkStream<'a> {
       IoStreamEvent::GetPodAllContainerLogs => {
         self.stream_pod_all_container_logs().await;
       }
+      IoStreamEvent::TailLogs(query) => {
+        self.stream_tail_logs(&query).await;
+      }
       IoStreamEvent::StartPortForward {
         kind,
         namespace,
@@ -111,6 +139,15 @@ impl<'a> NetworkStream<'a> {
       IoStreamEvent::StopPortForward { id } => {
         self.stop_port_forward(id).await;
       }
+      IoStreamEvent::StartExec { id, target } => {
+        // Runs until the shell exits or the pane is closed, so it must not hold
+        // up the stream worker.
+        let client = self.client.clone();
+        let app = Arc::clone(self.app);
+        tokio::spawn(async move {
+          run_exec_session(client, app, id, target).await;
+        });
+      }
     };
 
     let mut app = self.app.lock().await;
@@ -124,7 +161,7 @@ impl<'a> NetworkStream<'a> {
   }
 
   pub async fn stream_container_logs(&self, tail: bool) {
-    let (namespace, pod_name, cont_name, tail_lines, timestamps, cancel_rx) = {
+    let (namespace, pod_name, cont_name, tail_lines, since, timestamps, cancel_rx) = {
       let app = self.app.lock().await;
       let ns = app
         .data
@@ -142,7 +179,7 @@ impl<'a> NetworkStream<'a> {
       let tail_lines = app.initial_log_tail_lines();
       let timestamps = app.log_timestamps;
       let rx = app.new_log_cancel_rx();
-      (ns, pod, cont, tail_lines, timestamps, rx)
+      (ns, pod, cont, tail_lines, app.log_since, timestamps, rx)
     };
 
     if pod_name.is_empty() || cont_name.is_empty() {
@@ -155,45 +192,51 @@ impl<'a> NetworkStream<'a> {
     }
 
     let api: Api<Pod> = Api::namespaced(self.client.clone(), &namespace);
-    let mut since_seconds: Option<i64> = None;
-    let mut reconnect_count: u32 = 0;
+    let mut first = LogParams {
+      container: Some(cont_name.clone()),
+      follow: true,
+      previous: false,
+      tail_lines: tail.then_some(tail_lines),
+      ..Default::default()
+    };
+    if let Some(since) = since {
+      since.apply(&mut first);
+    }
+    let mut resume = LogResume::new(timestamps);
+    let mut failures: u32 = 0;
+    let mut cancel_rx = cancel_rx;
 
     loop {
-      let lp = LogParams {
-        container: Some(cont_name.clone()),
-        follow: true,
-        previous: false,
-        tail_lines: if since_seconds.is_none() && tail {
-          Some(tail_lines)
-        } else {
-          None
-        },
-        since_seconds,
-        timestamps,
-        ..Default::default()
-      };
+      match check_container(&api, &pod_name, &cont_name).await {
+        // Nothing more will be logged; the stream already read it all.
+        Ok(check) if check.pod_finished && resume.reconnecting() => break,
+        Ok(check) => {
+          if resume.restarted(check.restarts) {
+            let mut app = self.app.lock().await;
+            app
+              .data
+              .logs
+              .add_records(vec!["[kdash] container restarted".into()]);
+          }
+        }
+        Err(e) if is_not_found(&e) => {
+          let mut app = self.app.lock().await;
+          app
+            .data
+            .logs
+            .add_records(vec![format!("[kdash] pod {} no longer exists", pod_name)]);
+          break;
+        }
+        // Opening the stream reports anything that matters.
+        Err(_) => {}
+      }
 
-      match api.log_stream(&pod_name, &lp).await {
+      match api.log_stream(&pod_name, &resume.params(&first)).await {
         Ok(logs) => {
-          reconnect_count = 0;
+          failures = 0;
           let mut lines_stream = logs.lines();
           let mut batch: Vec<String> = Vec::with_capacity(BATCH_SIZE);
           let mut last_flush = Instant::now();
-          let mut cancel_rx = cancel_rx.clone();
-
-          // Build dedup set from existing records
-          let dedup_set: HashSet<String> = if since_seconds.is_some() {
-            let app = self.app.lock().await;
-            app
-              .data
-              .logs
-              .last_n_records(DEDUP_WINDOW)
-              .into_iter()
-              .map(|s| s.to_string())
-              .collect()
-          } else {
-            HashSet::new()
-          };
 
           loop {
             let flush_deadline =
@@ -218,22 +261,14 @@ impl<'a> NetworkStream<'a> {
               line = lines_stream.next() => {
                 match line {
                   Some(Ok(line)) => {
-                    let line = line.trim().to_string();
-                    if !line.is_empty() {
-                      // Skip duplicates on reconnect
-                      if since_seconds.is_some() && dedup_set.contains(&line) {
-                        continue;
-                      }
-                      batch.push(line);
-
-                      if batch.len() >= BATCH_SIZE {
-                        let mut app = self.app.lock().await;
-                        app.data.logs.add_records(std::mem::replace(
-                          &mut batch,
-                          Vec::with_capacity(BATCH_SIZE),
-                        ));
-                        last_flush = Instant::now();
-                      }
+                    resume.accept(&line, &mut batch);
+                    if batch.len() >= BATCH_SIZE {
+                      let mut app = self.app.lock().await;
+                      app.data.logs.add_records(std::mem::replace(
+                        &mut batch,
+                        Vec::with_capacity(BATCH_SIZE),
+                      ));
+                      last_flush = Instant::now();
                     }
                   }
                   Some(Err(e)) => {
@@ -271,8 +306,8 @@ impl<'a> NetworkStream<'a> {
             "Failed to open log stream for {}/{}: {}",
             pod_name, cont_name, e
           );
-          reconnect_count += 1;
-          if reconnect_count >= MAX_RECONNECT_ATTEMPTS {
+          failures += 1;
+          if failures >= MAX_RECONNECT_ATTEMPTS {
             self
               .handle_error(anyhow!(
                 "Failed to stream logs after {} attempts: {}",
@@ -285,21 +320,14 @@ impl<'a> NetworkStream<'a> {
         }
       }
 
-      // Check cancellation before reconnecting
-      if *cancel_rx.borrow() {
-        break;
-      }
-
-      // Ask Kubernetes for a small overlap window so reconnects only refetch recent logs.
-      since_seconds = Some(RECONNECT_OVERLAP_SECS);
-
-      // Backoff before reconnecting
-      let backoff = Duration::from_millis(500 * (reconnect_count as u64).min(4));
+      let backoff = resume.broke();
       debug!(
-        "Reconnecting log stream for {}/{} (attempt {}, backoff {:?})",
-        pod_name, cont_name, reconnect_count, backoff
+        "Reconnecting log stream for {}/{} (backoff {:?})",
+        pod_name, cont_name, backoff
       );
-      tokio::time::sleep(backoff).await;
+      if !wait_for_reconnect(backoff, &mut cancel_rx).await {
+        break;
+      }
     }
 
     let mut app = self.app.lock().await;
@@ -311,69 +339,12 @@ impl<'a> NetworkStream<'a> {
   /// last instance is static. Absence of a prior instance is surfaced in the
   /// log view rather than as a hard error.
   pub async fn fetch_previous_logs(&self) {
-    let (namespace, pod_name, cont_name, tail_lines, timestamps) = {
-      let app = self.app.lock().await;
-      let ns = app
-        .data
-        .pods
-        .get_selected_item_copy()
-        .map(|p| p.namespace)
-        .unwrap_or_else(|| std::env::var("NAMESPACE").unwrap_or_else(|_| "default".into()));
-      let pod = app
-        .data
-        .pods
-        .get_selected_item_copy()
-        .map(|p| p.name)
-        .unwrap_or_default();
-      let cont = app.data.selected.container.clone().unwrap_or_default();
-      let tail_lines = app.initial_log_tail_lines();
-      (ns, pod, cont, tail_lines, app.log_timestamps)
-    };
-
-    if pod_name.is_empty() || cont_name.is_empty() {
-      return;
-    }
-
-    let api: Api<Pod> = Api::namespaced(self.client.clone(), &namespace);
-    let lp = LogParams {
-      container: Some(cont_name.clone()),
-      previous: true,
-      follow: false,
-      tail_lines: Some(tail_lines),
-      timestamps,
-      ..Default::default()
-    };
-
-    match api.logs(&pod_name, &lp).await {
-      Ok(logs) => {
-        let lines: Vec<String> = logs.lines().map(|line| line.to_string()).collect();
-        let mut app = self.app.lock().await;
-        if lines.is_empty() {
-          app.data.logs.add_records(vec![format!(
-            "[kdash] No previous logs for container {} (it has not restarted)",
-            cont_name
-          )]);
-        } else {
-          app.data.logs.add_records(lines);
-        }
-      }
-      Err(e) => {
-        warn!(
-          "Failed to fetch previous logs for {}/{}: {}",
-          pod_name, cont_name, e
-        );
-        let mut app = self.app.lock().await;
-        app.data.logs.add_records(vec![format!(
-          "[kdash] No previous logs available for {} (the container has no prior terminated instance)",
-          cont_name
-        )]);
-      }
-    }
+    load_previous_logs(self.client.clone(), self.app, false).await;
   }
 
   /// Stream logs from all containers of the selected pod concurrently.
   pub async fn stream_pod_all_container_logs(&self) {
-    let (namespace, pod_name, container_names, tail_lines, timestamps, cancel_rx) = {
+    let (namespace, pod_name, container_names, history, timestamps, cancel_rx) = {
       let app = self.app.lock().await;
       let pod = app.data.pods.get_selected_item_copy();
       let ns = pod
@@ -385,9 +356,12 @@ impl<'a> NetworkStream<'a> {
         .as_ref()
         .map(|p| p.containers.iter().map(|c| c.name.clone()).collect())
         .unwrap_or_default();
-      let tail_lines = app.initial_log_tail_lines();
+      let history = LogHistory {
+        tail_lines: Some(app.initial_log_tail_lines()),
+        since: app.log_since,
+      };
       let rx = app.new_log_cancel_rx();
-      (ns, name, containers, tail_lines, app.log_timestamps, rx)
+      (ns, name, containers, history, app.log_timestamps, rx)
     };
 
     if pod_name.is_empty() || container_names.is_empty() {
@@ -427,8 +401,9 @@ impl<'a> NetworkStream<'a> {
             pod_name: pod,
             container_name: cont_name.clone(),
             short_name: cont_name,
+            follow_restarts: true,
           },
-          tail_lines,
+          history,
           timestamps,
           tx,
           cancel_rx,
@@ -500,10 +475,13 @@ impl<'a> NetworkStream<'a> {
   /// Stream logs from all pods matching a label selector concurrently.
   /// Lines are prefixed with the pod name for disambiguation.
   pub async fn stream_aggregate_logs(&self, namespace: &str, selector: &str) {
-    let (tail_lines, timestamps, cancel_rx) = {
+    let (history, timestamps, cancel_rx) = {
       let app = self.app.lock().await;
       (
-        app.initial_log_tail_lines(),
+        LogHistory {
+          tail_lines: Some(app.initial_log_tail_lines()),
+          since: app.log_since,
+        },
         app.log_timestamps,
         app.new_log_cancel_rx(),
       )
@@ -579,8 +557,9 @@ impl<'a> NetworkStream<'a> {
             pod_name,
             container_name: cont_name,
             short_name: prefix,
+            follow_restarts: true,
           },
-          tail_lines,
+          history,
           timestamps,
           tx,
           cancel_rx,
@@ -659,9 +638,151 @@ impl<'a> NetworkStream<'a> {
     );
   }
 
-  /// Spawn a background `kubectl port-forward` child, track it in app state, and
-  /// watch its output for readiness/failure. The TUI stays up (unlike the
-  /// foreground shell-exec/edit actions).
+  /// Follow every container a [`LogTailQuery`] matches, like stern: pods are
+  /// re-listed every few seconds so new ones join the stream and terminated
+  /// ones are marked. Streams already running start with the usual history;
+  /// containers that appear later are read from their first line.
+  pub async fn stream_tail_logs(&self, query: &LogTailQuery) {
+    let (history, timestamps, cancel_rx) = {
+      let app = self.app.lock().await;
+      (
+        LogHistory {
+          tail_lines: Some(app.initial_log_tail_lines()),
+          since: app.log_since,
+        },
+        app.log_timestamps,
+        app.new_log_cancel_rx(),
+      )
+    };
+    let (pod_re, container_re) = match query.regexes() {
+      Ok(regexes) => regexes,
+      Err(e) => {
+        self.handle_error(anyhow!(e)).await;
+        return;
+      }
+    };
+    let api: Api<Pod> = match &query.namespace {
+      Some(ns) => Api::namespaced(self.client.clone(), ns),
+      None => Api::all(self.client.clone()),
+    };
+
+    {
+      let mut app = self.app.lock().await;
+      app.is_streaming = true;
+    }
+    info!("Starting log tail for '{}'", query.label());
+
+    // polls are served from the pod watch once it is running
+    let lister = Network {
+      client: self.client.clone(),
+      app: self.app,
+      watches: self.watches.clone(),
+    };
+    let scope = WatchScope {
+      namespace: query.namespace.clone(),
+      ..WatchScope::default()
+    };
+    let mut tracker = TailTracker::new(pod_re, container_re, query.namespace.is_none());
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(256);
+    let mut join_set = tokio::task::JoinSet::new();
+    let mut batch: Vec<String> = Vec::with_capacity(BATCH_SIZE);
+    let mut last_flush = Instant::now();
+    let mut cancel_rx_collector = cancel_rx.clone();
+    let mut poll = tokio::time::interval(Duration::from_secs(TAIL_POLL_SECS));
+    let mut first_poll = true;
+
+    loop {
+      let flush_deadline =
+        tokio::time::sleep_until(last_flush + Duration::from_millis(BATCH_FLUSH_MS));
+
+      tokio::select! {
+        _ = cancel_rx_collector.changed() => {
+          if *cancel_rx_collector.borrow() {
+            if !batch.is_empty() {
+              let mut app = self.app.lock().await;
+              app.data.logs.add_records(batch);
+            }
+            break;
+          }
+        }
+        _ = poll.tick() => {
+          let pods = match lister.list_all_watched(api.clone(), scope.clone()).await {
+            Ok(pods) => pods,
+            Err(e) if first_poll => {
+              self
+                .handle_error(anyhow!("Failed to list pods for '{}': {}", query.label(), e))
+                .await;
+              break;
+            }
+            Err(e) => {
+              warn!("Log tail failed to list pods: {}", e);
+              continue;
+            }
+          };
+          // Reap streams that ended with their container.
+          while join_set.try_join_next().is_some() {}
+          let update = tracker.update(&pods, MAX_TAIL_STREAMS.saturating_sub(join_set.len()));
+          batch.extend(update.markers);
+          if first_poll && update.start.is_empty() {
+            batch.push("[kdash] No running pods match yet; new ones are added as they start".into());
+          }
+          for target in update.start {
+            if !first_poll {
+              batch.push(format!("[{}] [kdash] started tailing", target.short_name));
+            }
+            let client = self.client.clone();
+            let tx = tx.clone();
+            let cancel_rx = cancel_rx.clone();
+            let history = if first_poll {
+              history
+            } else {
+              LogHistory {
+                tail_lines: None,
+                since: None,
+              }
+            };
+            join_set.spawn(async move {
+              stream_single_pod_for_aggregate(client, target, history, timestamps, tx, cancel_rx)
+                .await;
+            });
+          }
+          first_poll = false;
+        }
+        line = rx.recv() => {
+          // `tx` is held here for new pods, so the channel never closes.
+          if let Some(line) = line {
+            batch.push(line);
+            if batch.len() >= BATCH_SIZE {
+              let mut app = self.app.lock().await;
+              app.data.logs.add_records(std::mem::replace(
+                &mut batch,
+                Vec::with_capacity(BATCH_SIZE),
+              ));
+              last_flush = Instant::now();
+            }
+          }
+        }
+        _ = flush_deadline => {
+          if !batch.is_empty() {
+            let mut app = self.app.lock().await;
+            app.data.logs.add_records(std::mem::replace(
+              &mut batch,
+              Vec::with_capacity(BATCH_SIZE),
+            ));
+            last_flush = Instant::now();
+          }
+        }
+      }
+    }
+
+    join_set.abort_all();
+    let mut app = self.app.lock().await;
+    app.is_streaming = false;
+    info!("Log tail for '{}' ended", query.label());
+  }
+
+  /// Validate the target, track it in app state and spawn the in-process
+  /// forwarding task. The TUI stays up and the forward runs until stopped.
   pub async fn start_port_forward(
     &self,
     kind: String,
@@ -670,134 +791,45 @@ impl<'a> NetworkStream<'a> {
     local_port: u16,
     remote_port: u16,
   ) {
-    let context = self.app.lock().await.data.selected.context.clone();
     let target = PortForwardTarget {
       kind,
       namespace,
       name,
       local_port,
       remote_port,
-      context,
     };
-    let command = match prepare_port_forward(&target) {
-      Ok(command) => command,
-      Err(error) => {
-        self.handle_error(anyhow!(error.to_string())).await;
-        return;
-      }
-    };
-
-    let mut child = match Command::new(&command.program)
-      .args(&command.args)
-      .stdin(Stdio::null())
-      .stdout(Stdio::piped())
-      .stderr(Stdio::piped())
-      .kill_on_drop(true)
-      .spawn()
-    {
-      Ok(child) => child,
-      Err(error) => {
-        self
-          .handle_error(anyhow!(
-            "Unable to start kubectl port-forward (is kubectl installed?): {}",
-            error
-          ))
-          .await;
-        return;
-      }
-    };
-
-    let stdout = child.stdout.take();
-    let stderr = child.stderr.take();
+    if let Err(error) = validate_port_forward(&target) {
+      self.handle_error(anyhow!(error.to_string())).await;
+      return;
+    }
 
-    let id = {
+    let (id, stats) = {
       let mut app = self.app.lock().await;
       app.add_port_forward(
-        target.kind,
-        target.namespace,
-        target.name,
+        target.kind.clone(),
+        target.namespace.clone(),
+        target.name.clone(),
         local_port,
         remote_port,
-        child,
       )
     };
 
-    if let (Some(stdout), Some(stderr)) = (stdout, stderr) {
-      let app = Arc::clone(self.app);
-      tokio::spawn(async move {
-        watch_port_forward(app, id, stdout, stderr).await;
-      });
-    }
+    let client = self.client.clone();
+    let task_app = Arc::clone(self.app);
+    let task = tokio::spawn(async move {
+      run_port_forward(client, task_app, id, target, stats).await;
+    });
+    let mut app = self.app.lock().await;
+    app.set_port_forward_task(id, task);
   }
 
-  /// Stop a tracked forward: remove it from app state and kill+reap the child on
-  /// the runtime that owns it.
+  /// Stop a tracked forward: remove it from app state and abort its task.
   pub async fn stop_port_forward(&self, id: u64) {
-    let child = {
-      let mut app = self.app.lock().await;
-      app.remove_port_forward(id)
-    };
-    if let Some(mut child) = child {
-      let _ = child.kill().await;
-    }
     let mut app = self.app.lock().await;
-    app.set_status_message("Stopped port-forward");
-  }
-}
-
-/// Read a forward's kubectl output until it exits. The first
-/// "Forwarding from …" line on stdout marks it active; an early exit (or any
-/// exit) reads stderr for the reason and marks it failed. Updates are no-ops if
-/// the forward was already removed (e.g. user stop).
-async fn watch_port_forward(
-  app: Arc<Mutex<App>>,
-  id: u64,
-  stdout: ChildStdout,
-  stderr: ChildStderr,
-) {
-  let mut lines = BufReader::new(stdout).lines();
-  let mut active = false;
-
-  // Reads until EOF/error (kubectl exited or was killed). Other lines ("Handling
-  // connection for …") are drained so the pipe never fills and blocks kubectl.
-  while let Ok(Some(line)) = lines.next_line().await {
-    if !active && line.contains("Forwarding from") {
-      active = true;
-      let mut app = app.lock().await;
-      app.set_port_forward_status(id, PortForwardStatus::Active);
+    if app.remove_port_forward(id) {
+      app.set_status_message("Stopped port-forward");
     }
   }
-
-  if active {
-    // Was forwarding and the process ended on its own (pod died, etc.).
-    let mut app = app.lock().await;
-    app.set_port_forward_status(id, PortForwardStatus::Failed("connection closed".into()));
-  } else {
-    let reason = read_port_forward_error(stderr).await;
-    let mut app = app.lock().await;
-    app.set_port_forward_status(id, PortForwardStatus::Failed(reason));
-  }
-}
-
-/// Read kubectl's stderr after an early exit and extract a short failure reason.
-async fn read_port_forward_error(stderr: ChildStderr) -> String {
-  let mut buf = String::new();
-  let _ = BufReader::new(stderr).read_to_string(&mut buf).await;
-  summarize_port_forward_error(&buf)
-}
-
-/// First non-empty stderr line, truncated to keep the toast/list line short.
-fn summarize_port_forward_error(stderr: &str) -> String {
-  let reason = stderr
-    .lines()
-    .map(str::trim)
-    .find(|line| !line.is_empty())
-    .unwrap_or("port-forward exited");
-  if reason.chars().count() > 80 {
-    reason.chars().take(77).collect::<String>() + "..."
-  } else {
-    reason.to_string()
-  }
 }
 
 /// Extract a short pod name suffix for log prefixes.
@@ -842,18 +874,458 @@ fn collect_pod_container_info(pods: &[Pod]) -> Vec<(String, String, String)> {
     .collect()
 }
 
+/// Load the selected container's previous instance into the log view, or into
+/// the previous pane of the split view when `split` is set, together with how
+/// that instance ended. Runs outside the stream worker for the split, which is
+/// busy following the live logs.
+pub async fn load_previous_logs(client: Client, app: &Arc<Mutex<App>>, split: bool) {
+  let (namespace, pod_name, cont_name, tail_lines, since, timestamps) = {
+    let app = app.lock().await;
+    let ns = app
+      .data
+      .pods
+      .get_selected_item_copy()
+      .map(|p| p.namespace)
+      .unwrap_or_else(|| std::env::var("NAMESPACE").unwrap_or_else(|_| "default".into()));
+    let pod = app
+      .data
+      .pods
+      .get_selected_item_copy()
+      .map(|p| p.name)
+      .unwrap_or_default();
+    let cont = app.data.selected.container.clone().unwrap_or_default();
+    let tail_lines = app.initial_log_tail_lines();
+    (ns, pod, cont, tail_lines, app.log_since, app.log_timestamps)
+  };
+
+  if pod_name.is_empty() || cont_name.is_empty() {
+    return;
+  }
+
+  let api: Api<Pod> = Api::namespaced(client, &namespace);
+  let mut lp = LogParams {
+    container: Some(cont_name.clone()),
+    previous: true,
+    follow: false,
+    tail_lines: Some(tail_lines),
+    timestamps,
+    ..Default::default()
+  };
+  if let Some(since) = since {
+    since.apply(&mut lp);
+  }
+
+  let lines = match api.logs(&pod_name, &lp).await {
+    Ok(logs) => {
+      let lines: Vec<String> = logs.lines().map(|line| line.to_string()).collect();
+      if lines.is_empty() {
+        vec![format!(
+          "[kdash] No previous logs for container {} (it has not restarted)",
+          cont_name
+        )]
+      } else {
+        lines
+      }
+    }
+    Err(e) => {
+      warn!(
+        "Failed to fetch previous logs for {}/{}: {}",
+        pod_name, cont_name, e
+      );
+      vec![format!(
+        "[kdash] No previous logs available for {} (the container has no prior terminated instance)",
+        cont_name
+      )]
+    }
+  };
+
+  let terminated = if split {
+    match api.get(&pod_name).await {
+      Ok(pod) => last_terminated(&pod, &cont_name, Utc::now()),
+      Err(e) => {
+        warn!("Failed to read the last state of {}: {}", pod_name, e);
+        None
+      }
+    }
+  } else {
+    None
+  };
+
+  let mut app = app.lock().await;
+  if !split {
+    app.data.logs.add_records(lines);
+  } else if let Some(log_split) = app
+    .data
+    .log_split
+    .as_mut()
+    .filter(|log_split| log_split.previous.id == cont_name)
+  {
+    log_split.previous.add_records(lines);
+    log_split.terminated = terminated;
+  }
+}
+
+/// How the container's previous instance ended, from `lastState.terminated`:
+/// `exit 137 (OOMKilled), 5m ago`.
+fn last_terminated(pod: &Pod, container: &str, now: DateTime<Utc>) -> Option<String> {
+  let terminated = pod
+    .status
+    .as_ref()?
+    .container_statuses
+    .as_ref()?
+    .iter()
+    .find(|status| status.name == container)?
+    .last_state
+    .as_ref()?
+    .terminated
+    .as_ref()?;
+  let mut summary = format!("exit {}", terminated.exit_code);
+  if let Some(signal) = terminated.signal.filter(|signal| *signal != 0) {
+    summary.push_str(&format!(" signal {}", signal));
+  }
+  if let Some(reason) = terminated.reason.as_deref().filter(|r| !r.is_empty()) {
+    summary.push_str(&format!(" ({})", reason));
+  }
+  if terminated.finished_at.is_some() {
+    summary.push_str(&format!(
+      ", {} ago",
+      to_age(terminated.finished_at.as_ref(), now)
+    ));
+  }
+  Some(summary)
+}
+
+/// Keeps a followed container's log continuous across reconnects. Lines are
+/// always requested with timestamps: the last one seen is where the next
+/// request resumes (`since_time`), lines up to it are dropped as already shown,
+/// and the timestamps are stripped again unless the view asked for them.
+struct LogResume {
+  show_timestamps: bool,
+  last: Option<DateTime<Utc>>,
+  /// Lines seen with the timestamp `last`, which a resumed stream replays.
+  seen_at_last: usize,
+  /// After a reconnect, how many replayed lines at `last` are still to skip;
+  /// `None` once the resumed stream has passed them.
+  replay: Option<usize>,
+  /// When the stream broke, until a line arrives on a new one.
+  broken_at: Option<std::time::Instant>,
+  /// Breaks since the last line; drives the backoff.
+  attempts: u32,
+  restarts: Option<i32>,
+}
+
+impl LogResume {
+  fn new(show_timestamps: bool) -> Self {
+    LogResume {
+      show_timestamps,
+      last: None,
+      seen_at_last: 0,
+      replay: None,
+      broken_at: None,
+      attempts: 0,
+      restarts: None,
+    }
+  }
+
+  /// `first` (the requested history) until a line was seen, then everything
+  /// from the second of the last one.
+  fn params(&self, first: &LogParams) -> LogParams {
+    let mut lp = first.clone();
+    lp.timestamps = true;
+    if let Some(last) = self.last {
+      lp.tail_lines = None;
+      lp.since_seconds = None;
+      lp.since_time = jiff::Timestamp::from_second(last.timestamp()).ok();
+    }
+    lp
+  }
+
+  /// Take a received line into `out`, after a `[kdash] reconnected after Ns`
+  /// marker if it is the first since a break. Blank lines and, after a
+  /// reconnect, the replayed lines already shown are dropped.
+  fn accept(&mut self, raw: &str, out: &mut Vec<String>) {
+    let raw = raw.trim_end();
+    let (token, message) = raw.split_once(' ').unwrap_or((raw, ""));
+    let line = match DateTime::parse_from_rfc3339(token) {
+      Ok(time) => {
+        let time = time.with_timezone(&Utc);
+        if self.replayed(time) {
+          return;
+        }
+        if self.last == Some(time) {
+          self.seen_at_last += 1;
+        } else {
+          self.last = Some(time);
+          self.seen_at_last = 1;
+        }
+        if message.trim().is_empty() {
+          return;
+        }
+        if self.show_timestamps {
+          format!("{} {}", token, message)
+        } else {
+          message.to_owned()
+        }
+      }
+      Err(_) if raw.trim().is_empty() => return,
+      Err(_) => raw.to_owned(),
+    };
+    self.attempts = 0;
+    if let Some(at) = self.broken_at.take() {
+      out.push(format!(
+        "[kdash] reconnected after {}s",
+        at.elapsed().as_secs()
+      ));
+    }
+    out.push(line);
+  }
+
+  /// Whether a line at `time` on a resumed stream was already shown: anything
+  /// before `last`, and the first `seen_at_last` lines at it.
+  fn replayed(&mut self, time: DateTime<Utc>) -> bool {
+    let (Some(last), Some(skip)) = (self.last, self.replay.as_mut()) else {
+      return false;
+    };
+    if time < last {
+      return true;
+    }
+    if time == last && *skip > 0 {
+      *skip -= 1;
+      return true;
+    }
+    self.replay = None;
+    false
+  }
+
+  /// The stream ended or failed to open. Returns how long to wait before the
+  /// next attempt: no wait at first, then doubling up to a cap.
+  fn broke(&mut self) -> Duration {
+    self.broken_at.get_or_insert_with(std::time::Instant::now);
+    self.replay = Some(self.seen_at_last);
+    self.attempts += 1;
+    reconnect_backoff(self.attempts)
+  }
+
+  fn reconnecting(&self) -> bool {
+    self.broken_at.is_some()
+  }
+
+  /// Note the container's restart count. Returns whether it went up since the
+  /// last check.
+  fn restarted(&mut self, restarts: Option<i32>) -> bool {
+    let before = self.restarts;
+    if restarts.is_some() {
+      self.restarts = restarts;
+    }
+    matches!((before, restarts), (Some(before), Some(now)) if now > before)
+  }
+}
+
+fn reconnect_backoff(attempt: u32) -> Duration {
+  match attempt {
+    0 | 1 => Duration::ZERO,
+    n => Duration::from_millis(500 << (n - 2).min(6))
+      .min(Duration::from_secs(MAX_RECONNECT_BACKOFF_SECS)),
+  }
+}
+
+/// Sleep before reconnecting. Returns false if the stream was cancelled first.
+async fn wait_for_reconnect(
+  backoff: Duration,
+  cancel_rx: &mut tokio::sync::watch::Receiver<bool>,
+) -> bool {
+  if *cancel_rx.borrow() {
+    return false;
+  }
+  tokio::select! {
+    _ = tokio::time::sleep(backoff) => {}
+    _ = cancel_rx.changed() => {}
+  }
+  !*cancel_rx.borrow()
+}
+
+/// What the pod says about a followed container before each (re)connect.
+struct ContainerCheck {
+  restarts: Option<i32>,
+  /// The pod succeeded or failed, so its containers won't run again.
+  pod_finished: bool,
+}
+
+async fn check_container(
+  api: &Api<Pod>,
+  pod_name: &str,
+  container: &str,
+) -> kube::Result<ContainerCheck> {
+  let status = api.get(pod_name).await?.status.unwrap_or_default();
+  Ok(ContainerCheck {
+    restarts: status
+      .container_statuses
+      .unwrap_or_default()
+      .into_iter()
+      .find(|c| c.name == container)
+      .map(|c| c.restart_count),
+    pod_finished: matches!(status.phase.as_deref(), Some("Succeeded" | "Failed")),
+  })
+}
+
+fn is_not_found(error: &kube::Error) -> bool {
+  matches!(error, kube::Error::Api(status) if status.is_not_found())
+}
+
+/// How much history each aggregate stream starts with.
+#[derive(Clone, Copy)]
+struct LogHistory {
+  /// `None` fetches the container's whole log.
+  tail_lines: Option<i64>,
+  since: Option<LogSince>,
+}
+
 struct AggregateStreamTarget {
   namespace: String,
   pod_name: String,
   container_name: String,
   short_name: String,
+  /// Keep streaming through container restarts. The multi-pod tail starts a
+  /// stream per container instance instead.
+  follow_restarts: bool,
+}
+
+/// What one pod list changes for the multi-pod tail.
+#[derive(Default)]
+struct TailUpdate {
+  start: Vec<AggregateStreamTarget>,
+  markers: Vec<String>,
+}
+
+/// Which containers the multi-pod tail is following and which pods it has
+/// marked as gone, across successive pod lists.
+struct TailTracker {
+  pod_re: Regex,
+  container_re: Option<Regex>,
+  /// Prefix lines with the namespace too, when tailing all of them.
+  all_namespaces: bool,
+  /// Container IDs a stream was started for; a restart gets a new ID.
+  started: HashSet<String>,
+  /// `namespace/pod` of matching pods in the last list.
+  listed: HashSet<String>,
+  /// `namespace/pod` already marked as terminated.
+  terminated: HashSet<String>,
+  /// Set once the stream cap was reported.
+  capped: bool,
+}
+
+impl TailTracker {
+  fn new(pod_re: Regex, container_re: Option<Regex>, all_namespaces: bool) -> Self {
+    TailTracker {
+      pod_re,
+      container_re,
+      all_namespaces,
+      started: HashSet::new(),
+      listed: HashSet::new(),
+      terminated: HashSet::new(),
+      capped: false,
+    }
+  }
+
+  /// Compare a fresh pod list with what is being followed: running containers
+  /// not seen before are returned to start (at most `room` of them), and pods
+  /// that finished, are being deleted or are gone get a marker line once.
+  fn update(&mut self, pods: &[Pod], room: usize) -> TailUpdate {
+    let mut update = TailUpdate::default();
+    let mut listed = HashSet::new();
+    for pod in pods {
+      let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+      let name = pod.metadata.name.clone().unwrap_or_default();
+      if name.is_empty() || !self.pod_re.is_match(&name) {
+        continue;
+      }
+      let key = format!("{}/{}", namespace, name);
+      let prefix = if self.all_namespaces { &key } else { &name };
+      let phase = pod.status.as_ref().and_then(|s| s.phase.as_deref());
+      let ended = if pod.metadata.deletion_timestamp.is_some() {
+        Some("terminating")
+      } else {
+        match phase {
+          Some("Succeeded") => Some("succeeded"),
+          Some("Failed") => Some("failed"),
+          _ => None,
+        }
+      };
+      match ended {
+        Some(reason) => {
+          if self.terminated.insert(key.clone()) {
+            update
+              .markers
+              .push(format!("[{}] [kdash] pod {}", prefix, reason));
+          }
+        }
+        // A StatefulSet pod can come back under the same name.
+        None => {
+          self.terminated.remove(&key);
+        }
+      }
+      let statuses = pod
+        .status
+        .as_ref()
+        .and_then(|s| s.container_statuses.as_deref())
+        .unwrap_or_default();
+      for status in statuses {
+        let running = status.state.as_ref().is_some_and(|s| s.running.is_some());
+        let matches = self
+          .container_re
+          .as_ref()
+          .is_none_or(|re| re.is_match(&status.name));
+        let id = status
+          .container_id
+          .clone()
+          .unwrap_or_else(|| format!("{}/{}#{}", key, status.name, status.restart_count));
+        if !running || !matches || self.started.contains(&id) {
+          continue;
+        }
+        if update.start.len() >= room {
+          if !self.capped {
+            self.capped = true;
+            update.markers.push(format!(
+              "[kdash] Not tailing more than {} containers",
+              MAX_TAIL_STREAMS
+            ));
+          }
+          continue;
+        }
+        self.started.insert(id);
+        update.start.push(AggregateStreamTarget {
+          namespace: namespace.clone(),
+          pod_name: name.clone(),
+          container_name: status.name.clone(),
+          short_name: format!("{}/{}", prefix, status.name),
+          follow_restarts: false,
+        });
+      }
+      listed.insert(key);
+    }
+    for key in self.listed.difference(&listed) {
+      if self.terminated.insert(key.clone()) {
+        let prefix = if self.all_namespaces {
+          key.as_str()
+        } else {
+          key.split_once('/').map_or(key.as_str(), |(_, name)| name)
+        };
+        update
+          .markers
+          .push(format!("[{}] [kdash] pod deleted", prefix));
+      }
+    }
+    self.listed = listed;
+    update
+  }
 }
 
-/// Stream logs from a single pod, prefixing each line and sending to the channel.
+/// Stream logs from a single pod, prefixing each line and sending to the
+/// channel. Breaks are reconnected like the single-container stream.
 async fn stream_single_pod_for_aggregate(
   client: Client,
   stream_target: AggregateStreamTarget,
-  tail_lines: i64,
+  history: LogHistory,
   timestamps: bool,
   tx: tokio::sync::mpsc::Sender<String>,
   cancel_rx: tokio::sync::watch::Receiver<bool>,
@@ -863,71 +1335,125 @@ async fn stream_single_pod_for_aggregate(
     pod_name,
     container_name,
     short_name,
+    follow_restarts,
   } = stream_target;
   let api: Api<Pod> = Api::namespaced(client, &namespace);
-  let lp = LogParams {
+  let mut first = LogParams {
     container: Some(container_name.clone()),
     follow: true,
     previous: false,
-    tail_lines: Some(tail_lines),
-    timestamps,
+    tail_lines: history.tail_lines,
     ..Default::default()
   };
+  if let Some(since) = history.since {
+    since.apply(&mut first);
+  }
+  let mut resume = LogResume::new(timestamps);
+  let mut failures: u32 = 0;
+  let mut cancel_rx = cancel_rx;
+  let mut out: Vec<String> = vec![];
+
+  loop {
+    match check_container(&api, &pod_name, &container_name).await {
+      Ok(check) if check.pod_finished && resume.reconnecting() => return,
+      Ok(check) => {
+        if resume.restarted(check.restarts) {
+          if !follow_restarts {
+            return;
+          }
+          out.push("[kdash] container restarted".into());
+        }
+      }
+      Err(e) if is_not_found(&e) => {
+        if follow_restarts {
+          out.push("[kdash] pod no longer exists".into());
+          send_prefixed(&tx, &short_name, &mut out).await;
+        }
+        return;
+      }
+      Err(_) => {}
+    }
+    if !send_prefixed(&tx, &short_name, &mut out).await {
+      return;
+    }
 
-  match api.log_stream(&pod_name, &lp).await {
-    Ok(logs) => {
-      let mut lines_stream = logs.lines();
-      let mut cancel_rx = cancel_rx;
-
-      loop {
-        tokio::select! {
-          _ = cancel_rx.changed() => {
-            if *cancel_rx.borrow() {
-              return;
+    match api.log_stream(&pod_name, &resume.params(&first)).await {
+      Ok(logs) => {
+        failures = 0;
+        let mut lines_stream = logs.lines();
+
+        loop {
+          tokio::select! {
+            _ = cancel_rx.changed() => {
+              if *cancel_rx.borrow() {
+                return;
+              }
             }
-          }
-          line = lines_stream.next() => {
-            match line {
-              Some(Ok(line)) => {
-                let line = line.trim().to_string();
-                if !line.is_empty() {
-                  let prefixed = format!("[{}] {}", short_name, line);
-                  if tx.send(prefixed).await.is_err() {
+            line = lines_stream.next() => {
+              match line {
+                Some(Ok(line)) => {
+                  resume.accept(&line, &mut out);
+                  if !send_prefixed(&tx, &short_name, &mut out).await {
                     return; // Receiver dropped
                   }
                 }
-              }
-              Some(Err(e)) => {
-                warn!(
-                  "Aggregate stream error for {}/{}: {}",
-                  pod_name, container_name, e
-                );
-                return;
-              }
-              None => {
-                debug!("Aggregate stream ended for {}/{}", pod_name, container_name);
-                return;
+                Some(Err(e)) => {
+                  warn!(
+                    "Aggregate stream error for {}/{}: {}",
+                    pod_name, container_name, e
+                  );
+                  break;
+                }
+                None => {
+                  debug!("Aggregate stream ended for {}/{}", pod_name, container_name);
+                  break;
+                }
               }
             }
           }
         }
       }
+      Err(e) => {
+        warn!(
+          "Failed to open aggregate log stream for {}/{}: {}",
+          pod_name, container_name, e
+        );
+        failures += 1;
+        if failures == 1 || failures >= MAX_RECONNECT_ATTEMPTS {
+          let _ = tx
+            .send(format!(
+              "[{}] Error: failed to stream logs: {}",
+              short_name, e
+            ))
+            .await;
+        }
+        if failures >= MAX_RECONNECT_ATTEMPTS {
+          return;
+        }
+      }
     }
-    Err(e) => {
-      warn!(
-        "Failed to open aggregate log stream for {}/{}: {}",
-        pod_name, container_name, e
-      );
-      let _ = tx
-        .send(format!(
-          "[{}] Error: failed to stream logs: {}",
-          short_name, e
-        ))
-        .await;
+
+    if !wait_for_reconnect(resume.broke(), &mut cancel_rx).await {
+      return;
     }
   }
 }
 
+/// Send `lines` with the `[prefix]` of their stream. Returns false once the
+/// collector is gone.
+async fn send_prefixed(
+  tx: &tokio::sync::mpsc::Sender<String>,
+  prefix: &str,
+  lines: &mut Vec<String>,
+) -> bool {
+  for line in lines.drain(..) {
+    if tx.send(format!("[{}] {}", prefix, line)).await.is_err() {
+      return false;
+    }
+  }
+  true
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -983,25 +1509,6 @@ mod tests {
     );
   }
 
-  #[test]
-  fn test_summarize_port_forward_error_picks_first_line_and_truncates() {
-    assert_eq!(
-      summarize_port_forward_error(
-        "\n  Unable to listen on port 8080: bind: address already in use\nmore"
-      ),
-      "Unable to listen on port 8080: bind: address already in use"
-    );
-    assert_eq!(
-      summarize_port_forward_error("   \n  "),
-      "port-forward exited"
-    );
-
-    let long = "x".repeat(200);
-    let summary = summarize_port_forward_error(&long);
-    assert_eq!(summary.chars().count(), 80);
-    assert!(summary.ends_with("..."));
-  }
-
   fn make_pod(name: &str, containers: &[&str]) -> Pod {
     use k8s_openapi::api::core::v1::{Container, PodSpec};
     Pod {
@@ -1108,4 +1615,207 @@ mod tests {
     let info = collect_pod_container_info(&[pod]);
     assert!(info.is_empty());
   }
+
+  #[test]
+  fn test_log_resume_strips_timestamps_and_drops_replayed_lines() {
+    let mut resume = LogResume::new(false);
+    let mut out = vec![];
+    resume.accept("2024-05-01T10:00:00.100Z starting", &mut out);
+    resume.accept("2024-05-01T10:00:00.200Z \tat main.go:12", &mut out);
+    resume.accept("2024-05-01T10:00:00.300Z ", &mut out);
+    assert_eq!(out, vec!["starting", "\tat main.go:12"]);
+
+    // Resumes from the second of the last line and skips what was shown.
+    let first = LogParams {
+      tail_lines: Some(100),
+      ..LogParams::default()
+    };
+    assert_eq!(resume.broke(), Duration::ZERO);
+    let lp = resume.params(&first);
+    assert!(lp.timestamps);
+    assert_eq!(lp.tail_lines, None);
+    assert_eq!(
+      lp.since_time.map(|time| time.to_string()),
+      Some("2024-05-01T10:00:00Z".to_owned())
+    );
+    out.clear();
+    resume.accept("2024-05-01T10:00:00.100Z starting", &mut out);
+    resume.accept("2024-05-01T10:00:00.300Z ", &mut out);
+    assert!(out.is_empty());
+    resume.accept("2024-05-01T10:00:01Z ready", &mut out);
+    assert_eq!(out, vec!["[kdash] reconnected after 0s", "ready"]);
+    assert!(!resume.reconnecting());
+
+    let mut shown = LogResume::new(true);
+    out.clear();
+    shown.accept("2024-05-01T10:00:00Z ready", &mut out);
+    assert_eq!(out, vec!["2024-05-01T10:00:00Z ready"]);
+  }
+
+  #[test]
+  fn test_log_resume_keeps_lines_sharing_a_timestamp() {
+    let mut resume = LogResume::new(false);
+    let mut out = vec![];
+    resume.accept("2024-05-01T10:00:00Z first", &mut out);
+    resume.accept("2024-05-01T10:00:00Z second", &mut out);
+    assert_eq!(out, vec!["first", "second"]);
+
+    // Only the two lines already shown at that time are skipped on resume.
+    resume.broke();
+    out.clear();
+    resume.accept("2024-05-01T10:00:00Z first", &mut out);
+    resume.accept("2024-05-01T10:00:00Z second", &mut out);
+    resume.accept("2024-05-01T10:00:00Z third", &mut out);
+    resume.accept("2024-05-01T10:00:00Z fourth", &mut out);
+    assert_eq!(out, vec!["[kdash] reconnected after 0s", "third", "fourth"]);
+  }
+
+  #[test]
+  fn test_log_resume_backs_off_and_spots_restarts() {
+    let mut resume = LogResume::new(false);
+    let waits: Vec<Duration> = (0..10).map(|_| resume.broke()).collect();
+    assert_eq!(waits[0], Duration::ZERO);
+    assert_eq!(waits[1], Duration::from_millis(500));
+    assert_eq!(waits[2], Duration::from_secs(1));
+    assert_eq!(waits[9], Duration::from_secs(MAX_RECONNECT_BACKOFF_SECS));
+
+    assert!(!resume.restarted(Some(2)));
+    assert!(!resume.restarted(None));
+    assert!(!resume.restarted(Some(2)));
+    assert!(resume.restarted(Some(3)));
+  }
+
+  #[test]
+  fn test_last_terminated_summarises_the_previous_instance() {
+    use k8s_openapi::{
+      api::core::v1::{ContainerState, ContainerStateTerminated, ContainerStatus, PodStatus},
+      apimachinery::pkg::apis::meta::v1::Time,
+    };
+    let mut pod = make_pod("api", &["app"]);
+    pod.status = Some(PodStatus {
+      container_statuses: Some(vec![ContainerStatus {
+        name: "app".into(),
+        last_state: Some(ContainerState {
+          terminated: Some(ContainerStateTerminated {
+            exit_code: 137,
+            reason: Some("OOMKilled".into()),
+            finished_at: Some(Time(jiff::Timestamp::from_second(1_700_000_000).unwrap())),
+            ..Default::default()
+          }),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }]),
+      ..Default::default()
+    });
+    let now = DateTime::from_timestamp(1_700_000_300, 0).unwrap();
+
+    assert_eq!(
+      last_terminated(&pod, "app", now).as_deref(),
+      Some("exit 137 (OOMKilled), 5m ago")
+    );
+    assert_eq!(last_terminated(&pod, "sidecar", now), None);
+  }
+
+  /// A pod in `namespace` whose containers are running when given an ID.
+  fn make_tail_pod(namespace: &str, name: &str, containers: &[(&str, Option<&str>)]) -> Pod {
+    use k8s_openapi::api::core::v1::{
+      ContainerState, ContainerStateRunning, ContainerStatus, PodStatus,
+    };
+    let mut pod = make_pod(name, &[]);
+    pod.metadata.namespace = Some(namespace.into());
+    pod.status = Some(PodStatus {
+      phase: Some("Running".into()),
+      container_statuses: Some(
+        containers
+          .iter()
+          .map(|(container, id)| ContainerStatus {
+            name: container.to_string(),
+            container_id: id.map(str::to_owned),
+            state: id.map(|_| ContainerState {
+              running: Some(ContainerStateRunning::default()),
+              ..Default::default()
+            }),
+            ..Default::default()
+          })
+          .collect(),
+      ),
+      ..Default::default()
+    });
+    pod
+  }
+
+  fn started(update: &TailUpdate) -> Vec<String> {
+    update
+      .start
+      .iter()
+      .map(|target| target.short_name.clone())
+      .collect()
+  }
+
+  #[test]
+  fn test_tail_tracker_adds_new_containers_once() {
+    let mut tracker = TailTracker::new(
+      Regex::new("^api-").unwrap(),
+      Some(Regex::new("app").unwrap()),
+      false,
+    );
+    let first = vec![
+      make_tail_pod(
+        "prod",
+        "api-1",
+        &[("app", Some("c1")), ("sidecar", Some("c2"))],
+      ),
+      make_tail_pod("prod", "web-1", &[("app", Some("c3"))]),
+      make_tail_pod("prod", "api-2", &[("app", None)]),
+    ];
+    let update = tracker.update(&first, MAX_TAIL_STREAMS);
+    assert_eq!(started(&update), vec!["api-1/app"]);
+    assert!(update.markers.is_empty());
+
+    // api-2 starts and api-1's container restarts with a new ID.
+    let second = vec![
+      make_tail_pod("prod", "api-1", &[("app", Some("c4"))]),
+      make_tail_pod("prod", "api-2", &[("app", Some("c5"))]),
+    ];
+    let update = tracker.update(&second, MAX_TAIL_STREAMS);
+    assert_eq!(started(&update), vec!["api-1/app", "api-2/app"]);
+    assert!(tracker.update(&second, MAX_TAIL_STREAMS).start.is_empty());
+  }
+
+  #[test]
+  fn test_tail_tracker_marks_terminated_and_deleted_pods_once() {
+    let mut tracker = TailTracker::new(Regex::new("api").unwrap(), None, true);
+    let mut done = make_tail_pod("prod", "api-job", &[]);
+    done.status.as_mut().unwrap().phase = Some("Succeeded".into());
+    let pods = vec![make_tail_pod("prod", "api-1", &[("app", Some("c1"))]), done];
+
+    let update = tracker.update(&pods, MAX_TAIL_STREAMS);
+    assert_eq!(started(&update), vec!["prod/api-1/app"]);
+    assert_eq!(update.markers, vec!["[prod/api-job] [kdash] pod succeeded"]);
+    assert!(tracker.update(&pods, MAX_TAIL_STREAMS).markers.is_empty());
+
+    let update = tracker.update(&pods[1..], MAX_TAIL_STREAMS);
+    assert_eq!(update.markers, vec!["[prod/api-1] [kdash] pod deleted"]);
+  }
+
+  #[test]
+  fn test_tail_tracker_stops_at_the_stream_cap() {
+    let mut tracker = TailTracker::new(Regex::new(".").unwrap(), None, false);
+    let pods = vec![
+      make_tail_pod("prod", "a", &[("app", Some("c1"))]),
+      make_tail_pod("prod", "b", &[("app", Some("c2"))]),
+    ];
+    let update = tracker.update(&pods, 1);
+    assert_eq!(started(&update), vec!["a/app"]);
+    assert_eq!(
+      update.markers,
+      vec![format!(
+        "[kdash] Not tailing more than {} containers",
+        MAX_TAIL_STREAMS
+      )]
+    );
+    // b is picked up once there is room.
+    assert_eq!(started(&tracker.update(&pods, 1)), vec!["b/app"]);
+  }
 }
@@ -0,0 +1,623 @@
+//! Watch-backed informer cache for resource tabs.
+//!
+//! Each resource kind (and namespace/selector scope) gets a store that is seeded from a
+//! regular list call and then kept current by a background `watch` from the
+//! list's `resourceVersion`. Polling reads the store instead of re-listing the
+//! whole collection. When a watch fails the store is marked failed and callers
+//! fall back to plain listing until [`WATCH_RETRY_SECS`] has passed.
+use std::{
+  any::{Any, TypeId},
+  collections::{BTreeMap, HashMap, HashSet},
+  fmt,
+  sync::{Arc, Mutex as StdMutex, RwLock},
+  time::{Duration, Instant},
+};
+
+use futures::StreamExt;
+use kube::{
+  api::{ListParams, WatchEvent, WatchParams},
+  Api, Resource, ResourceExt,
+};
+use log::{debug, warn};
+use serde::de::DeserializeOwned;
+use tokio::task::JoinHandle;
+
+use crate::app::models::ListSelector;
+
+/// Server-side timeout for a single watch request; the watch is re-issued from
+/// the last seen `resourceVersion` when it ends. Must stay below 295s.
+const WATCH_TIMEOUT_SECS: u32 = 290;
+/// How long a failed watch stays on the polling fallback before it is retried.
+const WATCH_RETRY_SECS: u64 = 30;
+/// HTTP status the API server uses when a `resourceVersion` is too old.
+const GONE: u16 = 410;
+/// Scoped (namespaced or selector-filtered) watches kept per kind, so moving
+/// between a few tabs and namespaces does not re-list each time.
+const MAX_SCOPES_PER_KIND: usize = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WatchStatus {
+  /// Watch is running and the store mirrors the server.
+  Watching,
+  /// Watch ended with an error; callers should poll until the retry window passes.
+  Failed { at: Instant, reason: String },
+}
+
+/// Objects for one resource kind keyed by `(namespace, name)`, plus the
+/// `resourceVersion` the store is synced to.
+pub struct ReflectorStore<K> {
+  objects: BTreeMap<(String, String), K>,
+  resource_version: Option<String>,
+  status: WatchStatus,
+}
+
+impl<K: Resource + Clone> ReflectorStore<K> {
+  pub fn new(items: Vec<K>, resource_version: Option<String>) -> Self {
+    let mut store = ReflectorStore {
+      objects: BTreeMap::new(),
+      resource_version: None,
+      status: WatchStatus::Watching,
+    };
+    store.replace(items, resource_version);
+    store
+  }
+
+  /// Replace the whole store with a fresh list result.
+  pub fn replace(&mut self, items: Vec<K>, resource_version: Option<String>) {
+    self.objects = items
+      .into_iter()
+      .map(|obj| (object_key(&obj), obj))
+      .collect();
+    self.resource_version = resource_version;
+    self.status = WatchStatus::Watching;
+  }
+
+  /// Apply a single watch event. Returns whether the objects changed, or an
+  /// error when the watch has to end: `410 Gone` leaves the store healthy so
+  /// the caller can re-list, anything else marks it failed.
+  pub fn apply(&mut self, event: WatchEvent<K>) -> Result<bool, String> {
+    match event {
+      WatchEvent::Added(obj) | WatchEvent::Modified(obj) => {
+        self.track_version(&obj);
+        self.objects.insert(object_key(&obj), obj);
+        Ok(true)
+      }
+      WatchEvent::Deleted(obj) => {
+        self.track_version(&obj);
+        self.objects.remove(&object_key(&obj));
+        Ok(true)
+      }
+      WatchEvent::Bookmark(bookmark) => {
+        self.resource_version = Some(bookmark.metadata.resource_version);
+        Ok(false)
+      }
+      WatchEvent::Error(status) if status.code == GONE => Err(status.message),
+      WatchEvent::Error(status) => {
+        self.fail(status.message.clone());
+        Err(status.message)
+      }
+    }
+  }
+
+  pub fn fail(&mut self, reason: String) {
+    self.status = WatchStatus::Failed {
+      at: Instant::now(),
+      reason,
+    };
+  }
+
+  pub fn resource_version(&self) -> Option<&str> {
+    self.resource_version.as_deref()
+  }
+
+  pub fn status(&self) -> &WatchStatus {
+    &self.status
+  }
+
+  /// Objects ordered by namespace then name, matching list call ordering.
+  pub fn snapshot(&self) -> Vec<K> {
+    self.objects.values().cloned().collect()
+  }
+
+  fn track_version(&mut self, obj: &K) {
+    if let Some(rv) = obj.meta().resource_version.as_ref() {
+      self.resource_version = Some(rv.clone());
+    }
+  }
+}
+
+fn object_key<K: Resource>(obj: &K) -> (String, String) {
+  (obj.namespace().unwrap_or_default(), obj.name_any())
+}
+
+type SharedStore<K> = Arc<RwLock<ReflectorStore<K>>>;
+/// A watched resource type and the scope it is watched in.
+pub type WatchKey = (TypeId, WatchScope);
+
+/// What a watch covers: the namespace its `Api` is bound to (`None` for all
+/// namespaces / cluster scope) and the tab's server-side selector.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WatchScope {
+  pub namespace: Option<String>,
+  pub selector: ListSelector,
+}
+
+impl WatchScope {
+  /// The unfiltered all-namespaces scope, shared by views such as nodes.
+  fn is_cluster_wide(&self) -> bool {
+    self.namespace.is_none() && self.selector.is_empty()
+  }
+}
+
+/// Watches whose stores changed since the UI last checked, so a tab is only
+/// re-read between polls when something it shows changed.
+#[derive(Clone, Debug, Default)]
+pub struct WatchChanges {
+  dirty: Arc<StdMutex<HashSet<WatchKey>>>,
+}
+
+impl WatchChanges {
+  pub fn mark(&self, key: &WatchKey) {
+    if let Ok(mut dirty) = self.dirty.lock() {
+      dirty.insert(key.clone());
+    }
+  }
+
+  /// Whether any of `keys` changed; those keys are reset.
+  pub fn take_any(&self, keys: &HashSet<WatchKey>) -> bool {
+    let Ok(mut dirty) = self.dirty.lock() else {
+      return false;
+    };
+    let before = dirty.len();
+    dirty.retain(|key| !keys.contains(key));
+    dirty.len() != before
+  }
+
+  pub fn clear(&self) {
+    if let Ok(mut dirty) = self.dirty.lock() {
+      dirty.clear();
+    }
+  }
+}
+
+struct WatchEntry {
+  store: Box<dyn Any + Send + Sync>,
+  handle: JoinHandle<()>,
+  last_used: Instant,
+}
+
+impl Drop for WatchEntry {
+  fn drop(&mut self) {
+    self.handle.abort();
+  }
+}
+
+/// Registry of running watches, keyed by resource type and [`WatchScope`].
+/// Besides the cluster-wide scope at most [`MAX_SCOPES_PER_KIND`] scopes are
+/// kept per kind, evicting the least recently used, so switching namespaces or
+/// selectors does not pile up watches.
+#[derive(Clone, Default)]
+pub struct WatchCache {
+  entries: Arc<StdMutex<HashMap<WatchKey, WatchEntry>>>,
+  /// Keys listed since the last [`WatchCache::take_reads`].
+  reads: Arc<StdMutex<HashSet<WatchKey>>>,
+}
+
+impl WatchCache {
+  /// Note that `K` in `scope` was listed, whether from a store or the server.
+  pub fn record_read<K: 'static>(&self, scope: &WatchScope) {
+    if let Ok(mut reads) = self.reads.lock() {
+      reads.insert((TypeId::of::<K>(), scope.clone()));
+    }
+  }
+
+  /// The keys listed since the last call, e.g. while handling one event.
+  pub fn take_reads(&self) -> HashSet<WatchKey> {
+    self
+      .reads
+      .lock()
+      .map(|mut reads| std::mem::take(&mut *reads))
+      .unwrap_or_default()
+  }
+
+  /// Current objects for `K` in `scope` if a healthy watch is feeding them.
+  pub fn snapshot<K>(&self, scope: &WatchScope) -> Option<Vec<K>>
+  where
+    K: Resource + Clone + Send + Sync + 'static,
+  {
+    let mut entries = self.entries.lock().ok()?;
+    let entry = entries.get_mut(&(TypeId::of::<K>(), scope.clone()))?;
+    entry.last_used = Instant::now();
+    let store = entry.store.downcast_ref::<SharedStore<K>>()?.read().ok()?;
+    match store.status() {
+      WatchStatus::Watching => Some(store.snapshot()),
+      WatchStatus::Failed { .. } => None,
+    }
+  }
+
+  /// Whether a new watch should be started for `K` in `scope`: either none is
+  /// running or the last one failed longer than the retry window ago.
+  pub fn should_start<K>(&self, scope: &WatchScope) -> bool
+  where
+    K: Resource + Clone + Send + Sync + 'static,
+  {
+    let Ok(entries) = self.entries.lock() else {
+      return false;
+    };
+    let Some(entry) = entries.get(&(TypeId::of::<K>(), scope.clone())) else {
+      return true;
+    };
+    let Some(store) = entry.store.downcast_ref::<SharedStore<K>>() else {
+      return true;
+    };
+    let status = store.read().map(|s| s.status().clone());
+    match status {
+      Ok(WatchStatus::Failed { at, .. }) => at.elapsed() >= Duration::from_secs(WATCH_RETRY_SECS),
+      Ok(WatchStatus::Watching) => entry.handle.is_finished(),
+      Err(_) => true,
+    }
+  }
+
+  /// Seed a store from a list result and start watching from its
+  /// `resourceVersion`. The key is marked in `changes` whenever the store is
+  /// updated.
+  pub fn start<K>(
+    &self,
+    api: Api<K>,
+    scope: WatchScope,
+    items: Vec<K>,
+    resource_version: Option<String>,
+    page_size: u32,
+    changes: WatchChanges,
+  ) where
+    K: Resource + Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+  {
+    let store: SharedStore<K> = Arc::new(RwLock::new(ReflectorStore::new(items, resource_version)));
+    let handle = tokio::spawn(run_watch(
+      api,
+      store.clone(),
+      (TypeId::of::<K>(), scope.clone()),
+      page_size,
+      changes,
+    ));
+    let Ok(mut entries) = self.entries.lock() else {
+      handle.abort();
+      return;
+    };
+    let type_id = TypeId::of::<K>();
+    let evict = !scope.is_cluster_wide();
+    entries.insert(
+      (type_id, scope),
+      WatchEntry {
+        store: Box::new(store),
+        handle,
+        last_used: Instant::now(),
+      },
+    );
+    if evict {
+      evict_scopes(&mut entries, type_id);
+    }
+  }
+
+  /// Stop every watch, e.g. when the client or context changes.
+  pub fn clear(&self) {
+    if let Ok(mut entries) = self.entries.lock() {
+      entries.clear();
+    }
+    self.take_reads();
+  }
+}
+
+/// Drop the least recently used scoped watches of `type_id` beyond
+/// [`MAX_SCOPES_PER_KIND`]; dropping an entry aborts its watch.
+fn evict_scopes(entries: &mut HashMap<WatchKey, WatchEntry>, type_id: TypeId) {
+  let mut scoped: Vec<(Instant, WatchKey)> = entries
+    .iter()
+    .filter(|((t, s), _)| *t == type_id && !s.is_cluster_wide())
+    .map(|(key, entry)| (entry.last_used, key.clone()))
+    .collect();
+  if scoped.len() <= MAX_SCOPES_PER_KIND {
+    return;
+  }
+  scoped.sort_by_key(|(last_used, _)| *last_used);
+  for (_, key) in scoped.drain(..scoped.len() - MAX_SCOPES_PER_KIND) {
+    entries.remove(&key);
+  }
+}
+
+/// Keep `store` in sync with the server until the watch fails. Watches that
+/// time out are re-issued from the last `resourceVersion`; a `410 Gone`
+/// triggers a re-list. Any other error marks the store failed so callers fall
+/// back to polling.
+async fn run_watch<K>(
+  api: Api<K>,
+  store: SharedStore<K>,
+  key: WatchKey,
+  page_size: u32,
+  changes: WatchChanges,
+) where
+  K: Resource + Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+{
+  let kind = crate::app::utils::friendly_type_name::<K>();
+  let selector = &key.1.selector;
+  loop {
+    let rv = match store.read() {
+      Ok(s) => s.resource_version().unwrap_or("0").to_owned(),
+      Err(_) => return,
+    };
+    let wp = selector.watch_params(WatchParams::default().timeout(WATCH_TIMEOUT_SECS));
+    let mut stream = match api.watch(&wp, &rv).await {
+      Ok(stream) => stream.boxed(),
+      Err(e) => {
+        mark_failed(&store, &kind, e.to_string());
+        return;
+      }
+    };
+
+    let mut relist = false;
+    while let Some(event) = stream.next().await {
+      let result = match event {
+        Ok(event) => match store.write() {
+          Ok(mut s) => s.apply(event),
+          Err(_) => return,
+        },
+        Err(e) => {
+          mark_failed(&store, &kind, e.to_string());
+          return;
+        }
+      };
+      match result {
+        Ok(true) => changes.mark(&key),
+        Ok(false) => {}
+        Err(reason) => {
+          let failed = store
+            .read()
+            .map(|s| matches!(s.status(), WatchStatus::Failed { .. }))
+            .unwrap_or(true);
+          if failed {
+            warn!("Watch for {} failed: {}", kind, reason);
+            return;
+          }
+          debug!("Watch for {} expired ({}), re-listing", kind, reason);
+          relist = true;
+          break;
+        }
+      }
+    }
+
+    if relist {
+      match list_all_pages(&api, selector, page_size).await {
+        Ok((items, resource_version)) => {
+          if let Ok(mut s) = store.write() {
+            s.replace(items, resource_version);
+          }
+          changes.mark(&key);
+        }
+        Err(e) => {
+          mark_failed(&store, &kind, e.to_string());
+          return;
+        }
+      }
+    }
+  }
+}
+
+/// List the whole collection in pages of `page_size`, returning the objects
+/// and the `resourceVersion` of the consistent snapshot they came from.
+async fn list_all_pages<K>(
+  api: &Api<K>,
+  selector: &ListSelector,
+  page_size: u32,
+) -> kube::Result<(Vec<K>, Option<String>)>
+where
+  K: Resource + Clone + DeserializeOwned + fmt::Debug,
+{
+  let mut items = vec![];
+  let mut lp = selector.list_params(ListParams::default().limit(page_size));
+  loop {
+    let page = api.list(&lp).await?;
+    items.extend(page.items);
+    match page.metadata.continue_.filter(|t| !t.is_empty()) {
+      Some(token) => lp = lp.continue_token(&token),
+      None => return Ok((items, page.metadata.resource_version)),
+    }
+  }
+}
+
+fn mark_failed<K>(store: &SharedStore<K>, kind: &str, reason: String)
+where
+  K: Resource + Clone,
+{
+  warn!(
+    "Watch for {} failed, falling back to polling: {}",
+    kind, reason
+  );
+  if let Ok(mut s) = store.write() {
+    s.fail(reason);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use k8s_openapi::api::core::v1::Pod;
+  use kube::{
+    api::ObjectMeta,
+    core::{
+      watch::{Bookmark, BookmarkMeta},
+      Status, TypeMeta,
+    },
+  };
+
+  use super::*;
+
+  fn pod(ns: &str, name: &str, rv: &str) -> Pod {
+    Pod {
+      metadata: ObjectMeta {
+        name: Some(name.into()),
+        namespace: Some(ns.into()),
+        resource_version: Some(rv.into()),
+        ..ObjectMeta::default()
+      },
+      ..Pod::default()
+    }
+  }
+
+  fn names(store: &ReflectorStore<Pod>) -> Vec<String> {
+    store.snapshot().iter().map(|p| p.name_any()).collect()
+  }
+
+  #[test]
+  fn test_store_seeded_from_list_is_ordered() {
+    let store = ReflectorStore::new(
+      vec![pod("b", "z", "1"), pod("a", "y", "2"), pod("a", "x", "3")],
+      Some("10".into()),
+    );
+    assert_eq!(names(&store), vec!["x", "y", "z"]);
+    assert_eq!(store.resource_version(), Some("10"));
+    assert_eq!(store.status(), &WatchStatus::Watching);
+  }
+
+  #[test]
+  fn test_store_applies_added_modified_deleted() {
+    let mut store = ReflectorStore::new(vec![pod("a", "x", "1")], Some("1".into()));
+
+    assert_eq!(store.apply(WatchEvent::Added(pod("a", "y", "2"))), Ok(true));
+    assert_eq!(names(&store), vec!["x", "y"]);
+    assert_eq!(store.resource_version(), Some("2"));
+
+    let mut modified = pod("a", "x", "3");
+    modified.metadata.labels = Some([("app".to_owned(), "api".to_owned())].into());
+    assert_eq!(store.apply(WatchEvent::Modified(modified)), Ok(true));
+    assert_eq!(store.snapshot()[0].labels().get("app").unwrap(), "api");
+    assert_eq!(store.resource_version(), Some("3"));
+
+    assert_eq!(
+      store.apply(WatchEvent::Deleted(pod("a", "y", "4"))),
+      Ok(true)
+    );
+    assert_eq!(names(&store), vec!["x"]);
+    assert_eq!(store.resource_version(), Some("4"));
+  }
+
+  #[test]
+  fn test_store_bookmark_only_advances_version() {
+    let mut store = ReflectorStore::new(vec![pod("a", "x", "1")], Some("1".into()));
+    let bookmark = Bookmark {
+      types: TypeMeta::default(),
+      metadata: BookmarkMeta {
+        resource_version: "42".into(),
+        annotations: Default::default(),
+      },
+    };
+    assert_eq!(store.apply(WatchEvent::Bookmark(bookmark)), Ok(false));
+    assert_eq!(store.resource_version(), Some("42"));
+    assert_eq!(names(&store), vec!["x"]);
+  }
+
+  #[test]
+  fn test_store_gone_requests_relist_without_failing() {
+    let mut store = ReflectorStore::new(vec![pod("a", "x", "1")], Some("1".into()));
+    let gone = Status::failure("too old resource version", "Expired").with_code(410);
+    assert!(store.apply(WatchEvent::Error(Box::new(gone))).is_err());
+    assert_eq!(store.status(), &WatchStatus::Watching);
+  }
+
+  #[test]
+  fn test_store_other_error_marks_failed() {
+    let mut store = ReflectorStore::new(vec![pod("a", "x", "1")], Some("1".into()));
+    let forbidden = Status::failure("forbidden", "Forbidden").with_code(403);
+    assert!(store.apply(WatchEvent::Error(Box::new(forbidden))).is_err());
+    assert!(matches!(store.status(), WatchStatus::Failed { reason, .. } if reason == "forbidden"));
+  }
+
+  #[tokio::test]
+  async fn test_watch_cache_snapshot_and_fallback() {
+    let cache = WatchCache::default();
+    let scope = WatchScope {
+      namespace: Some("team-a".to_owned()),
+      selector: ListSelector::default(),
+    };
+    assert!(cache.snapshot::<Pod>(&scope).is_none());
+    assert!(cache.should_start::<Pod>(&scope));
+
+    let store: SharedStore<Pod> = Arc::new(RwLock::new(ReflectorStore::new(
+      vec![pod("team-a", "x", "1")],
+      None,
+    )));
+    cache.entries.lock().unwrap().insert(
+      (TypeId::of::<Pod>(), scope.clone()),
+      WatchEntry {
+        store: Box::new(store.clone()),
+        handle: tokio::spawn(std::future::pending()),
+        last_used: Instant::now(),
+      },
+    );
+
+    assert_eq!(cache.snapshot::<Pod>(&scope).map(|p| p.len()), Some(1));
+    assert!(!cache.should_start::<Pod>(&scope));
+
+    // a failed watch falls back to polling and is not retried straight away
+    store.write().unwrap().fail("boom".into());
+    assert!(cache.snapshot::<Pod>(&scope).is_none());
+    assert!(!cache.should_start::<Pod>(&scope));
+
+    cache.clear();
+    assert!(cache.entries.lock().unwrap().is_empty());
+    assert!(cache.should_start::<Pod>(&scope));
+  }
+
+  #[test]
+  fn test_watch_changes_only_report_the_given_keys() {
+    let cache = WatchCache::default();
+    let scope = WatchScope {
+      namespace: Some("team-a".to_owned()),
+      selector: ListSelector::default(),
+    };
+    cache.record_read::<Pod>(&scope);
+    let reads = cache.take_reads();
+    assert_eq!(reads, HashSet::from([(TypeId::of::<Pod>(), scope.clone())]));
+    assert!(cache.take_reads().is_empty());
+
+    let changes = WatchChanges::default();
+    changes.mark(&(TypeId::of::<Pod>(), WatchScope::default()));
+    assert!(!changes.take_any(&reads));
+
+    changes.mark(&(TypeId::of::<Pod>(), scope));
+    assert!(changes.take_any(&reads));
+    assert!(!changes.take_any(&reads));
+  }
+
+  #[tokio::test]
+  async fn test_watch_cache_evicts_least_recently_used_scope() {
+    let cache = WatchCache::default();
+    let scope = |ns: &str| WatchScope {
+      namespace: Some(ns.to_owned()),
+      selector: ListSelector::default(),
+    };
+    let insert = |scope: WatchScope, age_secs: u64| {
+      let store: SharedStore<Pod> = Arc::new(RwLock::new(ReflectorStore::new(vec![], None)));
+      cache.entries.lock().unwrap().insert(
+        (TypeId::of::<Pod>(), scope),
+        WatchEntry {
+          store: Box::new(store),
+          handle: tokio::spawn(std::future::pending()),
+          last_used: Instant::now() - Duration::from_secs(age_secs),
+        },
+      );
+    };
+
+    insert(WatchScope::default(), 100);
+    for (ns, age) in [("a", 50), ("b", 40), ("c", 30), ("d", 20), ("e", 10)] {
+      insert(scope(ns), age);
+    }
+    // reading "a" makes "b" the least recently used
+    assert!(cache.snapshot::<Pod>(&scope("a")).is_some());
+    evict_scopes(&mut cache.entries.lock().unwrap(), TypeId::of::<Pod>());
+
+    assert!(cache.snapshot::<Pod>(&scope("b")).is_none());
+    for ns in ["a", "c", "d", "e"] {
+      assert!(cache.snapshot::<Pod>(&scope(ns)).is_some(), "{}", ns);
+    }
+    assert!(cache.snapshot::<Pod>(&WatchScope::default()).is_some());
+  }
+}
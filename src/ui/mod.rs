@@ -93,6 +93,9 @@ pub fn draw(f: &mut Frame<'_>, app: &mut App) {
   if app.modal.is_some() {
     draw_modal(f, app);
   }
+  if app.bulk_summary.is_some() {
+    draw_bulk_summary(f, app);
+  }
 
   // Toasts float over everything, bottom-centred: the persistent error toast
   // (dismissed with Esc) on the bottom row, the transient status toast above it.
@@ -113,6 +116,21 @@ fn draw_modal(f: &mut Frame<'_>, app: &App) {
     .into_iter()
     .map(|line| Line::from(line.into_owned()))
     .collect();
+  if !modal.targets.is_empty() {
+    // List every target the screen has room for; the rest are counted.
+    let room = (f.area().height as usize)
+      .saturating_sub(lines.len() + 7)
+      .max(1);
+    lines.push(Line::from(""));
+    lines.extend(capped_lines(
+      modal
+        .targets
+        .iter()
+        .map(|target| Line::from(format!("  • {}", target)))
+        .collect(),
+      room,
+    ));
+  }
   lines.push(Line::from(""));
   lines.push(mixed_line(
     [help_part(format!(
@@ -146,6 +164,84 @@ fn draw_modal(f: &mut Frame<'_>, app: &App) {
   f.render_widget(paragraph, area);
 }
 
+/// Keep the first `room` lines, replacing the overflow with a `… and N more`
+/// line.
+fn capped_lines(mut lines: Vec<Line<'static>>, room: usize) -> Vec<Line<'static>> {
+  if lines.len() > room {
+    let hidden = lines.len() - room + 1;
+    lines.truncate(room - 1);
+    lines.push(Line::from(format!("  … and {} more", hidden)));
+  }
+  lines
+}
+
+/// Per-target outcome of the last bulk action. Failures are listed first so
+/// they stay visible when the list doesn't fit.
+fn draw_bulk_summary(f: &mut Frame<'_>, app: &App) {
+  let palette = app.palette;
+  let Some(summary) = app.bulk_summary.as_ref() else {
+    return;
+  };
+
+  let width: u16 = 80;
+  let inner_width = width.saturating_sub(2).max(1) as usize;
+  let (failed, succeeded): (Vec<_>, Vec<_>) = summary
+    .results
+    .iter()
+    .partition(|(_, result)| result.is_err());
+  let results: Vec<Line<'static>> = failed
+    .into_iter()
+    .chain(succeeded)
+    .flat_map(|(target, result)| {
+      let (mark, detail, style) = match result {
+        Ok(status) => ("✓", status, style_success(palette)),
+        Err(error) => ("✗", error, style_failure(palette)),
+      };
+      textwrap::wrap(&format!("{} {}: {}", mark, target, detail), inner_width)
+        .into_iter()
+        .map(|line| Line::from(Span::styled(line.into_owned(), style)))
+        .collect::<Vec<_>>()
+    })
+    .collect();
+  let room = (f.area().height as usize).saturating_sub(6).max(1);
+  let mut lines = capped_lines(results, room);
+  lines.push(Line::from(""));
+  lines.push(mixed_line(
+    [help_part(format!(
+      "{}/{}:close ",
+      DEFAULT_KEYBINDING.esc.key.symbol(),
+      DEFAULT_KEYBINDING.submit.key.symbol(),
+    ))],
+    palette,
+  ));
+
+  let height = (lines.len() as u16).saturating_add(2);
+  let area = centered_rect(width, height, f.area());
+  let failures = summary.failed();
+  let title = format!(
+    " {} · {} ok, {} failed ",
+    summary.title,
+    summary.results.len() - failures,
+    failures
+  );
+  let border = if failures > 0 {
+    style_failure(palette)
+  } else {
+    style_success(palette)
+  };
+  let block = Block::default()
+    .title(mixed_bold_line([default_part(title)], palette))
+    .borders(Borders::ALL)
+    .style(border.bg(palette.bg));
+
+  let paragraph = Paragraph::new(lines)
+    .block(block)
+    .style(style_text(palette).bg(palette.bg));
+
+  f.render_widget(Clear, area);
+  f.render_widget(paragraph, area);
+}
+
 fn draw_input_modal(f: &mut Frame<'_>, app: &App) {
   let palette = app.palette;
   let Some(input) = app.input_modal.as_ref() else {
@@ -239,8 +335,9 @@ fn draw_action_menu(f: &mut Frame<'_>, app: &mut App) {
   draw_popup_menu(f, area, title, items, &mut menu.state, palette);
 }
 
-/// Active `kubectl port-forward` overlay: one row per forward with a
-/// status-coloured tag, navigable, stop with `d`/Enter.
+/// Active port-forwards overlay: per forward, a row with a status-coloured tag
+/// (and the backing pod for services) over a row of live traffic counters.
+/// Navigable, stop with `d`/Enter.
 fn draw_port_forwards(f: &mut Frame<'_>, app: &mut App) {
   let palette = app.palette;
   if app.port_forwards.is_empty() {
@@ -256,20 +353,38 @@ fn draw_port_forwards(f: &mut Frame<'_>, app: &mut App) {
         PortForwardStatus::Starting => style_warning(palette),
         PortForwardStatus::Failed(_) => style_failure(palette),
       };
-      ListItem::new(Line::from(vec![
-        Span::styled(
-          format!(
-            "{}/{} ({})  {} → {}  ",
-            pf.kind, pf.name, pf.namespace, pf.local_port, pf.remote_port
+      let backend = match &pf.pod {
+        Some(pod) if *pod != pf.name => format!(" → pod/{pod}"),
+        _ => String::new(),
+      };
+      let mut stats = vec![Span::styled(
+        format!("    {}", pf.stats.summary()),
+        style_secondary(palette),
+      )];
+      if let Some(error) = pf.stats.last_error() {
+        stats.push(Span::styled(format!("  · {error}"), style_warning(palette)));
+      }
+      ListItem::new(vec![
+        Line::from(vec![
+          Span::styled(
+            format!(
+              "{}/{}{} ({})  {} → {}  ",
+              pf.kind, pf.name, backend, pf.namespace, pf.local_port, pf.remote_port
+            ),
+            style_text(palette),
           ),
-          style_text(palette),
-        ),
-        Span::styled(format!("[{}]", pf.status.label()), status_style),
-      ]))
+          Span::styled(format!("[{}]", pf.status.label()), status_style),
+        ]),
+        Line::from(stats),
+      ])
     })
     .collect();
 
-  let area = centered_rect(70, (items.len() as u16).saturating_add(2), f.area());
+  let area = centered_rect(
+    70,
+    (items.len() as u16).saturating_mul(2).saturating_add(2),
+    f.area(),
+  );
   let title = title_with_dual_style(
     " Port-forwards ".to_string(),
     mixed_bold_line(
@@ -310,6 +425,9 @@ fn draw_app_title(f: &mut Frame<'_>, app: &App, area: Rect) {
       };
       left.push(Span::styled("● ", conn));
       left.push(Span::styled(ctx.name.clone(), fg));
+      if app.snapshot.is_some() {
+        left.push(Span::styled(" (read-only)", fg));
+      }
     }
     None => left.push(Span::styled("○ disconnected", fg)),
   }
@@ -318,6 +436,9 @@ fn draw_app_title(f: &mut Frame<'_>, app: &App, area: Rect) {
   let spinner = nw_loading_indicator(app.is_loading());
   if !spinner.is_empty() {
     left.push(Span::styled(format!("  {}", spinner), fg));
+    if let Some(progress) = &app.list_progress {
+      left.push(Span::styled(format!(" {}", progress.label()), fg));
+    }
   }
   f.render_widget(
     Paragraph::new(Line::from(left)).alignment(Alignment::Left),
@@ -597,6 +718,54 @@ mod tests {
     assert!(joined.contains("cancel"));
   }
 
+  #[test]
+  fn test_draw_modal_lists_bulk_targets_within_screen() {
+    let mut app = App::default();
+    app.route_home();
+    let targets = (0..40).map(|i| format!("default/job-{}", i)).collect();
+    app.open_modal(
+      crate::app::actions::Modal::confirm(
+        "Confirm delete",
+        "Delete 40 job(s)? This cannot be undone.",
+        crate::network::IoEvent::GetPods,
+      )
+      .with_targets(targets),
+    );
+
+    let lines = render_lines(&mut app, 120, 30);
+    let joined = lines.join("\n");
+
+    assert!(joined.contains("default/job-0"));
+    assert!(!joined.contains("default/job-39"));
+    assert!(joined.contains("more"));
+    assert!(joined.contains("confirm"));
+  }
+
+  #[test]
+  fn test_draw_bulk_summary_lists_failures_first() {
+    let mut app = App::default();
+    app.route_home();
+    app.bulk_summary = Some(crate::app::actions::BulkSummary {
+      title: "Delete results".into(),
+      results: vec![
+        ("default/job-a".into(), Ok("Deleting job-a".into())),
+        (
+          "default/job-b".into(),
+          Err("Failed to delete job-b. forbidden".into()),
+        ),
+      ],
+    });
+
+    let lines = render_lines(&mut app, 120, 30);
+    let joined = lines.join("\n");
+
+    assert!(joined.contains("1 ok, 1 failed"));
+    let failed = joined.find("✗ default/job-b").unwrap();
+    let ok = joined.find("✓ default/job-a").unwrap();
+    assert!(failed < ok);
+    assert!(joined.contains("close"));
+  }
+
   #[test]
   fn test_draw_input_modal_shows_prompt_buffer_and_hints() {
     use crate::app::actions::{InputAction, InputModal};
@@ -928,7 +1097,7 @@ mod tests {
       metadata: ListMeta::default(),
       items: vec![],
     };
-    KubeNode::from_api_with_pods(&Node::default(), &pods, &mut guard)
+    KubeNode::from_api_with_pods(&Node::default(), &pods.items, &mut guard)
   }
 
   fn seeded_pods() -> Vec<KubePod> {
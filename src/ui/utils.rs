@@ -12,7 +12,7 @@ use ratatui::{
 use super::HIGHLIGHT;
 use crate::app::{
   key_binding::DEFAULT_KEYBINDING,
-  models::{Named, StatefulTable},
+  models::{FilterableTable, ListSelector, Named, Sortable, StatefulTable, TableSort},
   ActiveBlock, App,
 };
 use crate::event::Key;
@@ -242,6 +242,18 @@ pub fn style_main_background(palette: Palette) -> Style {
 pub fn style_highlight() -> Style {
   Style::default().add_modifier(Modifier::REVERSED)
 }
+/// Occurrences of the log search query.
+pub fn style_search_match(palette: Palette) -> Style {
+  Style::default()
+    .fg(palette.warning)
+    .add_modifier(Modifier::REVERSED)
+}
+/// Table rows marked for a bulk action.
+pub fn style_marked(palette: Palette) -> Style {
+  Style::default()
+    .fg(palette.focus_border(true))
+    .add_modifier(Modifier::BOLD)
+}
 
 fn line_part_style(part: &LinePart<'_>, palette: Palette, bold: bool) -> Style {
   let style = match part {
@@ -750,6 +762,7 @@ macro_rules! draw_resource_tab {
       ActiveBlock::Pods => $crate::app::pods::draw_block_as_sub($f, $app, $area),
       ActiveBlock::Containers => $crate::app::pods::draw_containers_block($f, $app, $area),
       ActiveBlock::Logs => $crate::app::pods::draw_logs_block($f, $app, $area),
+      ActiveBlock::Shell => $crate::app::exec::draw_exec_block($f, $app, $area),
       ActiveBlock::Namespaces => $fn1($app.get_prev_route().active_block, $f, $app, $area),
       _ => $fn2($f, $app, $area),
     };
@@ -843,7 +856,7 @@ pub fn draw_yaml_block(f: &mut Frame<'_>, app: &mut App, area: Rect, title: Line
   }
 }
 
-fn draw_resource_table<'a, T: Named, F>(
+fn draw_resource_table<'a, T: Sortable, F>(
   f: &mut Frame<'_>,
   area: Rect,
   table_props: ResourceTableProps<'a, T>,
@@ -854,9 +867,14 @@ fn draw_resource_table<'a, T: Named, F>(
 ) where
   F: Fn(&T) -> Row<'a>,
 {
+  table_props
+    .resource
+    .set_sort_columns(&table_props.table_headers);
   if !table_props.resource.items.is_empty() {
+    // selector text (`l:` / `f:`) is applied server-side on Enter, not matched here
+    let has_filter = !table_props.resource.filter.is_empty()
+      && ListSelector::parse(&table_props.resource.filter).is_none();
     let filter = table_props.resource.filter.to_lowercase();
-    let has_filter = !filter.is_empty();
     let mut filtered_indices: Vec<usize> = Vec::new();
     let mut filtered_items: Vec<&T> = Vec::new();
     for (idx, item) in table_props.resource.items.iter().enumerate() {
@@ -889,20 +907,30 @@ fn draw_resource_table<'a, T: Named, F>(
     let visible_start = selected.saturating_sub(view_h);
     let visible_end = (selected + view_h * 2).min(filtered_items.len());
 
+    let marked = &table_props.resource.marked;
     let rows: Vec<Row<'a>> = filtered_items
       .iter()
       .enumerate()
       .map(|(fi, item)| {
         if fi >= visible_start && fi < visible_end {
-          row_cell_mapper(item)
+          let row = row_cell_mapper(item);
+          if !marked.is_empty() && marked.contains(&item.sort_id()) {
+            row.style(style_marked(palette))
+          } else {
+            row
+          }
         } else {
           Row::default()
         }
       })
       .collect();
 
+    let headers = sorted_headers(&table_props.table_headers, &table_props.resource.sort);
     let table = Table::new(rows, &table_props.column_widths)
-      .header(table_header_style(table_props.table_headers, palette))
+      .header(table_header_style(
+        headers.iter().map(String::as_str).collect(),
+        palette,
+      ))
       .block(block)
       .row_highlight_style(style_highlight())
       .highlight_symbol(HIGHLIGHT);
@@ -913,6 +941,39 @@ fn draw_resource_table<'a, T: Named, F>(
   }
 }
 
+/// Lead the title help with `[N marked]` while rows are marked for a bulk action.
+fn prepend_marked_count(help_line: &mut Line<'_>, marked: usize, palette: Palette) {
+  if marked == 0 {
+    return;
+  }
+  let mut spans = mixed_bold_line(
+    [
+      default_part(format!("[{} marked]", marked)),
+      help_part(format!(
+        " {}:unmark · ",
+        DEFAULT_KEYBINDING.esc.key.symbol()
+      )),
+    ],
+    palette,
+  )
+  .spans;
+  spans.append(&mut help_line.spans);
+  help_line.spans = spans;
+}
+
+/// Header labels with an arrow on the column the table is sorted by.
+fn sorted_headers(headers: &[&str], sort: &Option<TableSort>) -> Vec<String> {
+  headers
+    .iter()
+    .map(|header| match sort {
+      Some(sort) if sort.column == *header => {
+        format!("{} {}", header, if sort.descending { "↓" } else { "↑" })
+      }
+      _ => header.to_string(),
+    })
+    .collect()
+}
+
 /// Builds the help `Line` for a resource block title, weaving filter status
 /// into any existing inline help (placing it after a "containers" prefix when present).
 fn build_resource_help_line(
@@ -949,7 +1010,7 @@ fn build_resource_help_line(
 }
 
 /// Draw a kubernetes resource overview tab
-pub fn draw_resource_block<'a, T: Named, F>(
+pub fn draw_resource_block<'a, T: Sortable, F>(
   f: &mut Frame<'_>,
   area: Rect,
   table_props: ResourceTableProps<'a, T>,
@@ -995,7 +1056,20 @@ pub fn draw_resource_block<'a, T: Named, F>(
     return;
   }
 
-  let help_line = build_resource_help_line(inline_help, &filter, filter_active, palette);
+  let mut help_line = build_resource_help_line(inline_help, &filter, filter_active, palette);
+  prepend_marked_count(&mut help_line, resource.marked_count(), palette);
+  if let Some(selector) = &resource.selector {
+    let mut spans = mixed_bold_line(
+      [
+        default_part(format!("[{}]", selector.label())),
+        help_part(format!(" {}:clear · ", DEFAULT_KEYBINDING.esc.key.symbol())),
+      ],
+      palette,
+    )
+    .spans;
+    spans.append(&mut help_line.spans);
+    help_line.spans = spans;
+  }
   let title = title_with_dual_style(title, help_line, palette);
   let block = layout_block_top_border(title, palette);
   draw_resource_table(
@@ -1015,7 +1089,7 @@ pub fn draw_resource_block<'a, T: Named, F>(
   );
 }
 
-pub fn draw_route_resource_block<'a, T: Named, F>(
+pub fn draw_route_resource_block<'a, T: Sortable, F>(
   f: &mut Frame<'_>,
   area: Rect,
   table_props: ResourceTableProps<'a, T>,
@@ -1061,7 +1135,9 @@ pub fn draw_route_resource_block<'a, T: Named, F>(
     return;
   }
 
-  let title = title_with_dual_style(title, inline_help, palette);
+  let mut help_line = inline_help;
+  prepend_marked_count(&mut help_line, resource.marked_count(), palette);
+  let title = title_with_dual_style(title, help_line, palette);
   let block = layout_block_active_span(title, palette);
   draw_resource_table(
     f,
@@ -1154,7 +1230,26 @@ mod tests {
   };
 
   use super::*;
-  use crate::ui::theme::{palette_for, ThemeName};
+  use crate::{
+    app::models::SortValue,
+    ui::theme::{palette_for, ThemeName},
+  };
+
+  #[test]
+  fn test_sorted_headers_marks_sort_column() {
+    let sort = Some(TableSort {
+      column: "Age".into(),
+      descending: true,
+    });
+    assert_eq!(
+      sorted_headers(&["Name", "Age"], &sort),
+      vec!["Name".to_string(), "Age ↓".to_string()]
+    );
+    assert_eq!(
+      sorted_headers(&["Name", "Age"], &None),
+      vec!["Name".to_string(), "Age".to_string()]
+    );
+  }
 
   #[test]
   fn test_gauge_fill_style_tiers() {
@@ -1204,6 +1299,12 @@ mod tests {
         &self.name
       }
     }
+
+    impl Sortable for RenderTest {
+      fn sort_value(&self, _column: &str) -> SortValue {
+        SortValue::text(&self.name)
+      }
+    }
     terminal
       .draw(|f| {
         let size = f.area();
@@ -1338,6 +1439,12 @@ mod tests {
       }
     }
 
+    impl Sortable for RenderTest {
+      fn sort_value(&self, _column: &str) -> SortValue {
+        SortValue::text(&self.name)
+      }
+    }
+
     terminal
       .draw(|f| {
         let size = f.area();
@@ -1479,6 +1586,12 @@ mod tests {
       }
     }
 
+    impl Sortable for RenderTest {
+      fn sort_value(&self, _column: &str) -> SortValue {
+        SortValue::text(&self.name)
+      }
+    }
+
     terminal
       .draw(|f| {
         let size = f.area();
@@ -1627,6 +1740,12 @@ mod tests {
       }
     }
 
+    impl Sortable for RenderTest {
+      fn sort_value(&self, _column: &str) -> SortValue {
+        SortValue::text(&self.name)
+      }
+    }
+
     terminal
       .draw(|f| {
         let size = f.area();